@@ -121,6 +121,23 @@ async fn run(relative_path: &str, #[cfg(feature = "tracing")] layer: Layer) {
     println!("{:?}", start.elapsed());
   }
 
+  #[cfg(feature = "watch")]
+  {
+    // watch the fixture and print the time of each rebuild
+    let mut start = Instant::now();
+    compiler
+      .watch(Default::default(), |result| {
+        if let Err(e) = result {
+          println!("{e:?}");
+        }
+        println!("{:?}", start.elapsed());
+        start = Instant::now();
+        true
+      })
+      .await
+      .expect("TODO:");
+  }
+
   #[cfg(feature = "tracing")]
   {
     if let Some(guard) = guard {
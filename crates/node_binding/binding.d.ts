@@ -52,6 +52,11 @@ export class JsStats {
 export class Rspack {
   constructor(options: RawOptions, jsHooks: JsHooks | undefined | null, outputFilesystem: ThreadsafeNodeFS, jsLoaderRunner: (...args: any[]) => any)
   unsafe_set_disabled_hooks(hooks: Array<string>): void
+  /**
+   * Set the values of the runtime definitions of `builtins.define`, they are used by the next
+   * build or rebuild.
+   */
+  unsafe_set_runtime_define(define: Record<string, string>): void
   /**
    * Build with the given option passed to the constructor
    *
@@ -353,6 +358,8 @@ export interface JsStatsModule {
   issuerPath: Array<JsStatsModuleIssuer>
   reasons?: Array<JsStatsModuleReason>
   assets?: Array<string>
+  modules?: Array<JsStatsModule>
+  optimizationBailout: Array<string>
   source?: string | Buffer
 }
 
@@ -464,6 +471,7 @@ export interface RawBuiltins {
   minifyOptions?: RawMinification
   presetEnv?: RawPresetEnv
   define: Record<string, string>
+  importMetaEnv?: Record<string, string>
   provide: Record<string, string[]>
   treeShaking: string
   progress?: RawProgressPluginConfig
@@ -477,6 +485,10 @@ export interface RawBuiltins {
   pluginImport?: Array<RawPluginImportConfig>
   relay?: RawRelayConfig
   codeGeneration?: RawCodeGeneration
+  container?: RawContainerPluginOptions
+  containerReference?: RawContainerReferencePluginOptions
+  provideShared?: RawProvideSharedPluginOptions
+  consumeShared?: RawConsumeSharedPluginOptions
 }
 
 export interface RawCacheGroupOptions {
@@ -512,6 +524,36 @@ export interface RawCodeGeneration {
   keepComments: boolean
 }
 
+export interface RawConsumeOptions {
+  key: string
+  import?: string
+  shareScope: string
+  shareKey: string
+  requiredVersion?: string | false
+  packageName?: string
+  strictVersion: boolean
+  singleton: boolean
+  eager: boolean
+}
+
+export interface RawConsumeSharedPluginOptions {
+  consumes: Array<RawConsumeOptions>
+}
+
+export interface RawContainerPluginOptions {
+  name: string
+  shareScope: string
+  library: RawLibraryOptions
+  runtime?: string
+  filename?: string
+  exposes: Array<RawExposeOptions>
+}
+
+export interface RawContainerReferencePluginOptions {
+  remoteType: string
+  remotes: Array<RawRemoteOptions>
+}
+
 export interface RawCopyConfig {
   patterns: Array<RawPattern>
 }
@@ -555,10 +597,17 @@ export interface RawExperiments {
   lazyCompilation: boolean
   incrementalRebuild: RawIncrementalRebuild
   asyncWebAssembly: boolean
+  syncWebAssembly: boolean
   newSplitChunks: boolean
   css: boolean
 }
 
+export interface RawExposeOptions {
+  key: string
+  name?: string
+  import: Array<string>
+}
+
 export interface RawExternalItem {
   type: "string" | "regexp" | "object" | "function"
   stringPayload?: string
@@ -579,10 +628,11 @@ export interface RawExternalItemFnResult {
 }
 
 export interface RawExternalItemValue {
-  type: "string" | "bool" | "array"
+  type: "string" | "bool" | "array" | "object"
   stringPayload?: string
   boolPayload?: boolean
   arrayPayload?: Array<string>
+  objectPayload?: Record<string, Array<string>>
 }
 
 export interface RawExternalsPresets {
@@ -643,6 +693,11 @@ export interface RawHtmlPluginConfig {
   title?: string
   favicon?: string
   meta?: Record<string, Record<string, string>>
+  /**
+   * emit preload links for the async chunks preloaded by the included entries
+   * with `webpackPreload`
+   */
+  preload?: boolean
 }
 
 export interface RawIncrementalRebuild {
@@ -650,6 +705,10 @@ export interface RawIncrementalRebuild {
   emitAsset: boolean
 }
 
+export interface RawJavascriptParserOptions {
+  amd?: boolean
+}
+
 export interface RawLibraryAuxiliaryComment {
   root?: string
   commonjs?: string
@@ -711,6 +770,7 @@ export interface RawModuleRule {
   resourceQuery?: RawRuleSetCondition
   resourceFragment?: RawRuleSetCondition
   descriptionData?: Record<string, RawRuleSetCondition>
+  with?: Record<string, RawRuleSetCondition>
   sideEffects?: boolean
   use?: RawModuleRuleUses
   type?: string
@@ -763,6 +823,7 @@ export interface RawOptimizationOptions {
   removeEmptyChunks: boolean
   sideEffects: string
   realContentHash: boolean
+  concatenateModules: boolean
 }
 
 export interface RawOptions {
@@ -791,6 +852,7 @@ export interface RawOptions {
   cache: RawCacheOptions
   experiments: RawExperiments
   node?: RawNodeOption
+  recordsPath?: string
 }
 
 export interface RawOutputOptions {
@@ -833,8 +895,9 @@ export interface RawOutputOptions {
 }
 
 export interface RawParserOptions {
-  type: "asset" | "unknown"
+  type: "asset" | "javascript" | "unknown"
   asset?: RawAssetParserOptions
+  javascript?: RawJavascriptParserOptions
 }
 
 export interface RawPattern {
@@ -874,6 +937,18 @@ export interface RawProgressPluginConfig {
   prefix?: string
 }
 
+export interface RawProvideOptions {
+  key: string
+  shareKey: string
+  shareScope: string
+  version?: string | false
+  eager: boolean
+}
+
+export interface RawProvideSharedPluginOptions {
+  provides: Array<RawProvideOptions>
+}
+
 export interface RawPxToRemConfig {
   rootValue?: number
   unitPrecision?: number
@@ -901,6 +976,12 @@ export interface RawRelayConfig {
   language: 'javascript' | 'typescript' | 'flow'
 }
 
+export interface RawRemoteOptions {
+  key: string
+  external: Array<string>
+  shareScope: string
+}
+
 export interface RawResolveOptions {
   preferRelative?: boolean
   extensions?: Array<string>
@@ -937,6 +1018,8 @@ export interface RawRuleSetLogicalConditions {
 export interface RawSnapshotOptions {
   resolve: RawSnapshotStrategy
   module: RawSnapshotStrategy
+  immutablePaths?: Array<string>
+  managedPaths?: Array<string>
 }
 
 export interface RawSnapshotStrategy {
@@ -96,6 +96,8 @@ pub struct JsStatsModule {
   pub issuer_path: Vec<JsStatsModuleIssuer>,
   pub reasons: Option<Vec<JsStatsModuleReason>>,
   pub assets: Option<Vec<String>>,
+  pub modules: Option<Vec<JsStatsModule>>,
+  pub optimization_bailout: Vec<String>,
   pub source: Option<Either<String, Buffer>>,
 }
 
@@ -133,6 +135,11 @@ impl TryFrom<rspack_core::StatsModule<'_>> for JsStatsModule {
         .reasons
         .map(|i| i.into_iter().map(Into::into).collect()),
       assets: stats.assets,
+      modules: stats
+        .modules
+        .map(|i| i.into_iter().map(|m| m.try_into()).collect::<Result<_>>())
+        .transpose()?,
+      optimization_bailout: stats.optimization_bailout,
       source,
     })
   }
@@ -194,6 +194,24 @@ impl Rspack {
     Ok(())
   }
 
+  /// Set the values of the runtime definitions of `builtins.define`, they are used by the next
+  /// build or rebuild.
+  #[napi(
+    catch_unwind,
+    js_name = "unsafe_set_runtime_define",
+    ts_args_type = "define: Record<string, string>"
+  )]
+  pub fn set_runtime_define(
+    &self,
+    define: std::collections::HashMap<String, String>,
+  ) -> Result<()> {
+    let handle_set_runtime_define = |compiler: &Pin<Box<rspack_core::Compiler<_>>>| {
+      compiler.options.builtins.runtime_define.set(define);
+      Ok(())
+    };
+    unsafe { COMPILERS.borrow(&self.id, handle_set_runtime_define) }
+  }
+
   /// Build with the given option passed to the constructor
   ///
   /// Warning:
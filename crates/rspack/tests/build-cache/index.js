@@ -0,0 +1,6 @@
+import png from "./file.png";
+import external from "external";
+
+const locale = require.context("./locale", false, /\.js$/);
+
+console.log(png, external, locale("./en.js"));
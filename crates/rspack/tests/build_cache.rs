@@ -0,0 +1,194 @@
+use std::{
+  collections::HashSet,
+  path::{Path, PathBuf},
+  sync::{Arc, Mutex},
+};
+
+use async_trait::async_trait;
+use rspack_core::{
+  CacheOptions, Compiler, ExternalItem, ExternalItemValue, FileSystemCacheOptions,
+  MemoryCacheOptions, Module, Plugin, PluginExt, SnapshotStrategy,
+};
+use rspack_error::Result;
+use rspack_fs::AsyncNativeFileSystem;
+use rspack_plugin_externals::ExternalPlugin;
+use rspack_testing::{apply_from_fixture, TempFixture};
+
+/// Records the modules whose build results are served by the cache.
+#[derive(Debug, Default)]
+struct StillValidModulesPlugin {
+  modules: Arc<Mutex<Vec<String>>>,
+}
+
+#[async_trait]
+impl Plugin for StillValidModulesPlugin {
+  fn name(&self) -> &'static str {
+    "StillValidModulesPlugin"
+  }
+
+  async fn still_valid_module(&self, module: &dyn Module) -> Result<()> {
+    self
+      .modules
+      .lock()
+      .expect("should lock")
+      .push(module.identifier().to_string());
+    Ok(())
+  }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn should_serve_unchanged_modules_of_every_type_from_cache_in_rebuild() {
+  let fixture =
+    TempFixture::new(&PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/build-cache"));
+  let dir = fixture.path();
+  let (mut options, mut plugins) = apply_from_fixture(dir);
+  options.cache = CacheOptions::Memory(MemoryCacheOptions::default());
+  options.snapshot.module = SnapshotStrategy {
+    hash: false,
+    timestamp: true,
+  };
+  let still_valid_modules = Arc::new(Mutex::new(vec![]));
+  plugins.push(
+    StillValidModulesPlugin {
+      modules: still_valid_modules.clone(),
+    }
+    .boxed(),
+  );
+  plugins.push(
+    ExternalPlugin::new(
+      "var".to_string(),
+      vec![ExternalItem::Object(
+        [(
+          "external".to_string(),
+          ExternalItemValue::String("external".to_string()),
+        )]
+        .into_iter()
+        .collect(),
+      )],
+    )
+    .boxed(),
+  );
+  let mut compiler = Compiler::new(options, plugins, AsyncNativeFileSystem);
+  compiler.build().await.expect("should build");
+  assert!(still_valid_modules.lock().expect("should lock").is_empty());
+
+  let index = dir.join("index.js");
+  let content = std::fs::read_to_string(&index).expect("should read file");
+  std::fs::write(&index, format!("{content}console.log(\"changed\");\n"))
+    .expect("should write file");
+  compiler
+    .rebuild(
+      HashSet::from([index.to_string_lossy().to_string()]),
+      HashSet::default(),
+    )
+    .await
+    .expect("should rebuild");
+  assert!(
+    compiler.compilation.get_errors().next().is_none(),
+    "should rebuild without errors"
+  );
+
+  let still_valid_modules = still_valid_modules.lock().expect("should lock");
+  let is_still_valid = |pattern: &str| {
+    still_valid_modules
+      .iter()
+      .any(|module| module.contains(pattern))
+  };
+  assert!(!is_still_valid("index.js"));
+  assert!(is_still_valid("file.png"));
+  assert!(is_still_valid("external"));
+  assert!(is_still_valid("locale/en.js"));
+  assert!(is_still_valid("locale|"));
+  // the asset module isn't built in rebuild, its code generation result is served by the cache
+  assert!(compiler
+    .compilation
+    .assets()
+    .keys()
+    .any(|filename| filename.ends_with(".png")));
+}
+
+/// Builds with a new compiler restoring the filesystem cache, returns the emitted `main.js`.
+async fn build_with_runtime_value(dir: &Path, build_id: &str) -> String {
+  let (mut options, plugins) = apply_from_fixture(dir);
+  options.cache = CacheOptions::FileSystem(FileSystemCacheOptions::default());
+  options.builtins.runtime_define.set(
+    [("BUILD_ID".to_string(), format!("\"{build_id}\""))]
+      .into_iter()
+      .collect(),
+  );
+  let mut compiler = Compiler::new(options, plugins, AsyncNativeFileSystem);
+  compiler.build().await.expect("should build");
+  std::fs::read_to_string(dir.join("dist/main.js")).expect("should emit main.js")
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn should_rebuild_modules_using_changed_runtime_values_after_restart() {
+  let fixture =
+    TempFixture::new(&PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/runtime-define"));
+  let dir = fixture.path();
+  assert!(build_with_runtime_value(dir, "first")
+    .await
+    .contains("console.log(\"first\")"));
+  assert!(build_with_runtime_value(dir, "second")
+    .await
+    .contains("console.log(\"second\")"));
+}
+
+/// Builds to `output` with a new compiler, returns the emitted `main.js` and the modules served by the cache.
+async fn build_with_filesystem_cache(
+  dir: &Path,
+  output: &str,
+  cache: CacheOptions,
+) -> (String, Vec<String>) {
+  let (mut options, mut plugins) = apply_from_fixture(dir);
+  options.output.path = dir.join(output);
+  options.cache = cache;
+  let still_valid_modules = Arc::new(Mutex::new(vec![]));
+  plugins.push(
+    StillValidModulesPlugin {
+      modules: still_valid_modules.clone(),
+    }
+    .boxed(),
+  );
+  let mut compiler = Compiler::new(options, plugins, AsyncNativeFileSystem);
+  compiler.build().await.expect("should build");
+  assert!(
+    compiler.compilation.get_errors().next().is_none(),
+    "should build without errors"
+  );
+  let main =
+    std::fs::read_to_string(dir.join(output).join("main.js")).expect("should emit main.js");
+  let still_valid_modules = still_valid_modules.lock().expect("should lock").clone();
+  (main, still_valid_modules)
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn should_restore_modules_with_tree_shaking_and_concatenation_after_restart() {
+  let fixture = TempFixture::new(
+    &PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/concatenate-modules-tree-shaking"),
+  );
+  let dir = fixture.path();
+  let cache = CacheOptions::FileSystem(FileSystemCacheOptions::default());
+  let (first, _) = build_with_filesystem_cache(dir, "first", cache.clone()).await;
+
+  let (second, still_valid_modules) =
+    build_with_filesystem_cache(dir, "second", cache.clone()).await;
+  assert_eq!(first, second);
+  for module in ["a.js", "b.js", "c.js", "index.js"] {
+    assert!(
+      still_valid_modules.iter().any(|id| id.ends_with(module)),
+      "{module} should be restored: {still_valid_modules:?}"
+    );
+  }
+
+  // the restored modules concatenated with the changed one are built again
+  std::fs::write(
+    dir.join("b.js"),
+    "const a = \"changed\";\nexport { a as b };\n",
+  )
+  .expect("should write file");
+  let (third, _) = build_with_filesystem_cache(dir, "third", cache).await;
+  let (cold, _) = build_with_filesystem_cache(dir, "cold", CacheOptions::Disabled).await;
+  assert!(third.contains("\"changed\""), "{third}");
+  assert_eq!(third, cold);
+}
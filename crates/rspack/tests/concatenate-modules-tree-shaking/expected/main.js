@@ -0,0 +1,30 @@
+(self['webpackChunkwebpack'] = self['webpackChunkwebpack'] || []).push([["main"], {
+"./c.js": function (__unused_webpack_module, __webpack_exports__, __webpack_require__) {
+'use strict';
+__webpack_require__.r(__webpack_exports__);
+__webpack_require__.d(__webpack_exports__, {
+  'c': function() { return c; }
+});
+ const c = "c";
+},
+"./index.js": function (__unused_webpack_module, __webpack_exports__, __webpack_require__) {
+"use strict";
+__webpack_require__.r(__webpack_exports__);
+
+// CONCATENATED MODULE: ./a.js
+const a = "a";
+
+// CONCATENATED MODULE: ./b.js
+const a_b = "b";
+
+// CONCATENATED MODULE: ./index.js
+/* harmony import */ var _c__WEBPACK_IMPORTED_MODULE_0_ = __webpack_require__(/* ./c */ "./c.js");
+console.log(a, a_b, _c__WEBPACK_IMPORTED_MODULE_0_["c"], __webpack_require__(/* ./c */ "./c.js"));
+},
+
+},function(__webpack_require__) {
+var __webpack_exec__ = function(moduleId) { return __webpack_require__(__webpack_require__.s = moduleId) }
+var __webpack_exports__ = (__webpack_exec__("./index.js"));
+
+}
+]);
\ No newline at end of file
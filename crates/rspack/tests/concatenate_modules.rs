@@ -0,0 +1,79 @@
+use std::{
+  collections::HashSet,
+  path::{Path, PathBuf},
+};
+
+use rspack_core::{CacheOptions, Compiler, MemoryCacheOptions, NormalModuleAstOrSource};
+use rspack_fs::AsyncNativeFileSystem;
+use rspack_testing::{apply_from_fixture, TempFixture};
+
+fn compiler(dir: &Path, output: &str) -> Compiler<AsyncNativeFileSystem> {
+  let (mut options, plugins) = apply_from_fixture(dir);
+  options.output.path = dir.join(output);
+  options.cache = CacheOptions::Memory(MemoryCacheOptions::default());
+  options.experiments.incremental_rebuild.make = Some(Default::default());
+  Compiler::new(options, plugins, AsyncNativeFileSystem)
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn should_concatenate_unchanged_modules_in_rebuild() {
+  let fixture =
+    TempFixture::new(&PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/concatenate-modules"));
+  let dir = fixture.path();
+  let mut watching = compiler(dir, "watch");
+  watching.build().await.expect("should build");
+
+  std::fs::write(dir.join("b.js"), "export const b = \"changed\";\n").expect("should write file");
+  watching
+    .rebuild(
+      HashSet::from([dir.join("b.js").to_string_lossy().to_string()]),
+      HashSet::default(),
+    )
+    .await
+    .expect("should rebuild");
+  let rebuilt = std::fs::read_to_string(dir.join("watch/main.js")).expect("should emit main.js");
+  assert!(rebuilt.contains("// CONCATENATED MODULE: ./a.js"));
+  assert!(rebuilt.contains("\"changed\""));
+
+  compiler(dir, "cold").build().await.expect("should build");
+  let cold = std::fs::read_to_string(dir.join("cold/main.js")).expect("should emit main.js");
+  assert_eq!(rebuilt, cold);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn should_drop_ast_in_rebuild_with_hot() {
+  let fixture =
+    TempFixture::new(&PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/concatenate-modules"));
+  let dir = fixture.path();
+  let (mut options, plugins) = apply_from_fixture(dir);
+  options.output.path = dir.join("dist");
+  options.cache = CacheOptions::Memory(MemoryCacheOptions::default());
+  options.experiments.incremental_rebuild.make = Some(Default::default());
+  // concatenated modules can't be hot updated, the plugin doesn't run
+  options.dev_server.hot = true;
+  let mut compiler = Compiler::new(options, plugins, AsyncNativeFileSystem);
+  compiler.build().await.expect("should build");
+
+  std::fs::write(dir.join("b.js"), "export const b = \"changed\";\n").expect("should write file");
+  compiler
+    .rebuild(
+      HashSet::from([dir.join("b.js").to_string_lossy().to_string()]),
+      HashSet::default(),
+    )
+    .await
+    .expect("should rebuild");
+  let a = compiler
+    .compilation
+    .module_graph
+    .modules()
+    .values()
+    .filter_map(|module| module.as_normal_module())
+    .find(|module| module.request().ends_with("a.js"))
+    .expect("should have a.js");
+  assert!(matches!(
+    a.ast_or_source(),
+    NormalModuleAstOrSource::Unbuild
+  ));
+  let main = std::fs::read_to_string(dir.join("dist/main.js")).expect("should emit main.js");
+  assert!(!main.contains("// CONCATENATED MODULE:"));
+}
@@ -0,0 +1,72 @@
+use std::path::PathBuf;
+
+use rspack_core::{BoxPlugin, LibraryName, LibraryOptions, PluginExt};
+use rspack_plugin_mf::{
+  ContainerPlugin, ContainerPluginOptions, ContainerReferencePlugin,
+  ContainerReferencePluginOptions, ExposeOptions, RemoteOptions,
+};
+use rspack_testing::test_fixture_with_plugins;
+use testing_macros::fixture;
+
+/// The container of the fixture exposes `./test`, which is loaded by the fixture through a remote
+/// falling back to the container after two missing ones.
+fn container_plugins() -> Vec<BoxPlugin> {
+  vec![
+    ContainerPlugin::new(ContainerPluginOptions {
+      name: "container".to_string(),
+      share_scope: "default".to_string(),
+      library: LibraryOptions {
+        name: Some(LibraryName {
+          amd: None,
+          commonjs: None,
+          root: Some(vec!["container".to_string()]),
+        }),
+        export: None,
+        library_type: "self".to_string(),
+        umd_named_define: None,
+        auxiliary_comment: None,
+      },
+      runtime: None,
+      filename: None,
+      exposes: vec![(
+        "./test".to_string(),
+        ExposeOptions {
+          name: None,
+          import: vec!["./test.js".to_string()],
+        },
+      )],
+    })
+    .boxed(),
+    ContainerReferencePlugin::new(ContainerReferencePluginOptions {
+      remote_type: "var".to_string(),
+      remotes: vec![(
+        "remote".to_string(),
+        RemoteOptions {
+          external: vec![
+            "missing.first".to_string(),
+            "missing.second".to_string(),
+            "container".to_string(),
+          ],
+          share_scope: "default".to_string(),
+        },
+      )],
+    })
+    .boxed(),
+    rspack_plugin_ensure_chunk_conditions::EnsureChunkConditionsPlugin.boxed(),
+    rspack_plugin_library::AssignLibraryPlugin::new(
+      rspack_plugin_library::AssignLibraryPluginOptions {
+        library_type: "self".to_string(),
+        prefix: vec!["self".to_string()],
+        declare: false,
+        unnamed: rspack_plugin_library::Unnamed::Copy,
+        named: None,
+      },
+    )
+    .boxed(),
+  ]
+}
+
+#[fixture("tests/container")]
+fn container(fixture_path: PathBuf) {
+  test_fixture_with_plugins(&fixture_path, container_plugins());
+}
@@ -0,0 +1,487 @@
+(function() {
+var __webpack_modules__ = {
+"webpack/container/entry/container": function (__unused_webpack_module, exports, __webpack_require__) {
+var moduleMap = {
+"./test": function() {
+return Promise.all([__webpack_require__.el("./test.js")]).then(function() { return function() { return (__webpack_require__(/* ./test.js */"./test.js")); }; });
+}
+};
+var get = function(module, getScope) {
+  __webpack_require__.R = getScope;
+  getScope = (
+    __webpack_require__.o(moduleMap, module)
+      ? moduleMap[module]()
+      : Promise.resolve().then(function() {
+        throw new Error('Module "' + module + '" does not exist in container.');
+      })
+  );
+  __webpack_require__.R = undefined;
+  return getScope;
+};
+var init = function(shareScope, initScope) {
+  if (!__webpack_require__.S) return;
+  var name = "default";
+  var oldScope = __webpack_require__.S[name];
+  if (oldScope && oldScope !== shareScope) throw new Error("Container initialization failed as it has already been initialized with a different share scope");
+  __webpack_require__.S[name] = shareScope;
+  return __webpack_require__.I(name, initScope);
+};
+
+// This exports getters to disallow modifications
+__webpack_require__.d(exports, {
+  get: function() { return get; },
+  init: function() { return init; }
+});},
+
+}
+// The module cache
+ var __webpack_module_cache__ = {};
+function __webpack_require__(moduleId) {
+// Check if module is in cache
+        var cachedModule = __webpack_module_cache__[moduleId];
+        if (cachedModule !== undefined) {
+      return cachedModule.exports;
+      }
+      // Create a new module (and put it into the cache)
+      var module = (__webpack_module_cache__[moduleId] = {
+       exports: {}
+      });
+      // Execute the module function
+      __webpack_modules__[moduleId](module, module.exports, __webpack_require__);
+// Return the exports of the module
+ return module.exports;
+
+}
+// expose the modules object (__webpack_modules__)
+ __webpack_require__.m = __webpack_modules__;
+// webpack/runtime/load_chunk_with_module
+(function() {
+var map = {"./test.js": ["test_js",],"webpack/container/remote/remote/test": [],};
+
+__webpack_require__.el = function(module) {
+  var chunkId = map[module];
+  if (chunkId === undefined) {
+      return Promise.resolve();
+  }
+  if (chunkId.length > 1) {
+    return Promise.all(chunkId.map(__webpack_require__.e));
+  } else {
+    return __webpack_require__.e(chunkId[0]);
+  };
+}
+
+})();
+// webpack/runtime/ensure_chunk
+(function() {
+__webpack_require__.f = {};
+// This file contains only the entry chunk.
+// The chunk loading function for additional chunks
+__webpack_require__.e = function (chunkId) {
+	return Promise.all(
+		Object.keys(__webpack_require__.f).reduce(function (promises, key) {
+			__webpack_require__.f[key](chunkId, promises);
+			return promises;
+		}, [])
+	);
+};
+
+})();
+// webpack/runtime/public_path
+(function() {
+__webpack_require__.p = "/";
+
+})();
+// webpack/runtime/get_chunk_filename/__webpack_require__.u
+(function() {
+// This function allow to reference chunks
+        __webpack_require__.u = function (chunkId) {
+          // return url for filenames based on template
+          return {"test_js": "test_js.js",}[chunkId];
+        };
+      
+})();
+// webpack/runtime/get_chunk_filename/__webpack_require__.k
+(function() {
+// This function allow to reference chunks
+        __webpack_require__.k = function (chunkId) {
+          // return url for filenames based on template
+          return {"test_js": "test_js.css",}[chunkId];
+        };
+      
+})();
+// webpack/runtime/load_script
+(function() {
+var inProgress = {};
+
+// var dataWebpackPrefix = "webpack:";
+// loadScript function to load a script via script tag
+__webpack_require__.l = function loadScript(url, done, key, chunkId) {
+	if (inProgress[url]) {
+		inProgress[url].push(done);
+		return;
+	}
+	var script, needAttach;
+	if (key !== undefined) {
+		var scripts = document.getElementsByTagName("script");
+		for (var i = 0; i < scripts.length; i++) {
+			var s = scripts[i];
+			if (
+				s.getAttribute("src") == url
+				// || s.getAttribute("data-webpack") == dataWebpackPrefix + key
+			) {
+				script = s;
+				break;
+			}
+		}
+	}
+	if (!script) {
+		needAttach = true;
+		script = document.createElement("script");
+
+		script.charset = "utf-8";
+		script.timeout = 120;
+		// script.setAttribute("data-webpack", dataWebpackPrefix + key);
+		script.src = url;
+
+		if (false && script.src.indexOf(window.location.origin + '/') !== 0) {
+			script.crossOrigin = false;
+		}
+	}
+	inProgress[url] = [done];
+	var onScriptComplete = function (prev, event) {
+		script.onerror = script.onload = null;
+		clearTimeout(timeout);
+		var doneFns = inProgress[url];
+		delete inProgress[url];
+		script.parentNode && script.parentNode.removeChild(script);
+		doneFns &&
+			doneFns.forEach(function (fn) {
+				return fn(event);
+			});
+		if (prev) return prev(event);
+	};
+	var timeout = setTimeout(
+		onScriptComplete.bind(null, undefined, {
+			type: "timeout",
+			target: script
+		}),
+		120000
+	);
+	script.onerror = onScriptComplete.bind(null, script.onerror);
+	script.onload = onScriptComplete.bind(null, script.onload);
+	needAttach && document.head.appendChild(script);
+};
+
+})();
+// webpack/runtime/has_own_property
+(function() {
+__webpack_require__.o = function (obj, prop) {
+	return Object.prototype.hasOwnProperty.call(obj, prop);
+};
+
+})();
+// webpack/runtime/define_property_getters
+(function() {
+__webpack_require__.d = function(exports, definition) {
+	for(var key in definition) {
+        if(__webpack_require__.o(definition, key) && !__webpack_require__.o(exports, key)) {
+            Object.defineProperty(exports, key, { enumerable: true, get: definition[key] });
+        }
+    }
+};
+})();
+// webpack/runtime/make_namespace_object
+(function() {
+// define __esModule on exports
+__webpack_require__.r = function(exports) {
+	if(typeof Symbol !== 'undefined' && Symbol.toStringTag) {
+		Object.defineProperty(exports, Symbol.toStringTag, { value: 'Module' });
+	}
+	Object.defineProperty(exports, '__esModule', { value: true });
+};
+
+})();
+// webpack/runtime/sharing
+(function() {
+__webpack_require__.S = {};
+var initPromises = {};
+var initTokens = {};
+__webpack_require__.I = function (name, initScope) {
+	if (!initScope) initScope = [];
+	// handling circular init calls
+	var initToken = initTokens[name];
+	if (!initToken) initToken = initTokens[name] = {};
+	if (initScope.indexOf(initToken) >= 0) return;
+	initScope.push(initToken);
+	// only runs once
+	if (initPromises[name]) return initPromises[name];
+	// creates a new share scope if needed
+	if (!__webpack_require__.o(__webpack_require__.S, name))
+		__webpack_require__.S[name] = {};
+	// runs all init snippets from all modules reachable
+	var scope = __webpack_require__.S[name];
+	var warn = function (msg) {
+		if (typeof console !== "undefined" && console.warn) console.warn(msg);
+	};
+	var uniqueName = "__rspack_test__";
+	var register = function (name, version, factory, eager) {
+		var versions = (scope[name] = scope[name] || {});
+		var activeVersion = versions[version];
+		if (
+			!activeVersion ||
+			(!activeVersion.loaded &&
+				(!eager != !activeVersion.eager
+					? eager
+					: uniqueName > activeVersion.from))
+		)
+			versions[version] = { get: factory, from: uniqueName, eager: !!eager };
+	};
+	var initExternal = function (id) {
+		var handleError = function (err) {
+			warn("Initialization of sharing external failed: " + err);
+		};
+		try {
+			var module = __webpack_require__(id);
+			if (!module) return;
+			var initFn = function (module) {
+				return (
+					module &&
+					module.init &&
+					module.init(__webpack_require__.S[name], initScope)
+				);
+			};
+			if (module.then) return promises.push(module.then(initFn, handleError));
+			var initResult = initFn(module);
+			if (initResult && initResult.then)
+				return promises.push(initResult["catch"](handleError));
+		} catch (err) {
+			handleError(err);
+		}
+	};
+	var promises = [];
+	switch (name) {
+}
+	if (!promises.length) return (initPromises[name] = 1);
+	return (initPromises[name] = Promise.all(promises).then(function () {
+		return (initPromises[name] = 1);
+	}));
+};
+
+})();
+// webpack/runtime/css_loading
+(function() {
+var installedChunks = {};
+var uniqueName = "webpack";
+// loadCssChunkData is unnecessary
+var loadingAttribute = "data-webpack-loading";
+var loadStylesheet = function(chunkId, url, done, hmr) {
+	var link,
+		needAttach,
+		key = "chunk-" + chunkId;
+	if (!hmr) {
+		var links = document.getElementsByTagName("link");
+		for (var i = 0; i < links.length; i++) {
+			var l = links[i];
+			var href = l.getAttribute("href") || l.href;
+			if (href && !href.startsWith(__webpack_require__.p)) {
+				href =
+					__webpack_require__.p + (href.startsWith("/") ? href.slice(1) : href);
+			}
+			if (
+				l.rel == "stylesheet" &&
+				((href && href.startsWith(url)) ||
+					l.getAttribute("data-webpack") == uniqueName + ":" + key)
+			) {
+				link = l;
+				break;
+			}
+		}
+		if (!done) return link;
+	}
+	if (!link) {
+		needAttach = true;
+		link = document.createElement("link");
+		link.setAttribute("data-webpack", uniqueName + ":" + key);
+		link.setAttribute(loadingAttribute, 1);
+		link.rel = "stylesheet";
+		link.href = url;
+
+		if (false && link.href.indexOf(window.location.origin + '/') !== 0) {
+			link.crossOrigin = false;
+		}
+	}
+	var onLinkComplete = function (prev, event) {
+		link.onerror = link.onload = null;
+		link.removeAttribute(loadingAttribute);
+		clearTimeout(timeout);
+		if (event && event.type != "load") link.parentNode.removeChild(link);
+		done(event);
+		if (prev) return prev(event);
+	};
+	if (link.getAttribute(loadingAttribute)) {
+		var timeout = setTimeout(
+			onLinkComplete.bind(null, undefined, { type: "timeout", target: link }),
+			120000
+		);
+		link.onerror = onLinkComplete.bind(null, link.onerror);
+		link.onload = onLinkComplete.bind(null, link.onload);
+	} else onLinkComplete(undefined, { type: "load", target: link });
+	hmr
+		? hmr.parentNode.insertBefore(link, hmr)
+		: needAttach && document.head.appendChild(link);
+	return link;
+};
+__webpack_require__.f.css = function (chunkId, promises) {
+	// css chunk loading
+	var installedChunkData = __webpack_require__.o(installedChunks, chunkId)
+		? installedChunks[chunkId]
+		: undefined;
+	if (installedChunkData !== 0) {
+		// 0 means "already installed".
+
+		// a Promise means "currently loading".
+		if (installedChunkData) {
+			promises.push(installedChunkData[2]);
+		} else {
+			if ([].indexOf(chunkId) > -1) {
+				// setup Promise in chunk cache
+				var promise = new Promise(function (resolve, reject) {
+					installedChunkData = installedChunks[chunkId] = [resolve, reject];
+				});
+				promises.push((installedChunkData[2] = promise));
+
+				// start chunk loading
+				var url = __webpack_require__.p + __webpack_require__.k(chunkId);
+				// create error before stack unwound to get useful stacktrace later
+				var error = new Error();
+				var loadingEnded = function (event) {
+					if (__webpack_require__.o(installedChunks, chunkId)) {
+						installedChunkData = installedChunks[chunkId];
+						if (installedChunkData !== 0) installedChunks[chunkId] = undefined;
+						if (installedChunkData) {
+							if (event.type !== "load") {
+								var errorType = event && event.type;
+								var realSrc = event && event.target && event.target.src;
+								error.message =
+									"Loading css chunk " +
+									chunkId +
+									" failed.\n(" +
+									errorType +
+									": " +
+									realSrc +
+									")";
+								error.name = "ChunkLoadError";
+								error.type = errorType;
+								error.request = realSrc;
+								installedChunkData[1](error);
+							} else {
+								// loadCssChunkData(__webpack_require__.m, link, chunkId);
+								installedChunkData[0]();
+							}
+						}
+					}
+				};
+				var link = loadStylesheet(chunkId, url, loadingEnded);
+			} else installedChunks[chunkId] = 0;
+		}
+	}
+};
+
+})();
+// webpack/runtime/jsonp_chunk_loading
+(function() {
+var installedChunks = {"container": 0,};
+__webpack_require__.f.j = function (chunkId, promises) {
+	// JSONP chunk loading for javascript
+	var installedChunkData = __webpack_require__.o(installedChunks, chunkId)
+		? installedChunks[chunkId]
+		: undefined;
+	if (installedChunkData !== 0) {
+		// 0 means "already installed".
+
+		// a Promise means "currently loading".
+		if (installedChunkData) {
+			promises.push(installedChunkData[2]);
+		} else {
+			if (chunkId) {
+				// setup Promise in chunk cache
+				var promise = new Promise(function (resolve, reject) {
+					installedChunkData = installedChunks[chunkId] = [resolve, reject];
+				});
+				promises.push((installedChunkData[2] = promise));
+
+				// start chunk loading
+				var url = __webpack_require__.p + __webpack_require__.u(chunkId);
+				// create error before stack unwound to get useful stacktrace later
+				var error = new Error();
+				var loadingEnded = function (event) {
+					if (__webpack_require__.o(installedChunks, chunkId)) {
+						installedChunkData = installedChunks[chunkId];
+						if (installedChunkData !== 0) installedChunks[chunkId] = undefined;
+						if (installedChunkData) {
+							var errorType =
+								event && (event.type === "load" ? "missing" : event.type);
+							var realSrc = event && event.target && event.target.src;
+							error.message =
+								"Loading chunk " +
+								chunkId +
+								" failed.\n(" +
+								errorType +
+								": " +
+								realSrc +
+								")";
+							error.name = "ChunkLoadError";
+							error.type = errorType;
+							error.request = realSrc;
+							installedChunkData[1](error);
+						}
+					}
+				};
+				__webpack_require__.l(url, loadingEnded, "chunk-" + chunkId, chunkId);
+			} else installedChunks[chunkId] = 0;
+		}
+	}
+};
+// install a JSONP callback for chunk loading
+var webpackJsonpCallback = function (parentChunkLoadingFunction, data) {
+	var chunkIds = data[0],
+	moreModules = data[1],
+	runtime = data[2];
+	// add "moreModules" to the modules object,
+	// then flag all "chunkIds" as loaded and fire callback
+	var moduleId,
+		chunkId,
+		i = 0;
+	if (chunkIds.some(function(id) { return installedChunks[id] !== 0 })) {
+		for (moduleId in moreModules) {
+			if (__webpack_require__.o(moreModules, moduleId)) {
+				__webpack_require__.m[moduleId] = moreModules[moduleId];
+			}
+		}
+		if (runtime) var result = runtime(__webpack_require__);
+	}
+	if (parentChunkLoadingFunction) parentChunkLoadingFunction(data);
+	for (; i < chunkIds.length; i++) {
+		chunkId = chunkIds[i];
+		if (
+			__webpack_require__.o(installedChunks, chunkId) &&
+			installedChunks[chunkId]
+		) {
+			installedChunks[chunkId][0]();
+		}
+		installedChunks[chunkId] = 0;
+	}
+	
+};
+
+var chunkLoadingGlobal = self['webpackChunkwebpack'] = self['webpackChunkwebpack'] || [];
+chunkLoadingGlobal.forEach(webpackJsonpCallback.bind(null, 0));
+chunkLoadingGlobal.push = webpackJsonpCallback.bind(
+	null,
+	chunkLoadingGlobal.push.bind(chunkLoadingGlobal)
+);
+
+})();
+var __webpack_exports__ = __webpack_require__("webpack/container/entry/container");self["container"] = __webpack_exports__;
+
+})()
@@ -0,0 +1,36 @@
+(self['webpackChunkwebpack'] = self['webpackChunkwebpack'] || []).push([["main"], {
+"./index.js": function (__unused_webpack_module, exports, __webpack_require__) {
+__webpack_require__.el(/* remote/test */"webpack/container/remote/remote/test").then(__webpack_require__.t.bind(__webpack_require__, /* remote/test */"webpack/container/remote/remote/test", 21)).then(({ default: test })=>console.log(test));
+},
+"webpack/container/reference/remote/fallback-2": function (module, exports, __webpack_require__) {
+module.exports = container},
+"webpack/container/reference/remote": function (module, exports, __webpack_require__) {
+module.exports = missing.first},
+"webpack/container/reference/remote/fallback-1": function (module, exports, __webpack_require__) {
+module.exports = missing.second},
+"webpack/container/fallback/webpack/container/reference/remote/and 2 more": function (module, exports, __webpack_require__) {
+var ids = ["webpack/container/reference/remote","webpack/container/reference/remote/fallback-1","webpack/container/reference/remote/fallback-2"];
+var error, result, i = 0;
+var loop = function(next) {
+  while (i < ids.length) {
+    try { next = __webpack_require__(ids[i++]); } catch (e) { return handleError(e); }
+    if (next) return next.then ? next.then(handleResult, handleError) : handleResult(next);
+  }
+  if (error) throw error;
+};
+var handleResult = function(result) {
+  if (result) return result;
+  return loop();
+};
+var handleError = function(e) {
+  error = e;
+  return loop();
+};
+module.exports = loop();},
+
+},function(__webpack_require__) {
+var __webpack_exec__ = function(moduleId) { return __webpack_require__(__webpack_require__.s = moduleId) }
+var __webpack_exports__ = (__webpack_exec__("./index.js"));
+
+}
+]);
\ No newline at end of file
@@ -0,0 +1,3 @@
+(self['webpackChunkwebpack'] = self['webpackChunkwebpack'] || []).push([["webpack_container_remote_remote_test"], {
+
+}]);
\ No newline at end of file
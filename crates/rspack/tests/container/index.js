@@ -0,0 +1 @@
+import("remote/test").then(({ default: test }) => console.log(test));
@@ -0,0 +1 @@
+export default "loaded from the container";
@@ -0,0 +1 @@
+export default "loaded by the custom chunk loading";
@@ -0,0 +1,11 @@
+[["async_js"], {
+"./async.js": function (__unused_webpack_module, __webpack_exports__, __webpack_require__) {
+'use strict';
+__webpack_require__.r(__webpack_exports__);
+__webpack_require__.d(__webpack_exports__, {
+  'default': function() { return __WEBPACK_DEFAULT_EXPORT__; }
+});
+var __WEBPACK_DEFAULT_EXPORT__ = "loaded by the custom chunk loading";
+},
+
+}]
\ No newline at end of file
@@ -0,0 +1,117 @@
+(function() {
+var __webpack_modules__ = {
+"./index.js": function (__unused_webpack_module, exports, __webpack_require__) {
+__webpack_require__.el(/* ./async.js */"./async.js").then(__webpack_require__.bind(__webpack_require__, /* ./async.js */"./async.js")).then(({ default: value })=>console.log(value));
+},
+
+}
+// The module cache
+ var __webpack_module_cache__ = {};
+function __webpack_require__(moduleId) {
+// Check if module is in cache
+        var cachedModule = __webpack_module_cache__[moduleId];
+        if (cachedModule !== undefined) {
+      return cachedModule.exports;
+      }
+      // Create a new module (and put it into the cache)
+      var module = (__webpack_module_cache__[moduleId] = {
+       exports: {}
+      });
+      // Execute the module function
+      __webpack_modules__[moduleId](module, module.exports, __webpack_require__);
+// Return the exports of the module
+ return module.exports;
+
+}
+// expose the modules object (__webpack_modules__)
+ __webpack_require__.m = __webpack_modules__;
+// webpack/runtime/load_chunk_with_module
+(function() {
+var map = {"./async.js": ["async_js",],};
+
+__webpack_require__.el = function(module) {
+  var chunkId = map[module];
+  if (chunkId === undefined) {
+      return Promise.resolve();
+  }
+  if (chunkId.length > 1) {
+    return Promise.all(chunkId.map(__webpack_require__.e));
+  } else {
+    return __webpack_require__.e(chunkId[0]);
+  };
+}
+
+})();
+// webpack/runtime/ensure_chunk
+(function() {
+__webpack_require__.f = {};
+// This file contains only the entry chunk.
+// The chunk loading function for additional chunks
+__webpack_require__.e = function (chunkId) {
+	return Promise.all(
+		Object.keys(__webpack_require__.f).reduce(function (promises, key) {
+			__webpack_require__.f[key](chunkId, promises);
+			return promises;
+		}, [])
+	);
+};
+
+})();
+// webpack/runtime/get_chunk_filename/__webpack_require__.u
+(function() {
+// This function allow to reference chunks
+        __webpack_require__.u = function (chunkId) {
+          // return url for filenames based on template
+          return {"async_js": "async_js.js",}[chunkId];
+        };
+      
+})();
+// webpack/runtime/has_own_property
+(function() {
+__webpack_require__.o = function (obj, prop) {
+	return Object.prototype.hasOwnProperty.call(obj, prop);
+};
+
+})();
+// webpack/runtime/define_property_getters
+(function() {
+__webpack_require__.d = function(exports, definition) {
+	for(var key in definition) {
+        if(__webpack_require__.o(definition, key) && !__webpack_require__.o(exports, key)) {
+            Object.defineProperty(exports, key, { enumerable: true, get: definition[key] });
+        }
+    }
+};
+})();
+// webpack/runtime/make_namespace_object
+(function() {
+// define __esModule on exports
+__webpack_require__.r = function(exports) {
+	if(typeof Symbol !== 'undefined' && Symbol.toStringTag) {
+		Object.defineProperty(exports, Symbol.toStringTag, { value: 'Module' });
+	}
+	Object.defineProperty(exports, '__esModule', { value: true });
+};
+
+})();
+// webpack/runtime/custom_chunk_loading
+(function() {
+
+var installedChunks = {};
+__webpack_require__.f.custom = function(chunkId, promises) {
+  if (!__webpack_require__.o(installedChunks, chunkId)) {
+    promises.push(loadCustomChunk(__webpack_require__.u(chunkId)).then(function(chunk) {
+      var moreModules = chunk[1];
+      for (var moduleId in moreModules) {
+        if (__webpack_require__.o(moreModules, moduleId)) {
+          __webpack_require__.m[moduleId] = moreModules[moduleId];
+        }
+      }
+      installedChunks[chunkId] = 0;
+    }));
+  }
+};
+
+})();
+var __webpack_exports__ = __webpack_require__("./index.js");
+})()
@@ -0,0 +1 @@
+import("./async.js").then(({ default: value }) => console.log(value));
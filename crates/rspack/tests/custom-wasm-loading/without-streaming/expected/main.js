@@ -0,0 +1,234 @@
+(function() {
+var __webpack_modules__ = {
+"./index.js": function (module, __webpack_exports__, __webpack_require__) {
+__webpack_require__.a(module, async function (__webpack_handle_async_dependencies__, __webpack_async_result__) { try {
+'use strict';
+__webpack_require__.r(__webpack_exports__);
+/* harmony import */var _module__WEBPACK_IMPORTED_MODULE_0_ = __webpack_require__(/* ./module */"./module.js");
+var __webpack_async_dependencies__ = __webpack_handle_async_dependencies__([_module__WEBPACK_IMPORTED_MODULE_0_]);
+([_module__WEBPACK_IMPORTED_MODULE_0_] = __webpack_async_dependencies__.then ? (await __webpack_async_dependencies__)() : __webpack_async_dependencies__);
+console.log(_module__WEBPACK_IMPORTED_MODULE_0_["result"]);
+
+__webpack_async_result__();
+} catch(e) { __webpack_async_result__(e); } });},
+"./module.js": function (module, __webpack_exports__, __webpack_require__) {
+__webpack_require__.a(module, async function (__webpack_handle_async_dependencies__, __webpack_async_result__) { try {
+'use strict';
+__webpack_require__.r(__webpack_exports__);
+__webpack_require__.d(__webpack_exports__, {
+  'result': function() { return result; },
+  'getNumber': function() { return getNumber; }
+});
+/* harmony import */var _wasm_wasm__WEBPACK_IMPORTED_MODULE_0_ = __webpack_require__(/* ./wasm.wasm */"./wasm.wasm");
+var __webpack_async_dependencies__ = __webpack_handle_async_dependencies__([_wasm_wasm__WEBPACK_IMPORTED_MODULE_0_]);
+([_wasm_wasm__WEBPACK_IMPORTED_MODULE_0_] = __webpack_async_dependencies__.then ? (await __webpack_async_dependencies__)() : __webpack_async_dependencies__);
+ var result = (0, _wasm_wasm__WEBPACK_IMPORTED_MODULE_0_["getResult"])(1);
+ function getNumber() {
+    return 20;
+}
+
+__webpack_async_result__();
+} catch(e) { __webpack_async_result__(e); } });},
+"./module2.js": function (module, __webpack_exports__, __webpack_require__) {
+__webpack_require__.a(module, async function (__webpack_handle_async_dependencies__, __webpack_async_result__) { try {
+'use strict';
+__webpack_require__.r(__webpack_exports__);
+__webpack_require__.d(__webpack_exports__, {
+  'getNumber': function() { return getNumber; }
+});
+/* harmony import */var _wasm_wasm__WEBPACK_IMPORTED_MODULE_0_ = __webpack_require__(/* ./wasm.wasm */"./wasm.wasm");
+var __webpack_async_dependencies__ = __webpack_handle_async_dependencies__([_wasm_wasm__WEBPACK_IMPORTED_MODULE_0_]);
+([_wasm_wasm__WEBPACK_IMPORTED_MODULE_0_] = __webpack_async_dependencies__.then ? (await __webpack_async_dependencies__)() : __webpack_async_dependencies__);
+ function getNumber() {
+    return (0, _wasm_wasm__WEBPACK_IMPORTED_MODULE_0_["getNumber"])();
+}
+
+__webpack_async_result__();
+} catch(e) { __webpack_async_result__(e); } });},
+"./wasm.wasm": function (module, exports, __webpack_require__) {
+var __webpack_instantiate__ = function ([WEBPACK_IMPORTED_MODULE_0, WEBPACK_IMPORTED_MODULE_1]) {
+return __webpack_require__.v(exports, module.id, "7a1adc5fbe70fd29.module.wasm" , {
+"./module": {
+"getNumber": WEBPACK_IMPORTED_MODULE_0["getNumber"]
+},
+"./module2": {
+"getNumber": WEBPACK_IMPORTED_MODULE_1["getNumber"]
+}
+});
+}
+__webpack_require__.a(module, async function (__webpack_handle_async_dependencies__, __webpack_async_result__) {
+  try {
+    var WEBPACK_IMPORTED_MODULE_0 = __webpack_require__("./module.js");
+var WEBPACK_IMPORTED_MODULE_1 = __webpack_require__("./module2.js");
+
+    var __webpack_async_dependencies__ = __webpack_handle_async_dependencies__([WEBPACK_IMPORTED_MODULE_0, WEBPACK_IMPORTED_MODULE_1]);
+    var [WEBPACK_IMPORTED_MODULE_0, WEBPACK_IMPORTED_MODULE_1] = __webpack_async_dependencies__.then ? (await __webpack_async_dependencies__)() : __webpack_async_dependencies__;
+    await __webpack_require__.v(exports, module.id, "7a1adc5fbe70fd29.module.wasm" , {
+"./module": {
+"getNumber": WEBPACK_IMPORTED_MODULE_0["getNumber"]
+},
+"./module2": {
+"getNumber": WEBPACK_IMPORTED_MODULE_1["getNumber"]
+}
+});
+
+  __webpack_async_result__();
+
+  } catch(e) { __webpack_async_result__(e); }
+}, 1);
+},
+
+}
+// The module cache
+ var __webpack_module_cache__ = {};
+function __webpack_require__(moduleId) {
+// Check if module is in cache
+        var cachedModule = __webpack_module_cache__[moduleId];
+        if (cachedModule !== undefined) {
+      return cachedModule.exports;
+      }
+      // Create a new module (and put it into the cache)
+      var module = (__webpack_module_cache__[moduleId] = {
+      id: moduleId,
+ exports: {}
+      });
+      // Execute the module function
+      __webpack_modules__[moduleId](module, module.exports, __webpack_require__);
+// Return the exports of the module
+ return module.exports;
+
+}
+// webpack/runtime/has_own_property
+(function() {
+__webpack_require__.o = function (obj, prop) {
+	return Object.prototype.hasOwnProperty.call(obj, prop);
+};
+
+})();
+// webpack/runtime/async_module
+(function() {
+var webpackQueues =
+	typeof Symbol === "function"
+		? Symbol("webpack queues")
+		: "__webpack_queues__";
+var webpackExports =
+	typeof Symbol === "function"
+		? Symbol("webpack exports")
+		: "__webpack_exports__";
+var webpackError =
+	typeof Symbol === "function" ? Symbol("webpack error") : "__webpack_error__";
+var resolveQueue = queue => {
+	if (queue && !queue.d) {
+		queue.d = 1;
+		queue.forEach(fn => fn.r--);
+		queue.forEach(fn => (fn.r-- ? fn.r++ : fn()));
+	}
+};
+var wrapDeps = deps =>
+	deps.map(dep => {
+		if (dep !== null && typeof dep === "object") {
+			if (dep[webpackQueues]) return dep;
+			if (dep.then) {
+				var queue = [];
+				queue.d = 0;
+				dep.then(
+					r => {
+						obj[webpackExports] = r;
+						resolveQueue(queue);
+					},
+					e => {
+						obj[webpackError] = e;
+						resolveQueue(queue);
+					}
+				);
+				var obj = {};
+				obj[webpackQueues] = fn => fn(queue);
+				return obj;
+			}
+		}
+		var ret = {};
+		ret[webpackQueues] = x => {};
+		ret[webpackExports] = dep;
+		return ret;
+	});
+__webpack_require__.a = (module, body, hasAwait) => {
+	var queue;
+	hasAwait && ((queue = []).d = 1);
+	var depQueues = new Set();
+	var exports = module.exports;
+	var currentDeps;
+	var outerResolve;
+	var reject;
+	var promise = new Promise((resolve, rej) => {
+		reject = rej;
+		outerResolve = resolve;
+	});
+	promise[webpackExports] = exports;
+	promise[webpackQueues] = fn => (
+		queue && fn(queue), depQueues.forEach(fn), promise["catch"](x => {})
+	);
+	module.exports = promise;
+	body(
+		deps => {
+			currentDeps = wrapDeps(deps);
+			var fn;
+			var getResult = () =>
+				currentDeps.map(d => {
+					if (d[webpackError]) throw d[webpackError];
+					return d[webpackExports];
+				});
+			var promise = new Promise(resolve => {
+				fn = () => resolve(getResult);
+				fn.r = 0;
+				var fnQueue = q =>
+					q !== queue &&
+					!depQueues.has(q) &&
+					(depQueues.add(q), q && !q.d && (fn.r++, q.push(fn)));
+				currentDeps.map(dep => dep[webpackQueues](fnQueue));
+			});
+			return fn.r ? promise : getResult();
+		},
+		err => (
+			err ? reject((promise[webpackError] = err)) : outerResolve(exports),
+			resolveQueue(queue)
+		)
+	);
+	queue && (queue.d = 0);
+};
+
+})();
+// webpack/runtime/define_property_getters
+(function() {
+__webpack_require__.d = function(exports, definition) {
+	for(var key in definition) {
+        if(__webpack_require__.o(definition, key) && !__webpack_require__.o(exports, key)) {
+            Object.defineProperty(exports, key, { enumerable: true, get: definition[key] });
+        }
+    }
+};
+})();
+// webpack/runtime/make_namespace_object
+(function() {
+// define __esModule on exports
+__webpack_require__.r = function(exports) {
+	if(typeof Symbol !== 'undefined' && Symbol.toStringTag) {
+		Object.defineProperty(exports, Symbol.toStringTag, { value: 'Module' });
+	}
+	Object.defineProperty(exports, '__esModule', { value: true });
+};
+
+})();
+// rspack/runtime/wasm loading
+(function() {
+__webpack_require__.v = (exports, wasmModuleId, wasmModuleHash, importsObj) => {
+  var req = loadCustomWasm(wasmModuleHash, false);
+  // no support for streaming compilation
+  return req
+    .then(x => x.arrayBuffer())
+    .then(bytes => WebAssembly.instantiate(bytes, importsObj))
+    .then(res => Object.assign(exports, res.instance.exports));
+};
+
+})();
+var __webpack_exports__ = __webpack_require__("./index.js");
+})()
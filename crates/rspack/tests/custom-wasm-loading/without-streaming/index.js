@@ -0,0 +1,3 @@
+import { result } from "./module";
+
+console.log(result);
@@ -0,0 +1,160 @@
+use std::path::PathBuf;
+
+use rspack_core::{
+  rspack_sources::{BoxSource, ConcatSource, RawSource, SourceExt},
+  ApplyContext, ChunkFormat, ChunkUkey, Compilation, Compiler, Plugin, PluginContext, PluginExt,
+  RenderChunkArgs, RuntimeGlobals, RuntimeModule, RuntimeModuleExt, RUNTIME_MODULE_STAGE_ATTACH,
+};
+use rspack_error::Result;
+use rspack_fs::AsyncNativeFileSystem;
+use rspack_identifier::Identifier;
+use rspack_plugin_runtime::impl_runtime_module;
+use rspack_testing::{apply_from_fixture, test_fixture_with_plugins};
+use testing_macros::fixture;
+
+#[derive(Debug, Eq)]
+struct CustomChunkLoadingRuntimeModule {
+  id: Identifier,
+  chunk: Option<ChunkUkey>,
+}
+
+impl RuntimeModule for CustomChunkLoadingRuntimeModule {
+  fn name(&self) -> Identifier {
+    self.id
+  }
+
+  fn generate(&self, _compilation: &Compilation) -> BoxSource {
+    RawSource::from(format!(
+      r#"
+var installedChunks = {{}};
+{ensure_chunk_handlers}.custom = function(chunkId, promises) {{
+  if (!{has_own_property}(installedChunks, chunkId)) {{
+    promises.push(loadCustomChunk({get_chunk_script_filename}(chunkId)).then(function(chunk) {{
+      var moreModules = chunk[1];
+      for (var moduleId in moreModules) {{
+        if ({has_own_property}(moreModules, moduleId)) {{
+          {module_factories}[moduleId] = moreModules[moduleId];
+        }}
+      }}
+      installedChunks[chunkId] = 0;
+    }}));
+  }}
+}};
+"#,
+      ensure_chunk_handlers = RuntimeGlobals::ENSURE_CHUNK_HANDLERS,
+      has_own_property = RuntimeGlobals::HAS_OWN_PROPERTY,
+      get_chunk_script_filename = RuntimeGlobals::GET_CHUNK_SCRIPT_FILENAME,
+      module_factories = RuntimeGlobals::MODULE_FACTORIES,
+    ))
+    .boxed()
+  }
+
+  fn attach(&mut self, chunk: ChunkUkey) {
+    self.chunk = Some(chunk);
+  }
+
+  fn stage(&self) -> u8 {
+    RUNTIME_MODULE_STAGE_ATTACH
+  }
+}
+
+impl_runtime_module!(CustomChunkLoadingRuntimeModule);
+
+/// Provides the `custom` chunk loading type.
+#[derive(Debug)]
+struct CustomChunkLoadingTypePlugin;
+
+impl Plugin for CustomChunkLoadingTypePlugin {
+  fn name(&self) -> &'static str {
+    "CustomChunkLoadingTypePlugin"
+  }
+
+  fn apply(&self, ctx: PluginContext<&mut ApplyContext>) -> Result<()> {
+    ctx.context.register_chunk_loading_runtime_module_builder(
+      "custom".to_string(),
+      Box::new(|runtime_requirements| {
+        runtime_requirements.insert(RuntimeGlobals::GET_CHUNK_SCRIPT_FILENAME);
+        CustomChunkLoadingRuntimeModule {
+          id: Identifier::from("webpack/runtime/custom_chunk_loading"),
+          chunk: None,
+        }
+        .boxed()
+      }),
+    );
+    Ok(())
+  }
+}
+
+/// Renders a chunk as `[[chunkId], moreModules]`, which is the value the runtime module of the
+/// `custom` chunk loading type receives.
+struct CustomChunkFormat;
+
+impl ChunkFormat for CustomChunkFormat {
+  fn render_chunk(&self, args: &RenderChunkArgs) -> Result<BoxSource> {
+    let mut source = ConcatSource::default();
+    source.add(RawSource::from(format!(
+      r#"[["{}"], "#,
+      args.chunk().expect_id()
+    )));
+    source.add(args.module_source.clone());
+    source.add(RawSource::from("]"));
+    Ok(source.boxed())
+  }
+}
+
+/// Provides the `custom` chunk format.
+#[derive(Debug)]
+struct CustomChunkFormatPlugin;
+
+impl Plugin for CustomChunkFormatPlugin {
+  fn name(&self) -> &'static str {
+    "CustomChunkFormatPlugin"
+  }
+
+  fn apply(&self, ctx: PluginContext<&mut ApplyContext>) -> Result<()> {
+    ctx
+      .context
+      .register_chunk_format("custom".to_string(), Box::new(CustomChunkFormat));
+    Ok(())
+  }
+}
+
+#[fixture("tests/custom-chunk-loading")]
+fn should_load_chunks_with_registered_runtime_module(fixture_path: PathBuf) {
+  test_fixture_with_plugins(
+    &fixture_path,
+    vec![
+      CustomChunkLoadingTypePlugin.boxed(),
+      CustomChunkFormatPlugin.boxed(),
+    ],
+  );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn should_error_without_registered_runtime_module() {
+  let fixture_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/custom-chunk-loading");
+  let (options, plugins) = apply_from_fixture(&fixture_path);
+  let mut compiler = Compiler::new(options, plugins, AsyncNativeFileSystem);
+  let error = compiler
+    .build()
+    .await
+    .expect_err("should fail without the runtime module of the chunk loading type");
+  assert!(error
+    .to_string()
+    .contains("Unsupported chunk loading type custom"));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn should_error_without_registered_chunk_format() {
+  let fixture_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/custom-chunk-loading");
+  let (options, mut plugins) = apply_from_fixture(&fixture_path);
+  plugins.push(CustomChunkLoadingTypePlugin.boxed());
+  let mut compiler = Compiler::new(options, plugins, AsyncNativeFileSystem);
+  let error = compiler
+    .build()
+    .await
+    .expect_err("should fail without the chunk format");
+  assert!(error
+    .to_string()
+    .contains("Unsupported chunk format custom"));
+}
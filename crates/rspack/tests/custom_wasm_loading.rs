@@ -0,0 +1,53 @@
+use std::path::PathBuf;
+
+use rspack_core::{ApplyContext, Compiler, Plugin, PluginContext, PluginExt, WasmLoadingCode};
+use rspack_error::Result;
+use rspack_fs::AsyncNativeFileSystem;
+use rspack_testing::{apply_from_fixture, test_fixture_with_plugins};
+use testing_macros::fixture;
+
+/// Provides the `custom` wasm loading type, which responds with a `Response` for streaming
+/// compilation, and the `custom-without-streaming` type, which only responds with the array buffer.
+#[derive(Debug)]
+struct CustomWasmLoadingTypePlugin;
+
+impl Plugin for CustomWasmLoadingTypePlugin {
+  fn name(&self) -> &'static str {
+    "CustomWasmLoadingTypePlugin"
+  }
+
+  fn apply(&self, ctx: PluginContext<&mut ApplyContext>) -> Result<()> {
+    for (wasm_loading_type, supports_streaming) in
+      [("custom", true), ("custom-without-streaming", false)]
+    {
+      ctx.context.register_wasm_loading_code_builder(
+        wasm_loading_type.to_string(),
+        Box::new(move |_| WasmLoadingCode {
+          load_binary_code: format!("loadCustomWasm($PATH, {supports_streaming})"),
+          supports_streaming,
+        }),
+      );
+    }
+    Ok(())
+  }
+}
+
+#[fixture("tests/custom-wasm-loading/*")]
+fn should_load_wasm_with_registered_loading_code(fixture_path: PathBuf) {
+  test_fixture_with_plugins(&fixture_path, vec![CustomWasmLoadingTypePlugin.boxed()]);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn should_error_without_registered_loading_code() {
+  let fixture_path =
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/custom-wasm-loading/streaming");
+  let (options, plugins) = apply_from_fixture(&fixture_path);
+  let mut compiler = Compiler::new(options, plugins, AsyncNativeFileSystem);
+  let error = compiler
+    .build()
+    .await
+    .expect_err("should fail without the loading code of the wasm loading type");
+  assert!(error
+    .to_string()
+    .contains("Unsupported wasm loading type custom"));
+}
@@ -0,0 +1,66 @@
+use std::{collections::HashSet, path::PathBuf};
+
+use rspack_core::Compiler;
+use rspack_fs::AsyncNativeFileSystem;
+use rspack_testing::{apply_from_fixture, TempFixture};
+
+fn main_js(compiler: &Compiler<AsyncNativeFileSystem>) -> String {
+  compiler
+    .compilation
+    .assets()
+    .get("main.js")
+    .and_then(|asset| asset.get_source())
+    .expect("main.js should be emitted")
+    .source()
+    .to_string()
+}
+
+fn eval_module<'a>(main_js: &'a str, module_id: &str) -> &'a str {
+  let start = main_js
+    .find(&format!("\"{module_id}\": function"))
+    .expect("module should be rendered");
+  let end = main_js[start..]
+    .find("\");}")
+    .expect("module should be wrapped by eval");
+  &main_js[start..start + end]
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn should_rerender_changed_eval_modules_in_rebuild() {
+  // edit a copy, the fixture itself is a snapshot case
+  let fixture = TempFixture::new(
+    &PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+      .join("tests/fixtures/devtool-eval-cheap-module-source-map"),
+  );
+  let dir = fixture.path();
+
+  let (options, plugins) = apply_from_fixture(dir);
+  let mut compiler = Compiler::new(options, plugins, AsyncNativeFileSystem);
+  compiler.build().await.expect("should build");
+  let output = main_js(&compiler);
+  assert!(eval_module(&output, "./answer.ts").contains("value: 42"));
+
+  let answer = dir.join("answer.ts");
+  std::fs::write(
+    &answer,
+    "interface Answer {\n  value: number;\n}\n\nexport const answer: Answer = { value: 43 };\n",
+  )
+  .expect("should write file");
+  compiler
+    .rebuild(
+      HashSet::from([answer.to_string_lossy().to_string()]),
+      Default::default(),
+    )
+    .await
+    .expect("should rebuild");
+  let rebuilt = main_js(&compiler);
+
+  // the unchanged module is served from the render cache, the changed one is rendered again
+  assert_eq!(
+    eval_module(&output, "./index.js"),
+    eval_module(&rebuilt, "./index.js")
+  );
+  let answer_module = eval_module(&rebuilt, "./answer.ts");
+  assert!(answer_module.contains("value: 43"));
+  assert!(!answer_module.contains("value: 42"));
+}
@@ -1,5 +1,6 @@
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
+use insta::Settings;
 use rspack_testing::test_fixture;
 use rspack_tracing::enable_tracing_by_env;
 use testing_macros::fixture;
@@ -26,3 +27,40 @@ fn samples(fixture_path: PathBuf) {
 fn tree_shaking(fixture_path: PathBuf) {
   test_fixture(&fixture_path);
 }
+
+/// Snapshot the `optimizationBailout` of stats modules, which reports the side effects free modules
+/// skipped or kept by tree shaking, and the modules that can't be concatenated.
+fn assert_optimization_bailout(fixture_path: &Path, name: &str) {
+  let compiler = test_fixture(fixture_path);
+  let stats = compiler.compilation.get_stats();
+  let report = stats
+    .get_modules(false, false, false, false)
+    .expect("should get modules")
+    .into_iter()
+    .filter(|module| !module.optimization_bailout.is_empty())
+    .map(|module| {
+      format!(
+        "{}\n  {}",
+        module.name,
+        module.optimization_bailout.join("\n  ")
+      )
+    })
+    .collect::<Vec<_>>()
+    .join("\n");
+  let mut settings = Settings::clone_current();
+  settings.remove_snapshot_suffix();
+  settings.set_snapshot_path(fixture_path);
+  settings.bind(|| insta::assert_snapshot!(name, report, name));
+}
+
+#[fixture("tests/side-effects-report")]
+fn side_effects_report(fixture_path: PathBuf) {
+  assert_optimization_bailout(&fixture_path, "side_effects_report");
+}
+
+/// The declarations of concatenated modules are renamed by the tree shaking results, modules
+/// bailed out of tree shaking aren't concatenated.
+#[fixture("tests/concatenate-modules-tree-shaking")]
+fn concatenate_modules_tree_shaking(fixture_path: PathBuf) {
+  assert_optimization_bailout(&fixture_path, "concatenate_modules_tree_shaking");
+}
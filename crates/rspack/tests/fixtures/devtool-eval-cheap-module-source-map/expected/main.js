@@ -0,0 +1,12 @@
+(self['webpackChunkwebpack'] = self['webpackChunkwebpack'] || []).push([["main"], {
+"./index.js": function (__unused_webpack_module, __webpack_exports__, __webpack_require__) {
+eval("'use strict';\n__webpack_require__.r(__webpack_exports__);\n/* harmony import */var _answer_ts__WEBPACK_IMPORTED_MODULE_0_ = __webpack_require__(/* ./answer.ts */\"./answer.ts\");\n\nconsole.log(_answer_ts__WEBPACK_IMPORTED_MODULE_0_[\"answer\"].value);\n\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJzb3VyY2VzIjpbIi4vaW5kZXguanMiXSwic291cmNlc0NvbnRlbnQiOlsiaW1wb3J0IHsgYW5zd2VyIH0gZnJvbSBcIi4vYW5zd2VyLnRzXCI7XG5cbmNvbnNvbGUubG9nKGFuc3dlci52YWx1ZSk7XG4iXSwibmFtZXMiOltdLCJtYXBwaW5ncyI6Ijs7O0FBQUE7QUFFQSJ9\n//# sourceURL=webpack-internal:///./index.js\n");},
+"./answer.ts": function (__unused_webpack_module, __webpack_exports__, __webpack_require__) {
+eval("'use strict';\n__webpack_require__.r(__webpack_exports__);\n__webpack_require__.d(__webpack_exports__, {\n  'answer': function() { return answer; }\n});\n var answer = {\n    value: 42\n};\n\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJzb3VyY2VzIjpbIi4vYW5zd2VyLnRzIl0sInNvdXJjZXNDb250ZW50IjpbImludGVyZmFjZSBBbnN3ZXIge1xuICB2YWx1ZTogbnVtYmVyO1xufVxuXG5leHBvcnQgY29uc3QgYW5zd2VyOiBBbnN3ZXIgPSB7IHZhbHVlOiA0MiB9O1xuIl0sIm5hbWVzIjpbXSwibWFwcGluZ3MiOiI7Ozs7O0FBSUE7QUFBQTtBQUFBIn0=\n//# sourceURL=webpack-internal:///./answer.ts\n");},
+
+},function(__webpack_require__) {
+var __webpack_exec__ = function(moduleId) { return __webpack_require__(__webpack_require__.s = moduleId) }
+var __webpack_exports__ = (__webpack_exec__("./index.js"));
+
+}
+]);
\ No newline at end of file
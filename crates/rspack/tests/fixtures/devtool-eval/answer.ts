@@ -0,0 +1,5 @@
+interface Answer {
+  value: number;
+}
+
+export const answer: Answer = { value: 42 };
@@ -0,0 +1,12 @@
+(self['webpackChunkwebpack'] = self['webpackChunkwebpack'] || []).push([["main"], {
+"./index.js": function (__unused_webpack_module, __webpack_exports__, __webpack_require__) {
+eval("'use strict';\n__webpack_require__.r(__webpack_exports__);\n/* harmony import */var _answer_ts__WEBPACK_IMPORTED_MODULE_0_ = __webpack_require__(/* ./answer.ts */\"./answer.ts\");\n\nconsole.log(_answer_ts__WEBPACK_IMPORTED_MODULE_0_[\"answer\"].value);\n\n//# sourceURL=webpack://__rspack_test__/./index.js\n");},
+"./answer.ts": function (__unused_webpack_module, __webpack_exports__, __webpack_require__) {
+eval("'use strict';\n__webpack_require__.r(__webpack_exports__);\n__webpack_require__.d(__webpack_exports__, {\n  'answer': function() { return answer; }\n});\n var answer = {\n    value: 42\n};\n\n//# sourceURL=webpack://__rspack_test__/./answer.ts\n");},
+
+},function(__webpack_require__) {
+var __webpack_exec__ = function(moduleId) { return __webpack_require__(__webpack_require__.s = moduleId) }
+var __webpack_exports__ = (__webpack_exec__("./index.js"));
+
+}
+]);
\ No newline at end of file
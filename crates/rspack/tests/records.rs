@@ -0,0 +1,118 @@
+use std::{
+  path::{Path, PathBuf},
+  sync::Arc,
+};
+
+use rspack_core::{CompilationRecords, Compiler};
+use rspack_fs::AsyncNativeFileSystem;
+use rspack_testing::{apply_from_fixture, TempFixture};
+
+fn fixture() -> TempFixture {
+  TempFixture::new(&PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/records"))
+}
+
+async fn build(dir: &Path, records_path: Option<PathBuf>) -> Compiler<AsyncNativeFileSystem> {
+  let (mut options, plugins) = apply_from_fixture(dir);
+  options.records_path = records_path;
+  let mut compiler = Compiler::new(options, plugins, AsyncNativeFileSystem);
+  compiler.build().await.expect("should build");
+  compiler
+}
+
+fn module_identifier(compiler: &Compiler<AsyncNativeFileSystem>, file: &str) -> String {
+  compiler
+    .compilation
+    .module_graph
+    .modules()
+    .keys()
+    .find(|identifier| identifier.ends_with(file))
+    .expect("module should exist")
+    .to_string()
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn should_not_create_records_without_records_path_or_hmr() {
+  let fixture = fixture();
+  let dir = fixture.path();
+  let compiler = build(dir, None).await;
+  assert_eq!(*compiler.records, CompilationRecords::default());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn should_write_and_read_records() {
+  let fixture = fixture();
+  let dir = fixture.path();
+  let records_path = dir.join("records.json");
+  let compiler = build(dir, Some(records_path.clone())).await;
+  assert!(!compiler.records.module_ids.is_empty());
+  assert!(!compiler.records.chunk_ids.is_empty());
+
+  let records = CompilationRecords::read(&records_path).expect("should read records");
+  assert_eq!(records, *compiler.records);
+
+  assert_eq!(
+    CompilationRecords::read(&dir.join("missing.json")).expect("should read records"),
+    CompilationRecords::default()
+  );
+  std::fs::write(dir.join("broken.json"), "{").expect("should write file");
+  assert!(CompilationRecords::read(&dir.join("broken.json")).is_err());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn should_revive_module_ids() {
+  let fixture = fixture();
+  let dir = fixture.path();
+  let records_path = dir.join("records.json");
+  let compiler = build(dir, Some(records_path.clone())).await;
+  let identifier = module_identifier(&compiler, "a.js");
+
+  let mut records = CompilationRecords::read(&records_path).expect("should read records");
+  records
+    .module_ids
+    .insert(identifier.clone(), "revived".to_string());
+  records.write(&records_path).expect("should write records");
+
+  let compiler = build(dir, Some(records_path)).await;
+  assert_eq!(
+    compiler
+      .compilation
+      .chunk_graph
+      .get_module_id(identifier.as_str().into())
+      .as_deref(),
+    Some("revived")
+  );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn should_revive_chunk_ids() {
+  let fixture = fixture();
+  let dir = fixture.path();
+  let mut compiler = build(dir, Some(dir.join("records.json"))).await;
+  let compilation = &mut compiler.compilation;
+  let (split_point, ukey) = compilation
+    .chunk_graph
+    .split_point_module_identifier_to_chunk_ukey
+    .iter()
+    .map(|(identifier, ukey)| (identifier.to_string(), *ukey))
+    .find(|(identifier, _)| identifier.ends_with("b.js"))
+    .expect("async chunk should exist");
+
+  let mut records = (*compilation.records).clone();
+  records.chunk_ids.insert(split_point, "revived".to_string());
+  compilation.records = Arc::new(records);
+  let chunk = compilation
+    .chunk_by_ukey
+    .get_mut(&ukey)
+    .expect("chunk should exist");
+  chunk.id = None;
+  chunk.ids = vec![];
+
+  rspack_ids::assign_ascending_chunk_ids(&[ukey], compilation);
+  assert_eq!(
+    compilation
+      .chunk_by_ukey
+      .get(&ukey)
+      .and_then(|chunk| chunk.id.as_deref()),
+    Some("revived")
+  );
+}
@@ -0,0 +1 @@
+console.log(BUILD_ID);
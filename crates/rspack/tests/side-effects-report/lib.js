@@ -0,0 +1,2 @@
+export { used } from "./used";
+export { unused } from "./unused";
@@ -0,0 +1,28 @@
+(self['webpackChunkwebpack'] = self['webpackChunkwebpack'] || []).push([["main"], {
+"./index.js": function (__unused_webpack_module, __webpack_exports__, __webpack_require__) {
+'use strict';
+__webpack_require__.r(__webpack_exports__);
+/* harmony import */var _lib2__WEBPACK_IMPORTED_MODULE_1_ = __webpack_require__(/* ./lib2 */"./lib2.js");
+/* harmony import */var _lib2__WEBPACK_IMPORTED_MODULE_1__default = /*#__PURE__*/__webpack_require__.n(_lib2__WEBPACK_IMPORTED_MODULE_1_);
+
+const a = __webpack_require__(/* ./lib */"./lib.js").a;
+a();
+_lib2__WEBPACK_IMPORTED_MODULE_1_["b"];
+},
+"./lib.js": function (__unused_webpack_module, exports, __webpack_require__) {
+var __webpack_unused_export__;
+exports.a = function() {};
+__webpack_unused_export__ = function() {};
+},
+"./lib2.js": function (module, exports, __webpack_require__) {
+module.exports = {
+    b: 1
+};
+},
+
+},function(__webpack_require__) {
+var __webpack_exec__ = function(moduleId) { return __webpack_require__(__webpack_require__.s = moduleId) }
+var __webpack_exports__ = (__webpack_exec__("./index.js"));
+
+}
+]);
\ No newline at end of file
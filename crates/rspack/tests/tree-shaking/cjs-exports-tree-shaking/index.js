@@ -0,0 +1,4 @@
+import { b } from "./lib2";
+const a = require("./lib").a;
+a();
+b;
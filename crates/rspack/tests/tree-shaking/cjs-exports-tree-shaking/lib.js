@@ -0,0 +1,2 @@
+exports.a = function () {};
+exports.unused = function () {};
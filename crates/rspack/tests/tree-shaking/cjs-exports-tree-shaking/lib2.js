@@ -0,0 +1,4 @@
+module.exports = {
+	b: 1,
+	c: function () {}
+};
@@ -0,0 +1,8 @@
+import { a } from "./a";
+import { createStore } from "./store";
+
+export function used() {
+	return a;
+}
+
+export const store = /*#__PURE__*/ createStore();
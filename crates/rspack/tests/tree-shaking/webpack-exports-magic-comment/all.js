@@ -0,0 +1,7 @@
+export function c() {
+  return "c";
+}
+
+export function d() {
+  return "d";
+}
@@ -0,0 +1,17 @@
+(self['webpackChunkwebpack'] = self['webpackChunkwebpack'] || []).push([["all_js"], {
+"./all.js": function (__unused_webpack_module, __webpack_exports__, __webpack_require__) {
+'use strict';
+__webpack_require__.r(__webpack_exports__);
+__webpack_require__.d(__webpack_exports__, {
+  'c': function() { return c; },
+  'd': function() { return d; }
+});
+ function c() {
+    return "c";
+}
+ function d() {
+    return "d";
+}
+},
+
+}]);
\ No newline at end of file
@@ -0,0 +1,12 @@
+(self['webpackChunkwebpack'] = self['webpackChunkwebpack'] || []).push([["main"], {
+"./index.js": function (__unused_webpack_module, exports, __webpack_require__) {
+__webpack_require__.el(/* ./lib */"./lib.js").then(__webpack_require__.bind(__webpack_require__, /* ./lib */"./lib.js")).then(({ a })=>a());
+__webpack_require__.el(/* ./all */"./all.js").then(__webpack_require__.bind(__webpack_require__, /* ./all */"./all.js")).then(({ c })=>c());
+},
+
+},function(__webpack_require__) {
+var __webpack_exec__ = function(moduleId) { return __webpack_require__(__webpack_require__.s = moduleId) }
+var __webpack_exports__ = (__webpack_exec__("./index.js"));
+
+}
+]);
\ No newline at end of file
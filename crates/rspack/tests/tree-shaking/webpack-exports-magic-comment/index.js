@@ -0,0 +1,2 @@
+import(/* webpackExports: ["a"] */ "./lib").then(({ a }) => a());
+import("./all").then(({ c }) => c());
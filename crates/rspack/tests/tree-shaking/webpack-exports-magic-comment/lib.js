@@ -0,0 +1,7 @@
+export function a() {
+  return "a";
+}
+
+export function b() {
+  return "b";
+}
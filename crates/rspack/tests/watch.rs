@@ -0,0 +1,32 @@
+use std::path::PathBuf;
+
+use rspack_testing::{watch_fixture, TempFixture};
+
+#[tokio::test(flavor = "multi_thread")]
+async fn should_keep_watching_after_syntax_errors() {
+  let fixture = TempFixture::new(&PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/watch"));
+  let dir = fixture.path();
+  let main = || std::fs::read_to_string(dir.join("dist/main.js")).expect("should emit main.js");
+  let mut compilations = 0;
+  watch_fixture(
+    dir,
+    &[
+      ("value.js", "export default \"changed\";\n"),
+      ("value.js", "export default \"broken\n"),
+      ("value.js", "export default \"fixed\";\n"),
+    ],
+    |index, result| {
+      compilations += 1;
+      let compilation = result.expect("should compile");
+      let has_errors = compilation.get_errors().next().is_some();
+      match index {
+        0 => assert!(!has_errors && main().contains("\"initial\"")),
+        1 => assert!(!has_errors && main().contains("\"changed\"")),
+        2 => assert!(has_errors, "should report the syntax error"),
+        _ => assert!(!has_errors && main().contains("\"fixed\"")),
+      }
+    },
+  )
+  .await;
+  assert_eq!(compilations, 4);
+}
@@ -2,8 +2,9 @@ use std::collections::HashMap;
 
 use napi_derive::napi;
 use rspack_core::{
-  BoxPlugin, CompilerOptions, DevServerOptions, Devtool, Experiments, IncrementalRebuild,
+  BoxPlugin, CompilerOptions, Context, DevServerOptions, Devtool, Experiments, IncrementalRebuild,
   IncrementalRebuildMakeState, ModuleOptions, ModuleType, OutputOptions, PluginExt,
+  SnapshotOptions,
 };
 use serde::Deserialize;
 
@@ -85,6 +86,7 @@ pub struct RawOptions {
   pub cache: RawCacheOptions,
   pub experiments: RawExperiments,
   pub node: Option<RawNodeOption>,
+  pub records_path: Option<String>,
 }
 
 impl RawOptionsApply for RawOptions {
@@ -95,7 +97,7 @@ impl RawOptionsApply for RawOptions {
     plugins: &mut Vec<BoxPlugin>,
     loader_runner: &JsLoaderRunner,
   ) -> Result<Self::Options, rspack_error::Error> {
-    let context = self.context.into();
+    let context: Context = self.context.into();
     // https://github.com/web-infra-dev/rspack/discussions/3252#discussioncomment-6182939
     // will solve the order problem by add EntryOptionPlugin on js side, and we can only
     // care about EntryOptions instead EntryDescription
@@ -113,6 +115,7 @@ impl RawOptionsApply for RawOptions {
                 public_path: desc.public_path.clone().map(Into::into),
                 base_uri: desc.base_uri.clone(),
                 filename: desc.filename.clone().map(Into::into),
+                library: None,
               },
             )
             .boxed(),
@@ -138,6 +141,7 @@ impl RawOptionsApply for RawOptions {
         emit_asset: self.experiments.incremental_rebuild.emit_asset,
       },
       async_web_assembly: self.experiments.async_web_assembly,
+      sync_web_assembly: self.experiments.sync_web_assembly,
       new_split_chunks: self.experiments.new_split_chunks,
       css: self.experiments.css,
     };
@@ -145,7 +149,8 @@ impl RawOptionsApply for RawOptions {
       self.optimization.apply(plugins, loader_runner)
     })?;
     let stats = self.stats.into();
-    let snapshot = self.snapshot.into();
+    let mut snapshot: SnapshotOptions = self.snapshot.into();
+    snapshot.resolve_paths(context.as_ref());
     let node = self.node.map(|n| n.into());
     let dev_server: DevServerOptions = self.dev_server.into();
     let builtins = self.builtins.apply(plugins, loader_runner)?;
@@ -168,6 +173,7 @@ impl RawOptionsApply for RawOptions {
       plugins.push(rspack_plugin_runtime::HotModuleReplacementPlugin {}.boxed());
     }
     plugins.push(rspack_plugin_runtime::RuntimePlugin {}.boxed());
+    plugins.push(rspack_plugin_runtime::ChunkPrefetchPreloadPlugin.boxed());
     if experiments.lazy_compilation {
       plugins.push(rspack_plugin_runtime::LazyCompilationPlugin {}.boxed());
     }
@@ -222,6 +228,9 @@ impl RawOptionsApply for RawOptions {
     if experiments.async_web_assembly {
       plugins.push(rspack_plugin_wasm::AsyncWasmPlugin::new().boxed());
     }
+    if experiments.sync_web_assembly {
+      plugins.push(rspack_plugin_wasm::SyncWasmPlugin::new().boxed());
+    }
     rspack_plugin_worker::worker_plugin(
       output.worker_chunk_loading.clone(),
       output.worker_wasm_loading.clone(),
@@ -266,6 +275,7 @@ impl RawOptionsApply for RawOptions {
       node,
       dev_server,
       builtins,
+      records_path: self.records_path.map(Into::into),
     })
   }
 }
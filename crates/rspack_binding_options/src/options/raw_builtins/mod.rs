@@ -1,7 +1,7 @@
 use napi_derive::napi;
 use rspack_core::{
-  Builtins, CodeGeneration, Define, Minification, MinificationCondition, MinificationConditions,
-  PluginExt, PresetEnv, Provide,
+  Builtins, CodeGeneration, Define, ImportMetaEnv, Minification, MinificationCondition,
+  MinificationConditions, PluginExt, PresetEnv, Provide,
 };
 use rspack_error::internal_error;
 use rspack_plugin_banner::{BannerConfig, BannerPlugin};
@@ -9,6 +9,9 @@ use rspack_plugin_copy::CopyPlugin;
 use rspack_plugin_css::{plugin::CssConfig, CssPlugin};
 use rspack_plugin_dev_friendly_split_chunks::DevFriendlySplitChunksPlugin;
 use rspack_plugin_html::HtmlPlugin;
+use rspack_plugin_mf::{
+  ConsumeSharedPlugin, ContainerPlugin, ContainerReferencePlugin, ProvideSharedPlugin,
+};
 use rspack_plugin_progress::ProgressPlugin;
 use serde::Deserialize;
 
@@ -19,6 +22,7 @@ mod raw_copy;
 mod raw_css;
 mod raw_decorator;
 mod raw_html;
+mod raw_mf;
 mod raw_plugin_import;
 mod raw_postcss;
 mod raw_progress;
@@ -28,6 +32,7 @@ mod raw_relay;
 pub use raw_css::*;
 pub use raw_decorator::*;
 pub use raw_html::*;
+pub use raw_mf::*;
 pub use raw_postcss::*;
 pub use raw_progress::*;
 pub use raw_react::*;
@@ -212,6 +217,8 @@ pub struct RawBuiltins {
   pub preset_env: Option<RawPresetEnv>,
   #[napi(ts_type = "Record<string, string>")]
   pub define: Define,
+  #[napi(ts_type = "Record<string, string>")]
+  pub import_meta_env: Option<ImportMetaEnv>,
   #[napi(ts_type = "Record<string, string[]>")]
   pub provide: Provide,
   pub tree_shaking: String,
@@ -226,6 +233,10 @@ pub struct RawBuiltins {
   pub plugin_import: Option<Vec<RawPluginImportConfig>>,
   pub relay: Option<RawRelayConfig>,
   pub code_generation: Option<RawCodeGeneration>,
+  pub container: Option<RawContainerPluginOptions>,
+  pub container_reference: Option<RawContainerReferencePluginOptions>,
+  pub provide_shared: Option<RawProvideSharedPluginOptions>,
+  pub consume_shared: Option<RawConsumeSharedPluginOptions>,
 }
 
 impl RawOptionsApply for RawBuiltins {
@@ -274,10 +285,25 @@ impl RawOptionsApply for RawBuiltins {
         .for_each(|banner| plugins.push(BannerPlugin::new(banner).boxed()));
     }
 
+    if let Some(container) = self.container {
+      plugins.push(ContainerPlugin::new(container.into()).boxed());
+    }
+    if let Some(container_reference) = self.container_reference {
+      plugins.push(ContainerReferencePlugin::new(container_reference.into()).boxed());
+    }
+    if let Some(provide_shared) = self.provide_shared {
+      plugins.push(ProvideSharedPlugin::new(provide_shared.into()).boxed());
+    }
+    if let Some(consume_shared) = self.consume_shared {
+      plugins.push(ConsumeSharedPlugin::new(consume_shared.into()).boxed());
+    }
+
     Ok(Builtins {
       minify_options: self.minify_options.map(|i| i.try_into()).transpose()?,
       preset_env: self.preset_env.map(Into::into),
       define: self.define,
+      runtime_define: Default::default(),
+      import_meta_env: self.import_meta_env,
       provide: self.provide,
       tree_shaking: self.tree_shaking.into(),
       react: self.react.into(),
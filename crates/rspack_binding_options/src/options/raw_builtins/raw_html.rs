@@ -43,6 +43,9 @@ pub struct RawHtmlPluginConfig {
   pub title: Option<String>,
   pub favicon: Option<String>,
   pub meta: Option<HashMap<String, HashMap<String, String>>>,
+  /// emit preload links for the async chunks preloaded by the included entries
+  /// with `webpackPreload`
+  pub preload: Option<bool>,
 }
 
 impl From<RawHtmlPluginConfig> for HtmlPluginConfig {
@@ -77,6 +80,7 @@ impl From<RawHtmlPluginConfig> for HtmlPluginConfig {
       title: value.title,
       favicon: value.favicon,
       meta: value.meta,
+      preload: value.preload.unwrap_or_default(),
     }
   }
 }
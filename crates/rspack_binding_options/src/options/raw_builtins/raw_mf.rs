@@ -0,0 +1,192 @@
+use napi::Either;
+use napi_derive::napi;
+use rspack_plugin_mf::{
+  ConsumeOptions, ConsumeSharedPluginOptions, ConsumeVersion, ContainerPluginOptions,
+  ContainerReferencePluginOptions, ExposeOptions, ProvideOptions, ProvideSharedPluginOptions,
+  ProvideVersion, RemoteOptions,
+};
+use serde::Deserialize;
+
+use crate::RawLibraryOptions;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[napi(object)]
+pub struct RawContainerPluginOptions {
+  pub name: String,
+  pub share_scope: String,
+  pub library: RawLibraryOptions,
+  pub runtime: Option<String>,
+  pub filename: Option<String>,
+  pub exposes: Vec<RawExposeOptions>,
+}
+
+impl From<RawContainerPluginOptions> for ContainerPluginOptions {
+  fn from(value: RawContainerPluginOptions) -> Self {
+    Self {
+      name: value.name,
+      share_scope: value.share_scope,
+      library: value.library.into(),
+      runtime: value.runtime,
+      filename: value.filename.map(Into::into),
+      exposes: value.exposes.into_iter().map(|e| e.into()).collect(),
+    }
+  }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[napi(object)]
+pub struct RawExposeOptions {
+  pub key: String,
+  pub name: Option<String>,
+  pub import: Vec<String>,
+}
+
+impl From<RawExposeOptions> for (String, ExposeOptions) {
+  fn from(value: RawExposeOptions) -> Self {
+    (
+      value.key,
+      ExposeOptions {
+        name: value.name,
+        import: value.import,
+      },
+    )
+  }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[napi(object)]
+pub struct RawContainerReferencePluginOptions {
+  pub remote_type: String,
+  pub remotes: Vec<RawRemoteOptions>,
+}
+
+impl From<RawContainerReferencePluginOptions> for ContainerReferencePluginOptions {
+  fn from(value: RawContainerReferencePluginOptions) -> Self {
+    Self {
+      remote_type: value.remote_type,
+      remotes: value.remotes.into_iter().map(|e| e.into()).collect(),
+    }
+  }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[napi(object)]
+pub struct RawRemoteOptions {
+  pub key: String,
+  pub external: Vec<String>,
+  pub share_scope: String,
+}
+
+impl From<RawRemoteOptions> for (String, RemoteOptions) {
+  fn from(value: RawRemoteOptions) -> Self {
+    (
+      value.key,
+      RemoteOptions {
+        external: value.external,
+        share_scope: value.share_scope,
+      },
+    )
+  }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[napi(object)]
+pub struct RawConsumeSharedPluginOptions {
+  pub consumes: Vec<RawConsumeOptions>,
+}
+
+impl From<RawConsumeSharedPluginOptions> for ConsumeSharedPluginOptions {
+  fn from(value: RawConsumeSharedPluginOptions) -> Self {
+    Self {
+      consumes: value.consumes.into_iter().map(|e| e.into()).collect(),
+    }
+  }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[napi(object)]
+pub struct RawConsumeOptions {
+  pub key: String,
+  pub import: Option<String>,
+  pub share_scope: String,
+  pub share_key: String,
+  #[serde(skip_deserializing)]
+  #[napi(ts_type = "string | false")]
+  pub required_version: Option<Either<String, bool>>,
+  pub package_name: Option<String>,
+  pub strict_version: bool,
+  pub singleton: bool,
+  pub eager: bool,
+}
+
+impl From<RawConsumeOptions> for (String, ConsumeOptions) {
+  fn from(value: RawConsumeOptions) -> Self {
+    (
+      value.key,
+      ConsumeOptions {
+        share_key: value.share_key,
+        share_scope: value.share_scope,
+        import: value.import,
+        required_version: value.required_version.map(|version| match version {
+          Either::A(version) => ConsumeVersion::Version(version),
+          Either::B(_) => ConsumeVersion::False,
+        }),
+        package_name: value.package_name,
+        strict_version: value.strict_version,
+        singleton: value.singleton,
+        eager: value.eager,
+      },
+    )
+  }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[napi(object)]
+pub struct RawProvideSharedPluginOptions {
+  pub provides: Vec<RawProvideOptions>,
+}
+
+impl From<RawProvideSharedPluginOptions> for ProvideSharedPluginOptions {
+  fn from(value: RawProvideSharedPluginOptions) -> Self {
+    Self {
+      provides: value.provides.into_iter().map(|e| e.into()).collect(),
+    }
+  }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[napi(object)]
+pub struct RawProvideOptions {
+  pub key: String,
+  pub share_key: String,
+  pub share_scope: String,
+  #[serde(skip_deserializing)]
+  #[napi(ts_type = "string | false")]
+  pub version: Option<Either<String, bool>>,
+  pub eager: bool,
+}
+
+impl From<RawProvideOptions> for (String, ProvideOptions) {
+  fn from(value: RawProvideOptions) -> Self {
+    (
+      value.key,
+      ProvideOptions {
+        share_key: value.share_key,
+        share_scope: value.share_scope,
+        version: value.version.map(|version| match version {
+          Either::A(version) => ProvideVersion::Version(version),
+          Either::B(_) => ProvideVersion::False,
+        }),
+        eager: value.eager,
+      },
+    )
+  }
+}
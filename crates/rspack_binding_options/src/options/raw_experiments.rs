@@ -16,6 +16,7 @@ pub struct RawExperiments {
   pub lazy_compilation: bool,
   pub incremental_rebuild: RawIncrementalRebuild,
   pub async_web_assembly: bool,
+  pub sync_web_assembly: bool,
   pub new_split_chunks: bool,
   pub css: bool,
 }
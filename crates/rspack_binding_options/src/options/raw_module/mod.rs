@@ -11,8 +11,9 @@ use rspack_core::{
   AssetGeneratorDataUrl, AssetGeneratorDataUrlOptions, AssetGeneratorOptions,
   AssetInlineGeneratorOptions, AssetParserDataUrl, AssetParserDataUrlOptions, AssetParserOptions,
   AssetResourceGeneratorOptions, BoxLoader, DescriptionData, FuncUseCtx, GeneratorOptions,
-  GeneratorOptionsByModuleType, ModuleOptions, ModuleRule, ModuleRuleEnforce, ModuleRuleUse,
-  ModuleType, ParserOptions, ParserOptionsByModuleType,
+  GeneratorOptionsByModuleType, ImportAttributesData, JavascriptParserOptions, ModuleOptions,
+  ModuleRule, ModuleRuleEnforce, ModuleRuleUse, ModuleType, ParserOptions,
+  ParserOptionsByModuleType,
 };
 use rspack_error::internal_error;
 use serde::Deserialize;
@@ -254,6 +255,7 @@ pub struct RawModuleRule {
   pub resource_query: Option<RawRuleSetCondition>,
   pub resource_fragment: Option<RawRuleSetCondition>,
   pub description_data: Option<HashMap<String, RawRuleSetCondition>>,
+  pub with: Option<HashMap<String, RawRuleSetCondition>>,
   pub side_effects: Option<bool>,
   pub r#use: Option<RawModuleRuleUses>,
   pub r#type: Option<String>,
@@ -275,9 +277,10 @@ pub struct RawModuleRule {
 #[serde(rename_all = "camelCase")]
 #[napi(object)]
 pub struct RawParserOptions {
-  #[napi(ts_type = r#""asset" | "unknown""#)]
+  #[napi(ts_type = r#""asset" | "javascript" | "unknown""#)]
   pub r#type: String,
   pub asset: Option<RawAssetParserOptions>,
+  pub javascript: Option<RawJavascriptParserOptions>,
 }
 
 impl From<RawParserOptions> for ParserOptions {
@@ -289,15 +292,36 @@ impl From<RawParserOptions> for ParserOptions {
           .expect("should have an \"asset\" when RawParserOptions.type is \"asset\"")
           .into(),
       ),
+      "javascript" => Self::Javascript(
+        value
+          .javascript
+          .expect("should have a \"javascript\" when RawParserOptions.type is \"javascript\"")
+          .into(),
+      ),
       "unknown" => Self::Unknown,
       _ => panic!(
-        "Failed to resolve the RawParserOptions.type {}. Expected type is \"asset\", \"unknown\".",
+        "Failed to resolve the RawParserOptions.type {}. Expected type is \"asset\", \"javascript\", \"unknown\".",
         value.r#type
       ),
     }
   }
 }
 
+#[derive(Debug, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+#[napi(object)]
+pub struct RawJavascriptParserOptions {
+  pub amd: Option<bool>,
+}
+
+impl From<RawJavascriptParserOptions> for JavascriptParserOptions {
+  fn from(value: RawJavascriptParserOptions) -> Self {
+    Self {
+      amd: value.amd.unwrap_or_default(),
+    }
+  }
+}
+
 #[derive(Debug, Deserialize, Default)]
 #[serde(rename_all = "camelCase")]
 #[napi(object)]
@@ -639,6 +663,16 @@ impl RawOptionsApply for RawModuleRule {
       })
       .transpose()?;
 
+    let with = self
+      .with
+      .map(|data| {
+        data
+          .into_iter()
+          .map(|(k, v)| Ok((k, v.try_into()?)))
+          .collect::<rspack_error::Result<ImportAttributesData>>()
+      })
+      .transpose()?;
+
     let enforce = self
       .enforce
       .map(|enforce| match &*enforce {
@@ -662,6 +696,7 @@ impl RawOptionsApply for RawModuleRule {
         .transpose()?,
       resource: self.resource.map(|raw| raw.try_into()).transpose()?,
       description_data,
+      with,
       r#use: uses.transpose()?.unwrap_or_default(),
       r#type: module_type,
       parser: self.parser.map(|raw| raw.into()),
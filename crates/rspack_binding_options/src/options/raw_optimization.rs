@@ -21,6 +21,7 @@ pub struct RawOptimizationOptions {
   pub remove_empty_chunks: bool,
   pub side_effects: String,
   pub real_content_hash: bool,
+  pub concatenate_modules: bool,
 }
 
 impl RawOptionsApply for RawOptimizationOptions {
@@ -55,10 +56,14 @@ impl RawOptionsApply for RawOptimizationOptions {
     if self.real_content_hash {
       plugins.push(rspack_plugin_real_content_hash::RealContentHashPlugin.boxed());
     }
+    if self.concatenate_modules {
+      plugins.push(rspack_plugin_javascript::ModuleConcatenationPlugin.boxed());
+    }
     Ok(Optimization {
       remove_available_modules: self.remove_available_modules,
       remove_empty_chunks: self.remove_empty_chunks,
       side_effects: SideEffectOption::from(self.side_effects.as_str()),
+      concatenate_modules: self.concatenate_modules,
     })
   }
 }
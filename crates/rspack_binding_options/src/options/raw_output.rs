@@ -3,7 +3,6 @@ use rspack_core::{
   to_identifier, BoxPlugin, CrossOriginLoading, LibraryAuxiliaryComment, LibraryName,
   LibraryOptions, OutputOptions, PluginExt, TrustedTypes,
 };
-use rspack_error::internal_error;
 use serde::Deserialize;
 
 use crate::JsLoaderRunner;
@@ -172,9 +171,7 @@ impl RawOptionsApply for RawOutputOptions {
     }
     self.apply_library_plugin(plugins);
     for wasm_loading_type in self.enabled_wasm_loading_types {
-      plugins.push(rspack_plugin_wasm::enable_wasm_loading_plugin(
-        wasm_loading_type.as_str().into(),
-      ));
+      rspack_plugin_wasm::enable_wasm_loading_plugin(wasm_loading_type.as_str().into(), plugins);
     }
 
     Ok(OutputOptions {
@@ -229,12 +226,9 @@ impl RawOutputOptions {
         plugins.push(rspack_plugin_runtime::ModuleChunkFormatPlugin {}.boxed());
       }
       "false" => {}
-      _ => {
-        return Err(internal_error!(
-          "Unsupported chunk format '{}'",
-          self.chunk_format
-        ))
-      }
+      chunk_format => plugins.push(
+        rspack_plugin_runtime::CustomChunkFormatPlugin::new(chunk_format.to_string()).boxed(),
+      ),
     }
     Ok(())
   }
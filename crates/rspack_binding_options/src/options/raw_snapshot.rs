@@ -1,3 +1,5 @@
+use std::path::PathBuf;
+
 use napi_derive::napi;
 use rspack_core::{SnapshotOptions, SnapshotStrategy};
 use serde::Deserialize;
@@ -25,15 +27,32 @@ impl From<RawSnapshotStrategy> for SnapshotStrategy {
 pub struct RawSnapshotOptions {
   pub resolve: RawSnapshotStrategy,
   pub module: RawSnapshotStrategy,
+  pub immutable_paths: Option<Vec<String>>,
+  pub managed_paths: Option<Vec<String>>,
 }
 
 impl From<RawSnapshotOptions> for SnapshotOptions {
   fn from(value: RawSnapshotOptions) -> Self {
-    let RawSnapshotOptions { resolve, module } = value;
+    let RawSnapshotOptions {
+      resolve,
+      module,
+      immutable_paths,
+      managed_paths,
+    } = value;
 
     SnapshotOptions {
       resolve: resolve.into(),
       module: module.into(),
+      immutable_paths: immutable_paths
+        .unwrap_or_default()
+        .into_iter()
+        .map(PathBuf::from)
+        .collect(),
+      managed_paths: managed_paths
+        .unwrap_or_default()
+        .into_iter()
+        .map(PathBuf::from)
+        .collect(),
     }
   }
 }
@@ -52,6 +52,16 @@ impl<'me> CodeSplitter<'me> {
         .iter()
         .filter_map(|dep| module_graph.module_identifier_by_dependency_id(dep))
         .collect::<Vec<_>>();
+      let mut included_module_identifiers = compilation
+        .global_entry
+        .include_dependencies
+        .iter()
+        .chain(&entry_data.include_dependencies)
+        .filter_map(|dep| module_graph.module_identifier_by_dependency_id(dep))
+        .copied()
+        .collect::<Vec<_>>();
+      included_module_identifiers.sort_unstable();
+      included_module_identifiers.dedup();
 
       let chunk = Compilation::add_named_chunk(
         name.to_string(),
@@ -127,6 +137,16 @@ impl<'me> CodeSplitter<'me> {
           entrypoint.ukey,
         );
       }
+
+      // included modules are added to the entry chunk, but not executed on startup
+      for module_identifier in included_module_identifiers {
+        compilation.chunk_graph.add_module(module_identifier);
+
+        input_entrypoints_and_modules
+          .entry(entrypoint.ukey)
+          .or_default()
+          .push(module_identifier);
+      }
     }
 
     for (name, entry_data) in &compilation.entries {
@@ -415,6 +435,9 @@ impl<'me> CodeSplitter<'me> {
             .expect("chunk group not found");
           chunk_group.parents.insert(item.chunk_group);
           chunk_group.runtime.extend(runtime.clone());
+          if let Some(group_options) = group_options {
+            chunk_group.add_orders(group_options);
+          }
         }
         continue;
       } else {
@@ -475,7 +498,10 @@ impl<'me> CodeSplitter<'me> {
         ChunkGroup::new(
           ChunkGroupKind::Normal,
           item_chunk_group.runtime.clone(),
-          ChunkGroupOptions::default().name_optional(group_options.and_then(|x| x.name.as_deref())),
+          ChunkGroupOptions::default()
+            .name_optional(group_options.and_then(|x| x.name.as_deref()))
+            .prefetch_order(group_options.and_then(|x| x.prefetch_order))
+            .preload_order(group_options.and_then(|x| x.preload_order)),
           ChunkGroupInfo {
             chunk_loading: item_chunk_group.info.chunk_loading,
             async_chunks: item_chunk_group.info.async_chunks,
@@ -1,3 +1,10 @@
+//! Caches reused across rebuilds, and across restarts with the filesystem cache.
+//!
+//! Modules restored from the cache are not built, they have neither the ast nor the code generatable
+//! dependencies. The tree shaking analysis of them is served by [AnalyzeModuleOccasion] and the code
+//! generation by [CodeGenerateOccasion]. The ones which can't be served, e.g. when the modules they
+//! import changed, or when they are concatenated into other modules, are built again in place.
+
 use std::{
   path::PathBuf,
   sync::{
@@ -14,10 +21,11 @@ mod snapshot;
 mod storage;
 pub use local::*;
 use occasion::{
-  BuildModuleOccasion, CodeGenerateOccasion, CreateChunkAssetsOccasion, ResolveModuleOccasion,
+  AnalyzeModuleOccasion, BuildModuleOccasion, CodeGenerateOccasion, CreateChunkAssetsOccasion,
+  ResolveModuleOccasion,
 };
 use snapshot::SnapshotManager;
-use storage::new_storage;
+use storage::{new_persistent_storage, new_storage};
 
 #[derive(Debug)]
 pub struct Cache {
@@ -25,6 +33,7 @@ pub struct Cache {
   snapshot_manager: Arc<SnapshotManager>,
   pub resolve_module_occasion: ResolveModuleOccasion,
   pub build_module_occasion: BuildModuleOccasion,
+  pub analyze_module_occasion: AnalyzeModuleOccasion,
   pub code_generate_occasion: CodeGenerateOccasion,
   pub create_chunk_assets_occasion: CreateChunkAssetsOccasion,
 }
@@ -32,18 +41,30 @@ pub struct Cache {
 impl Cache {
   pub fn new(options: Arc<CompilerOptions>) -> Self {
     let snapshot_manager = Arc::new(SnapshotManager::new(options.snapshot.clone()));
+    let context = &options.context;
     Self {
       is_idle: true.into(),
       snapshot_manager: snapshot_manager.clone(),
       resolve_module_occasion: ResolveModuleOccasion::new(
-        new_storage(&options.cache),
+        new_persistent_storage(&options.cache, context, "resolve-module"),
         snapshot_manager.clone(),
+        &options.resolve,
       ),
       build_module_occasion: BuildModuleOccasion::new(
-        new_storage(&options.cache),
+        new_persistent_storage(&options.cache, context, "build-module"),
+        options.clone(),
         snapshot_manager,
       ),
-      code_generate_occasion: CodeGenerateOccasion::new(new_storage(&options.cache)),
+      analyze_module_occasion: AnalyzeModuleOccasion::new(new_persistent_storage(
+        &options.cache,
+        context,
+        "analyze-module",
+      )),
+      code_generate_occasion: CodeGenerateOccasion::new(new_persistent_storage(
+        &options.cache,
+        context,
+        "code-generate",
+      )),
       create_chunk_assets_occasion: CreateChunkAssetsOccasion::new(new_storage(&options.cache)),
     }
   }
@@ -59,6 +80,10 @@ impl Cache {
       .is_ok()
     {
       self.snapshot_manager.clear();
+      self.resolve_module_occasion.begin_idle();
+      self.build_module_occasion.begin_idle();
+      self.analyze_module_occasion.begin_idle();
+      self.code_generate_occasion.begin_idle();
     }
   }
 
@@ -0,0 +1,160 @@
+use rspack_identifier::Identifier;
+use rspack_symbol::BetterId;
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
+use serde::{Deserialize, Serialize};
+use swc_core::ecma::atoms::JsWord;
+
+use crate::{
+  cache::storage::{self, Persistable},
+  tree_shaking::{
+    visitor::{OptimizeAnalyzeResult, SymbolRef},
+    BailoutFlag, SideEffectType,
+  },
+  BoxModule, ModuleSyntax,
+};
+
+type Storage = dyn storage::Storage<(u64, OptimizeAnalyzeResult)>;
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PersistedAnalyzeResult {
+  module_identifier: Identifier,
+  export_map: HashMap<JsWord, SymbolRef>,
+  import_map: Vec<(BetterId, SymbolRef)>,
+  inherit_export_maps: Vec<(Identifier, HashMap<JsWord, SymbolRef>)>,
+  reachable_import_of_export: HashMap<JsWord, HashSet<SymbolRef>>,
+  used_symbol_refs: HashSet<SymbolRef>,
+  bail_out_module_identifiers: Vec<(Identifier, u8)>,
+  /// `Some` if the side effects are from the configuration, `None` if they are analyzed
+  side_effects_configuration: Option<bool>,
+  side_effects: bool,
+  module_syntax: u8,
+  commonjs_es_module: bool,
+  inner_graph: Vec<(BetterId, HashSet<SymbolRef>)>,
+}
+
+/// The marks and the state of the analyzer only make sense for the ast they are created from,
+/// they are not persisted.
+impl Persistable for (u64, OptimizeAnalyzeResult) {
+  fn persist(&self) -> Option<serde_json::Value> {
+    let (fingerprint, result) = self;
+    let (side_effects_configuration, side_effects) = match result.side_effects {
+      SideEffectType::Configuration(side_effects) => (Some(side_effects), side_effects),
+      SideEffectType::Analyze(side_effects) => (None, side_effects),
+    };
+    let persisted = PersistedAnalyzeResult {
+      module_identifier: result.module_identifier,
+      export_map: result.export_map.clone(),
+      import_map: result
+        .import_map
+        .iter()
+        .map(|(id, symbol)| (id.clone(), symbol.clone()))
+        .collect(),
+      inherit_export_maps: result
+        .inherit_export_maps
+        .iter()
+        .map(|(module_identifier, export_map)| (*module_identifier, export_map.clone()))
+        .collect(),
+      reachable_import_of_export: result.reachable_import_of_export.clone(),
+      used_symbol_refs: result.used_symbol_refs.clone(),
+      bail_out_module_identifiers: result
+        .bail_out_module_identifiers
+        .iter()
+        .map(|(module_identifier, flag)| (*module_identifier, flag.bits()))
+        .collect(),
+      side_effects_configuration,
+      side_effects,
+      module_syntax: result.module_syntax.bits(),
+      commonjs_es_module: result.commonjs_es_module,
+      inner_graph: result
+        .inner_graph
+        .iter()
+        .map(|(id, symbols)| (id.clone(), symbols.clone()))
+        .collect(),
+    };
+    serde_json::to_value((fingerprint, persisted)).ok()
+  }
+
+  fn restore(value: serde_json::Value) -> Option<Self> {
+    let (fingerprint, persisted) =
+      serde_json::from_value::<(u64, PersistedAnalyzeResult)>(value).ok()?;
+    let mut result = OptimizeAnalyzeResult::default();
+    result.module_identifier = persisted.module_identifier;
+    result.export_map = persisted.export_map;
+    result.import_map = persisted.import_map.into_iter().collect();
+    result.inherit_export_maps = persisted.inherit_export_maps.into_iter().collect();
+    result.reachable_import_of_export = persisted.reachable_import_of_export;
+    result.used_symbol_refs = persisted.used_symbol_refs;
+    for (module_identifier, bits) in persisted.bail_out_module_identifiers {
+      result
+        .bail_out_module_identifiers
+        .insert(module_identifier, BailoutFlag::from_bits(bits)?);
+    }
+    result.side_effects = match persisted.side_effects_configuration {
+      Some(side_effects) => SideEffectType::Configuration(side_effects),
+      None => SideEffectType::Analyze(persisted.side_effects),
+    };
+    result.module_syntax = ModuleSyntax::from_bits(persisted.module_syntax)?;
+    result.commonjs_es_module = persisted.commonjs_es_module;
+    result.inner_graph = persisted.inner_graph.into_iter().collect();
+    Some((fingerprint, result))
+  }
+}
+
+/// Caches the tree shaking analysis of javascript modules, which is the only part of tree shaking
+/// reading the ast, so that modules restored from the cache don't need to be built for it.
+#[derive(Debug)]
+pub struct AnalyzeModuleOccasion {
+  storage: Option<Box<Storage>>,
+}
+
+impl AnalyzeModuleOccasion {
+  pub fn new(storage: Option<Box<Storage>>) -> Self {
+    Self { storage }
+  }
+
+  pub fn begin_idle(&self) {
+    if let Some(s) = self.storage.as_ref() {
+      s.begin_idle();
+    }
+  }
+
+  /// Whether the analysis of the unbuilt module can be served from the cache, the `fingerprint`
+  /// covers everything the analysis reads besides the ast, e.g. the modules the dependencies resolved to.
+  pub fn has_cache(&self, id: &Identifier, fingerprint: u64) -> bool {
+    match &self.storage {
+      Some(s) => s.get(id).map_or(false, |(cached_fingerprint, _)| {
+        cached_fingerprint == fingerprint
+      }),
+      None => false,
+    }
+  }
+
+  pub fn use_cache<G>(
+    &self,
+    module: &BoxModule,
+    fingerprint: u64,
+    generator: G,
+  ) -> Option<OptimizeAnalyzeResult>
+  where
+    G: FnOnce(&BoxModule) -> Option<OptimizeAnalyzeResult>,
+  {
+    let storage = match &self.storage {
+      Some(s) => s,
+      // no cache return directly
+      None => return generator(module),
+    };
+
+    let id = module.identifier();
+    if !module.is_built() {
+      return storage.get(&id).and_then(|(cached_fingerprint, result)| {
+        (cached_fingerprint == fingerprint).then_some(result)
+      });
+    }
+
+    // run generator and save to cache
+    let result = generator(module)?;
+    storage.set(id, (fingerprint, result.clone()));
+    Some(result)
+  }
+}
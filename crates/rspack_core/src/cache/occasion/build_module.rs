@@ -1,31 +1,322 @@
-use std::{path::Path, sync::Arc};
+use std::{collections::BTreeMap, path::Path, path::PathBuf, sync::Arc};
 
 use futures::Future;
-use rspack_error::{Result, TWithDiagnosticArray};
+use rspack_error::{IntoTWithDiagnosticArray, Result, TWithDiagnosticArray};
 use rspack_identifier::Identifier;
+use serde::{Deserialize, Serialize};
+use swc_core::ecma::atoms::JsWord;
 
 use crate::{
   cache::snapshot::{Snapshot, SnapshotManager},
-  cache::storage,
-  BoxModule, BuildResult,
+  cache::storage::{self, Persistable},
+  BoxModule, BuildInfo, BuildMeta, BuildMetaDefaultObject, BuildMetaExportsType, BuildResult,
+  ChunkGroupOptions, CompilerOptions, Context, Dependency, DependencyCategory, DependencyId,
+  DependencyType, ErrorSpan, ImportAttributes, ModuleDependency,
 };
 
 type Storage = dyn storage::Storage<(Snapshot, TWithDiagnosticArray<BuildResult>)>;
 
+/// A module dependency restored from the persistent cache.
+///
+/// It only keeps what is needed to build the module graph, the code generation of
+/// a restored module is served by [super::CodeGenerateOccasion], or it's built again.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RestoredModuleDependency {
+  #[serde(skip)]
+  id: DependencyId,
+  request: String,
+  user_request: String,
+  category: DependencyCategory,
+  dependency_type: DependencyType,
+  context: Option<String>,
+  #[serde(skip)]
+  context_cache: Option<Context>,
+  span: Option<ErrorSpan>,
+  weak: bool,
+  optional: bool,
+  is_async: bool,
+  chunk_name: Option<String>,
+  prefetch_order: Option<i32>,
+  preload_order: Option<i32>,
+  #[serde(skip)]
+  group_options: Option<ChunkGroupOptions>,
+  attributes: Option<ImportAttributes>,
+  referenced_exports: Option<Vec<JsWord>>,
+  export_all: bool,
+}
+
+impl RestoredModuleDependency {
+  fn from_dependency(dep: &dyn ModuleDependency) -> Option<Self> {
+    // context options and entry options carry regex and output options, they are not persisted
+    if dep.options().is_some() {
+      return None;
+    }
+    let group_options = match dep.group_options() {
+      Some(options) if options.entry_options.is_some() => return None,
+      options => options.cloned(),
+    };
+    Some(Self {
+      id: DependencyId::new(),
+      request: dep.request().to_string(),
+      user_request: dep.user_request().to_string(),
+      category: *dep.category(),
+      dependency_type: dep.dependency_type().clone(),
+      context: dep.get_context().map(|c| c.as_str().to_string()),
+      context_cache: None,
+      span: dep.span().copied(),
+      weak: dep.weak(),
+      optional: dep.get_optional(),
+      is_async: group_options.is_some(),
+      chunk_name: group_options
+        .as_ref()
+        .and_then(|options| options.name.clone()),
+      prefetch_order: group_options
+        .as_ref()
+        .and_then(|options| options.prefetch_order),
+      preload_order: group_options
+        .as_ref()
+        .and_then(|options| options.preload_order),
+      group_options,
+      attributes: dep.get_attributes().cloned(),
+      referenced_exports: dep.get_referenced_exports().map(|exports| exports.to_vec()),
+      export_all: dep.is_export_all(),
+    })
+  }
+
+  fn restored(mut self) -> Self {
+    self.id = DependencyId::new();
+    self.context_cache = self.context.clone().map(Context::from);
+    if self.is_async {
+      self.group_options = Some(ChunkGroupOptions {
+        name: self.chunk_name.clone(),
+        entry_options: None,
+        prefetch_order: self.prefetch_order,
+        preload_order: self.preload_order,
+      });
+    }
+    self
+  }
+}
+
+impl Dependency for RestoredModuleDependency {
+  fn category(&self) -> &DependencyCategory {
+    &self.category
+  }
+
+  fn dependency_type(&self) -> &DependencyType {
+    &self.dependency_type
+  }
+
+  fn get_context(&self) -> Option<&Context> {
+    self.context_cache.as_ref()
+  }
+}
+
+impl ModuleDependency for RestoredModuleDependency {
+  fn id(&self) -> &DependencyId {
+    &self.id
+  }
+
+  fn request(&self) -> &str {
+    &self.request
+  }
+
+  fn user_request(&self) -> &str {
+    &self.user_request
+  }
+
+  fn span(&self) -> Option<&ErrorSpan> {
+    self.span.as_ref()
+  }
+
+  fn weak(&self) -> bool {
+    self.weak
+  }
+
+  fn get_optional(&self) -> bool {
+    self.optional
+  }
+
+  fn group_options(&self) -> Option<&ChunkGroupOptions> {
+    self.group_options.as_ref()
+  }
+
+  fn set_request(&mut self, request: String) {
+    self.request = request;
+  }
+
+  fn get_attributes(&self) -> Option<&ImportAttributes> {
+    self.attributes.as_ref()
+  }
+
+  fn get_referenced_exports(&self) -> Option<&[JsWord]> {
+    self.referenced_exports.as_deref()
+  }
+
+  fn is_export_all(&self) -> bool {
+    self.export_all
+  }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PersistedBuildResult {
+  strict: bool,
+  hash: Option<String>,
+  file_dependencies: Vec<PathBuf>,
+  context_dependencies: Vec<PathBuf>,
+  missing_dependencies: Vec<PathBuf>,
+  build_dependencies: Vec<PathBuf>,
+  asset_filenames: Vec<String>,
+  value_dependencies: BTreeMap<String, String>,
+  module_concatenation_bailout: Option<String>,
+  meta_strict: bool,
+  strict_harmony_module: bool,
+  is_async: bool,
+  esm: bool,
+  exports_type: BuildMetaExportsType,
+  default_object: BuildMetaDefaultObject,
+  module_argument: String,
+  exports_argument: String,
+  dependencies: Vec<RestoredModuleDependency>,
+}
+
+/// `BuildMeta` use static str for arguments, map the persisted one back to the known arguments.
+fn restore_argument(argument: &str, fallback: &'static str) -> &'static str {
+  match argument {
+    "module" => "module",
+    "exports" => "exports",
+    "__webpack_module__" => "__webpack_module__",
+    "__webpack_exports__" => "__webpack_exports__",
+    _ => fallback,
+  }
+}
+
+impl Persistable for (Snapshot, TWithDiagnosticArray<BuildResult>) {
+  fn persist(&self) -> Option<serde_json::Value> {
+    let (snapshot, result) = self;
+    // diagnostics are not persisted, modules with warnings should be rebuilt to report them
+    if !result.diagnostic.is_empty() {
+      return None;
+    }
+    let BuildResult {
+      build_meta,
+      build_info,
+      dependencies,
+    } = &result.inner;
+    let dependencies = dependencies
+      .iter()
+      .map(|dep| RestoredModuleDependency::from_dependency(dep.as_ref()))
+      .collect::<Option<Vec<_>>>()?;
+
+    let persisted = PersistedBuildResult {
+      strict: build_info.strict,
+      hash: build_info
+        .hash
+        .as_ref()
+        .map(|hash| hash.encoded().to_string()),
+      file_dependencies: build_info.file_dependencies.iter().cloned().collect(),
+      context_dependencies: build_info.context_dependencies.iter().cloned().collect(),
+      missing_dependencies: build_info.missing_dependencies.iter().cloned().collect(),
+      build_dependencies: build_info.build_dependencies.iter().cloned().collect(),
+      asset_filenames: build_info.asset_filenames.iter().cloned().collect(),
+      value_dependencies: build_info.value_dependencies.clone(),
+      module_concatenation_bailout: build_info.module_concatenation_bailout.clone(),
+      meta_strict: build_meta.strict,
+      strict_harmony_module: build_meta.strict_harmony_module,
+      is_async: build_meta.is_async,
+      esm: build_meta.esm,
+      exports_type: build_meta.exports_type.clone(),
+      default_object: build_meta.default_object.clone(),
+      module_argument: build_meta.module_argument.to_string(),
+      exports_argument: build_meta.exports_argument.to_string(),
+      dependencies,
+    };
+    serde_json::to_value((snapshot, persisted)).ok()
+  }
+
+  fn restore(value: serde_json::Value) -> Option<Self> {
+    let (snapshot, persisted) =
+      serde_json::from_value::<(Snapshot, PersistedBuildResult)>(value).ok()?;
+    let build_info = BuildInfo {
+      cacheable: true,
+      hash: persisted.hash.as_deref().map(Into::into),
+      strict: persisted.strict,
+      file_dependencies: persisted.file_dependencies.into_iter().collect(),
+      context_dependencies: persisted.context_dependencies.into_iter().collect(),
+      missing_dependencies: persisted.missing_dependencies.into_iter().collect(),
+      build_dependencies: persisted.build_dependencies.into_iter().collect(),
+      asset_filenames: persisted.asset_filenames.into_iter().collect(),
+      value_dependencies: persisted.value_dependencies,
+      module_concatenation_bailout: persisted.module_concatenation_bailout,
+    };
+    let build_meta = BuildMeta {
+      strict: persisted.meta_strict,
+      strict_harmony_module: persisted.strict_harmony_module,
+      is_async: persisted.is_async,
+      esm: persisted.esm,
+      exports_type: persisted.exports_type,
+      default_object: persisted.default_object,
+      module_argument: restore_argument(&persisted.module_argument, "module"),
+      exports_argument: restore_argument(&persisted.exports_argument, "exports"),
+    };
+    let dependencies = persisted
+      .dependencies
+      .into_iter()
+      .map(|dep| Box::new(dep.restored()) as Box<dyn ModuleDependency>)
+      .collect();
+    Some((
+      snapshot,
+      BuildResult {
+        build_meta,
+        build_info,
+        dependencies,
+      }
+      .with_empty_diagnostic(),
+    ))
+  }
+}
+
 #[derive(Debug)]
 pub struct BuildModuleOccasion {
   storage: Option<Box<Storage>>,
+  options: Arc<CompilerOptions>,
   snapshot_manager: Arc<SnapshotManager>,
 }
 
 impl BuildModuleOccasion {
-  pub fn new(storage: Option<Box<Storage>>, snapshot_manager: Arc<SnapshotManager>) -> Self {
+  pub fn new(
+    storage: Option<Box<Storage>>,
+    options: Arc<CompilerOptions>,
+    snapshot_manager: Arc<SnapshotManager>,
+  ) -> Self {
     Self {
       storage,
+      options,
       snapshot_manager,
     }
   }
 
+  /// The runtime values of `builtins.define` used by the module are unchanged,
+  /// they may differ from the ones of the process which persisted the build result.
+  fn check_value_dependencies_valid(&self, build_info: &BuildInfo) -> bool {
+    if build_info.value_dependencies.is_empty() {
+      return true;
+    }
+    let definitions = self.options.builtins.definitions();
+    build_info
+      .value_dependencies
+      .iter()
+      .all(|(key, value)| definitions.get(key) == Some(value))
+  }
+
+  pub fn begin_idle(&self) {
+    if let Some(s) = self.storage.as_ref() {
+      s.begin_idle();
+    }
+  }
+
   pub fn remove_cache(&self, id: &Identifier) {
     if let Some(s) = self.storage.as_ref() {
       s.remove(id);
@@ -47,27 +338,23 @@ impl BuildModuleOccasion {
       None => return Ok((Ok(generator(module).await?), false)),
     };
 
-    let mut need_cache = false;
     let id = module.identifier().to_owned();
-    if module.as_normal_module().is_some() {
-      // normal module
-      // TODO cache all module type
-      if let Some((snapshot, data)) = storage.get(&id) {
-        let valid = self
-          .snapshot_manager
-          .check_snapshot_valid(&snapshot)
-          .await
-          .unwrap_or(false);
-        if valid {
-          return Ok((Ok(data), true));
-        }
-      };
-      need_cache = true;
-    }
+    if let Some((snapshot, data)) = storage.get(&id) {
+      let valid = self
+        .snapshot_manager
+        .check_snapshot_valid(&snapshot)
+        .await
+        .unwrap_or(false)
+        && self.check_value_dependencies_valid(&data.inner.build_info);
+      // an unbuilt module is code generated from cache, or built again in place when the ast is needed
+      if valid {
+        return Ok((Ok(data), true));
+      }
+    };
 
     // run generator and save to cache
     let data = generator(module).await?;
-    if need_cache && data.inner.build_info.cacheable {
+    if data.inner.build_info.cacheable {
       let mut paths: Vec<&Path> = Vec::new();
       paths.extend(
         data
@@ -1,8 +1,198 @@
-use rspack_error::Result;
+use rspack_error::{internal_error, Result};
+use rspack_identifier::Identifier;
+use rspack_sources::{
+  MapOptions, RawSource, Source, SourceExt, SourceMap, SourceMapSource, WithoutOriginalOptions,
+};
+use serde::{Deserialize, Serialize};
 
-use crate::{cache::storage, BoxModule, CodeGenerationResult, NormalModuleAstOrSource};
+use crate::{
+  cache::storage::{self, Persistable},
+  AssetInfo, AstOrSource, BoxModule, CodeGenerationDataAssetInfo, CodeGenerationDataFilename,
+  CodeGenerationDataUrl, CodeGenerationResult, GenerationResult, InitFragment, InitFragmentStage,
+  RuntimeGlobals, SourceType,
+};
 
-type Storage = dyn storage::Storage<CodeGenerationResult>;
+type Storage = dyn storage::Storage<(u64, CodeGenerationResult)>;
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PersistedSource {
+  source_type: String,
+  source: String,
+  map: Option<String>,
+  /// base64 encoded buffer of binary sources
+  buffer: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PersistedInitFragment {
+  key: String,
+  content: String,
+  stage: u8,
+  end_content: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PersistedCodeGenerationResult {
+  sources: Vec<PersistedSource>,
+  url: Option<String>,
+  filename: Option<String>,
+  asset_info: Option<AssetInfo>,
+  chunk_init_fragments: Vec<PersistedInitFragment>,
+  runtime_requirements: u64,
+  hash: Option<String>,
+}
+
+fn source_type_to_str(source_type: &SourceType) -> &'static str {
+  match source_type {
+    SourceType::JavaScript => "javascript",
+    SourceType::Css => "css",
+    SourceType::Wasm => "wasm",
+    SourceType::Asset => "asset",
+    SourceType::Remote => "remote",
+    SourceType::ShareInit => "share-init",
+    SourceType::ConsumeShared => "consume-shared",
+    SourceType::Unknown => "unknown",
+  }
+}
+
+/// Sources of these types are buffers, e.g. wasm binaries and images.
+fn is_binary_source_type(source_type: &SourceType) -> bool {
+  matches!(source_type, SourceType::Wasm | SourceType::Asset)
+}
+
+fn source_type_from_str(source_type: &str) -> SourceType {
+  match source_type {
+    "javascript" => SourceType::JavaScript,
+    "css" => SourceType::Css,
+    "wasm" => SourceType::Wasm,
+    "asset" => SourceType::Asset,
+    "remote" => SourceType::Remote,
+    "share-init" => SourceType::ShareInit,
+    "consume-shared" => SourceType::ConsumeShared,
+    _ => SourceType::Unknown,
+  }
+}
+
+impl Persistable for (u64, CodeGenerationResult) {
+  fn persist(&self) -> Option<serde_json::Value> {
+    let (fingerprint, result) = self;
+    let mut sources = vec![];
+    for (source_type, generation_result) in result.inner() {
+      // ast only results are not supported
+      let source = generation_result.ast_or_source.as_source()?;
+      let persisted = if is_binary_source_type(source_type) {
+        PersistedSource {
+          source_type: source_type_to_str(source_type).to_string(),
+          source: String::new(),
+          map: None,
+          buffer: Some(rspack_base64::encode_to_string(source.buffer())),
+        }
+      } else {
+        PersistedSource {
+          source_type: source_type_to_str(source_type).to_string(),
+          source: source.source().to_string(),
+          map: source
+            .map(&MapOptions::default())
+            .and_then(|map| map.to_json().ok()),
+          buffer: None,
+        }
+      };
+      sources.push(persisted);
+    }
+
+    let url = result.data.get::<CodeGenerationDataUrl>();
+    let filename = result.data.get::<CodeGenerationDataFilename>();
+    let asset_info = result.data.get::<CodeGenerationDataAssetInfo>();
+    let known_data_count = [url.is_some(), filename.is_some(), asset_info.is_some()]
+      .iter()
+      .filter(|known| **known)
+      .count();
+    if result.data.len() != known_data_count {
+      // unknown data set by plugins can't be persisted
+      return None;
+    }
+
+    let persisted = PersistedCodeGenerationResult {
+      sources,
+      url: url.map(|url| url.inner().to_string()),
+      filename: filename.map(|filename| filename.inner().to_string()),
+      asset_info: asset_info.map(|asset_info| asset_info.inner().clone()),
+      chunk_init_fragments: result
+        .chunk_init_fragments
+        .iter()
+        .map(|(key, fragment)| PersistedInitFragment {
+          key: key.clone(),
+          content: fragment.content.clone(),
+          stage: fragment.stage.bits(),
+          end_content: fragment.end_content.clone(),
+        })
+        .collect(),
+      runtime_requirements: result.runtime_requirements.bits(),
+      hash: result.hash.as_ref().map(|hash| hash.encoded().to_string()),
+    };
+    serde_json::to_value((fingerprint, persisted)).ok()
+  }
+
+  fn restore(value: serde_json::Value) -> Option<Self> {
+    let (fingerprint, persisted) =
+      serde_json::from_value::<(u64, PersistedCodeGenerationResult)>(value).ok()?;
+    let mut result = CodeGenerationResult::default();
+    for PersistedSource {
+      source_type,
+      source,
+      map,
+      buffer,
+    } in persisted.sources
+    {
+      let source = if let Some(buffer) = buffer {
+        RawSource::from(rspack_base64::decode_to_vec(buffer).ok()?).boxed()
+      } else {
+        match map.and_then(|map| SourceMap::from_json(&map).ok()) {
+          Some(source_map) => SourceMapSource::new(WithoutOriginalOptions {
+            value: source,
+            name: "",
+            source_map,
+          })
+          .boxed(),
+          None => RawSource::from(source).boxed(),
+        }
+      };
+      result.add(
+        source_type_from_str(&source_type),
+        GenerationResult::from(AstOrSource::from(source)),
+      );
+    }
+    if let Some(url) = persisted.url {
+      result.data.insert(CodeGenerationDataUrl::new(url));
+    }
+    if let Some(filename) = persisted.filename {
+      result
+        .data
+        .insert(CodeGenerationDataFilename::new(filename));
+    }
+    if let Some(asset_info) = persisted.asset_info {
+      result
+        .data
+        .insert(CodeGenerationDataAssetInfo::new(asset_info));
+    }
+    for fragment in persisted.chunk_init_fragments {
+      result.chunk_init_fragments.insert(
+        fragment.key,
+        InitFragment::new(
+          fragment.content,
+          InitFragmentStage::from_bits(fragment.stage)?,
+          fragment.end_content,
+        ),
+      );
+    }
+    result.runtime_requirements = RuntimeGlobals::from_bits(persisted.runtime_requirements)?;
+    result.hash = persisted.hash.as_deref().map(Into::into);
+    Some((fingerprint, result))
+  }
+}
 
 #[derive(Debug)]
 pub struct CodeGenerateOccasion {
@@ -14,10 +204,35 @@ impl CodeGenerateOccasion {
     Self { storage }
   }
 
-  #[allow(clippy::unwrap_in_result)]
+  pub fn begin_idle(&self) {
+    if let Some(s) = self.storage.as_ref() {
+      s.begin_idle();
+    }
+  }
+
+  /// Whether the code generation result of the module is cached, modules without it can't skip the build.
+  pub fn has_cache(&self, id: &Identifier) -> bool {
+    match &self.storage {
+      Some(s) => s.contains(id),
+      None => false,
+    }
+  }
+
+  /// Whether the cached code generation result of the unbuilt module is still valid, the `fingerprint`
+  /// covers the tree shaking results read by the code generation, modules without it are built again in place.
+  pub fn has_valid_cache(&self, id: &Identifier, fingerprint: u64) -> bool {
+    match &self.storage {
+      Some(s) => s.get(id).map_or(false, |(cached_fingerprint, _)| {
+        cached_fingerprint == fingerprint
+      }),
+      None => false,
+    }
+  }
+
   pub fn use_cache<'a, G>(
     &self,
     module: &'a BoxModule,
+    fingerprint: u64,
     generator: G,
   ) -> Result<CodeGenerationResult>
   where
@@ -29,26 +244,27 @@ impl CodeGenerateOccasion {
       None => return generator(module),
     };
 
-    let mut need_cache = false;
+    // the result of a concatenated module depends on all the inner modules,
+    // and it shares the identifier with its root module
+    if module.get_concatenated_modules().is_some() {
+      return generator(module);
+    }
+
     let id = module.identifier();
-    if let Some(module) = module.as_normal_module() {
-      // only cache normal module
-      // TODO cache all module type
-      if matches!(module.ast_or_source(), NormalModuleAstOrSource::Unbuild) {
-        if let Some(data) = storage.get(&id) {
-          return Ok(data);
-        }
-        // unbuild and no cache is unexpected
-        panic!("unexpected unbuild module");
-      }
-      need_cache = true;
+    if !module.is_built() {
+      // unbuilt modules without a valid cached result are built before code generation
+      return storage
+        .get(&id)
+        .filter(|(cached_fingerprint, _)| *cached_fingerprint == fingerprint)
+        .map(|(_, data)| data)
+        .ok_or_else(|| {
+          internal_error!("Unbuilt module {id} has no valid cached code generation result")
+        });
     }
 
     // run generator and save to cache
     let data = generator(module)?;
-    if need_cache {
-      storage.set(id, data.clone());
-    }
+    storage.set(id, (fingerprint, data.clone()));
     Ok(data)
   }
 }
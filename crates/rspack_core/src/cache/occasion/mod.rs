@@ -2,6 +2,8 @@ mod resolve_module;
 pub use resolve_module::*;
 mod build_module;
 pub use build_module::*;
+mod analyze_module;
+pub use analyze_module::*;
 mod code_generate;
 pub use code_generate::*;
 mod create_chunk_assets;
@@ -1,33 +1,162 @@
-use std::sync::Arc;
+use std::{
+  path::{Path, PathBuf},
+  sync::Arc,
+};
 
 use futures::Future;
-use rspack_identifier::Identifier;
+use serde::{Deserialize, Serialize};
 
 use crate::{
   cache::snapshot::{Snapshot, SnapshotManager},
-  cache::storage,
-  ModuleIdentifier, ResolveArgs, ResolveError, ResolveResult,
+  cache::storage::{self, Persistable},
+  calc_hash, ModuleIdentifier, Resolve, ResolveArgs, ResolveError, ResolveResult, ResolverFactory,
 };
 
-type Storage = dyn storage::Storage<(Snapshot, ResolveResult)>;
+type Storage = dyn storage::Storage<ResolveCacheItem>;
+
+/// A resolve result with the snapshot of the files and directories it depends on.
+#[derive(Debug, Clone)]
+pub struct ResolveCacheItem {
+  snapshot: Snapshot,
+  result: ResolveResult,
+  /// The description data is not persisted, it's loaded again by the resolver when the result
+  /// is restored from the filesystem.
+  has_description: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+enum PersistedResolveResult {
+  Resource {
+    path: PathBuf,
+    query: Option<String>,
+    fragment: Option<String>,
+    #[serde(default)]
+    has_description: bool,
+  },
+  Ignored,
+}
+
+impl Persistable for ResolveCacheItem {
+  fn persist(&self) -> Option<serde_json::Value> {
+    let result = match &self.result {
+      ResolveResult::Resource(resource) => PersistedResolveResult::Resource {
+        path: resource.path.clone(),
+        query: resource.query.clone(),
+        fragment: resource.fragment.clone(),
+        has_description: self.has_description,
+      },
+      ResolveResult::Ignored => PersistedResolveResult::Ignored,
+    };
+    serde_json::to_value((&self.snapshot, result)).ok()
+  }
+
+  fn restore(value: serde_json::Value) -> Option<Self> {
+    let (snapshot, result) =
+      serde_json::from_value::<(Snapshot, PersistedResolveResult)>(value).ok()?;
+    let (result, has_description) = match result {
+      PersistedResolveResult::Resource {
+        path,
+        query,
+        fragment,
+        has_description,
+      } => (
+        ResolveResult::Resource(nodejs_resolver::Resource {
+          path,
+          query,
+          fragment,
+          description: None,
+        }),
+        has_description,
+      ),
+      PersistedResolveResult::Ignored => (ResolveResult::Ignored, false),
+    };
+    Some(Self {
+      snapshot,
+      result,
+      has_description,
+    })
+  }
+}
+
+/// Paths which the resolve result depends on:
+///
+/// - the resolved file
+/// - the description file of the package containing it
+/// - the directory of the resolved file, whose entries decide the extension and main files
+/// - the `node_modules` directories looked up for a module request, or their parent directories
+///   if they don't exist yet, up to the one containing the result or the package root of the
+///   context when the result is not in a `node_modules`
+fn snapshot_paths(context: &Path, specifier: &str, result: &ResolveResult) -> Vec<PathBuf> {
+  let ResolveResult::Resource(resource) = result else {
+    return vec![];
+  };
+  let mut paths = vec![resource.path.clone()];
+  if let Some(description) = &resource.description {
+    paths.push(Path::join(description.dir().as_ref(), "package.json"));
+  }
+  if let Some(parent) = resource.path.parent() {
+    paths.push(parent.to_path_buf());
+  }
+  let is_module_request = !(specifier.starts_with('.')
+    || specifier.starts_with('/')
+    || Path::new(specifier).is_absolute());
+  if is_module_request {
+    let last = context
+      .ancestors()
+      .find(|dir| resource.path.starts_with(dir.join("node_modules")))
+      .or_else(|| {
+        context
+          .ancestors()
+          .find(|dir| dir.join("package.json").is_file())
+      })
+      .unwrap_or(context);
+    for dir in context.ancestors() {
+      let node_modules = dir.join("node_modules");
+      if node_modules.is_dir() {
+        paths.push(node_modules);
+      } else {
+        paths.push(dir.to_path_buf());
+      }
+      if dir == last {
+        break;
+      }
+    }
+  }
+  paths
+}
 
 #[derive(Debug)]
 pub struct ResolveModuleOccasion {
   storage: Option<Box<Storage>>,
   snapshot_manager: Arc<SnapshotManager>,
+  /// Hash of the base resolve options, the cache entries are scoped by it
+  options_hash: u64,
 }
 
 impl ResolveModuleOccasion {
-  pub fn new(storage: Option<Box<Storage>>, snapshot_manager: Arc<SnapshotManager>) -> Self {
+  pub fn new(
+    storage: Option<Box<Storage>>,
+    snapshot_manager: Arc<SnapshotManager>,
+    options: &Resolve,
+  ) -> Self {
     Self {
       storage,
       snapshot_manager,
+      options_hash: calc_hash(options),
+    }
+  }
+
+  pub fn begin_idle(&self) {
+    if let Some(s) = self.storage.as_ref() {
+      s.begin_idle();
     }
   }
 
   pub async fn use_cache<'a, G, F>(
     &self,
     args: ResolveArgs<'a>,
+    resolver_factory: &ResolverFactory,
     generator: G,
   ) -> Result<ResolveResult, ResolveError>
   where
@@ -40,44 +169,180 @@ impl ResolveModuleOccasion {
       None => return generator(args).await,
     };
 
+    let options_hash = calc_hash(&(
+      self.options_hash,
+      &args.resolve_options,
+      args.resolve_to_context,
+      args.dependency_category,
+    ));
     let id = ModuleIdentifier::from(format!(
-      "{:?}|{}|{}|{:?}",
-      args.context,
-      args
-        .importer
-        .copied()
-        .unwrap_or_else(|| Identifier::from("")),
-      args.specifier,
-      args.dependency_type
+      "{options_hash:016x}|{:?}|{:?}|{}|{:?}",
+      args.importer, args.context, args.specifier, args.dependency_type
     ));
     {
       // read
-      if let Some((snapshot, data)) = storage.get(&id) {
+      if let Some(mut item) = storage.get(&id) {
         let valid = self
           .snapshot_manager
-          .check_snapshot_valid(&snapshot)
+          .check_snapshot_valid(&item.snapshot)
           .await
           .unwrap_or(false);
 
         if valid {
-          return Ok(data);
+          if item.has_description
+            && let ResolveResult::Resource(resource) = &mut item.result
+            && resource.description.is_none()
+          {
+            resource.description = resolver_factory.load_description(&resource.path);
+            storage.set(id, item.clone());
+          }
+          return Ok(item.result);
         }
       };
     }
 
     // run generator and save to cache
-    let data = generator(args).await?;
-    let mut paths = Vec::new();
-    if let ResolveResult::Resource(resource) = &data {
-      paths.push(resource.path.as_path());
-    }
+    let context = args.context.clone();
+    let specifier = args.specifier;
+    let result = generator(args).await?;
+    let paths = snapshot_paths(context.as_ref(), specifier, &result);
+    let paths = paths.iter().map(PathBuf::as_path).collect::<Vec<_>>();
 
     let snapshot = self
       .snapshot_manager
       .create_snapshot(&paths, |option| &option.resolve)
       .await
       .map_err(|err| ResolveError(err.to_string(), err))?;
-    storage.set(id, (snapshot, data.clone()));
-    Ok(data)
+    let has_description =
+      matches!(&result, ResolveResult::Resource(resource) if resource.description.is_some());
+    storage.set(
+      id,
+      ResolveCacheItem {
+        snapshot,
+        result: result.clone(),
+        has_description,
+      },
+    );
+    Ok(result)
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use std::sync::atomic::{AtomicUsize, Ordering};
+
+  use rustc_hash::FxHashSet as HashSet;
+
+  use super::*;
+  use crate::{
+    CacheOptions, Context, DependencyCategory, DependencyType, MemoryCacheOptions, SnapshotOptions,
+  };
+
+  fn resource(path: PathBuf) -> ResolveResult {
+    ResolveResult::Resource(nodejs_resolver::Resource {
+      path,
+      query: None,
+      fragment: None,
+      description: None,
+    })
+  }
+
+  fn write_file(path: &Path) {
+    std::fs::create_dir_all(path.parent().expect("should have parent")).expect("should create dir");
+    std::fs::write(path, "").expect("should write file");
+  }
+
+  #[test]
+  fn should_stop_snapshot_at_package_root() {
+    let dir = crate::unique_temp_dir("rspack_resolve_snapshot_package_root_test");
+    let context = dir.join("pkg/src");
+    let file = context.join("aliased.js");
+    write_file(&dir.join("pkg/package.json"));
+    write_file(&file);
+
+    let paths = snapshot_paths(&context, "aliased", &resource(file.clone()));
+    assert_eq!(paths, vec![file, context.clone(), context, dir.join("pkg")]);
+
+    let _ = std::fs::remove_dir_all(&dir);
+  }
+
+  #[test]
+  fn should_snapshot_node_modules_up_to_the_result() {
+    let dir = crate::unique_temp_dir("rspack_resolve_snapshot_node_modules_test");
+    let context = dir.join("pkg/src");
+    let file = dir.join("node_modules/dep/index.js");
+    write_file(&dir.join("pkg/package.json"));
+    write_file(&context.join("index.js"));
+    write_file(&file);
+
+    let paths = snapshot_paths(&context, "dep", &resource(file.clone()));
+    assert_eq!(
+      paths,
+      vec![
+        file,
+        dir.join("node_modules/dep"),
+        context,
+        dir.join("pkg"),
+        dir.join("node_modules"),
+      ]
+    );
+
+    let _ = std::fs::remove_dir_all(&dir);
+  }
+
+  #[tokio::test]
+  async fn should_scope_cache_by_importer() {
+    let dir = crate::unique_temp_dir("rspack_resolve_cache_importer_test");
+    write_file(&dir.join("a.js"));
+    write_file(&dir.join("b.js"));
+    let occasion = ResolveModuleOccasion::new(
+      storage::new_storage(&CacheOptions::Memory(MemoryCacheOptions::default())),
+      Arc::new(SnapshotManager::new(SnapshotOptions::default())),
+      &Resolve::default(),
+    );
+    let resolver_factory = ResolverFactory::default();
+    let calls = AtomicUsize::new(0);
+    let context = Context::new(dir.to_string_lossy().to_string());
+
+    let resolve = |importer: &str| {
+      let importer = ModuleIdentifier::from(dir.join(importer).to_string_lossy().to_string());
+      let context = context.clone();
+      let resolver_factory = &resolver_factory;
+      let occasion = &occasion;
+      let calls = &calls;
+      let dir = &dir;
+      async move {
+        let mut file_dependencies = HashSet::default();
+        let mut missing_dependencies = HashSet::default();
+        let args = ResolveArgs {
+          importer: Some(&importer),
+          context,
+          specifier: "./a",
+          dependency_type: &DependencyType::EsmImport,
+          dependency_category: &DependencyCategory::Esm,
+          span: None,
+          resolve_options: None,
+          resolve_to_context: false,
+          optional: false,
+          file_dependencies: &mut file_dependencies,
+          missing_dependencies: &mut missing_dependencies,
+        };
+        let result = occasion
+          .use_cache(args, resolver_factory, |_| async {
+            calls.fetch_add(1, Ordering::Relaxed);
+            Ok(resource(dir.join("a.js")))
+          })
+          .await;
+        assert!(result.is_ok(), "should resolve");
+      }
+    };
+
+    resolve("index.js").await;
+    resolve("index.js").await;
+    assert_eq!(calls.load(Ordering::Relaxed), 1);
+    resolve("other.js").await;
+    assert_eq!(calls.load(Ordering::Relaxed), 2);
+
+    let _ = std::fs::remove_dir_all(&dir);
   }
 }
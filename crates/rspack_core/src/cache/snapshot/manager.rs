@@ -6,7 +6,7 @@ use std::{
 
 use dashmap::{DashMap, DashSet};
 use rspack_error::Result;
-use rustc_hash::{FxHashMap as HashMap, FxHasher};
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet, FxHasher};
 
 use super::Snapshot;
 use crate::{calc_hash, SnapshotOptions, SnapshotStrategy};
@@ -23,6 +23,8 @@ pub struct SnapshotManager {
   update_time_cache: DashMap<PathBuf, SystemTime, BuildHasherDefault<FxHasher>>,
   /// cache file hash
   hash_cache: DashMap<PathBuf, u64, BuildHasherDefault<FxHasher>>,
+  /// cache package version of managed paths
+  managed_version_cache: DashMap<PathBuf, Option<String>, BuildHasherDefault<FxHasher>>,
   /// has modified file
   modified_files: DashSet<PathBuf>,
 }
@@ -33,42 +35,99 @@ impl SnapshotManager {
       options,
       update_time_cache: Default::default(),
       hash_cache: Default::default(),
+      managed_version_cache: Default::default(),
       modified_files: Default::default(),
     }
   }
 
+  fn is_immutable_path(&self, path: &Path) -> bool {
+    self
+      .options
+      .immutable_paths
+      .iter()
+      .any(|immutable_path| path.starts_with(immutable_path))
+  }
+
+  /// Get the root of the package which contains the path, if the path is in managed paths.
+  /// e.g. `node_modules/@scope/pkg` for `node_modules/@scope/pkg/lib/index.js`
+  fn managed_package_root(&self, path: &Path) -> Option<PathBuf> {
+    self.options.managed_paths.iter().find_map(|managed_path| {
+      let mut components = path.strip_prefix(managed_path).ok()?.components();
+      let mut root = managed_path.join(components.next()?);
+      if root
+        .file_name()
+        .map_or(false, |name| name.to_string_lossy().starts_with('@'))
+      {
+        root.push(components.next()?);
+      }
+      // files directly in the package root are still in the package
+      components.next()?;
+      Some(root)
+    })
+  }
+
+  /// Read the version in `package.json` of the package root.
+  async fn managed_version(&self, package_root: &Path) -> Option<String> {
+    if let Some(version) = self.managed_version_cache.get(package_root) {
+      return version.clone();
+    }
+    let version = tokio::fs::read(package_root.join("package.json"))
+      .await
+      .ok()
+      .and_then(|content| serde_json::from_slice::<serde_json::Value>(&content).ok())
+      .and_then(|package_json| {
+        package_json
+          .get("version")
+          .and_then(|version| version.as_str())
+          .map(|version| version.to_string())
+      });
+    self
+      .managed_version_cache
+      .insert(package_root.to_owned(), version.clone());
+    version
+  }
+
   pub async fn create_snapshot<F>(&self, paths: &[&Path], f: F) -> Result<Snapshot>
   where
     F: FnOnce(&SnapshotOptions) -> &SnapshotStrategy,
   {
-    // TODO file_paths deduplication && calc immutable path
     let strategy = f(&self.options);
+    let mut managed_versions = HashMap::default();
+    let mut visited = HashSet::default();
+    let mut checked_paths = Vec::with_capacity(paths.len());
+    for &path in paths {
+      if !visited.insert(path) || self.is_immutable_path(path) {
+        continue;
+      }
+      if let Some(package_root) = self.managed_package_root(path) {
+        if managed_versions.contains_key(&package_root) {
+          continue;
+        }
+        // files in managed paths are validated by the version of their package
+        if let Some(version) = self.managed_version(&package_root).await {
+          managed_versions.insert(package_root, version);
+          continue;
+        }
+      }
+      checked_paths.push(path);
+    }
+
     let mut file_update_times = HashMap::default();
-    file_update_times.reserve(paths.len());
+    file_update_times.reserve(checked_paths.len());
     let mut file_hashes = HashMap::default();
-    file_hashes.reserve(paths.len());
+    file_hashes.reserve(checked_paths.len());
     if strategy.timestamp {
-      for &path in paths {
+      for &path in &checked_paths {
         file_update_times.insert(path.to_owned(), SystemTime::now());
       }
     }
     if strategy.hash {
       let hash_cache = &self.hash_cache;
-      for &path in paths {
+      for &path in &checked_paths {
         let hash = match hash_cache.get(path) {
           Some(hash) => *hash,
           None => {
-            let res = if path.is_dir() {
-              let dir = &mut tokio::fs::read_dir(path).await?;
-              let mut sub_files = vec![];
-              while let Some(entry) = dir.next_entry().await? {
-                let dir_u8 = entry.path().as_os_str().to_string_lossy().to_string();
-                sub_files.push(dir_u8);
-              }
-              calc_hash(&sub_files)
-            } else {
-              calc_hash(&tokio::fs::read(path).await?)
-            };
+            let res = calc_path_hash(path).await?;
             hash_cache.insert(path.to_owned(), res);
             res
           }
@@ -80,6 +139,7 @@ impl SnapshotManager {
     Ok(Snapshot {
       file_update_times,
       file_hashes,
+      managed_versions,
     })
   }
 
@@ -87,8 +147,14 @@ impl SnapshotManager {
     let Snapshot {
       file_update_times,
       file_hashes,
-      ..
+      managed_versions,
     } = snapshot;
+    for (package_root, snapshot_version) in managed_versions {
+      if self.managed_version(package_root).await.as_ref() != Some(snapshot_version) {
+        return Ok(false);
+      }
+    }
+
     if !file_update_times.is_empty() {
       // check update time
       let update_time_cache = &self.update_time_cache;
@@ -123,7 +189,7 @@ impl SnapshotManager {
         let current_hash = match hash_cache.get(path) {
           Some(h) => *h,
           None => {
-            let res = calc_hash(&tokio::fs::read(path).await?);
+            let res = calc_path_hash(path).await?;
             hash_cache.insert(path.clone(), res);
             res
           }
@@ -140,6 +206,7 @@ impl SnapshotManager {
   pub fn clear(&self) {
     self.update_time_cache.clear();
     self.hash_cache.clear();
+    self.managed_version_cache.clear();
     self.modified_files.clear();
   }
 
@@ -149,3 +216,110 @@ impl SnapshotManager {
     }
   }
 }
+
+/// Hash the content of a file, or the entries of a directory.
+async fn calc_path_hash(path: &Path) -> Result<u64> {
+  if path.is_dir() {
+    let dir = &mut tokio::fs::read_dir(path).await?;
+    let mut sub_files = vec![];
+    while let Some(entry) = dir.next_entry().await? {
+      let dir_u8 = entry.path().as_os_str().to_string_lossy().to_string();
+      sub_files.push(dir_u8);
+    }
+    // the order of read_dir is platform dependent
+    sub_files.sort();
+    Ok(calc_hash(&sub_files))
+  } else {
+    Ok(calc_hash(&tokio::fs::read(path).await?))
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  fn create_manager(context: &Path) -> SnapshotManager {
+    let mut options = SnapshotOptions {
+      module: SnapshotStrategy {
+        hash: false,
+        timestamp: true,
+      },
+      immutable_paths: vec![PathBuf::from("immutable")],
+      managed_paths: vec![PathBuf::from("node_modules")],
+      ..Default::default()
+    };
+    options.resolve_paths(context);
+    SnapshotManager::new(options)
+  }
+
+  fn write_package(dir: &Path, version: &str) {
+    std::fs::create_dir_all(dir.join("lib")).expect("should create dir");
+    std::fs::write(
+      dir.join("package.json"),
+      format!(r#"{{ "name": "pkg", "version": "{version}" }}"#),
+    )
+    .expect("should write file");
+    std::fs::write(dir.join("lib/index.js"), "module.exports = 1").expect("should write file");
+  }
+
+  #[tokio::test]
+  async fn should_check_managed_paths_by_version() {
+    let dir = crate::unique_temp_dir("rspack_snapshot_managed_paths_test");
+    let package_root = dir.join("node_modules/@scope/pkg");
+    write_package(&package_root, "1.0.0");
+    let file = package_root.join("lib/index.js");
+
+    let manager = create_manager(&dir);
+    let snapshot = manager
+      .create_snapshot(&[file.as_path()], |options| &options.module)
+      .await
+      .expect("should create snapshot");
+    assert!(snapshot.file_update_times.is_empty());
+    assert_eq!(
+      snapshot.managed_versions.get(&package_root),
+      Some(&"1.0.0".to_string())
+    );
+
+    // files in managed paths are not checked by their update time
+    std::fs::write(&file, "module.exports = 2").expect("should write file");
+    manager.clear();
+    assert!(manager
+      .check_snapshot_valid(&snapshot)
+      .await
+      .expect("should check snapshot"));
+
+    write_package(&package_root, "1.0.1");
+    manager.clear();
+    assert!(!manager
+      .check_snapshot_valid(&snapshot)
+      .await
+      .expect("should check snapshot"));
+
+    let _ = std::fs::remove_dir_all(&dir);
+  }
+
+  #[tokio::test]
+  async fn should_skip_immutable_paths() {
+    let dir = crate::unique_temp_dir("rspack_snapshot_immutable_paths_test");
+    let immutable_file = dir.join("immutable/a.js");
+    let file = dir.join("b.js");
+    std::fs::create_dir_all(dir.join("immutable")).expect("should create dir");
+    std::fs::write(&immutable_file, "a").expect("should write file");
+    std::fs::write(&file, "b").expect("should write file");
+
+    let manager = create_manager(&dir);
+    let snapshot = manager
+      .create_snapshot(&[immutable_file.as_path(), file.as_path()], |options| {
+        &options.module
+      })
+      .await
+      .expect("should create snapshot");
+    assert!(snapshot.managed_versions.is_empty());
+    assert_eq!(
+      snapshot.file_update_times.keys().collect::<Vec<_>>(),
+      vec![&file]
+    );
+
+    let _ = std::fs::remove_dir_all(&dir);
+  }
+}
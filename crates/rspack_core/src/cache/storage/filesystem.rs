@@ -0,0 +1,556 @@
+use std::{
+  hash::BuildHasherDefault,
+  path::{Path, PathBuf},
+  sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc, Mutex,
+  },
+  thread::JoinHandle,
+  time::{Instant, SystemTime, UNIX_EPOCH},
+};
+
+use dashmap::{DashMap, DashSet};
+use once_cell::sync::OnceCell;
+use rspack_identifier::{Identifier, IdentifierHasher};
+use rustc_hash::FxHashMap as HashMap;
+use serde::{Deserialize, Serialize};
+
+use super::{Persistable, Storage};
+use crate::{calc_hash, Context, FileSystemCacheOptions};
+
+/// The index of a pack, entries are stored in separate files so only the changed ones are written.
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PackIndex {
+  version: String,
+  build_dependencies: u64,
+  /// last used time of each entry
+  entries: HashMap<String, u64>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct PackEntry {
+  id: String,
+  data: serde_json::Value,
+}
+
+type IdentifierDashMap<V> = DashMap<Identifier, V, BuildHasherDefault<IdentifierHasher>>;
+type IdentifierDashSet = DashSet<Identifier, BuildHasherDefault<IdentifierHasher>>;
+
+/// The directory `<cache_location>/<scope>` holding `index.json` and one file per entry.
+#[derive(Debug)]
+struct Pack {
+  dir: PathBuf,
+  version: String,
+  build_dependencies: Vec<PathBuf>,
+  /// lazily calculated hash of build dependencies
+  build_dependencies_hash: OnceCell<u64>,
+  max_age: Option<u64>,
+  profile: bool,
+}
+
+impl Pack {
+  fn index_path(&self) -> PathBuf {
+    self.dir.join("index.json")
+  }
+
+  fn entry_path(&self, id: &Identifier) -> PathBuf {
+    self
+      .dir
+      .join(format!("{:016x}.json", calc_hash(&id.as_str())))
+  }
+
+  fn is_expired(&self, now: u64, last_used: u64) -> bool {
+    self
+      .max_age
+      .map_or(false, |max_age| now.saturating_sub(last_used) > max_age)
+  }
+
+  /// Any change of build dependencies invalidates the whole pack.
+  fn build_dependencies_hash(&self) -> u64 {
+    *self.build_dependencies_hash.get_or_init(|| {
+      let mut contents = vec![];
+      for path in &self.build_dependencies {
+        collect_build_dependency(path, &mut contents);
+      }
+      calc_hash(&contents)
+    })
+  }
+
+  /// Returns the entries of a valid index, `None` if the pack is missing or outdated.
+  fn read_index(&self) -> Option<HashMap<Identifier, u64>> {
+    let content = std::fs::read(self.index_path()).ok()?;
+    let index = match serde_json::from_slice::<PackIndex>(&content) {
+      Ok(index) => index,
+      Err(err) => {
+        tracing::warn!(
+          "Failed to read cache index {}: {}",
+          self.index_path().display(),
+          err
+        );
+        return None;
+      }
+    };
+    if index.version != self.version || index.build_dependencies != self.build_dependencies_hash() {
+      return None;
+    }
+    Some(
+      index
+        .entries
+        .into_iter()
+        .map(|(id, last_used)| (Identifier::from(id), last_used))
+        .collect(),
+    )
+  }
+
+  fn read_entry(&self, id: &Identifier) -> Option<serde_json::Value> {
+    let content = std::fs::read(self.entry_path(id)).ok()?;
+    let entry = serde_json::from_slice::<PackEntry>(&content).ok()?;
+    // the file name is a hash of the id, make sure it's not a collision
+    (entry.id == id.as_str()).then_some(entry.data)
+  }
+
+  /// Write the changed entries and the index, entries which are not changed are left untouched.
+  fn store(
+    &self,
+    index: &mut HashMap<Identifier, u64>,
+    changed: Vec<(Identifier, Option<(u64, serde_json::Value)>)>,
+    used: Vec<(Identifier, u64)>,
+  ) -> std::io::Result<()> {
+    let start = Instant::now();
+    std::fs::create_dir_all(&self.dir)?;
+    let changed_count = changed.len();
+    for (id, item) in changed {
+      match item {
+        Some((last_used, data)) => {
+          let content = serde_json::to_vec(&PackEntry {
+            id: id.to_string(),
+            data,
+          })?;
+          write_file(&self.entry_path(&id), &content)?;
+          index.insert(id, last_used);
+        }
+        None => {
+          if index.remove(&id).is_some() {
+            let _ = std::fs::remove_file(self.entry_path(&id));
+          }
+        }
+      }
+    }
+    for (id, last_used) in used {
+      if let Some(entry) = index.get_mut(&id) {
+        *entry = last_used;
+      }
+    }
+    let now = now();
+    index.retain(|id, last_used| {
+      let expired = self.is_expired(now, *last_used);
+      if expired {
+        let _ = std::fs::remove_file(self.entry_path(id));
+      }
+      !expired
+    });
+
+    let content = serde_json::to_vec(&PackIndex {
+      version: self.version.clone(),
+      build_dependencies: self.build_dependencies_hash(),
+      entries: index
+        .iter()
+        .map(|(id, last_used)| (id.to_string(), *last_used))
+        .collect(),
+    })?;
+    write_file(&self.index_path(), &content)?;
+
+    if self.profile {
+      tracing::info!(
+        "store {} of {} entries to {} in {:?}",
+        changed_count,
+        index.len(),
+        self.dir.display(),
+        start.elapsed()
+      );
+    }
+    Ok(())
+  }
+}
+
+/// FileSystemStorage keeps items in memory like [super::MemoryStorage] and writes the changed ones
+/// to `<cache_location>/<scope>` in a background thread when the compiler becomes idle.
+///
+/// The index is read lazily on first access, entries are read only when they are used.
+#[derive(Debug)]
+pub struct FileSystemStorage<Item> {
+  pack: Arc<Pack>,
+  /// items used or created in this process
+  data: IdentifierDashMap<(u64, Item)>,
+  /// entries of the index which are not used yet
+  restored: OnceCell<IdentifierDashMap<u64>>,
+  /// ids of items set or removed since the last store
+  changed: IdentifierDashSet,
+  /// entries on the disk, only accessed by the writing thread after restored
+  index: Arc<Mutex<HashMap<Identifier, u64>>>,
+  /// whether the pack on the disk is outdated and should be cleared before writing
+  outdated: AtomicBool,
+  dirty: AtomicBool,
+  writing: Mutex<Option<JoinHandle<()>>>,
+}
+
+impl<Item> FileSystemStorage<Item>
+where
+  Item: Persistable + Clone,
+{
+  pub fn new(options: &FileSystemCacheOptions, context: &Context, scope: &'static str) -> Self {
+    let context: &Path = context.as_ref();
+    Self {
+      pack: Arc::new(Pack {
+        dir: options.cache_location(context).join(scope),
+        version: format!("{}|{}", options.name, options.version),
+        build_dependencies: options
+          .build_dependencies
+          .iter()
+          .map(|dep| context.join(dep))
+          .collect(),
+        build_dependencies_hash: OnceCell::new(),
+        max_age: (options.max_age != 0).then_some(options.max_age as u64),
+        profile: options.profile,
+      }),
+      data: Default::default(),
+      restored: OnceCell::new(),
+      changed: Default::default(),
+      index: Default::default(),
+      outdated: AtomicBool::new(false),
+      dirty: AtomicBool::new(false),
+      writing: Mutex::new(None),
+    }
+  }
+
+  fn restored(&self) -> &IdentifierDashMap<u64> {
+    self.restored.get_or_init(|| {
+      let start = Instant::now();
+      let restored = IdentifierDashMap::default();
+      let Some(mut entries) = self.pack.read_index() else {
+        self.outdated.store(true, Ordering::Release);
+        return restored;
+      };
+      let now = now();
+      entries.retain(|_, last_used| !self.pack.is_expired(now, *last_used));
+      for (id, last_used) in &entries {
+        restored.insert(*id, *last_used);
+      }
+      *self.index.lock().expect("should lock cache index") = entries;
+      if self.pack.profile {
+        tracing::info!(
+          "restore index of {} entries from {} in {:?}",
+          restored.len(),
+          self.pack.dir.display(),
+          start.elapsed()
+        );
+      }
+      restored
+    })
+  }
+
+  fn restore(&self, id: &Identifier) -> Option<Item> {
+    self.restored().remove(id)?;
+    let item = Item::restore(self.pack.read_entry(id)?)?;
+    self.data.insert(*id, (now(), item.clone()));
+    self.dirty.store(true, Ordering::Release);
+    Some(item)
+  }
+
+  fn mark_changed(&self, id: Identifier) {
+    self.restored().remove(&id);
+    self.changed.insert(id);
+    self.dirty.store(true, Ordering::Release);
+  }
+}
+
+impl<Item> Storage<Item> for FileSystemStorage<Item>
+where
+  Item: Persistable + Clone + std::fmt::Debug + Send + Sync + 'static,
+{
+  fn get(&self, id: &Identifier) -> Option<Item> {
+    if let Some(mut item) = self.data.get_mut(id) {
+      item.0 = now();
+      self.dirty.store(true, Ordering::Release);
+      return Some(item.1.clone());
+    }
+    self.restore(id)
+  }
+
+  fn contains(&self, id: &Identifier) -> bool {
+    // restore the entry here, it may no longer be restorable
+    self.data.contains_key(id) || self.restore(id).is_some()
+  }
+
+  fn set(&self, id: Identifier, data: Item) {
+    self.data.insert(id, (now(), data));
+    self.mark_changed(id);
+  }
+
+  fn remove(&self, id: &Identifier) {
+    self.data.remove(id);
+    self.mark_changed(*id);
+  }
+
+  fn begin_idle(&self) {
+    // make sure the index on the disk is read before it's written
+    self.restored();
+    if self
+      .dirty
+      .compare_exchange(true, false, Ordering::AcqRel, Ordering::Relaxed)
+      .is_err()
+    {
+      return;
+    }
+    let mut changed = vec![];
+    self.changed.retain(|id| {
+      changed.push((*id, self.data.get(id).map(|item| (item.0, item.1.clone()))));
+      false
+    });
+    let used = self
+      .data
+      .iter()
+      .map(|item| (*item.key(), item.value().0))
+      .collect::<Vec<_>>();
+    let outdated = self.outdated.swap(false, Ordering::AcqRel);
+
+    let pack = self.pack.clone();
+    let index = self.index.clone();
+    let mut writing = self.writing.lock().expect("should lock cache writing");
+    // only one thread writes to the pack at the same time
+    if let Some(handle) = writing.take() {
+      let _ = handle.join();
+    }
+    *writing = Some(std::thread::spawn(move || {
+      if outdated {
+        let _ = std::fs::remove_dir_all(&pack.dir);
+      }
+      // items are serialized here to keep it off the compiler thread
+      let changed = changed
+        .into_iter()
+        .map(|(id, item)| {
+          let item = item.and_then(|(last_used, item)| Some((last_used, item.persist()?)));
+          (id, item)
+        })
+        .collect();
+      let mut index = index.lock().expect("should lock cache index");
+      if let Err(err) = pack.store(&mut index, changed, used) {
+        tracing::warn!("Failed to write cache pack {}: {}", pack.dir.display(), err);
+      }
+    }));
+  }
+}
+
+impl<Item> Drop for FileSystemStorage<Item> {
+  /// Wait for the pending write so the pack is complete when the process exits.
+  fn drop(&mut self) {
+    if let Some(handle) = self
+      .writing
+      .get_mut()
+      .ok()
+      .and_then(|writing| writing.take())
+    {
+      let _ = handle.join();
+    }
+  }
+}
+
+/// Write to a temporary file first to avoid leaving a broken file.
+fn write_file(path: &Path, content: &[u8]) -> std::io::Result<()> {
+  let mut temp_path = path.as_os_str().to_owned();
+  temp_path.push("_");
+  std::fs::write(&temp_path, content)?;
+  std::fs::rename(&temp_path, path)
+}
+
+/// Collects the path and content of a build dependency, files inside a directory are
+/// collected recursively in a stable order so editing any of them changes the hash.
+fn collect_build_dependency(path: &Path, contents: &mut Vec<Vec<u8>>) {
+  let Ok(metadata) = std::fs::metadata(path) else {
+    contents.push(path.to_string_lossy().as_bytes().to_vec());
+    return;
+  };
+  if metadata.is_dir() {
+    let mut entries = std::fs::read_dir(path)
+      .map(|dir| {
+        dir
+          .filter_map(|entry| entry.ok())
+          // symlinked directories are not followed to avoid cycles
+          .filter(|entry| {
+            entry
+              .file_type()
+              .map_or(false, |t| !t.is_symlink() || entry.path().is_file())
+          })
+          .map(|entry| entry.path())
+          .collect::<Vec<_>>()
+      })
+      .unwrap_or_default();
+    entries.sort();
+    for entry in entries {
+      collect_build_dependency(&entry, contents);
+    }
+  } else {
+    contents.push(path.to_string_lossy().as_bytes().to_vec());
+    contents.push(std::fs::read(path).unwrap_or_default());
+  }
+}
+
+fn now() -> u64 {
+  SystemTime::now()
+    .duration_since(UNIX_EPOCH)
+    .map(|d| d.as_millis() as u64)
+    .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[derive(Debug, Clone, PartialEq)]
+  struct Item(String);
+
+  impl Persistable for Item {
+    fn persist(&self) -> Option<serde_json::Value> {
+      (!self.0.is_empty()).then(|| serde_json::Value::String(self.0.clone()))
+    }
+
+    fn restore(value: serde_json::Value) -> Option<Self> {
+      value.as_str().map(|s| Item(s.to_string()))
+    }
+  }
+
+  fn create_storage(context: &Context, version: &str, max_age: u32) -> FileSystemStorage<Item> {
+    FileSystemStorage::new(
+      &FileSystemCacheOptions {
+        version: version.to_string(),
+        build_dependencies: vec!["config".to_string()],
+        max_age,
+        ..Default::default()
+      },
+      context,
+      "test",
+    )
+  }
+
+  #[test]
+  fn should_restore_persisted_items() {
+    let dir = crate::unique_temp_dir("rspack_filesystem_storage_test");
+    let context = Context::new(dir.to_string_lossy().to_string());
+
+    let storage = create_storage(&context, "1", 0);
+    storage.set("a".into(), Item("a".to_string()));
+    storage.set("memory-only".into(), Item(String::new()));
+    storage.begin_idle();
+    drop(storage);
+
+    let storage = create_storage(&context, "1", 0);
+    assert!(storage.contains(&"a".into()));
+    assert!(!storage.contains(&"memory-only".into()));
+    assert_eq!(storage.get(&"a".into()), Some(Item("a".to_string())));
+    assert_eq!(storage.get(&"memory-only".into()), None);
+    drop(storage);
+
+    let storage = create_storage(&context, "2", 0);
+    assert_eq!(storage.get(&"a".into()), None);
+
+    let _ = std::fs::remove_dir_all(&dir);
+  }
+
+  #[test]
+  fn should_only_write_changed_items() {
+    let dir = crate::unique_temp_dir("rspack_filesystem_storage_changed_test");
+    let context = Context::new(dir.to_string_lossy().to_string());
+
+    let storage = create_storage(&context, "1", 0);
+    storage.set("a".into(), Item("a".to_string()));
+    storage.set("b".into(), Item("b".to_string()));
+    storage.begin_idle();
+    drop(storage);
+
+    let entry_path = |id: &str| create_storage(&context, "1", 0).pack.entry_path(&id.into());
+    // make an unchanged entry distinguishable from a rewritten one
+    std::fs::write(entry_path("a"), r#"{"id":"a","data":"not rewritten"}"#)
+      .expect("should write file");
+
+    let storage = create_storage(&context, "1", 0);
+    assert_eq!(
+      storage.get(&"a".into()),
+      Some(Item("not rewritten".to_string()))
+    );
+    storage.set("b".into(), Item("c".to_string()));
+    storage.remove(&"a".into());
+    storage.begin_idle();
+    drop(storage);
+
+    assert!(!entry_path("a").exists());
+    let storage = create_storage(&context, "1", 0);
+    assert_eq!(storage.get(&"a".into()), None);
+    assert_eq!(storage.get(&"b".into()), Some(Item("c".to_string())));
+
+    let _ = std::fs::remove_dir_all(&dir);
+  }
+
+  #[test]
+  fn should_keep_old_items_without_max_age() {
+    let dir = crate::unique_temp_dir("rspack_filesystem_storage_max_age_test");
+    let context = Context::new(dir.to_string_lossy().to_string());
+
+    let storage = create_storage(&context, "1", 0);
+    storage.set("a".into(), Item("a".to_string()));
+    storage.begin_idle();
+    drop(storage);
+
+    // pretend the entry is not used for a long time
+    let storage = create_storage(&context, "1", 0);
+    let index_path = storage.pack.index_path();
+    drop(storage);
+    let mut index: PackIndex =
+      serde_json::from_slice(&std::fs::read(&index_path).expect("should read index"))
+        .expect("should parse index");
+    index.entries.insert("a".to_string(), 0);
+    std::fs::write(
+      &index_path,
+      serde_json::to_vec(&index).expect("should serialize index"),
+    )
+    .expect("should write index");
+
+    let storage = create_storage(&context, "1", 0);
+    assert_eq!(storage.get(&"a".into()), Some(Item("a".to_string())));
+    drop(storage);
+
+    std::fs::write(
+      &index_path,
+      serde_json::to_vec(&index).expect("should serialize index"),
+    )
+    .expect("should write index");
+    let storage = create_storage(&context, "1", 1000);
+    assert_eq!(storage.get(&"a".into()), None);
+
+    let _ = std::fs::remove_dir_all(&dir);
+  }
+
+  #[test]
+  fn should_invalidate_when_nested_build_dependency_changed() {
+    let dir = crate::unique_temp_dir("rspack_filesystem_storage_build_dependencies_test");
+    let context = Context::new(dir.to_string_lossy().to_string());
+    let nested = dir.join("config/nested");
+    std::fs::create_dir_all(&nested).expect("should create dir");
+    std::fs::write(nested.join("a.js"), "a").expect("should write file");
+
+    let storage = create_storage(&context, "1", 0);
+    storage.set("a".into(), Item("a".to_string()));
+    storage.begin_idle();
+    drop(storage);
+
+    let storage = create_storage(&context, "1", 0);
+    assert_eq!(storage.get(&"a".into()), Some(Item("a".to_string())));
+    drop(storage);
+
+    std::fs::write(nested.join("a.js"), "b").expect("should write file");
+    let storage = create_storage(&context, "1", 0);
+    assert_eq!(storage.get(&"a".into()), None);
+
+    let _ = std::fs::remove_dir_all(&dir);
+  }
+}
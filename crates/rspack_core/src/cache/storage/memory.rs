@@ -25,6 +25,9 @@ where
   fn get(&self, id: &Identifier) -> Option<Item> {
     self.data.get(id).map(|item| item.clone())
   }
+  fn contains(&self, id: &Identifier) -> bool {
+    self.data.contains_key(id)
+  }
   fn set(&self, id: Identifier, data: Item) {
     self.data.insert(id, data);
   }
@@ -2,20 +2,33 @@ use std::fmt::Debug;
 
 use rspack_identifier::Identifier;
 
-use crate::CacheOptions;
+use crate::{CacheOptions, Context};
 
+mod filesystem;
 mod memory;
+use filesystem::FileSystemStorage;
 use memory::MemoryStorage;
 
 pub trait Storage<Item>: Debug + Send + Sync {
   fn get(&self, id: &Identifier) -> Option<Item>;
+  /// Whether there is an item of the id, without cloning or restoring it.
+  fn contains(&self, id: &Identifier) -> bool;
   fn set(&self, id: Identifier, data: Item);
   fn remove(&self, id: &Identifier);
-  // fn begin_idle(&self);
+  /// Called when the compiler becomes idle, persistent storages write their data back here.
+  fn begin_idle(&self) {}
   // fn end_idle(&self);
   // fn clear(&self);
 }
 
+/// Items which can be written to a persistent storage.
+pub trait Persistable: Sized {
+  /// Convert the item to a serializable value, `None` means the item can only live in memory.
+  fn persist(&self) -> Option<serde_json::Value>;
+  /// Restore the item from the value generated by [Persistable::persist].
+  fn restore(value: serde_json::Value) -> Option<Self>;
+}
+
 pub fn new_storage<Item>(options: &CacheOptions) -> Option<Box<dyn Storage<Item>>>
 where
   Item: Debug + Clone + Send + Sync + 'static,
@@ -25,3 +38,21 @@ where
     _ => Some(Box::new(MemoryStorage::new())),
   }
 }
+
+/// Create a storage which could be persisted to the filesystem when `cache.type` is `filesystem`,
+/// the `scope` is used to separate the cache files of each occasion.
+pub fn new_persistent_storage<Item>(
+  options: &CacheOptions,
+  context: &Context,
+  scope: &'static str,
+) -> Option<Box<dyn Storage<Item>>>
+where
+  Item: Debug + Clone + Send + Sync + Persistable + 'static,
+{
+  match options {
+    CacheOptions::FileSystem(options) => {
+      Some(Box::new(FileSystemStorage::new(options, context, scope)))
+    }
+    _ => new_storage(options),
+  }
+}
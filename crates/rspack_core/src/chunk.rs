@@ -5,8 +5,8 @@ use rspack_hash::{RspackHash, RspackHashDigest};
 use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
 
 use crate::{
-  ChunkGraph, ChunkGroupByUkey, ChunkGroupUkey, ChunkUkey, Compilation, EntryOptions, Filename,
-  ModuleGraph, RuntimeSpec, SourceType,
+  ChunkGraph, ChunkGroupByUkey, ChunkGroupOrderKey, ChunkGroupUkey, ChunkUkey, Compilation,
+  EntryOptions, Filename, ModuleGraph, RuntimeSpec, SourceType,
 };
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -304,6 +304,132 @@ impl Chunk {
     chunks
   }
 
+  /// The chunks in the child groups which have the order, sorted from the highest order to the
+  /// lowest. Only the last chunk of a chunk group provides the children of the group.
+  pub fn get_children_by_order(
+    &self,
+    order_key: &ChunkGroupOrderKey,
+    compilation: &Compilation,
+  ) -> Vec<ChunkUkey> {
+    let mut list = vec![];
+    for group_ukey in &self.groups {
+      let group = compilation
+        .chunk_group_by_ukey
+        .get(group_ukey)
+        .expect("Group should exist");
+      if group.chunks.last() != Some(&self.ukey) {
+        continue;
+      }
+      for child_group_ukey in &group.children {
+        let child_group = compilation
+          .chunk_group_by_ukey
+          .get(child_group_ukey)
+          .expect("Group should exist");
+        if let Some(order) = child_group.options.order(order_key) {
+          list.push((order, child_group));
+        }
+      }
+    }
+    list.sort_by(|(a_order, a_group), (b_order, b_group)| {
+      b_order
+        .cmp(a_order)
+        .then_with(|| a_group.id(compilation).cmp(&b_group.id(compilation)))
+    });
+
+    let mut chunks = vec![];
+    for (_, group) in list {
+      for chunk_ukey in &group.chunks {
+        if !chunks.contains(chunk_ukey) {
+          chunks.push(*chunk_ukey);
+        }
+      }
+    }
+    chunks
+  }
+
+  /// The child chunk ids with the order of all the async chunks, keyed by the async chunk id.
+  pub fn get_child_ids_by_orders_map(
+    &self,
+    order_key: &ChunkGroupOrderKey,
+    compilation: &Compilation,
+  ) -> HashMap<String, Vec<String>> {
+    self
+      .get_all_async_chunks(&compilation.chunk_group_by_ukey)
+      .iter()
+      .filter_map(|chunk_ukey| {
+        let chunk = compilation
+          .chunk_by_ukey
+          .get(chunk_ukey)
+          .expect("Chunk should exist");
+        let children = chunk.get_children_by_order(order_key, compilation);
+        if children.is_empty() {
+          return None;
+        }
+        let child_ids = children
+          .iter()
+          .map(|chunk_ukey| {
+            compilation
+              .chunk_by_ukey
+              .get(chunk_ukey)
+              .expect("Chunk should exist")
+              .expect_id()
+              .to_string()
+          })
+          .collect();
+        Some((chunk.expect_id().to_string(), child_ids))
+      })
+      .collect()
+  }
+
+  /// The chunks in the child groups which have the order, sorted from the highest order to the
+  /// lowest. The children of the same chunk group are merged, and returned with the chunks of the
+  /// parent group as `(parent chunks, child chunks)`.
+  #[allow(clippy::unwrap_in_result)]
+  pub fn get_children_of_type_in_order(
+    &self,
+    order_key: &ChunkGroupOrderKey,
+    compilation: &Compilation,
+  ) -> Option<Vec<(Vec<ChunkUkey>, Vec<ChunkUkey>)>> {
+    let mut list = vec![];
+    for group_ukey in &self.groups {
+      let group = compilation
+        .chunk_group_by_ukey
+        .get(group_ukey)
+        .expect("Group should exist");
+      for child_group_ukey in &group.children {
+        let child_group = compilation
+          .chunk_group_by_ukey
+          .get(child_group_ukey)
+          .expect("Group should exist");
+        if let Some(order) = child_group.options.order(order_key) {
+          list.push((order, group, child_group));
+        }
+      }
+    }
+    if list.is_empty() {
+      return None;
+    }
+    list.sort_by(|(a_order, a_group, _), (b_order, b_group, _)| {
+      b_order
+        .cmp(a_order)
+        .then_with(|| a_group.id(compilation).cmp(&b_group.id(compilation)))
+    });
+
+    let mut result: Vec<(Vec<ChunkUkey>, Vec<ChunkUkey>)> = vec![];
+    for (_, group, child_group) in list {
+      if let Some((on_chunks, chunks)) = result.last_mut() && *on_chunks == group.chunks {
+        for chunk_ukey in &child_group.chunks {
+          if !chunks.contains(chunk_ukey) {
+            chunks.push(*chunk_ukey);
+          }
+        }
+      } else {
+        result.push((group.chunks.clone(), child_group.chunks.clone()));
+      }
+    }
+    Some(result)
+  }
+
   // pub fn get_all_referenced_async_entry_points() -> HashSet<ChunkUkey> {}
 
   pub fn get_render_hash(&self, length: usize) -> Option<&str> {
@@ -178,6 +178,12 @@ impl ChunkGroup {
     false
   }
 
+  /// Merge the orders of another `import()` of the chunk group, the higher orders win.
+  pub fn add_orders(&mut self, options: &ChunkGroupOptions) {
+    self.options.prefetch_order = self.options.prefetch_order.max(options.prefetch_order);
+    self.options.preload_order = self.options.preload_order.max(options.preload_order);
+  }
+
   pub fn id(&self, compilation: &Compilation) -> String {
     self
       .chunks
@@ -213,6 +219,10 @@ impl ChunkGroupKind {
 pub struct ChunkGroupOptions {
   pub name: Option<String>,
   pub entry_options: Option<EntryOptions>,
+  /// Order of `webpackPrefetch`, `true` is 0
+  pub prefetch_order: Option<i32>,
+  /// Order of `webpackPreload`, `true` is 0
+  pub preload_order: Option<i32>,
 }
 
 impl ChunkGroupOptions {
@@ -235,6 +245,29 @@ impl ChunkGroupOptions {
     self.entry_options = v.map(|v| v.into());
     self
   }
+
+  pub fn prefetch_order(mut self, v: Option<i32>) -> Self {
+    self.prefetch_order = v;
+    self
+  }
+
+  pub fn preload_order(mut self, v: Option<i32>) -> Self {
+    self.preload_order = v;
+    self
+  }
+
+  pub fn order(&self, order_key: &ChunkGroupOrderKey) -> Option<i32> {
+    match order_key {
+      ChunkGroupOrderKey::Prefetch => self.prefetch_order,
+      ChunkGroupOrderKey::Preload => self.preload_order,
+    }
+  }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ChunkGroupOrderKey {
+  Prefetch,
+  Preload,
 }
 
 #[derive(Debug, Default, Clone)]
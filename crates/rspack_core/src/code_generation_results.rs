@@ -69,6 +69,22 @@ impl CodeGenerationDataAssetInfo {
   }
 }
 
+#[derive(Clone, Debug)]
+pub struct ShareInitData {
+  pub share_scope: String,
+  /// Snippets with a lower stage are run first when the share scope is initialized
+  pub init_stage: i8,
+  /// Code run in the share scope initialization, it can call `register(name, version, factory, eager)`
+  /// and `initExternal(moduleId)`
+  pub init: String,
+}
+
+/// Code to run when the share scope is initialized, collected by the share runtime module.
+#[derive(Clone, Debug, Default)]
+pub struct CodeGenerationDataShareInit {
+  pub items: Vec<ShareInitData>,
+}
+
 #[derive(Debug, Default, Clone)]
 pub struct CodeGenerationData {
   inner: anymap::Map<dyn CloneAny + Send + Sync>,
@@ -21,6 +21,7 @@ use rspack_hash::{RspackHash, RspackHashDigest};
 use rspack_identifier::{IdentifierMap, IdentifierSet};
 use rspack_sources::{BoxSource, CachedSource, SourceExt};
 use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet, FxHasher};
+use serde::{Deserialize, Serialize};
 use swc_core::ecma::ast::ModuleItem;
 use tokio::sync::mpsc::error::TryRecvError;
 use tracing::instrument;
@@ -29,18 +30,20 @@ use super::make::{MakeParam, RebuildDepsBuilder};
 use crate::{
   build_chunk_graph::build_chunk_graph,
   cache::{use_code_splitting_cache, Cache, CodeSplittingCache},
-  is_source_equal,
-  tree_shaking::{optimizer, visitor::SymbolRef, BailoutFlag, OptimizeDependencyResult},
+  calc_hash, is_source_equal,
+  tree_shaking::{
+    optimizer, visitor::SymbolRef, BailoutFlag, OptimizeDependencyResult, SideEffectsKeptReason,
+  },
   AddQueue, AddTask, AddTaskResult, AdditionalChunkRuntimeRequirementsArgs, BoxModule,
-  BoxModuleDependency, BuildQueue, BuildTask, BuildTaskResult, Chunk, ChunkByUkey,
+  BoxModuleDependency, BuildContext, BuildQueue, BuildTask, BuildTaskResult, Chunk, ChunkByUkey,
   ChunkContentHash, ChunkGraph, ChunkGroup, ChunkGroupUkey, ChunkHashArgs, ChunkKind, ChunkUkey,
   CleanQueue, CleanTask, CleanTaskResult, CodeGenerationResult, CodeGenerationResults,
-  CompilerOptions, ContentHashArgs, DependencyId, Entry, EntryData, EntryOptions, Entrypoint,
-  FactorizeQueue, FactorizeTask, FactorizeTaskResult, Filename, Module, ModuleGraph,
-  ModuleIdentifier, ModuleType, PathData, ProcessAssetsArgs, ProcessDependenciesQueue,
-  ProcessDependenciesResult, ProcessDependenciesTask, RenderManifestArgs, Resolve, ResolverFactory,
-  RuntimeGlobals, RuntimeModule, RuntimeSpec, SharedPluginDriver, SourceType, Stats, TaskResult,
-  WorkerTask,
+  CompilationRecords, CompilerContext, CompilerOptions, ContentHashArgs, DependencyId, Entry,
+  EntryData, EntryOptions, Entrypoint, FactorizeQueue, FactorizeTask, FactorizeTaskResult,
+  Filename, Module, ModuleGraph, ModuleIdentifier, ModuleType, PathData, ProcessAssetsArgs,
+  ProcessDependenciesQueue, ProcessDependenciesResult, ProcessDependenciesTask, RenderManifestArgs,
+  Resolve, ResolverFactory, RuntimeGlobals, RuntimeModule, RuntimeSpec, SharedPluginDriver,
+  SourceType, Stats, TaskResult, WorkerTask,
 };
 use crate::{tree_shaking::visitor::OptimizeAnalyzeResult, Context};
 
@@ -59,6 +62,8 @@ pub struct Compilation {
   pub hot_index: u32,
   pub options: Arc<CompilerOptions>,
   pub entries: Entry,
+  /// Dependencies added to all entries
+  pub global_entry: EntryData,
   pub module_graph: ModuleGraph,
   pub make_failed_dependencies: HashSet<BuildDependency>,
   pub make_failed_module: HashSet<ModuleIdentifier>,
@@ -99,7 +104,13 @@ pub struct Compilation {
   pub missing_dependencies: IndexSet<PathBuf, BuildHasherDefault<FxHasher>>,
   pub build_dependencies: IndexSet<PathBuf, BuildHasherDefault<FxHasher>>,
   pub side_effects_free_modules: IdentifierSet,
+  /// See [crate::tree_shaking::OptimizeDependencyResult::side_effects_skipped_modules]
+  pub side_effects_skipped_modules: IdentifierMap<IdentifierSet>,
+  /// See [crate::tree_shaking::OptimizeDependencyResult::side_effects_kept_modules]
+  pub side_effects_kept_modules: IdentifierMap<SideEffectsKeptReason>,
   pub module_item_map: IdentifierMap<Vec<ModuleItem>>,
+  /// Records of the previous compilation.
+  pub records: Arc<CompilationRecords>,
 }
 
 impl Compilation {
@@ -123,6 +134,7 @@ impl Compilation {
       chunk_by_ukey: Default::default(),
       chunk_group_by_ukey: Default::default(),
       entries: Default::default(),
+      global_entry: Default::default(),
       chunk_graph: Default::default(),
       entrypoints: Default::default(),
       async_entrypoints: Default::default(),
@@ -152,8 +164,11 @@ impl Compilation {
       missing_dependencies: Default::default(),
       build_dependencies: Default::default(),
       side_effects_free_modules: IdentifierSet::default(),
+      side_effects_skipped_modules: IdentifierMap::default(),
+      side_effects_kept_modules: IdentifierMap::default(),
       module_item_map: IdentifierMap::default(),
       include_module_ids: IdentifierSet::default(),
+      records: Default::default(),
     }
   }
 
@@ -163,12 +178,18 @@ impl Compilation {
     } else {
       let data = EntryData {
         dependencies: vec![entry],
+        include_dependencies: vec![],
         options,
       };
       self.entries.insert(name.to_owned(), data);
     }
   }
 
+  /// Add a module to the chunks of all entries without executing it on startup.
+  pub fn add_include(&mut self, dependency: DependencyId) {
+    self.global_entry.include_dependencies.push(dependency);
+  }
+
   pub fn update_asset(
     &mut self,
     filename: &str,
@@ -430,6 +451,9 @@ impl Compilation {
             .and_then(|m| m.as_normal_module())
             .and_then(|module| module.name_for_condition())
             .map(|issuer| issuer.to_string()),
+          parent_module
+            .and_then(|m| m.as_normal_module())
+            .and_then(|module| module.resource_resolved_data().resource_description.clone()),
         );
       });
 
@@ -526,6 +550,9 @@ impl Compilation {
               .as_normal_module()
               .and_then(|module| module.name_for_condition())
               .map(|issuer| issuer.to_string()),
+            module
+              .as_normal_module()
+              .and_then(|module| module.resource_resolved_data().resource_description.clone()),
           );
         });
 
@@ -790,10 +817,12 @@ impl Compilation {
     resolve_options: Option<Resolve>,
     lazy_visit_modules: std::collections::HashSet<String>,
     issuer: Option<String>,
+    issuer_description: Option<Arc<nodejs_resolver::DescriptionData>>,
   ) {
     queue.add_task(FactorizeTask {
       original_module_identifier,
       issuer,
+      issuer_description,
       original_module_context,
       dependencies,
       is_entry,
@@ -808,10 +837,125 @@ impl Compilation {
     });
   }
 
+  /// Hash of the tree shaking results, module ids and exports types read by the code generation of
+  /// each module, the cached code generation result of an unbuilt module is only valid when the hash
+  /// is unchanged.
+  fn code_generation_fingerprints(&self) -> IdentifierMap<u64> {
+    // the symbols are hashed by their debug string, which is stable across processes
+    let mut used_symbols: IdentifierMap<Vec<String>> = IdentifierMap::default();
+    for symbol in &self.used_symbol_ref {
+      let symbol_string = format!("{symbol:?}");
+      if symbol.importer() != symbol.src() {
+        used_symbols
+          .entry(symbol.importer())
+          .or_default()
+          .push(symbol_string.clone());
+      }
+      used_symbols
+        .entry(symbol.src())
+        .or_default()
+        .push(symbol_string);
+    }
+    for symbols in used_symbols.values_mut() {
+      symbols.sort_unstable();
+    }
+    let module_state = |module_identifier: &ModuleIdentifier| {
+      (
+        module_identifier.as_str(),
+        self.include_module_ids.contains(module_identifier),
+        self.side_effects_free_modules.contains(module_identifier),
+        self
+          .bailout_module_identifiers
+          .get(module_identifier)
+          .map(|flag| flag.bits()),
+      )
+    };
+    self
+      .module_graph
+      .module_graph_modules()
+      .iter()
+      .map(|(module_identifier, mgm)| {
+        let imported_modules = mgm
+          .dependencies
+          .iter()
+          .filter_map(|id| self.module_graph.module_identifier_by_dependency_id(id))
+          .map(|imported_module_identifier| {
+            // the id and the interop code of an imported module are inlined into the importer
+            let exports_type = self
+              .module_graph
+              .module_graph_module_by_identifier(imported_module_identifier)
+              .and_then(|mgm| mgm.build_meta.as_ref())
+              .map(|build_meta| (&build_meta.exports_type, &build_meta.default_object));
+            (
+              module_state(imported_module_identifier),
+              self
+                .chunk_graph
+                .chunk_graph_module_by_module_identifier
+                .get(imported_module_identifier)
+                .and_then(|cgm| cgm.id.as_deref()),
+              exports_type,
+            )
+          })
+          .collect::<Vec<_>>();
+        let fingerprint = calc_hash(&(
+          used_symbols.get(module_identifier),
+          module_state(module_identifier),
+          imported_modules,
+        ));
+        (*module_identifier, fingerprint)
+      })
+      .collect()
+  }
+
+  /// Unbuilt modules which can't be code generated from the cache, including the ones concatenated into other modules.
+  fn unbuilt_modules_without_code_generation_cache(
+    &self,
+    fingerprints: &IdentifierMap<u64>,
+  ) -> IdentifierSet {
+    let is_unbuilt = |module_identifier: &ModuleIdentifier| {
+      self
+        .module_graph
+        .module_by_identifier(module_identifier)
+        .is_some_and(|module| !module.is_built())
+    };
+    let mut modules = IdentifierSet::default();
+    for (module_identifier, module) in self.module_graph.modules() {
+      if let Some(concatenated_modules) = module.get_concatenated_modules() {
+        // concatenated modules are generated from the inner modules and never cached
+        modules.extend(
+          concatenated_modules
+            .iter()
+            .filter(|module_identifier| is_unbuilt(module_identifier)),
+        );
+      } else if !module.is_built()
+        && !self.cache.code_generate_occasion.has_valid_cache(
+          module_identifier,
+          fingerprints
+            .get(module_identifier)
+            .copied()
+            .unwrap_or_default(),
+        )
+        && !self
+          .chunk_graph
+          .get_module_runtimes(*module_identifier, &self.chunk_by_ukey)
+          .is_empty()
+      {
+        modules.insert(*module_identifier);
+      }
+    }
+    modules
+  }
+
   #[instrument(name = "compilation:code_generation", skip(self))]
   async fn code_generation(&mut self) -> Result<()> {
+    let fingerprints = self.code_generation_fingerprints();
+    self
+      .build_unbuilt_modules(self.unbuilt_modules_without_code_generation_cache(&fingerprints))
+      .await?;
+
     fn run_iteration(
       compilation: &mut Compilation,
+      fingerprints: &IdentifierMap<u64>,
       filter_op: impl Fn(&(&ModuleIdentifier, &Box<dyn Module>)) -> bool + Sync + Send,
     ) -> Result<()> {
       let results = compilation
@@ -829,7 +973,14 @@ impl Compilation {
           compilation
             .cache
             .code_generate_occasion
-            .use_cache(module, |module| module.code_generation(compilation))
+            .use_cache(
+              module,
+              fingerprints
+                .get(module_identifier)
+                .copied()
+                .unwrap_or_default(),
+              |module| module.code_generation(compilation),
+            )
             .map(|result| (*module_identifier, result))
         })
         .collect::<Result<Vec<(ModuleIdentifier, CodeGenerationResult)>>>()?;
@@ -856,11 +1007,11 @@ impl Compilation {
       Ok(())
     }
 
-    run_iteration(self, |(_, module)| {
+    run_iteration(self, &fingerprints, |(_, module)| {
       module.get_code_generation_dependencies().is_none()
     })?;
 
-    run_iteration(self, |(_, module)| {
+    run_iteration(self, &fingerprints, |(_, module)| {
       module.get_code_generation_dependencies().is_some()
     })?;
 
@@ -948,9 +1099,110 @@ impl Compilation {
   pub async fn optimize_dependency(
     &mut self,
   ) -> Result<TWithDiagnosticArray<OptimizeDependencyResult>> {
+    // the analysis of unbuilt modules is served by the cache, build the ones without a valid one
+    let unanalyzed_modules = self
+      .module_graph
+      .modules()
+      .iter()
+      .filter(|(module_identifier, module)| {
+        module.module_type().is_js_like()
+          && !module.is_built()
+          && !self
+            .optimize_analyze_result_map
+            .contains_key(module_identifier)
+          && !self.cache.analyze_module_occasion.has_cache(
+            module_identifier,
+            optimizer::analyze_fingerprint(self, module_identifier),
+          )
+      })
+      .map(|(module_identifier, _)| *module_identifier)
+      .collect::<IdentifierSet>();
+    self.build_unbuilt_modules(unanalyzed_modules).await?;
     optimizer::CodeSizeOptimizer::new(self).run().await
   }
 
+  /// Modules restored from the cache have neither the ast nor the code generatable dependencies,
+  /// build the ones which can't be served from the cache again in place. The dependencies created by
+  /// the new build take over the connections of the restored ones, so the module graph is unchanged.
+  pub async fn build_unbuilt_modules(&mut self, module_identifiers: IdentifierSet) -> Result<()> {
+    if module_identifiers.is_empty() {
+      return Ok(());
+    }
+    let options = self.options.clone();
+    let resolver_factory = self.resolver_factory.clone();
+    let plugin_driver = self.plugin_driver.clone();
+    let results = self
+      .module_graph
+      .modules_mut()
+      .iter_mut()
+      .filter(|(module_identifier, module)| {
+        module_identifiers.contains(*module_identifier) && !module.is_built()
+      })
+      .map(|(module_identifier, module)| {
+        let options = options.clone();
+        let resolver_factory = resolver_factory.clone();
+        let plugin_driver = plugin_driver.clone();
+        async move {
+          let result = module
+            .build(BuildContext {
+              compiler_context: CompilerContext {
+                options: options.clone(),
+                resolver_factory,
+              },
+              plugin_driver,
+              compiler_options: &options,
+            })
+            .await;
+          (*module_identifier, result)
+        }
+      })
+      .collect::<FuturesResults<_>>()
+      .into_inner();
+
+    for (module_identifier, result) in results {
+      let (build_result, diagnostics) = result?.split_into_parts();
+      self.push_batch_diagnostic(diagnostics);
+      let restored_dependencies = self
+        .module_graph
+        .dependencies_by_module_identifier(&module_identifier)
+        .map(|dependencies| dependencies.to_vec())
+        .unwrap_or_default();
+      let is_same_dependencies = restored_dependencies.len() == build_result.dependencies.len()
+        && restored_dependencies
+          .iter()
+          .zip(build_result.dependencies.iter())
+          .all(|(id, dependency)| {
+            self
+              .module_graph
+              .dependency_by_id(id)
+              .is_some_and(|restored| {
+                restored.request() == dependency.request()
+                  && restored.dependency_type() == dependency.dependency_type()
+              })
+          });
+      if !is_same_dependencies {
+        return Err(internal_error!(
+          "Dependencies of module {module_identifier} are changed when it is built again"
+        ));
+      }
+      let mut dependency_ids = vec![];
+      for (id, dependency) in restored_dependencies
+        .iter()
+        .zip(build_result.dependencies.into_iter())
+      {
+        dependency_ids.push(*dependency.id());
+        self.module_graph.replace_dependency(id, dependency);
+      }
+      if let Some(mgm) = self
+        .module_graph
+        .module_graph_module_by_identifier_mut(&module_identifier)
+      {
+        mgm.dependencies = dependency_ids;
+      }
+    }
+    Ok(())
+  }
+
   pub async fn done(&mut self, plugin_driver: SharedPluginDriver) -> Result<()> {
     let stats = &mut Stats::new(self);
     plugin_driver.done(stats).await?;
@@ -1400,7 +1652,7 @@ impl CompilationAsset {
   }
 }
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
 pub struct AssetInfo {
   /// if the asset can be long term cached forever (contains a hash)
   pub immutable: bool,
@@ -1475,7 +1727,7 @@ impl AssetInfo {
   }
 }
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
 pub struct AssetInfoRelated {
   pub source_map: Option<String>,
 }
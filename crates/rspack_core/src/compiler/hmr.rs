@@ -1,10 +1,9 @@
-use std::{hash::Hash, ops::Sub, path::PathBuf};
+use std::{hash::Hash, ops::Sub, path::PathBuf, sync::Arc};
 
-use rayon::prelude::*;
 use rspack_error::Result;
 use rspack_fs::AsyncWritableFileSystem;
 use rspack_hash::{RspackHash, RspackHashDigest};
-use rspack_identifier::{IdentifierMap, IdentifierSet};
+use rspack_identifier::IdentifierSet;
 use rspack_sources::{RawSource, SourceExt};
 use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
 
@@ -35,70 +34,21 @@ impl<T> Compiler<T>
 where
   T: AsyncWritableFileSystem + Send + Sync,
 {
-  // TODO: remove this function when we had `record` in compiler.
   pub async fn rebuild(
     &mut self,
     changed_files: std::collections::HashSet<String>,
     removed_files: std::collections::HashSet<String>,
   ) -> Result<()> {
     assert!(!changed_files.is_empty() || !removed_files.is_empty());
-    let old = self.compilation.get_stats();
-    let old_hash = self.compilation.hash.clone();
-    fn collect_changed_modules(
-      compilation: &Compilation,
-    ) -> (
-      IdentifierMap<(RspackHashDigest, String)>,
-      IdentifierMap<String>,
-    ) {
-      let modules_map = compilation
-        .chunk_graph
-        .chunk_graph_module_by_module_identifier
-        .par_iter()
-        .filter_map(|(identifier, cgm)| {
-          let module_hash = compilation.module_graph.get_module_hash(identifier);
-          let cid = cgm.id.as_deref();
-          if let Some(module_hash) = module_hash && let Some(cid) = cid {
-              Some((
-                  *identifier,
-                  (
-                      module_hash.clone(),
-                      cid.to_string(),
-                  ),
-              ))
-          } else {
-              None
-          }
-        })
-        .collect::<IdentifierMap<_>>();
-
-      let old_runtime_modules = compilation
-        .runtime_modules
-        .iter()
-        .map(|(identifier, module)| {
-          (
-            *identifier,
-            module.generate(compilation).source().to_string(),
-          )
-        })
-        .collect();
+    // diff with the records of the previous compilation, so it doesn't need to be kept
+    let old_records = self.records.clone();
+    let old_hash = old_records.hash.as_deref().map(RspackHashDigest::from);
 
-      (modules_map, old_runtime_modules)
-    }
-
-    let (old_all_modules, old_runtime_modules) = collect_changed_modules(old.compilation);
-    // TODO: should use `records`
-
-    let mut all_old_runtime: RuntimeSpec = Default::default();
-    for entry_ukey in old.compilation.get_chunk_graph_entries() {
-      if let Some(runtime) = old
-        .compilation
-        .chunk_by_ukey
-        .get(&entry_ukey)
-        .map(|entry_chunk| entry_chunk.runtime.clone())
-      {
-        all_old_runtime.extend(runtime);
-      }
-    }
+    let all_old_runtime = old_records
+      .runtimes
+      .iter()
+      .map(|runtime| Arc::from(runtime.as_str()))
+      .collect::<RuntimeSpec>();
 
     let mut hot_update_main_content_by_runtime = all_old_runtime
       .iter()
@@ -110,18 +60,45 @@ where
       })
       .collect::<HashMap<String, HotUpdateContent>>();
 
-    let mut old_chunks: Vec<(String, RuntimeSpec)> = vec![];
-    for (_, chunk) in old.compilation.chunk_by_ukey.iter() {
-      if chunk.kind != ChunkKind::HotUpdate {
-        old_chunks.push((chunk.expect_id().to_string(), chunk.runtime.clone()));
-      }
-    }
+    let old_chunks = old_records
+      .chunk_runtimes
+      .iter()
+      .map(|(chunk_id, runtime)| {
+        (
+          chunk_id.clone(),
+          runtime
+            .iter()
+            .map(|runtime| Arc::from(runtime.as_str()))
+            .collect::<RuntimeSpec>(),
+        )
+      })
+      .collect::<Vec<_>>();
 
     // build without stats
     {
       let mut modified_files = HashSet::default();
       modified_files.extend(changed_files.iter().map(PathBuf::from));
       modified_files.extend(removed_files.iter().map(PathBuf::from));
+      // the modules using the changed runtime values of `builtins.define` are rebuilt too
+      let definitions = self.options.builtins.definitions();
+      let module_graph = &self.compilation.module_graph;
+      modified_files.extend(
+        module_graph
+          .module_graph_modules()
+          .iter()
+          .filter(|(_, mgm)| {
+            mgm.build_info.as_ref().is_some_and(|build_info| {
+              build_info
+                .value_dependencies
+                .iter()
+                .any(|(key, value)| definitions.get(key) != Some(value))
+            })
+          })
+          .filter_map(|(identifier, _)| {
+            module_graph.normal_module_source_path_by_identifier(identifier)
+          })
+          .map(|path| PathBuf::from(path.as_ref())),
+      );
 
       self.cache.end_idle();
       self
@@ -136,6 +113,7 @@ where
         self.resolver_factory.clone(),
         self.cache.clone(),
       );
+      new_compilation.records = self.records.clone();
 
       if let Some(state) = self.options.get_incremental_rebuild_make_state() {
         state.set_is_not_first();
@@ -153,6 +131,7 @@ where
         new_compilation.make_failed_module =
           std::mem::take(&mut self.compilation.make_failed_module);
         new_compilation.entries = std::mem::take(&mut self.compilation.entries);
+        new_compilation.global_entry = std::mem::take(&mut self.compilation.global_entry);
         new_compilation.lazy_visit_modules =
           std::mem::take(&mut self.compilation.lazy_visit_modules);
         new_compilation.file_dependencies = std::mem::take(&mut self.compilation.file_dependencies);
@@ -176,26 +155,30 @@ where
           std::mem::take(&mut self.compilation.code_splitting_cache);
 
         new_compilation.has_module_import_export_change = false;
-        // remove prev build ast in modules
-        fast_drop(
-          new_compilation
-            .module_graph
-            .modules_mut()
-            .values_mut()
-            .map(|module| {
-              if let Some(m) = module.as_normal_module_mut() {
-                let is_ast_unbuild = matches!(m.ast_or_source(), NormalModuleAstOrSource::Unbuild);
-                if !is_ast_unbuild {
-                  return Some(std::mem::replace(
-                    m.ast_or_source_mut(),
-                    NormalModuleAstOrSource::Unbuild,
-                  ));
+        // remove prev build ast in modules, concatenated modules are generated from the ast
+        // of the inner modules, so keep it to produce the same output as a cold build
+        if !self.options.is_module_concatenation_enabled() {
+          fast_drop(
+            new_compilation
+              .module_graph
+              .modules_mut()
+              .values_mut()
+              .map(|module| {
+                if let Some(m) = module.as_normal_module_mut() {
+                  let is_ast_unbuild =
+                    matches!(m.ast_or_source(), NormalModuleAstOrSource::Unbuild);
+                  if !is_ast_unbuild {
+                    return Some(std::mem::replace(
+                      m.ast_or_source_mut(),
+                      NormalModuleAstOrSource::Unbuild,
+                    ));
+                  }
                 }
-              }
-              None
-            })
-            .collect::<Vec<Option<NormalModuleAstOrSource>>>(),
-        );
+                None
+              })
+              .collect::<Vec<Option<NormalModuleAstOrSource>>>(),
+          );
+        }
       }
 
       fast_set(&mut self.compilation, new_compilation);
@@ -228,27 +211,27 @@ where
       return Ok(());
     }
 
-    let (now_all_modules, now_runtime_modules) = collect_changed_modules(&self.compilation);
+    let now_records = self.records.clone();
 
     let mut updated_modules: IdentifierSet = Default::default();
     let mut updated_runtime_modules: IdentifierSet = Default::default();
     let mut completely_removed_modules: HashSet<String> = Default::default();
 
-    for (old_uri, (old_hash, old_module_id)) in &old_all_modules {
-      if let Some((now_hash, _)) = now_all_modules.get(old_uri) {
+    for (old_uri, old_module_id, old_module_hash) in old_records.modules() {
+      if let Some(now_module_hash) = now_records.module_hashes.get(old_uri) {
         // updated
-        if now_hash != old_hash {
-          updated_modules.insert(*old_uri);
+        if now_module_hash != old_module_hash {
+          updated_modules.insert(ModuleIdentifier::from(old_uri));
         }
       } else {
         // deleted
         completely_removed_modules.insert(old_module_id.to_string());
       }
     }
-    for identifier in now_all_modules.keys() {
-      if !old_all_modules.contains_key(identifier) {
+    for (identifier, _, _) in now_records.modules() {
+      if !old_records.module_hashes.contains_key(identifier) {
         // added
-        updated_modules.insert(*identifier);
+        updated_modules.insert(ModuleIdentifier::from(identifier));
       }
     }
 
@@ -257,18 +240,18 @@ where
     //   updated_modules, completely_removed_modules
     // );
 
-    for (identifier, old_runtime_module_content) in &old_runtime_modules {
-      if let Some(new_runtime_module_content) = now_runtime_modules.get(identifier) {
+    for (identifier, old_runtime_module_hash) in &old_records.runtime_modules {
+      if let Some(new_runtime_module_hash) = now_records.runtime_modules.get(identifier) {
         // updated
-        if new_runtime_module_content != old_runtime_module_content {
-          updated_runtime_modules.insert(*identifier);
+        if new_runtime_module_hash != old_runtime_module_hash {
+          updated_runtime_modules.insert(ModuleIdentifier::from(identifier.as_str()));
         }
       }
     }
-    for identifier in now_runtime_modules.keys() {
-      if !old_runtime_modules.contains_key(identifier) {
+    for identifier in now_records.runtime_modules.keys() {
+      if !old_records.runtime_modules.contains_key(identifier) {
         // added
-        updated_runtime_modules.insert(*identifier);
+        updated_runtime_modules.insert(ModuleIdentifier::from(identifier.as_str()));
       }
     }
 
@@ -2,13 +2,16 @@ mod compilation;
 mod hmr;
 mod make;
 mod queue;
+mod records;
 mod resolver;
+mod watch;
 
 use std::{path::Path, sync::Arc};
 
 pub use compilation::*;
 pub use make::MakeParam;
 pub use queue::*;
+pub use records::*;
 pub use resolver::*;
 use rspack_error::Result;
 use rspack_fs::AsyncWritableFileSystem;
@@ -16,6 +19,7 @@ use rspack_futures::FuturesResults;
 use rspack_identifier::IdentifierSet;
 use rustc_hash::FxHashMap as HashMap;
 use tracing::instrument;
+pub use watch::*;
 
 use crate::{
   cache::Cache, fast_set, AssetEmittedArgs, CompilerOptions, Plugin, PluginDriver,
@@ -36,6 +40,8 @@ where
   /// emitted asset versions
   /// the key of HashMap is filename, the value of HashMap is version
   pub emitted_asset_versions: HashMap<String, String>,
+  /// records of the last compilation
+  pub records: Arc<CompilationRecords>,
 }
 
 impl<T> Compiler<T>
@@ -72,6 +78,7 @@ where
       resolver_factory,
       cache,
       emitted_asset_versions: Default::default(),
+      records: Default::default(),
     }
   }
 
@@ -83,10 +90,14 @@ where
   #[instrument(name = "build", skip_all)]
   pub async fn build(&mut self) -> Result<()> {
     self.cache.end_idle();
-    // TODO: clear the outdate cache entries in resolver,
-    // TODO: maybe it's better to use external entries.
+    // the entries of the resolver are not invalidated by file changes, the resolve results of
+    // the unchanged requests are served by `resolve_module_occasion` which is validated by snapshots
     self.plugin_driver.resolver_factory.clear_entries();
 
+    if let Some(records_path) = &self.options.records_path {
+      self.records = Arc::new(CompilationRecords::read(records_path)?);
+    }
+
     fast_set(
       &mut self.compilation,
       Compilation::new(
@@ -97,6 +108,7 @@ where
         self.cache.clone(),
       ),
     );
+    self.compilation.records = self.records.clone();
 
     self.plugin_driver.before_compile().await?;
 
@@ -162,6 +174,8 @@ where
       self.compilation.used_symbol_ref = analyze_result.used_symbol_ref;
       self.compilation.bailout_module_identifiers = analyze_result.bail_out_module_identifiers;
       self.compilation.side_effects_free_modules = analyze_result.side_effects_free_modules;
+      self.compilation.side_effects_skipped_modules = analyze_result.side_effects_skipped_modules;
+      self.compilation.side_effects_kept_modules = analyze_result.side_effects_kept_modules;
       self.compilation.module_item_map = analyze_result.module_item_map;
       if self.options.builtins.tree_shaking.enable()
         && self.options.optimization.side_effects.is_enable()
@@ -177,6 +191,12 @@ where
       .after_compile(&mut self.compilation)
       .await?;
 
+    // records are only used to keep ids stable between builds and to diff the hot updates,
+    // generating runtime modules for them is expensive, so skip them if neither is needed
+    if self.options.dev_server.hot || self.options.records_path.is_some() {
+      self.records = Arc::new(CompilationRecords::new(&self.compilation));
+    }
+
     // Consume plugin driver diagnostic
     let plugin_driver_diagnostics = self.plugin_driver.take_diagnostic();
     self
@@ -192,6 +212,10 @@ where
       self.emit_assets().await?;
     }
 
+    if let Some(records_path) = &self.options.records_path {
+      self.records.write(records_path)?;
+    }
+
     self.compilation.done(self.plugin_driver.clone()).await?;
     Ok(())
   }
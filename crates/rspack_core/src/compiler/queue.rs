@@ -27,6 +27,7 @@ pub struct FactorizeTask {
   pub original_module_identifier: Option<ModuleIdentifier>,
   pub original_module_context: Option<Context>,
   pub issuer: Option<String>,
+  pub issuer_description: Option<Arc<nodejs_resolver::DescriptionData>>,
   pub dependencies: Vec<BoxModuleDependency>,
   pub is_entry: bool,
   pub module_type: Option<ModuleType>,
@@ -91,6 +92,7 @@ impl WorkerTask for FactorizeTask {
             options: self.options.clone(),
             lazy_visit_modules: self.lazy_visit_modules,
             issuer: self.issuer,
+            issuer_description: self.issuer_description,
           },
           self.resolver_factory,
           self.plugin_driver,
@@ -0,0 +1,168 @@
+use std::path::Path;
+
+use rspack_error::{internal_error, Result};
+use rustc_hash::FxHashMap as HashMap;
+use serde::{Deserialize, Serialize};
+
+use crate::{calc_hash, Chunk, ChunkKind, Compilation, RuntimeSpec};
+
+/// Records of a compilation which are used by the next compilation, the same as `records` of webpack.
+///
+/// Module ids and chunk ids are recorded to keep them stable between compilations, hashes and
+/// the module-to-chunk map are recorded to find out the updates for hot module replacement.
+/// They could be read from and written to `recordsPath`, so ids stay stable between separate builds.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CompilationRecords {
+  pub hash: Option<String>,
+  /// module identifier -> module id
+  pub module_ids: HashMap<String, String>,
+  /// module identifier -> module hash
+  pub module_hashes: HashMap<String, String>,
+  /// chunk key -> chunk id, see [CompilationRecords::chunk_key]
+  pub chunk_ids: HashMap<String, String>,
+  /// chunk id -> chunk hash
+  pub chunk_hashes: HashMap<String, String>,
+  /// chunk id -> identifiers of modules in the chunk
+  pub chunk_modules: HashMap<String, Vec<String>>,
+  /// chunk id -> runtime of the chunk
+  pub chunk_runtimes: HashMap<String, Vec<String>>,
+  /// runtime module identifier -> hash of the generated runtime module
+  pub runtime_modules: HashMap<String, u64>,
+  /// runtimes of all entries
+  pub runtimes: Vec<String>,
+}
+
+impl CompilationRecords {
+  pub fn new(compilation: &Compilation) -> Self {
+    let chunk_graph = &compilation.chunk_graph;
+    let module_graph = &compilation.module_graph;
+    let mut records = Self {
+      hash: compilation
+        .hash
+        .as_ref()
+        .map(|hash| hash.encoded().to_string()),
+      ..Default::default()
+    };
+
+    for (identifier, cgm) in &chunk_graph.chunk_graph_module_by_module_identifier {
+      let Some(id) = &cgm.id else {
+        continue;
+      };
+      records
+        .module_ids
+        .insert(identifier.to_string(), id.clone());
+      if let Some(hash) = module_graph.get_module_hash(identifier) {
+        records
+          .module_hashes
+          .insert(identifier.to_string(), hash.encoded().to_string());
+      }
+    }
+
+    for chunk in compilation.chunk_by_ukey.values() {
+      if chunk.kind == ChunkKind::HotUpdate {
+        continue;
+      }
+      let Some(id) = &chunk.id else {
+        continue;
+      };
+      if let Some(key) = Self::chunk_key(chunk, compilation) {
+        records.chunk_ids.insert(key, id.clone());
+      }
+      if let Some(hash) = &chunk.hash {
+        records
+          .chunk_hashes
+          .insert(id.clone(), hash.encoded().to_string());
+      }
+      let mut modules = chunk_graph
+        .get_chunk_graph_chunk(&chunk.ukey)
+        .modules
+        .iter()
+        .map(|identifier| identifier.to_string())
+        .collect::<Vec<_>>();
+      modules.sort_unstable();
+      records.chunk_modules.insert(id.clone(), modules);
+      records
+        .chunk_runtimes
+        .insert(id.clone(), runtime_to_vec(&chunk.runtime));
+    }
+
+    records.runtime_modules = compilation
+      .runtime_modules
+      .iter()
+      .map(|(identifier, module)| {
+        (
+          identifier.to_string(),
+          calc_hash(&module.generate(compilation).source()),
+        )
+      })
+      .collect();
+
+    let mut runtimes: RuntimeSpec = Default::default();
+    for entry_ukey in compilation.get_chunk_graph_entries() {
+      if let Some(entry_chunk) = compilation.chunk_by_ukey.get(&entry_ukey) {
+        runtimes.extend(entry_chunk.runtime.iter().cloned());
+      }
+    }
+    records.runtimes = runtime_to_vec(&runtimes);
+
+    records
+  }
+
+  /// The key to identify a chunk between compilations, which is the name of the chunk,
+  /// or the identifier of the module which splits the chunk.
+  pub fn chunk_key(chunk: &Chunk, compilation: &Compilation) -> Option<String> {
+    if let Some(name) = &chunk.name {
+      return Some(name.clone());
+    }
+    compilation
+      .chunk_graph
+      .split_point_module_identifier_to_chunk_ukey
+      .iter()
+      .find(|(_, ukey)| **ukey == chunk.ukey)
+      .map(|(identifier, _)| identifier.to_string())
+  }
+
+  /// Read records from the file, empty records are returned if the file doesn't exist.
+  pub fn read(path: &Path) -> Result<Self> {
+    if !path.exists() {
+      return Ok(Default::default());
+    }
+    let content = std::fs::read(path)?;
+    serde_json::from_slice(&content).map_err(|e| {
+      internal_error!(
+        "Failed to parse records {}: {}",
+        path.display(),
+        e.to_string()
+      )
+    })
+  }
+
+  pub fn write(&self, path: &Path) -> Result<()> {
+    if let Some(dir) = path.parent() {
+      std::fs::create_dir_all(dir)?;
+    }
+    let content = serde_json::to_vec_pretty(self).map_err(|e| internal_error!(e.to_string()))?;
+    std::fs::write(path, content)?;
+    Ok(())
+  }
+
+  /// Modules which have both id and hash recorded.
+  pub fn modules(&self) -> impl Iterator<Item = (&str, &str, &str)> {
+    self.module_ids.iter().filter_map(|(identifier, id)| {
+      self
+        .module_hashes
+        .get(identifier)
+        .map(|hash| (identifier.as_str(), id.as_str(), hash.as_str()))
+    })
+  }
+}
+
+fn runtime_to_vec(runtime: &RuntimeSpec) -> Vec<String> {
+  let mut runtime = runtime
+    .iter()
+    .map(|runtime| runtime.to_string())
+    .collect::<Vec<_>>();
+  runtime.sort_unstable();
+  runtime
+}
@@ -73,6 +73,16 @@ impl ResolverFactory {
       resolver
     }
   }
+
+  /// Load the description file data of the package which contains the resolved path, used to
+  /// restore the resolve results from the persistent cache.
+  pub fn load_description(&self, path: &Path) -> Option<Arc<nodejs_resolver::DescriptionData>> {
+    let parent = path.parent()?;
+    match self.resolver.resolve(parent, path.to_str()?) {
+      Ok(ResolveResult::Resource(resource)) => resource.description,
+      _ => None,
+    }
+  }
 }
 
 #[derive(Debug)]
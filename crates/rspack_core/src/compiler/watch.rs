@@ -0,0 +1,215 @@
+use std::{
+  collections::HashSet,
+  path::{Path, PathBuf},
+  time::{Duration, SystemTime},
+};
+
+use rayon::prelude::*;
+use rspack_error::{internal_error, Result};
+use rspack_fs::AsyncWritableFileSystem;
+use rustc_hash::FxHashMap as HashMap;
+
+use crate::{Compilation, Compiler};
+
+#[derive(Debug, Clone)]
+pub struct WatchOptions {
+  /// Changes in this period after the last change are aggregated into one rebuild.
+  pub aggregate_timeout: Duration,
+  /// Interval of checking the dependencies for changes.
+  pub poll: Duration,
+}
+
+impl Default for WatchOptions {
+  fn default() -> Self {
+    // the same aggregate timeout as webpack
+    Self {
+      aggregate_timeout: Duration::from_millis(20),
+      poll: Duration::from_millis(100),
+    }
+  }
+}
+
+#[derive(Debug, Default)]
+pub struct WatchChanges {
+  pub changed_files: HashSet<String>,
+  pub removed_files: HashSet<String>,
+}
+
+/// Watch file, context and missing dependencies of a compilation by polling their modified time.
+///
+/// The modified times are read in parallel on the blocking thread pool, so polling doesn't
+/// block the async runtime.
+#[derive(Debug)]
+pub struct DependenciesWatcher {
+  /// path -> modified time, `None` means the path doesn't exist
+  states: HashMap<PathBuf, Option<SystemTime>>,
+}
+
+impl DependenciesWatcher {
+  /// Paths modified after `start_time` are treated as changed, since the compilation
+  /// may have read them before the modification.
+  pub async fn new(compilation: &Compilation, start_time: SystemTime) -> Result<Self> {
+    let paths = compilation
+      .file_dependencies
+      .iter()
+      .chain(compilation.context_dependencies.iter())
+      .chain(compilation.missing_dependencies.iter())
+      .cloned()
+      .collect::<Vec<_>>();
+    let states = tokio::task::spawn_blocking(move || {
+      paths
+        .into_par_iter()
+        .map(|path| {
+          let state = modified_time(&path).filter(|time| *time <= start_time);
+          (path, state)
+        })
+        .collect()
+    })
+    .await
+    .map_err(|e| internal_error!("Failed to watch dependencies: {e}"))?;
+    Ok(Self { states })
+  }
+
+  /// Collect changes since the last check, returns `true` if anything changed.
+  pub async fn collect_changes(&mut self, changes: &mut WatchChanges) -> Result<bool> {
+    let mut states = std::mem::take(&mut self.states);
+    let (states, changed) = tokio::task::spawn_blocking(move || {
+      let changed = states
+        .par_iter_mut()
+        .filter_map(|(path, state)| {
+          let current = modified_time(path);
+          if current == *state {
+            return None;
+          }
+          *state = current;
+          Some((path.to_string_lossy().to_string(), current.is_some()))
+        })
+        .collect::<Vec<_>>();
+      (states, changed)
+    })
+    .await
+    .map_err(|e| internal_error!("Failed to watch dependencies: {e}"))?;
+    self.states = states;
+
+    for (path, exists) in &changed {
+      if *exists {
+        changes.removed_files.remove(path);
+        changes.changed_files.insert(path.clone());
+      } else {
+        changes.changed_files.remove(path);
+        changes.removed_files.insert(path.clone());
+      }
+    }
+    Ok(!changed.is_empty())
+  }
+
+  /// Keep watching the paths of `other` which are not watched yet.
+  pub fn extend(&mut self, other: DependenciesWatcher) {
+    for (path, state) in other.states {
+      self.states.entry(path).or_insert(state);
+    }
+  }
+
+  /// Wait for changes, changes are aggregated until nothing changes within `aggregate_timeout`.
+  pub async fn wait(&mut self, options: &WatchOptions) -> Result<WatchChanges> {
+    let mut changes = WatchChanges::default();
+    while !self.collect_changes(&mut changes).await? {
+      tokio::time::sleep(options.poll).await;
+    }
+    loop {
+      tokio::time::sleep(options.aggregate_timeout).await;
+      if !self.collect_changes(&mut changes).await? {
+        return Ok(changes);
+      }
+    }
+  }
+}
+
+fn modified_time(path: &Path) -> Option<SystemTime> {
+  std::fs::metadata(path)
+    .and_then(|metadata| metadata.modified())
+    .ok()
+}
+
+impl<T> Compiler<T>
+where
+  T: AsyncWritableFileSystem + Send + Sync,
+{
+  /// Build, then rebuild whenever the file, context or missing dependencies change.
+  ///
+  /// `handler` is called with the result of each compilation, watching stops when it returns `false`.
+  /// A failed compilation doesn't stop watching, the next change starts a new build.
+  pub async fn watch<F>(&mut self, options: WatchOptions, mut handler: F) -> Result<()>
+  where
+    F: FnMut(Result<&Compilation>) -> bool,
+  {
+    let mut start_time = SystemTime::now();
+    let mut result = self.build().await;
+    let mut last_watcher: Option<DependenciesWatcher> = None;
+    loop {
+      let failed = result.is_err();
+      if !handler(result.map(|_| &self.compilation)) {
+        return Ok(());
+      }
+      let mut watcher = DependenciesWatcher::new(&self.compilation, start_time).await?;
+      if failed {
+        // the failed compilation may not have collected all the dependencies
+        if let Some(last_watcher) = last_watcher.take() {
+          watcher.extend(last_watcher);
+        }
+      }
+      let changes = watcher.wait(&options).await?;
+      last_watcher = Some(watcher);
+      start_time = SystemTime::now();
+      result = if failed {
+        self.build().await
+      } else {
+        self
+          .rebuild(changes.changed_files, changes.removed_files)
+          .await
+      };
+    }
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[tokio::test]
+  async fn should_collect_changes() {
+    let dir = crate::unique_temp_dir("rspack_dependencies_watcher_test");
+    let file = dir.join("a.js");
+    let missing = dir.join("b.js");
+    std::fs::write(&file, "a").expect("should write file");
+
+    let mut watcher = DependenciesWatcher {
+      states: [
+        (file.clone(), modified_time(&file)),
+        (missing.clone(), modified_time(&missing)),
+      ]
+      .into_iter()
+      .collect(),
+    };
+    let mut changes = WatchChanges::default();
+    assert!(!watcher
+      .collect_changes(&mut changes)
+      .await
+      .expect("should collect changes"));
+
+    std::fs::write(&missing, "b").expect("should write file");
+    std::fs::remove_file(&file).expect("should remove file");
+    assert!(watcher
+      .collect_changes(&mut changes)
+      .await
+      .expect("should collect changes"));
+    assert!(changes
+      .changed_files
+      .contains(&missing.to_string_lossy().to_string()));
+    assert!(changes
+      .removed_files
+      .contains(&file.to_string_lossy().to_string()));
+
+    let _ = std::fs::remove_dir_all(&dir);
+  }
+}
@@ -0,0 +1,189 @@
+use std::sync::Arc;
+
+use indexmap::IndexMap;
+use swc_core::ecma::atoms::JsWord;
+
+use crate::ModuleIdentifier;
+
+const MODULE_REFERENCE_PREFIX: &str = "__WEBPACK_MODULE_REFERENCE__";
+
+/// Exports of a module collected while it's code generated in the scope of a concatenated module.
+#[derive(Debug, Default, Clone)]
+pub struct ConcatenatedModuleExports {
+  /// export name -> local binding of the module
+  pub exports: IndexMap<JsWord, JsWord>,
+  /// export name -> (referenced module, export names of the referenced module),
+  /// empty export names reference the namespace object of the module
+  pub reexports: IndexMap<JsWord, (ModuleIdentifier, Vec<JsWord>)>,
+  /// export name -> expression which only references the top level bindings of the module
+  pub raw_exports: IndexMap<JsWord, String>,
+  /// `export * from '...'` of concatenated modules
+  pub star_reexports: Vec<ModuleIdentifier>,
+}
+
+/// A reference to the exports of a concatenated module, created by [ConcatenationScope::create_module_reference].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModuleReference {
+  pub module: ModuleIdentifier,
+  /// Export names, empty means the namespace object of the module
+  pub ids: Vec<JsWord>,
+  pub call: bool,
+}
+
+// Align with https://github.com/webpack/webpack/blob/4b4ca3bb53f36a5b8fc6bc1bd976ed7af161bd80/lib/ConcatenationScope.js
+/// Dependency templates use it to render references to the other modules in the same concatenated module
+/// and to register exports instead of defining them on the exports object.
+#[derive(Debug)]
+pub struct ConcatenationScope {
+  modules: Arc<[ModuleIdentifier]>,
+  current_module: ModuleIdentifier,
+  exports: ConcatenatedModuleExports,
+}
+
+impl ConcatenationScope {
+  pub fn new(modules: Arc<[ModuleIdentifier]>, current_module: ModuleIdentifier) -> Self {
+    Self {
+      modules,
+      current_module,
+      exports: Default::default(),
+    }
+  }
+
+  pub fn current_module(&self) -> &ModuleIdentifier {
+    &self.current_module
+  }
+
+  pub fn is_module_in_scope(&self, module: &ModuleIdentifier) -> bool {
+    self.modules.contains(module)
+  }
+
+  pub fn register_export(&mut self, export_name: JsWord, symbol: JsWord) {
+    self.exports.exports.entry(export_name).or_insert(symbol);
+  }
+
+  pub fn register_reexport(
+    &mut self,
+    export_name: JsWord,
+    module: ModuleIdentifier,
+    ids: Vec<JsWord>,
+  ) {
+    self
+      .exports
+      .reexports
+      .entry(export_name)
+      .or_insert((module, ids));
+  }
+
+  pub fn register_raw_export(&mut self, export_name: JsWord, expression: String) {
+    self
+      .exports
+      .raw_exports
+      .entry(export_name)
+      .or_insert(expression);
+  }
+
+  pub fn register_star_reexport(&mut self, module: ModuleIdentifier) {
+    if !self.exports.star_reexports.contains(&module) {
+      self.exports.star_reexports.push(module);
+    }
+  }
+
+  /// Returns an identifier which is replaced with the final binding once all the modules are generated.
+  pub fn create_module_reference(
+    &self,
+    module: &ModuleIdentifier,
+    ids: &[JsWord],
+    call: bool,
+  ) -> String {
+    let index = self
+      .modules
+      .iter()
+      .position(|m| m == module)
+      .expect("should be a module in the concatenation scope");
+    let ids = ids
+      .iter()
+      .map(|id| id.as_ref())
+      .collect::<Vec<_>>()
+      .join("\n");
+    format!(
+      "{MODULE_REFERENCE_PREFIX}{index}_{}{}__",
+      encode_hex(&ids),
+      if call { "_call" } else { "" }
+    )
+  }
+
+  pub fn match_module_reference(&self, name: &str) -> Option<ModuleReference> {
+    let mut parts = name
+      .strip_prefix(MODULE_REFERENCE_PREFIX)?
+      .strip_suffix("__")?
+      .split('_');
+    let module = *self.modules.get(parts.next()?.parse::<usize>().ok()?)?;
+    let ids = decode_hex(parts.next()?)?;
+    let call = match parts.next() {
+      Some("call") => true,
+      Some(_) => return None,
+      None => false,
+    };
+    Some(ModuleReference {
+      module,
+      ids: if ids.is_empty() {
+        vec![]
+      } else {
+        ids.split('\n').map(JsWord::from).collect()
+      },
+      call,
+    })
+  }
+
+  pub fn take_exports(&mut self) -> ConcatenatedModuleExports {
+    std::mem::take(&mut self.exports)
+  }
+}
+
+fn encode_hex(value: &str) -> String {
+  value.bytes().map(|byte| format!("{byte:02x}")).collect()
+}
+
+fn decode_hex(value: &str) -> Option<String> {
+  if value.len() % 2 != 0 {
+    return None;
+  }
+  let bytes = (0..value.len())
+    .step_by(2)
+    .map(|i| u8::from_str_radix(value.get(i..i + 2)?, 16).ok())
+    .collect::<Option<Vec<_>>>()?;
+  String::from_utf8(bytes).ok()
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn should_match_module_reference() {
+    let a = ModuleIdentifier::from("a");
+    let b = ModuleIdentifier::from("b");
+    let scope = ConcatenationScope::new(Arc::from([a, b]), a);
+
+    let reference = scope.create_module_reference(&b, &["foo".into(), "default".into()], true);
+    assert_eq!(
+      scope.match_module_reference(&reference),
+      Some(ModuleReference {
+        module: b,
+        ids: vec!["foo".into(), "default".into()],
+        call: true,
+      })
+    );
+
+    let reference = scope.create_module_reference(&a, &[], false);
+    assert_eq!(
+      scope.match_module_reference(&reference),
+      Some(ModuleReference {
+        module: a,
+        ids: vec![],
+        call: false,
+      })
+    );
+    assert_eq!(scope.match_module_reference("foo"), None);
+  }
+}
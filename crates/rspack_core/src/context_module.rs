@@ -18,10 +18,10 @@ use rustc_hash::FxHashSet as HashSet;
 
 use crate::{
   contextify, stringify_map, AstOrSource, BoxModuleDependency, BuildContext, BuildInfo, BuildMeta,
-  BuildResult, ChunkGraph, CodeGenerationResult, Compilation, ContextElementDependency,
-  DependencyCategory, DependencyId, DependencyType, GenerationResult, LibIdentOptions, Module,
-  ModuleType, Resolve, ResolveOptionsWithDependencyType, ResolverFactory, RuntimeGlobals,
-  SourceType,
+  BuildResult, ChunkGraph, ChunkGroupOptions, CodeGenerationResult, Compilation,
+  ContextElementDependency, DependencyCategory, DependencyId, DependencyType, GenerationResult,
+  LibIdentOptions, Module, ModuleType, Resolve, ResolveOptionsWithDependencyType, ResolverFactory,
+  RuntimeGlobals, SourceType,
 };
 
 #[derive(Debug, Clone)]
@@ -52,10 +52,14 @@ pub struct ContextOptions {
   pub recursive: bool,
   pub reg_exp: RspackRegex,
   pub reg_str: String, // generate context module id
+  /// Regex literal like `/\.json$/i`, only the matched requests are included
   pub include: Option<String>,
+  /// Regex literal like `/\.json$/i`, the matched requests are excluded
   pub exclude: Option<String>,
   pub category: DependencyCategory,
   pub request: String,
+  /// Options of the chunk groups created for the lazy elements
+  pub group_options: Option<ChunkGroupOptions>,
 }
 
 impl Display for ContextOptions {
@@ -70,7 +74,15 @@ impl Display for ContextOptions {
       self.exclude,
       self.category,
       self.request
-    )
+    )?;
+    if let Some(name) = self
+      .group_options
+      .as_ref()
+      .and_then(|options| options.name.as_ref())
+    {
+      write!(f, "|{name}")?;
+    }
+    Ok(())
   }
 }
 
@@ -82,6 +94,7 @@ impl PartialEq for ContextOptions {
       && self.include == other.include
       && self.exclude == other.exclude
       && self.category == other.category
+      && self.group_options == other.group_options
   }
 }
 
@@ -95,6 +108,11 @@ impl Hash for ContextOptions {
     self.include.hash(state);
     self.exclude.hash(state);
     self.category.hash(state);
+    if let Some(options) = &self.group_options {
+      options.name.hash(state);
+      options.prefetch_order.hash(state);
+      options.preload_order.hash(state);
+    }
   }
 }
 
@@ -181,7 +199,8 @@ impl ContextModule {
   #[inline]
   pub fn get_source_string(&self, compilation: &Compilation) -> Result<BoxSource> {
     match self.options.context_options.mode {
-      ContextMode::Lazy => Ok(self.get_lazy_source(compilation)),
+      // the elements of lazy-once are also split into their own chunks
+      ContextMode::Lazy | ContextMode::LazyOnce => Ok(self.get_lazy_source(compilation)),
       _ => self.generate_source(compilation),
     }
   }
@@ -395,16 +414,25 @@ impl ContextModule {
       ctx: &str,
       dir: &Path,
       dependencies: &mut Vec<BoxModuleDependency>,
+      context_dependencies: &mut HashSet<PathBuf>,
       options: &ContextModuleOptions,
       resolve_options: &nodejs_resolver::Options,
     ) -> Result<()> {
       if dir.is_dir() {
+        context_dependencies.insert(dir.to_path_buf());
         for entry in fs::read_dir(dir)? {
           let entry = entry?;
           let path = entry.path();
           if path.is_dir() {
             if options.context_options.recursive {
-              visit_dirs(ctx, &path, dependencies, options, resolve_options)?;
+              visit_dirs(
+                ctx,
+                &path,
+                dependencies,
+                context_dependencies,
+                options,
+                resolve_options,
+              )?;
             }
           } else if path
             .file_name()
@@ -462,24 +490,53 @@ impl ContextModule {
       dependency_category: self.options.context_options.category,
     });
 
+    // every visited directory is a context dependency, so adding files to nested
+    // directories invalidates the cached build
+    let mut context_dependencies: HashSet<PathBuf> = Default::default();
+    context_dependencies.insert(PathBuf::from(&self.options.resource));
     visit_dirs(
       &self.options.resource,
       Path::new(&self.options.resource),
       &mut dependencies,
+      &mut context_dependencies,
       &self.options,
       resolver.options(),
     )?;
 
+    let include = self
+      .options
+      .context_options
+      .include
+      .as_deref()
+      .map(regex_from_literal)
+      .transpose()?;
+    let exclude = self
+      .options
+      .context_options
+      .exclude
+      .as_deref()
+      .map(regex_from_literal)
+      .transpose()?;
+    if include.is_some() || exclude.is_some() {
+      dependencies.retain(|dependency| {
+        let request = dependency.user_request();
+        include
+          .as_ref()
+          .map_or(true, |include| include.test(request))
+          && !exclude
+            .as_ref()
+            .map_or(false, |exclude| exclude.test(request))
+      });
+    }
+
     tracing::trace!("resolving dependencies for {:?}", dependencies);
 
     let mut hasher = RspackHash::from(&build_context.compiler_options.output);
     self.update_hash(&mut hasher);
 
-    let mut context_dependencies: HashSet<PathBuf> = Default::default();
-    context_dependencies.insert(PathBuf::from(&self.options.resource));
-
     let build_info = BuildInfo {
       hash: Some(hasher.digest(&build_context.compiler_options.output.hash_digest)),
+      cacheable: true,
       context_dependencies,
       ..Default::default()
     };
@@ -499,6 +556,15 @@ fn create_identifier(options: &ContextModuleOptions) -> Identifier {
   Identifier::from(format!("{options}"))
 }
 
+/// Compile a regex literal like `/\.json$/i`.
+fn regex_from_literal(literal: &str) -> Result<RspackRegex> {
+  let (expr, flags) = literal
+    .strip_prefix('/')
+    .and_then(|literal| literal.rsplit_once('/'))
+    .ok_or_else(|| internal_error!("Invalid regex literal {literal}"))?;
+  RspackRegex::with_flags(expr, flags)
+}
+
 pub fn normalize_context(str: &str) -> String {
   if str == "./" || str == "." {
     return "".to_string();
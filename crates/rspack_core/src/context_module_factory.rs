@@ -97,7 +97,9 @@ impl ContextModuleFactory {
     let resource_data = self
       .cache
       .resolve_module_occasion
-      .use_cache(resolve_args, |args| resolve(args, plugin_driver))
+      .use_cache(resolve_args, &plugin_driver.resolver_factory, |args| {
+        resolve(args, plugin_driver)
+      })
       .await;
     let module = match resource_data {
       Ok(ResolveResult::Resource(resource)) => Box::new(ContextModule::new(
@@ -1,6 +1,6 @@
 use crate::{
-  Context, ContextMode, ContextOptions, Dependency, DependencyCategory, DependencyId,
-  DependencyType, ModuleDependency,
+  ChunkGroupOptions, Context, ContextMode, ContextOptions, Dependency, DependencyCategory,
+  DependencyId, DependencyType, ModuleDependency,
 };
 
 #[derive(Debug, Eq, PartialEq, Clone, Hash)]
@@ -56,6 +56,10 @@ impl ModuleDependency for ContextElementDependency {
     Some(&self.options)
   }
 
+  fn group_options(&self) -> Option<&ChunkGroupOptions> {
+    self.options.group_options.as_ref()
+  }
+
   fn set_request(&mut self, request: String) {
     self.request = request;
   }
@@ -6,6 +6,7 @@ use std::sync::atomic::Ordering::Relaxed;
 pub use entry::*;
 use once_cell::sync::Lazy;
 use rspack_util::ext::AsAny;
+use serde::{Deserialize, Serialize};
 pub use span::SpanExt;
 mod runtime_template;
 pub use runtime_template::*;
@@ -18,6 +19,7 @@ mod const_dependency;
 use std::{
   any::Any,
   borrow::Cow,
+  collections::HashMap,
   fmt::{Debug, Display},
   hash::Hash,
 };
@@ -26,12 +28,13 @@ pub use const_dependency::ConstDependency;
 mod dependency_template;
 pub use dependency_template::*;
 use dyn_clone::{clone_trait_object, DynClone};
+use swc_core::ecma::atoms::JsWord;
 
 use crate::{ChunkGroupOptions, Context, ContextMode, ContextOptions, ErrorSpan};
 
 // Used to describe dependencies' types, see webpack's `type` getter in `Dependency`
 // Note: This is almost the same with the old `ResolveKind`
-#[derive(Default, Clone, PartialEq, Eq, Hash, Debug)]
+#[derive(Default, Clone, PartialEq, Eq, Hash, Debug, Serialize, Deserialize)]
 pub enum DependencyType {
   #[default]
   Unknown,
@@ -78,6 +81,22 @@ pub enum DependencyType {
   WasmExportImported,
   /// static exports
   StaticExports,
+  /// container entry
+  ContainerEntry,
+  /// module exposed by a container
+  ContainerExposed,
+  /// external container of a remote module
+  RemoteToExternal,
+  /// fallback containers of a remote module
+  RemoteFallback,
+  /// module provided to a share scope
+  ProvideSharedModule,
+  /// actual module of a provide shared module
+  ProvideModuleForShared,
+  /// fallback of a consume shared module
+  ConsumeSharedFallback,
+  /// item of the dependency array of AMD `define` and `require`
+  AmdRequireItem,
   Custom(Cow<'static, str>),
 }
 
@@ -107,17 +126,26 @@ impl Display for DependencyType {
       DependencyType::WasmImport => write!(f, "wasm import"),
       DependencyType::WasmExportImported => write!(f, "wasm export imported"),
       DependencyType::StaticExports => write!(f, "static exports"),
+      DependencyType::ContainerEntry => write!(f, "container entry"),
+      DependencyType::ContainerExposed => write!(f, "container exposed"),
+      DependencyType::RemoteToExternal => write!(f, "remote to external"),
+      DependencyType::RemoteFallback => write!(f, "remote fallback"),
+      DependencyType::ProvideSharedModule => write!(f, "provide shared module"),
+      DependencyType::ProvideModuleForShared => write!(f, "provide module for shared"),
+      DependencyType::ConsumeSharedFallback => write!(f, "consume shared fallback"),
+      DependencyType::AmdRequireItem => write!(f, "amd require item"),
       DependencyType::Custom(ty) => write!(f, "custom {ty}"),
     }
   }
 }
 
-#[derive(Default, Copy, Clone, PartialEq, Eq, Hash, Debug)]
+#[derive(Default, Copy, Clone, PartialEq, Eq, Hash, Debug, Serialize, Deserialize)]
 pub enum DependencyCategory {
   #[default]
   Unknown,
   Esm,
   CommonJS,
+  Amd,
   Url,
   CssImport,
   CssCompose,
@@ -130,6 +158,7 @@ impl From<&str> for DependencyCategory {
     match value {
       "esm" => Self::Esm,
       "commonjs" => Self::CommonJS,
+      "amd" => Self::Amd,
       "url" => Self::Url,
       "wasm" => Self::Wasm,
       "css-import" => Self::CssImport,
@@ -147,6 +176,7 @@ impl Display for DependencyCategory {
       DependencyCategory::Unknown => write!(f, "unknown"),
       DependencyCategory::Esm => write!(f, "esm"),
       DependencyCategory::CommonJS => write!(f, "commonjs"),
+      DependencyCategory::Amd => write!(f, "amd"),
       DependencyCategory::Url => write!(f, "url"),
       DependencyCategory::CssImport => write!(f, "css-import"),
       DependencyCategory::CssCompose => write!(f, "css-compose"),
@@ -156,6 +186,10 @@ impl Display for DependencyCategory {
   }
 }
 
+/// Import attributes of `import json from "./a.json" assert { type: "json" }`
+/// and `import("./a.json", { with: { type: "json" } })`
+pub type ImportAttributes = HashMap<String, String>;
+
 pub trait Dependency: AsAny + DynClone + Send + Sync + Debug {
   fn category(&self) -> &DependencyCategory {
     &DependencyCategory::Unknown
@@ -218,6 +252,21 @@ pub trait ModuleDependency: Dependency {
   fn group_options(&self) -> Option<&ChunkGroupOptions> {
     None
   }
+
+  fn get_attributes(&self) -> Option<&ImportAttributes> {
+    None
+  }
+
+  /// The exports of the module used through this dependency, `None` means all of them are
+  /// possibly used, e.g. `webpackExports` of `import()`
+  fn get_referenced_exports(&self) -> Option<&[JsWord]> {
+    None
+  }
+
+  /// Whether all the exports of the module are reexported through this dependency, e.g. `export * from "./a"`
+  fn is_export_all(&self) -> bool {
+    false
+  }
 }
 
 impl ModuleDependency for Box<dyn ModuleDependency> {
@@ -253,6 +302,18 @@ impl ModuleDependency for Box<dyn ModuleDependency> {
     (**self).group_options()
   }
 
+  fn get_attributes(&self) -> Option<&ImportAttributes> {
+    (**self).get_attributes()
+  }
+
+  fn get_referenced_exports(&self) -> Option<&[JsWord]> {
+    (**self).get_referenced_exports()
+  }
+
+  fn is_export_all(&self) -> bool {
+    (**self).is_export_all()
+  }
+
   fn set_request(&mut self, request: String) {
     (**self).set_request(request);
   }
@@ -293,12 +354,27 @@ pub type BoxModuleDependency = Box<dyn ModuleDependency>;
 pub type BoxDependency = Box<dyn Dependency>;
 
 pub fn is_async_dependency(dep: &BoxModuleDependency) -> bool {
+  // `import()` in eager and weak mode doesn't create a chunk group
   if matches!(dep.dependency_type(), DependencyType::DynamicImport) {
-    return true;
+    return dep.group_options().is_some();
   }
   if matches!(dep.dependency_type(), DependencyType::NewWorker) {
     return true;
   }
+  // each exposed module of a container is loaded on demand by `get`
+  if matches!(dep.dependency_type(), DependencyType::ContainerExposed) {
+    return true;
+  }
+  // shared modules are loaded on demand unless they are eager, which have no chunk group options,
+  // so are the items of AMD `require`, while the items of AMD `define` are loaded synchronously
+  if matches!(
+    dep.dependency_type(),
+    DependencyType::ProvideModuleForShared
+      | DependencyType::ConsumeSharedFallback
+      | DependencyType::AmdRequireItem
+  ) {
+    return dep.group_options().is_some();
+  }
   if matches!(dep.dependency_type(), DependencyType::ContextElement) {
     if let Some(options) = dep.options() {
       return matches!(options.mode, ContextMode::Lazy | ContextMode::LazyOnce);
@@ -1,8 +1,8 @@
 use swc_core::ecma::atoms::JsWord;
 
 use crate::{
-  Compilation, DependencyId, ExportsType, InitFragment, InitFragmentStage, ModuleGraph,
-  ModuleIdentifier, RuntimeGlobals, TemplateContext,
+  is_async_dependency, Compilation, DependencyId, ExportsType, InitFragment, InitFragmentStage,
+  ModuleGraph, ModuleIdentifier, RuntimeGlobals, TemplateContext,
 };
 
 pub fn export_from_import(
@@ -248,10 +248,17 @@ pub fn module_namespace_promise(
     }
   }
 
-  format!(
-    "{}{appending}",
+  // the module of an eager or weak `import()` is not in an async chunk
+  let promise = if compilation
+    .module_graph
+    .dependency_by_id(id)
+    .map_or(true, is_async_dependency)
+  {
     block_promise(&module_id_expr, runtime_requirements)
-  )
+  } else {
+    "Promise.resolve()".to_string()
+  };
+  format!("{promise}{appending}")
 }
 
 pub fn block_promise(module_id_str: &str, runtime_requirements: &mut RuntimeGlobals) -> String {
@@ -286,6 +293,39 @@ pub fn module_raw(
   }
 }
 
+/// A function returning the factory of the module, the module should be in the same chunk.
+pub fn sync_module_factory(
+  compilation: &Compilation,
+  runtime_requirements: &mut RuntimeGlobals,
+  id: &DependencyId,
+  request: &str,
+) -> String {
+  let factory = format!(
+    "function() {{ return {}; }}",
+    module_raw(compilation, runtime_requirements, id, request, false)
+  );
+  format!("function() {{ return {factory}; }}")
+}
+
+/// A function returning a promise of the factory of the module, which loads the chunks of the
+/// module first.
+pub fn async_module_factory(
+  compilation: &Compilation,
+  runtime_requirements: &mut RuntimeGlobals,
+  id: &DependencyId,
+  request: &str,
+) -> String {
+  let factory = format!(
+    "function() {{ return {}; }}",
+    module_raw(compilation, runtime_requirements, id, request, false)
+  );
+  let ensure_chunk = module_id(compilation, id, request, false);
+  format!(
+    "function() {{ return {}.then(function() {{ return {factory}; }}); }}",
+    block_promise(&ensure_chunk, runtime_requirements)
+  )
+}
+
 pub fn miss_module(request: &str) -> String {
   format!("Object({}())", throw_missing_module_error_function(request))
 }
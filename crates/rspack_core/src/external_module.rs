@@ -1,4 +1,5 @@
 use std::borrow::Cow;
+use std::collections::BTreeMap;
 use std::hash::Hash;
 
 use rspack_error::{internal_error, IntoTWithDiagnosticArray, Result, TWithDiagnosticArray};
@@ -18,15 +19,15 @@ static EXTERNAL_MODULE_JS_SOURCE_TYPES: &[SourceType] = &[SourceType::JavaScript
 static EXTERNAL_MODULE_CSS_SOURCE_TYPES: &[SourceType] = &[SourceType::Css];
 
 #[derive(Debug, Clone)]
-pub struct ExternalRequest(pub Vec<String>);
+pub struct ExternalRequestValue(pub Vec<String>);
 
-impl Serialize for ExternalRequest {
+impl Serialize for ExternalRequestValue {
   fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
     self.0.serialize(serializer)
   }
 }
 
-impl ExternalRequest {
+impl ExternalRequestValue {
   pub fn as_str(&self) -> &str {
     // we're sure array have more than one element,because it is valid in js side
     self.0.get(0).expect("should have at least element")
@@ -35,6 +36,35 @@ impl ExternalRequest {
     &self.0
   }
 }
+
+/// The request of an external, which is either the same for all external types or,
+/// in the object form like `{ root: "React", commonjs: "react" }`, keyed by the type.
+#[derive(Debug, Clone)]
+pub enum ExternalRequest {
+  Single(ExternalRequestValue),
+  Map(BTreeMap<String, ExternalRequestValue>),
+}
+
+impl Serialize for ExternalRequest {
+  fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+    match self {
+      Self::Single(value) => value.serialize(serializer),
+      Self::Map(map) => map.serialize(serializer),
+    }
+  }
+}
+
+impl ExternalRequest {
+  /// The request used by the external type, fails when the object form has no entry for it.
+  pub fn for_type(&self, external_type: &str) -> Result<&ExternalRequestValue> {
+    match self {
+      Self::Single(value) => Ok(value),
+      Self::Map(map) => map
+        .get(external_type)
+        .ok_or_else(|| internal_error!("Missing external configuration for type:{external_type}")),
+    }
+  }
+}
 pub fn property_access(o: &Vec<String>, mut start: usize) -> String {
   let mut str = String::default();
   while start < o.len() {
@@ -46,20 +76,62 @@ pub fn property_access(o: &Vec<String>, mut start: usize) -> String {
 }
 
 fn get_source_for_global_variable_external(
-  request: &ExternalRequest,
+  request: &ExternalRequestValue,
   external_type: &ExternalType,
 ) -> String {
   let object_lookup = property_access(request.as_array(), 0);
   format!("{external_type}{object_lookup}")
 }
 
-fn get_source_for_default_case(_optional: bool, request: &ExternalRequest) -> String {
+fn get_source_for_default_case(_optional: bool, request: &ExternalRequestValue) -> String {
   let request = request.as_array();
   let variable_name = request.get(0).expect("should have at least one element");
   let object_lookup = property_access(request, 1);
   format!("{variable_name}{object_lookup}")
 }
 
+/// Splits a `global@url` request of a script external into `[url, global]`.
+fn extract_url_and_global(value: &str) -> Result<Vec<String>> {
+  match value.find('@') {
+    Some(index) if index > 0 && index < value.len() - 1 => Ok(vec![
+      value[index + 1..].to_string(),
+      value[..index].to_string(),
+    ]),
+    _ => Err(internal_error!("Invalid request \"{value}\"")),
+  }
+}
+
+fn get_source_for_script_external(request: &ExternalRequestValue) -> Result<String> {
+  let url_and_global = if request.as_array().len() == 1 {
+    Cow::Owned(extract_url_and_global(request.as_str())?)
+  } else {
+    Cow::Borrowed(request.as_array())
+  };
+  let url =
+    serde_json::to_string(&url_and_global[0]).map_err(|e| internal_error!(e.to_string()))?;
+  let global_name = &url_and_global[1];
+  let global_name_json =
+    serde_json::to_string(global_name).map_err(|e| internal_error!(e.to_string()))?;
+  Ok(format!(
+    r#"var __webpack_error__ = new Error();
+module.exports = new Promise(function(resolve, reject) {{
+  if(typeof {global_name} !== "undefined") return resolve();
+  {}({url}, function(event) {{
+    if(typeof {global_name} !== "undefined") return resolve();
+    var errorType = event && (event.type === 'load' ? 'missing' : event.type);
+    var realSrc = event && event.target && event.target.src;
+    __webpack_error__.message = 'Loading script failed.\n(' + errorType + ': ' + realSrc + ')';
+    __webpack_error__.name = 'ScriptExternalLoadError';
+    __webpack_error__.type = errorType;
+    __webpack_error__.request = realSrc;
+    reject(__webpack_error__);
+  }}, {global_name_json});
+}}).then(function() {{ return {global_name}{}; }})"#,
+    RuntimeGlobals::LOAD_SCRIPT,
+    property_access(&url_and_global, 2),
+  ))
+}
+
 #[derive(Debug)]
 pub struct ExternalModule {
   id: Identifier,
@@ -70,10 +142,17 @@ pub struct ExternalModule {
 }
 
 impl ExternalModule {
-  pub fn new(request: Vec<String>, external_type: ExternalType, user_request: String) -> Self {
+  pub fn new(request: ExternalRequest, external_type: ExternalType, user_request: String) -> Self {
+    let id = match &request {
+      ExternalRequest::Single(value) => format!("external {external_type} {:?}", value.0),
+      ExternalRequest::Map(map) => format!(
+        "external {external_type} {:?}",
+        map.iter().map(|(k, v)| (k, &v.0)).collect::<Vec<_>>()
+      ),
+    };
     Self {
-      id: Identifier::from(format!("external {external_type} {request:?}")),
-      request: ExternalRequest(request),
+      id: Identifier::from(id),
+      request,
       external_type,
       user_request,
     }
@@ -83,8 +162,8 @@ impl ExternalModule {
     &self.external_type
   }
 
-  fn get_source_for_commonjs(&self) -> String {
-    let request = &self.request.as_array();
+  fn get_source_for_commonjs(&self, request: &ExternalRequestValue) -> String {
+    let request = request.as_array();
     let module_name = request.get(0).expect("should have at least one element");
     format!(
       "module.exports = require('{}'){}",
@@ -93,38 +172,43 @@ impl ExternalModule {
     )
   }
 
-  fn get_source_for_import(&self, compilation: &Compilation) -> String {
+  fn get_source_for_import(
+    &self,
+    request: &ExternalRequestValue,
+    compilation: &Compilation,
+  ) -> String {
     format!(
       "module.exports = {}({})",
       compilation.options.output.import_function_name,
-      serde_json::to_string(&self.request).expect("invalid json to_string")
+      serde_json::to_string(request).expect("invalid json to_string")
     )
   }
 
   pub fn get_source(
     &self,
     compilation: &Compilation,
-  ) -> (BoxSource, ChunkInitFragments, RuntimeGlobals) {
+  ) -> Result<(BoxSource, ChunkInitFragments, RuntimeGlobals)> {
     let mut chunk_init_fragments: ChunkInitFragments = Default::default();
     let mut runtime_requirements: RuntimeGlobals = Default::default();
+    let request = || self.request.for_type(&self.external_type);
     let source = match self.external_type.as_str() {
       "this" => format!(
         "module.exports = (function() {{ return {}; }}())",
-        get_source_for_global_variable_external(&self.request, &self.external_type)
+        get_source_for_global_variable_external(request()?, &self.external_type)
       ),
       "window" | "self" => format!(
         "module.exports = {}",
-        get_source_for_global_variable_external(&self.request, &self.external_type)
+        get_source_for_global_variable_external(request()?, &self.external_type)
       ),
       "global" => format!(
         "module.exports ={} ",
         get_source_for_global_variable_external(
-          &self.request,
+          request()?,
           &compilation.options.output.global_object
         )
       ),
       "commonjs" | "commonjs2" | "commonjs-module" | "commonjs-static" => {
-        self.get_source_for_commonjs()
+        self.get_source_for_commonjs(request()?)
       }
       "node-commonjs" => {
         if compilation.options.output.module {
@@ -138,10 +222,10 @@ impl ExternalModule {
             ));
           format!(
             "__WEBPACK_EXTERNAL_createRequire(import.meta.url)('{}')",
-            self.request.as_str()
+            request()?.as_str()
           )
         } else {
-          self.get_source_for_commonjs()
+          self.get_source_for_commonjs(request()?)
         }
       }
       "amd" | "amd-require" | "umd" | "umd2" | "system" | "jsonp" => {
@@ -155,11 +239,11 @@ impl ExternalModule {
           to_identifier(id)
         )
       }
-      "import" => self.get_source_for_import(compilation),
+      "import" => self.get_source_for_import(request()?, compilation),
       "var" | "promise" | "const" | "let" | "assign" => {
         format!(
           "module.exports = {}",
-          get_source_for_default_case(false, &self.request)
+          get_source_for_default_case(false, request()?)
         )
       }
       "module" => {
@@ -175,7 +259,7 @@ impl ExternalModule {
             .or_insert(InitFragment::new(
               format!(
                 "import * as __WEBPACK_EXTERNAL_MODULE_{identifier}__ from '{}';\n",
-                self.request.as_str()
+                request()?.as_str()
               ),
               InitFragmentStage::STAGE_HARMONY_IMPORTS,
               None,
@@ -188,18 +272,21 @@ impl ExternalModule {
             RuntimeGlobals::DEFINE_PROPERTY_GETTERS,
           )
         } else {
-          self.get_source_for_import(compilation)
+          self.get_source_for_import(request()?, compilation)
         }
       }
-      // TODO "script"
+      "script" => {
+        runtime_requirements.insert(RuntimeGlobals::LOAD_SCRIPT);
+        get_source_for_script_external(request()?)?
+      }
       _ => "".to_string(),
     };
     runtime_requirements.insert(RuntimeGlobals::MODULE);
-    (
+    Ok((
       RawSource::from(source).boxed(),
       chunk_init_fragments,
       runtime_requirements,
-    )
+    ))
   }
 }
 
@@ -263,6 +350,7 @@ impl Module for ExternalModule {
 
     let build_info = BuildInfo {
       hash: Some(hasher.digest(&build_context.compiler_options.output.hash_digest)),
+      cacheable: true,
       ..Default::default()
     };
 
@@ -295,14 +383,18 @@ impl Module for ExternalModule {
           GenerationResult::from(AstOrSource::from(
             RawSource::from(format!(
               "module.exports = {};",
-              serde_json::to_string(&self.request.as_str())
+              serde_json::to_string(self.request.for_type(&self.external_type)?.as_str())
                 .map_err(|e| internal_error!(e.to_string()))?
             ))
             .boxed(),
           )),
         );
         cgr.data.insert(CodeGenerationDataUrl::new(
-          self.request.as_str().to_string(),
+          self
+            .request
+            .for_type(&self.external_type)?
+            .as_str()
+            .to_string(),
         ));
       }
       "css-import" => {
@@ -311,7 +403,7 @@ impl Module for ExternalModule {
           GenerationResult::from(AstOrSource::from(
             RawSource::from(format!(
               "@import url({});",
-              serde_json::to_string(&self.request.as_str())
+              serde_json::to_string(self.request.for_type(&self.external_type)?.as_str())
                 .map_err(|e| internal_error!(e.to_string()))?
             ))
             .boxed(),
@@ -319,7 +411,7 @@ impl Module for ExternalModule {
         );
       }
       _ => {
-        let (source, chunk_init_fragments, runtime_requirements) = self.get_source(compilation);
+        let (source, chunk_init_fragments, runtime_requirements) = self.get_source(compilation)?;
         cgr.add(
           SourceType::JavaScript,
           GenerationResult::from(AstOrSource::from(source)),
@@ -57,6 +57,8 @@ pub use runtime::*;
 pub use runtime_module::*;
 mod code_generation_results;
 pub use code_generation_results::*;
+mod concatenation_scope;
+pub use concatenation_scope::*;
 mod entrypoint;
 pub use entrypoint::*;
 mod loader;
@@ -80,6 +82,9 @@ pub enum SourceType {
   Css,
   Wasm,
   Asset,
+  Remote,
+  ShareInit,
+  ConsumeShared,
   #[default]
   Unknown,
 }
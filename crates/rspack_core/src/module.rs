@@ -1,6 +1,6 @@
 use std::hash::Hash;
 use std::path::PathBuf;
-use std::{any::Any, borrow::Cow, fmt::Debug};
+use std::{any::Any, borrow::Cow, collections::BTreeMap, fmt::Debug};
 
 use async_trait::async_trait;
 use rspack_error::{IntoTWithDiagnosticArray, Result, TWithDiagnosticArray};
@@ -9,6 +9,7 @@ use rspack_identifier::{Identifiable, Identifier};
 use rspack_sources::Source;
 use rspack_util::ext::{AsAny, DynEq, DynHash};
 use rustc_hash::FxHashSet as HashSet;
+use serde::{Deserialize, Serialize};
 
 use crate::{
   ChunkUkey, CodeGenerationResult, Compilation, CompilerContext, CompilerOptions, Context,
@@ -33,9 +34,14 @@ pub struct BuildInfo {
   pub missing_dependencies: HashSet<PathBuf>,
   pub build_dependencies: HashSet<PathBuf>,
   pub asset_filenames: HashSet<String>,
+  /// The keys and values of the definitions used by the module, the module is rebuilt when any
+  /// of them changes.
+  pub value_dependencies: BTreeMap<String, String>,
+  /// The reason why the module can't be concatenated with other modules, e.g. it uses `eval()`.
+  pub module_concatenation_bailout: Option<String>,
 }
 
-#[derive(Debug, Default, Clone, Hash)]
+#[derive(Debug, Default, Clone, Hash, Serialize, Deserialize)]
 pub enum BuildMetaExportsType {
   #[default]
   Unset,
@@ -53,7 +59,7 @@ pub enum ExportsType {
   Dynamic,
 }
 
-#[derive(Debug, Default, Clone, Hash)]
+#[derive(Debug, Default, Clone, Hash, Serialize, Deserialize)]
 pub enum BuildMetaDefaultObject {
   #[default]
   False,
@@ -133,6 +139,7 @@ pub trait Module: Debug + Send + Sync + AsAny + DynHash + DynEq + Identifiable {
 
     let build_info = BuildInfo {
       hash: Some(hasher.digest(&build_context.compiler_options.output.hash_digest)),
+      cacheable: true,
       ..Default::default()
     };
 
@@ -146,6 +153,13 @@ pub trait Module: Debug + Send + Sync + AsAny + DynHash + DynEq + Identifiable {
     )
   }
 
+  /// Whether the state created by `build` is available for code generation.
+  /// Modules like the `NormalModule` are not built when their build result is served by the cache,
+  /// they could only be code generated from the cache as well.
+  fn is_built(&self) -> bool {
+    true
+  }
+
   /// The actual code generation of the module, which will be called by the `Compilation`.
   /// The code generation result should not be cached as it is implemented elsewhere to
   /// provide a universal cache mechanism (time to invalidate cache, etc.)
@@ -193,6 +207,11 @@ pub trait Module: Debug + Send + Sync + AsAny + DynHash + DynEq + Identifiable {
     None
   }
 
+  /// Modules merged into this module by module concatenation, in evaluation order.
+  fn get_concatenated_modules(&self) -> Option<&[ModuleIdentifier]> {
+    None
+  }
+
   fn has_chunk_condition(&self) -> bool {
     false
   }
@@ -300,6 +300,40 @@ impl ModuleGraph {
     removed
   }
 
+  /// Replace a dependency with a new one of the same request, the new dependency takes over the
+  /// connection of the old one. e.g. the dependencies of a module restored from the cache are replaced
+  /// by the ones created when the module is built again.
+  pub fn replace_dependency(
+    &mut self,
+    dependency_id: &DependencyId,
+    dependency: BoxModuleDependency,
+  ) {
+    let new_dependency_id = *dependency.id();
+    if let Some(module_identifier) = self
+      .dependency_id_to_module_identifier
+      .remove(dependency_id)
+    {
+      self
+        .dependency_id_to_module_identifier
+        .insert(new_dependency_id, module_identifier);
+    }
+    if let Some(connection_id) = self.dependency_id_to_connection_id.remove(dependency_id) {
+      self
+        .dependency_id_to_connection_id
+        .insert(new_dependency_id, connection_id);
+      self
+        .connection_id_to_dependency_id
+        .insert(connection_id, new_dependency_id);
+      if let Some(connection) = self.connections[*connection_id].as_mut() {
+        self.connections_map.remove(connection);
+        connection.dependency_id = new_dependency_id;
+        self.connections_map.insert(*connection, connection_id);
+      }
+    }
+    self.remove_dependency(dependency_id);
+    self.add_dependency(dependency);
+  }
+
   pub fn get_pre_order_index(&self, module_identifier: &ModuleIdentifier) -> Option<usize> {
     self
       .module_graph_module_by_identifier(module_identifier)
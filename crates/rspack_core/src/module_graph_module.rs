@@ -27,6 +27,8 @@ pub struct ModuleGraphModule {
   pub factory_meta: Option<FactoryMeta>,
   pub build_info: Option<BuildInfo>,
   pub build_meta: Option<BuildMeta>,
+  /// Reasons why the module is not concatenated, reported in stats
+  pub optimization_bailout: Vec<String>,
 }
 
 impl ModuleGraphModule {
@@ -46,6 +48,7 @@ impl ModuleGraphModule {
       factory_meta: None,
       build_info: None,
       build_meta: None,
+      optimization_bailout: vec![],
     }
   }
 
@@ -26,10 +26,11 @@ use serde_json::json;
 
 use crate::{
   contextify, get_context, BoxLoader, BoxModule, BuildContext, BuildInfo, BuildMeta, BuildResult,
-  CodeGenerationResult, Compilation, CompilerOptions, Context, DependencyTemplate, GenerateContext,
-  GeneratorOptions, LibIdentOptions, LoaderRunnerPluginProcessResource, Module, ModuleAst,
-  ModuleDependency, ModuleGraph, ModuleIdentifier, ModuleType, ParseContext, ParseResult,
-  ParserAndGenerator, ParserOptions, Resolve, SourceType,
+  CodeGenerationResult, Compilation, CompilerOptions, ConcatenationScope, Context,
+  DependencyTemplate, GenerateContext, GeneratorOptions, LibIdentOptions,
+  LoaderRunnerPluginProcessResource, Module, ModuleAst, ModuleDependency, ModuleGraph,
+  ModuleIdentifier, ModuleType, ParseContext, ParseResult, ParserAndGenerator, ParserOptions,
+  Resolve, SourceType,
 };
 
 bitflags! {
@@ -304,6 +305,75 @@ impl NormalModule {
   pub fn loaders_mut_vec(&mut self) -> &mut Vec<BoxLoader> {
     &mut self.loaders
   }
+
+  /// Code generate the module as a part of a concatenated module, references to the modules
+  /// in the scope and exports of the module are rendered by the `concatenation_scope`.
+  pub fn code_generation_with_concatenation_scope(
+    &self,
+    compilation: &Compilation,
+    concatenation_scope: &mut ConcatenationScope,
+  ) -> Result<CodeGenerationResult> {
+    self.generate(compilation, Some(concatenation_scope))
+  }
+
+  fn generate(
+    &self,
+    compilation: &Compilation,
+    mut concatenation_scope: Option<&mut ConcatenationScope>,
+  ) -> Result<CodeGenerationResult> {
+    if let NormalModuleAstOrSource::BuiltSucceed(ast_or_source) = self.ast_or_source() {
+      let mut code_generation_result = CodeGenerationResult::default();
+      for source_type in self.source_types() {
+        let mut generation_result = self.parser_and_generator.generate(
+          ast_or_source,
+          self,
+          &mut GenerateContext {
+            compilation,
+            module_generator_options: self.generator_options.as_ref(),
+            runtime_requirements: &mut code_generation_result.runtime_requirements,
+            data: &mut code_generation_result.data,
+            requested_source_type: *source_type,
+            concatenation_scope: concatenation_scope.as_deref_mut(),
+          },
+        )?;
+        generation_result.ast_or_source = generation_result
+          .ast_or_source
+          .map(|i| i, |s| CachedSource::new(s).boxed());
+        code_generation_result.add(*source_type, generation_result);
+      }
+      code_generation_result.set_hash(
+        &compilation.options.output.hash_function,
+        &compilation.options.output.hash_digest,
+        &compilation.options.output.hash_salt,
+      );
+      Ok(code_generation_result)
+    } else if let NormalModuleAstOrSource::BuiltFailed(error_message) = self.ast_or_source() {
+      let mut code_generation_result = CodeGenerationResult::default();
+
+      // If the module build failed and the module is able to emit JavaScript source,
+      // we should emit an error message to the runtime, otherwise we do nothing.
+      if self.source_types().contains(&SourceType::JavaScript) {
+        code_generation_result.add(
+          SourceType::JavaScript,
+          AstOrSource::new(
+            None,
+            Some(RawSource::from(format!("throw new Error({});\n", json!(error_message))).boxed()),
+          ),
+        );
+      }
+      code_generation_result.set_hash(
+        &compilation.options.output.hash_function,
+        &compilation.options.output.hash_digest,
+        &compilation.options.output.hash_salt,
+      );
+      Ok(code_generation_result)
+    } else {
+      Err(internal_error!(
+        "Failed to generate code because ast or source is not set for module {}",
+        self.request
+      ))
+    }
+  }
 }
 
 impl Identifiable for NormalModule {
@@ -420,6 +490,7 @@ impl Module for NormalModule {
     let mut hasher = RspackHash::from(&build_context.compiler_options.output);
     self.update_hash(&mut hasher);
     build_meta.hash(&mut hasher);
+    build_info.value_dependencies.hash(&mut hasher);
 
     build_info.hash = Some(hasher.digest(&build_context.compiler_options.output.hash_digest));
     build_info.cacheable = loader_result.cacheable;
@@ -439,58 +510,12 @@ impl Module for NormalModule {
     )
   }
 
-  fn code_generation(&self, compilation: &Compilation) -> Result<CodeGenerationResult> {
-    if let NormalModuleAstOrSource::BuiltSucceed(ast_or_source) = self.ast_or_source() {
-      let mut code_generation_result = CodeGenerationResult::default();
-      for source_type in self.source_types() {
-        let mut generation_result = self.parser_and_generator.generate(
-          ast_or_source,
-          self,
-          &mut GenerateContext {
-            compilation,
-            module_generator_options: self.generator_options.as_ref(),
-            runtime_requirements: &mut code_generation_result.runtime_requirements,
-            data: &mut code_generation_result.data,
-            requested_source_type: *source_type,
-          },
-        )?;
-        generation_result.ast_or_source = generation_result
-          .ast_or_source
-          .map(|i| i, |s| CachedSource::new(s).boxed());
-        code_generation_result.add(*source_type, generation_result);
-      }
-      code_generation_result.set_hash(
-        &compilation.options.output.hash_function,
-        &compilation.options.output.hash_digest,
-        &compilation.options.output.hash_salt,
-      );
-      Ok(code_generation_result)
-    } else if let NormalModuleAstOrSource::BuiltFailed(error_message) = self.ast_or_source() {
-      let mut code_generation_result = CodeGenerationResult::default();
+  fn is_built(&self) -> bool {
+    !matches!(self.ast_or_source, NormalModuleAstOrSource::Unbuild)
+  }
 
-      // If the module build failed and the module is able to emit JavaScript source,
-      // we should emit an error message to the runtime, otherwise we do nothing.
-      if self.source_types().contains(&SourceType::JavaScript) {
-        code_generation_result.add(
-          SourceType::JavaScript,
-          AstOrSource::new(
-            None,
-            Some(RawSource::from(format!("throw new Error({});\n", json!(error_message))).boxed()),
-          ),
-        );
-      }
-      code_generation_result.set_hash(
-        &compilation.options.output.hash_function,
-        &compilation.options.output.hash_digest,
-        &compilation.options.output.hash_salt,
-      );
-      Ok(code_generation_result)
-    } else {
-      Err(internal_error!(
-        "Failed to generate code because ast or source is not set for module {}",
-        self.request
-      ))
-    }
+  fn code_generation(&self, compilation: &Compilation) -> Result<CodeGenerationResult> {
+    self.generate(compilation, None)
   }
 
   fn name_for_condition(&self) -> Option<Cow<str>> {
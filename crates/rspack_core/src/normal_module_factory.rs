@@ -5,6 +5,7 @@ use regex::Regex;
 use rspack_error::{internal_error, IntoTWithDiagnosticArray, Result, TWithDiagnosticArray};
 use rspack_identifier::Identifiable;
 use rspack_loader_runner::{get_scheme, Scheme};
+use serde::{Deserialize, Serialize};
 use sugar_path::{AsPath, SugarPath};
 use swc_core::common::Span;
 
@@ -13,9 +14,9 @@ use crate::{
   module_rules_matcher, parse_resource, resolve, stringify_loaders_and_resource,
   tree_shaking::visitor::{get_side_effects_from_package_json, SideEffects},
   BoxLoader, CompilerOptions, Dependency, DependencyCategory, DependencyType, FactorizeArgs,
-  FactoryMeta, FuncUseCtx, GeneratorOptions, MissingModule, ModuleArgs, ModuleDependency,
-  ModuleExt, ModuleFactory, ModuleFactoryCreateData, ModuleFactoryResult, ModuleIdentifier,
-  ModuleRule, ModuleRuleEnforce, ModuleRuleUse, ModuleType, NormalModule,
+  FactoryMeta, FuncUseCtx, GeneratorOptions, ImportAttributes, MissingModule, ModuleArgs,
+  ModuleDependency, ModuleExt, ModuleFactory, ModuleFactoryCreateData, ModuleFactoryResult,
+  ModuleIdentifier, ModuleRule, ModuleRuleEnforce, ModuleRuleUse, ModuleType, NormalModule,
   NormalModuleAfterResolveArgs, NormalModuleBeforeResolveArgs, ParserOptions, RawModule, Resolve,
   ResolveArgs, ResolveError, ResolveOptionsWithDependencyType, ResolveResult, ResolverFactory,
   ResourceData, ResourceParsedData, SharedPluginDriver,
@@ -299,7 +300,9 @@ impl NormalModuleFactory {
       let resource_data = self
         .cache
         .resolve_module_occasion
-        .use_cache(resolve_args, |args| resolve(args, plugin_driver))
+        .use_cache(resolve_args, &plugin_driver.resolver_factory, |args| {
+          resolve(args, plugin_driver)
+        })
         .await;
       match resource_data {
         Ok(ResolveResult::Resource(resource)) => {
@@ -352,6 +355,7 @@ impl NormalModuleFactory {
           &resource_data
         },
         data.dependency.category(),
+        data.dependency.get_attributes(),
       )
       .await?;
 
@@ -503,6 +507,7 @@ impl NormalModuleFactory {
     &self,
     resource_data: &ResourceData,
     dependency: &DependencyCategory,
+    attributes: Option<&ImportAttributes>,
   ) -> Result<Vec<&ModuleRule>> {
     let mut rules = Vec::new();
     module_rules_matcher(
@@ -510,6 +515,7 @@ impl NormalModuleFactory {
       resource_data,
       self.context.issuer.as_deref(),
       dependency,
+      attributes.unwrap_or(&Default::default()),
       &mut rules,
     )
     .await?;
@@ -630,6 +636,8 @@ pub struct NormalModuleFactoryContext {
   pub options: Arc<CompilerOptions>,
   pub lazy_visit_modules: std::collections::HashSet<String>,
   pub issuer: Option<String>,
+  /// Description file data of the issuer, e.g. the `package.json` of the issuer
+  pub issuer_description: Option<Arc<nodejs_resolver::DescriptionData>>,
 }
 
 /// Using `u32` instead of `usize` to reduce memory usage,
@@ -637,7 +645,7 @@ pub struct NormalModuleFactoryContext {
 /// Rspan aka `Rspack span`, just avoiding conflict with span in other crate
 /// ## Warning
 /// RSpan is zero based, `Span` of `swc` is 1 based. see https://swc-css.netlify.app/?code=eJzLzC3ILypRSFRIK8rPVVAvSS0u0csqVgcAZaoIKg
-#[derive(Debug, Hash, PartialEq, Eq, Clone, Copy, Default)]
+#[derive(Debug, Hash, PartialEq, Eq, Clone, Copy, Default, Serialize, Deserialize)]
 pub struct ErrorSpan {
   pub start: u32,
   pub end: u32,
@@ -1,5 +1,6 @@
 use std::fmt::Debug;
 use std::hash::Hash;
+use std::sync::{Arc, RwLock};
 use std::{collections::HashMap, fmt::Display, path::PathBuf};
 
 use async_recursion::async_recursion;
@@ -11,8 +12,27 @@ use swc_plugin_import::PluginImportConfig;
 use crate::{try_any, AssetInfo};
 
 pub type Define = HashMap<String, String>;
+/// The properties of `import.meta.env`, the values are code like `Define`.
+pub type ImportMetaEnv = HashMap<String, String>;
 pub type Provide = HashMap<String, Vec<String>>;
 
+/// The definitions whose values are computed by functions on the node side, they are updated
+/// before each compilation.
+#[derive(Debug, Clone, Default)]
+pub struct RuntimeDefine {
+  values: Arc<RwLock<Define>>,
+}
+
+impl RuntimeDefine {
+  pub fn get(&self) -> Define {
+    self.values.read().expect("should get lock").clone()
+  }
+
+  pub fn set(&self, values: Define) {
+    *self.values.write().expect("should get lock") = values;
+  }
+}
+
 #[derive(Debug, Clone, Default)]
 pub struct ReactOptions {
   pub runtime: Option<Runtime>,
@@ -88,6 +108,9 @@ pub struct Builtins {
   pub minify_options: Option<Minification>,
   pub preset_env: Option<PresetEnv>,
   pub define: Define,
+  pub runtime_define: RuntimeDefine,
+  /// `import.meta.env` is replaced with the object at compile time if it's configured.
+  pub import_meta_env: Option<ImportMetaEnv>,
   pub provide: Provide,
   pub tree_shaking: TreeShaking,
   pub react: ReactOptions,
@@ -100,6 +123,15 @@ pub struct Builtins {
   pub code_generation: Option<CodeGeneration>,
 }
 
+impl Builtins {
+  /// `define` with the latest values of `runtime_define`.
+  pub fn definitions(&self) -> Define {
+    let mut definitions = self.define.clone();
+    definitions.extend(self.runtime_define.get());
+    definitions
+  }
+}
+
 #[derive(Debug, Clone, Default, Hash)]
 pub struct Minification {
   pub passes: usize,
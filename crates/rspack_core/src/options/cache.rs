@@ -1,16 +1,19 @@
+use std::path::{Path, PathBuf};
+
 #[derive(Debug, Clone, Default)]
 pub struct MemoryCacheOptions {
   /// Define the lifespan of unused cache entries in the memory cache.
   pub max_generations: u32,
 }
 
+/// Options of the filesystem cache.
 #[derive(Debug, Clone, Default)]
 pub struct FileSystemCacheOptions {
   // Collect unused memory allocated during deserialization
   // allow_collecting_memory: bool,
   // Define the lifespan of unused cache entries in the memory cache.
   // max_memory_generations: u32,
-  /// The amount of time in milliseconds that unused cache entries are allowed to stay in the filesystem cache; defaults to one month.
+  /// The amount of time in milliseconds that unused cache entries are allowed to stay in the filesystem cache; `0` means entries never expire.
   pub max_age: u32,
   /// Track and log detailed timing information for individual cache items of type 'filesystem'.
   pub profile: bool,
@@ -36,6 +39,25 @@ pub struct FileSystemCacheOptions {
   pub version: String,
 }
 
+impl FileSystemCacheOptions {
+  /// Locations for the cache, fallback to `path.resolve(cache.cacheDirectory, cache.name)`.
+  pub fn cache_location(&self, context: &Path) -> PathBuf {
+    if !self.cache_location.is_empty() {
+      return context.join(&self.cache_location);
+    }
+    let cache_directory = if self.cache_directory.is_empty() {
+      context.join("node_modules/.cache/rspack")
+    } else {
+      context.join(&self.cache_directory)
+    };
+    if self.name.is_empty() {
+      cache_directory.join("default")
+    } else {
+      cache_directory.join(&self.name)
+    }
+  }
+}
+
 #[derive(Debug, Default, Clone)]
 pub enum CacheOptions {
   #[default]
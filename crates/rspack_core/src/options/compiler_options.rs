@@ -1,3 +1,5 @@
+use std::path::PathBuf;
+
 use crate::{
   Builtins, CacheOptions, Context, DevServerOptions, Devtool, Experiments,
   IncrementalRebuildMakeState, Mode, ModuleOptions, NodeOption, Optimization, OutputOptions,
@@ -22,6 +24,8 @@ pub struct CompilerOptions {
   pub experiments: Experiments,
   pub node: Option<NodeOption>,
   pub optimization: Optimization,
+  /// Path of the file which records of compilations are read from and written to.
+  pub records_path: Option<PathBuf>,
 }
 
 impl CompilerOptions {
@@ -36,4 +40,10 @@ impl CompilerOptions {
   pub fn is_incremental_rebuild_emit_asset_enabled(&self) -> bool {
     self.experiments.incremental_rebuild.emit_asset
   }
+
+  /// Whether modules are concatenated, concatenated modules can't be hot updated,
+  /// so `optimization.concatenateModules` is ignored with hot module replacement.
+  pub fn is_module_concatenation_enabled(&self) -> bool {
+    self.optimization.concatenate_modules && !self.dev_server.hot
+  }
 }
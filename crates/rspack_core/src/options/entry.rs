@@ -1,6 +1,6 @@
 use indexmap::IndexMap;
 
-use crate::{ChunkLoading, DependencyId, Filename, PublicPath};
+use crate::{ChunkLoading, DependencyId, Filename, LibraryOptions, PublicPath};
 
 pub type Entry = IndexMap<String, EntryData>;
 
@@ -17,9 +17,11 @@ pub struct EntryDescription {
   pub filename: Option<Filename>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Default)]
 pub struct EntryData {
   pub dependencies: Vec<DependencyId>,
+  /// Modules added to the chunks of the entry, which are not executed on startup
+  pub include_dependencies: Vec<DependencyId>,
   pub options: EntryOptions,
 }
 
@@ -31,4 +33,6 @@ pub struct EntryOptions {
   pub public_path: Option<PublicPath>,
   pub base_uri: Option<String>,
   pub filename: Option<Filename>,
+  /// Overrides `output.library` for the chunks of the entry
+  pub library: Option<LibraryOptions>,
 }
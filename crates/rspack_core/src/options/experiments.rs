@@ -26,6 +26,7 @@ pub struct Experiments {
   pub lazy_compilation: bool,
   pub incremental_rebuild: IncrementalRebuild,
   pub async_web_assembly: bool,
+  pub sync_web_assembly: bool,
   pub new_split_chunks: bool,
   pub css: bool,
 }
@@ -11,7 +11,9 @@ pub type Externals = Vec<ExternalItem>;
 pub enum ExternalItemValue {
   String(String),
   Bool(bool),
-  Array(Vec<String>), // TODO: string[] | Record<string, string|string[]>
+  Array(Vec<String>),
+  /// The request for each external type, e.g. `{ root: ["React"], commonjs: ["react"] }`.
+  Object(HashMap<String, Vec<String>>),
 }
 
 pub type ExternalItemObject = HashMap<String, ExternalItemValue>;
@@ -30,6 +30,7 @@ impl ParserOptionsByModuleType {
 #[derive(Debug, Clone)]
 pub enum ParserOptions {
   Asset(AssetParserOptions),
+  Javascript(JavascriptParserOptions),
   Unknown,
 }
 
@@ -41,6 +42,20 @@ impl ParserOptions {
     };
     maybe.filter(|_| matches!(module_type, ModuleType::Asset))
   }
+
+  pub fn get_javascript(&self, module_type: &ModuleType) -> Option<&JavascriptParserOptions> {
+    let maybe = match self {
+      ParserOptions::Javascript(i) => Some(i),
+      _ => None,
+    };
+    maybe.filter(|_| module_type.is_js_like())
+  }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct JavascriptParserOptions {
+  /// Whether to parse AMD `define` and `require`.
+  pub amd: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -201,6 +216,8 @@ impl From<String> for DataUrlEncoding {
 
 pub type DescriptionData = HashMap<String, RuleSetCondition>;
 
+pub type ImportAttributesData = HashMap<String, RuleSetCondition>;
+
 pub type RuleSetConditionFnMatcher =
   Box<dyn Fn(&str) -> BoxFuture<'static, Result<bool>> + Sync + Send>;
 
@@ -314,6 +331,8 @@ pub struct ModuleRule {
   pub scheme: Option<RuleSetCondition>,
   pub mimetype: Option<RuleSetCondition>,
   pub description_data: Option<DescriptionData>,
+  /// A condition matcher against the import attributes, e.g. `with: { type: "json" }`.
+  pub with: Option<ImportAttributesData>,
   pub side_effects: Option<bool>,
   /// The `ModuleType` to use for the matched resource.
   pub r#type: Option<ModuleType>,
@@ -49,4 +49,5 @@ pub struct Optimization {
   pub remove_available_modules: bool,
   pub remove_empty_chunks: bool,
   pub side_effects: SideEffectOption,
+  pub concatenate_modules: bool,
 }
@@ -86,7 +86,9 @@ pub enum ChunkLoadingType {
   Require,
   AsyncNode,
   Import,
-  // TODO: Custom
+  /// A chunk loading type that is not built in. The plugin that introduces it registers its
+  /// runtime module by `ApplyContext::register_chunk_loading_runtime_module_builder`.
+  Custom(String),
 }
 
 impl From<&str> for ChunkLoadingType {
@@ -97,7 +99,7 @@ impl From<&str> for ChunkLoadingType {
       "require" => Self::Require,
       "async-node" => Self::AsyncNode,
       "import" => Self::Import,
-      _ => unimplemented!("custom chunkLoading in not supported yet"),
+      custom => Self::Custom(custom.to_string()),
     }
   }
 }
@@ -122,6 +124,11 @@ pub enum WasmLoadingType {
   Fetch,
   AsyncNode,
   AsyncNodeModule,
+  /// Uses `fetch` when running in a browser or worker, otherwise reads the file with Node's `fs`.
+  Universal,
+  /// A wasm loading type that is not built in. The plugin that introduces it registers how
+  /// the binary is loaded by `ApplyContext::register_wasm_loading_code_builder`.
+  Custom(String),
 }
 
 impl From<&str> for WasmLoadingType {
@@ -130,7 +137,8 @@ impl From<&str> for WasmLoadingType {
       "fetch" => Self::Fetch,
       "async-node" => Self::AsyncNode,
       "async-node-module" => Self::AsyncNodeModule,
-      _ => todo!(),
+      "universal" => Self::Universal,
+      custom => Self::Custom(custom.to_string()),
     }
   }
 }
@@ -513,7 +521,7 @@ pub fn get_js_chunk_filename_template<'filename>(
   }
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
 pub struct LibraryOptions {
   pub name: Option<LibraryName>,
   pub export: Option<Vec<String>>,
@@ -523,7 +531,7 @@ pub struct LibraryOptions {
   pub auxiliary_comment: Option<LibraryAuxiliaryComment>,
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
 pub struct LibraryAuxiliaryComment {
   pub root: Option<String>,
   pub commonjs: Option<String>,
@@ -531,7 +539,7 @@ pub struct LibraryAuxiliaryComment {
   pub amd: Option<String>,
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
 pub struct LibraryName {
   pub amd: Option<String>,
   pub commonjs: Option<String>,
@@ -1,3 +1,5 @@
+use std::path::{Path, PathBuf};
+
 #[derive(Debug, Clone, Default)]
 pub struct SnapshotStrategy {
   pub hash: bool,
@@ -14,8 +16,26 @@ pub struct SnapshotOptions {
   pub resolve: SnapshotStrategy,
   /// Snapshots for building modules.
   pub module: SnapshotStrategy,
-  // An array of paths that are managed by a package manager and contain a version or a hash in their paths.
-  // immutable_paths: Vec<String>,
-  // An array of paths that are managed by a package manager.
-  // managed_paths: Vec<String>,
+  /// An array of paths that are managed by a package manager and contain a version or a hash in their paths.
+  /// Files in these paths are never checked.
+  pub immutable_paths: Vec<PathBuf>,
+  /// An array of paths that are managed by a package manager.
+  /// Files in these paths are checked by the version in the `package.json` of their package.
+  pub managed_paths: Vec<PathBuf>,
+}
+
+impl SnapshotOptions {
+  /// Resolve relative immutable and managed paths against the context,
+  /// they are compared with the absolute paths of modules.
+  pub fn resolve_paths(&mut self, context: &Path) {
+    for path in self
+      .immutable_paths
+      .iter_mut()
+      .chain(self.managed_paths.iter_mut())
+    {
+      if path.is_relative() {
+        *path = context.join(&*path);
+      }
+    }
+  }
 }
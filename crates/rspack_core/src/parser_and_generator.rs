@@ -6,8 +6,8 @@ use rspack_sources::BoxSource;
 
 use crate::{
   AstOrSource, BuildInfo, BuildMeta, CodeGenerationData, Compilation, CompilerOptions,
-  DependencyTemplate, GenerationResult, GeneratorOptions, Module, ModuleDependency,
-  ModuleIdentifier, ModuleType, ParserOptions, RuntimeGlobals, SourceType,
+  ConcatenationScope, DependencyTemplate, GenerationResult, GeneratorOptions, Module,
+  ModuleDependency, ModuleIdentifier, ModuleType, ParserOptions, RuntimeGlobals, SourceType,
 };
 
 #[derive(Debug)]
@@ -39,6 +39,8 @@ pub struct GenerateContext<'a> {
   pub runtime_requirements: &'a mut RuntimeGlobals,
   pub data: &'a mut CodeGenerationData,
   pub requested_source_type: SourceType,
+  /// Set when the module is code generated as a part of a concatenated module.
+  pub concatenation_scope: Option<&'a mut ConcatenationScope>,
 }
 
 pub trait ParserAndGenerator: Send + Sync + Debug {
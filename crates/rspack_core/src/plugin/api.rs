@@ -13,7 +13,7 @@ use crate::{
   ModuleType, NormalModule, NormalModuleAfterResolveArgs, NormalModuleBeforeResolveArgs,
   NormalModuleFactoryContext, OptimizeChunksArgs, ParserAndGenerator, PluginContext,
   ProcessAssetsArgs, RenderArgs, RenderChunkArgs, RenderManifestArgs, RenderModuleContentArgs,
-  RenderStartupArgs, Resolver, SourceType, ThisCompilationArgs,
+  RenderStartupArgs, Resolver, RuntimeGlobals, RuntimeModule, SourceType, ThisCompilationArgs,
 };
 
 // use anyhow::{Context, Result};
@@ -448,10 +448,56 @@ pub type BoxedParserAndGenerator = Box<dyn ParserAndGenerator>;
 pub type BoxedParserAndGeneratorBuilder =
   Box<dyn 'static + Send + Sync + Fn() -> BoxedParserAndGenerator>;
 
+/// Creates the chunk loading runtime module of a custom chunk loading type, the runtime
+/// requirements of the chunk are passed in so the builder can add the ones it needs.
+pub type BoxedChunkLoadingRuntimeModuleBuilder =
+  Box<dyn 'static + Send + Sync + Fn(&mut RuntimeGlobals) -> Box<dyn RuntimeModule>>;
+
+/// The code loading the binary of a wasm module for a custom wasm loading type.
+#[derive(Debug, Clone)]
+pub struct WasmLoadingCode {
+  /// An expression evaluating to a promise of the response of the wasm file, `$PATH` in it is
+  /// replaced with the path of the file.
+  pub load_binary_code: String,
+  /// Whether the response can be compiled by `WebAssembly.instantiateStreaming`, otherwise
+  /// it only needs an `arrayBuffer` method.
+  pub supports_streaming: bool,
+}
+
+/// Creates the wasm loading code of a custom wasm loading type, the runtime requirements of
+/// the chunk are passed in so the builder can add the ones it needs.
+pub type BoxedWasmLoadingCodeBuilder =
+  Box<dyn 'static + Send + Sync + Fn(&mut RuntimeGlobals) -> WasmLoadingCode>;
+
+/// Renders the chunks of a custom chunk format, e.g. in the shape the runtime module of a custom
+/// chunk loading type expects.
+pub trait ChunkFormat: Send + Sync {
+  /// Adds the runtime requirements of a chunk rendered in this format.
+  fn additional_chunk_runtime_requirements(
+    &self,
+    _args: &mut AdditionalChunkRuntimeRequirementsArgs,
+  ) -> Result<()> {
+    Ok(())
+  }
+
+  /// Hashes what the rendered chunk depends on besides its modules.
+  fn js_chunk_hash(&self, _args: &mut JsChunkHashArgs) -> Result<()> {
+    Ok(())
+  }
+
+  fn render_chunk(&self, args: &RenderChunkArgs) -> Result<BoxSource>;
+}
+
+pub type BoxedChunkFormat = Box<dyn ChunkFormat>;
+
 #[derive(Default)]
 pub struct ApplyContext {
   pub(crate) registered_parser_and_generator_builder:
     DashMap<ModuleType, BoxedParserAndGeneratorBuilder>,
+  pub(crate) registered_chunk_loading_runtime_module_builder:
+    DashMap<String, BoxedChunkLoadingRuntimeModuleBuilder>,
+  pub(crate) registered_chunk_format: DashMap<String, BoxedChunkFormat>,
+  pub(crate) registered_wasm_loading_code_builder: DashMap<String, BoxedWasmLoadingCodeBuilder>,
 }
 
 impl ApplyContext {
@@ -464,4 +510,36 @@ impl ApplyContext {
       .registered_parser_and_generator_builder
       .insert(module_type, parser_and_generator_builder);
   }
+
+  /// Provides the runtime module of the custom chunk loading type `chunk_loading_type`,
+  /// which is added to the chunks loading their async chunks with it.
+  pub fn register_chunk_loading_runtime_module_builder(
+    &self,
+    chunk_loading_type: String,
+    runtime_module_builder: BoxedChunkLoadingRuntimeModuleBuilder,
+  ) {
+    self
+      .registered_chunk_loading_runtime_module_builder
+      .insert(chunk_loading_type, runtime_module_builder);
+  }
+
+  /// Provides the custom chunk format `chunk_format`, which renders the chunks when it is set
+  /// as `output.chunkFormat`.
+  pub fn register_chunk_format(&self, chunk_format: String, chunk_format_impl: BoxedChunkFormat) {
+    self
+      .registered_chunk_format
+      .insert(chunk_format, chunk_format_impl);
+  }
+
+  /// Provides how the custom wasm loading type `wasm_loading_type` loads the binary of
+  /// wasm modules, which is used by the wasm loading runtime modules.
+  pub fn register_wasm_loading_code_builder(
+    &self,
+    wasm_loading_type: String,
+    wasm_loading_code_builder: BoxedWasmLoadingCodeBuilder,
+  ) {
+    self
+      .registered_wasm_loading_code_builder
+      .insert(wasm_loading_type, wasm_loading_code_builder);
+  }
 }
@@ -9,9 +9,9 @@ use rustc_hash::FxHashSet as HashSet;
 use crate::ast::css::Ast as CssAst;
 use crate::ast::javascript::Ast as JsAst;
 use crate::{
-  Chunk, ChunkUkey, Compilation, Context, DependencyCategory, DependencyType, ErrorSpan,
-  FactoryMeta, ModuleDependency, ModuleIdentifier, Resolve, RuntimeGlobals, SharedPluginDriver,
-  Stats,
+  BoxModule, Chunk, ChunkUkey, CodeGenerationResult, Compilation, Context, DependencyCategory,
+  DependencyType, ErrorSpan, FactoryMeta, ModuleDependency, ModuleIdentifier, Resolve,
+  RuntimeGlobals, SharedPluginDriver, Stats,
 };
 // #[derive(Debug)]
 // pub struct ParseModuleArgs<'a> {
@@ -236,6 +236,8 @@ impl<'me> RenderChunkArgs<'me> {
 #[derive(Debug)]
 pub struct RenderModuleContentArgs<'a> {
   pub module_source: &'a BoxSource,
+  pub module: &'a BoxModule,
+  pub code_generation_result: &'a CodeGenerationResult,
   pub compilation: &'a Compilation,
 }
 
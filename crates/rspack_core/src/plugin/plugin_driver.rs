@@ -11,8 +11,9 @@ use tracing::instrument;
 
 use crate::{
   AdditionalChunkRuntimeRequirementsArgs, ApplyContext, AssetEmittedArgs, BoxLoader,
-  BoxedParserAndGeneratorBuilder, Chunk, ChunkAssetArgs, ChunkContentHash, ChunkHashArgs,
-  Compilation, CompilationArgs, CompilerOptions, Content, ContentHashArgs, DoneArgs, FactorizeArgs,
+  BoxedChunkFormat, BoxedChunkLoadingRuntimeModuleBuilder, BoxedParserAndGeneratorBuilder,
+  BoxedWasmLoadingCodeBuilder, Chunk, ChunkAssetArgs, ChunkContentHash, ChunkHashArgs, Compilation,
+  CompilationArgs, CompilerOptions, Content, ContentHashArgs, DoneArgs, FactorizeArgs,
   JsChunkHashArgs, MakeParam, Module, ModuleArgs, ModuleType, NormalModule,
   NormalModuleAfterResolveArgs, NormalModuleBeforeResolveArgs, NormalModuleFactoryContext,
   OptimizeChunksArgs, Plugin, PluginAdditionalChunkRuntimeRequirementsOutput,
@@ -32,6 +33,13 @@ pub struct PluginDriver {
   pub resolver_factory: Arc<ResolverFactory>,
   // pub registered_parser: HashMap<ModuleType, BoxedParser>,
   pub registered_parser_and_generator_builder: HashMap<ModuleType, BoxedParserAndGeneratorBuilder>,
+  /// The runtime module builders of the custom chunk loading types, keyed by the type
+  pub registered_chunk_loading_runtime_module_builder:
+    HashMap<String, BoxedChunkLoadingRuntimeModuleBuilder>,
+  /// The custom chunk formats, keyed by the format
+  pub registered_chunk_format: HashMap<String, BoxedChunkFormat>,
+  /// The wasm loading code builders of the custom wasm loading types, keyed by the type
+  pub registered_wasm_loading_code_builder: HashMap<String, BoxedWasmLoadingCodeBuilder>,
   /// Collecting error generated by plugin phase, e.g., `Syntax Error`
   pub diagnostics: Arc<Mutex<Vec<Diagnostic>>>,
 }
@@ -43,6 +51,9 @@ impl std::fmt::Debug for PluginDriver {
       .field("plugins", &self.plugins)
       // field("registered_parser", &self.registered_parser)
       .field("registered_parser_and_generator_builder", &"{..}")
+      .field("registered_chunk_loading_runtime_module_builder", &"{..}")
+      .field("registered_chunk_format", &"{..}")
+      .field("registered_wasm_loading_code_builder", &"{..}")
       .field("diagnostics", &self.diagnostics)
       .finish()
   }
@@ -54,7 +65,7 @@ impl PluginDriver {
     mut plugins: Vec<Box<dyn Plugin>>,
     resolver_factory: Arc<ResolverFactory>,
   ) -> Self {
-    let registered_parser_and_generator_builder = plugins
+    let apply_contexts = plugins
       .par_iter_mut()
       .map(|plugin| {
         let mut apply_context = ApplyContext::default();
@@ -63,13 +74,20 @@ impl PluginDriver {
           .expect("TODO:");
         apply_context
       })
-      .flat_map(|apply_context| {
-        apply_context
-          .registered_parser_and_generator_builder
-          .into_iter()
-          .collect::<Vec<_>>()
-      })
-      .collect::<HashMap<ModuleType, BoxedParserAndGeneratorBuilder>>();
+      .collect::<Vec<_>>();
+    let mut registered_parser_and_generator_builder = HashMap::default();
+    let mut registered_chunk_loading_runtime_module_builder = HashMap::default();
+    let mut registered_chunk_format = HashMap::default();
+    let mut registered_wasm_loading_code_builder = HashMap::default();
+    for apply_context in apply_contexts {
+      registered_parser_and_generator_builder
+        .extend(apply_context.registered_parser_and_generator_builder);
+      registered_chunk_loading_runtime_module_builder
+        .extend(apply_context.registered_chunk_loading_runtime_module_builder);
+      registered_chunk_format.extend(apply_context.registered_chunk_format);
+      registered_wasm_loading_code_builder
+        .extend(apply_context.registered_wasm_loading_code_builder);
+    }
 
     Self {
       options,
@@ -77,6 +95,9 @@ impl PluginDriver {
       resolver_factory,
       // registered_parser,
       registered_parser_and_generator_builder,
+      registered_chunk_loading_runtime_module_builder,
+      registered_chunk_format,
+      registered_wasm_loading_code_builder,
       diagnostics: Arc::new(Mutex::new(vec![])),
     }
   }
@@ -215,6 +215,58 @@ bitflags! {
     const NODE_MODULE_DECORATOR = 1 << 47;
 
     const HARMONY_MODULE_DECORATOR = 1 << 48;
+
+    /**
+     * the get scope of the container which is currently requested by a remote,
+     * the exposed modules are loaded within it
+     */
+    const CURRENT_REMOTE_GET_SCOPE = 1 << 49;
+
+    /**
+     * an object with all share scopes
+     */
+    const SHARE_SCOPE_MAP = 1 << 50;
+
+    /**
+     * initialize the share scope with the provided modules and the remote containers
+     * Signature: function(shareScopeName: string, initScope?: object[]) => Promise<void> | void
+     */
+    const INITIALIZE_SHARING = 1 << 51;
+
+    /**
+     * the chunk prefetch function
+     */
+    const PREFETCH_CHUNK = 1 << 52;
+
+    /**
+     * an object with handlers to prefetch a chunk
+     */
+    const PREFETCH_CHUNK_HANDLERS = 1 << 53;
+
+    /**
+     * the chunk preload function
+     */
+    const PRELOAD_CHUNK = 1 << 54;
+
+    /**
+     * an object with handlers to preload a chunk
+     */
+    const PRELOAD_CHUNK_HANDLERS = 1 << 55;
+
+    /**
+     * the AMD define function
+     */
+    const AMD_DEFINE = 1 << 56;
+
+    /**
+     * the AMD options
+     */
+    const AMD_OPTIONS = 1 << 57;
+
+    /**
+     * object with all WebAssembly.instance exports
+     */
+    const WASM_INSTANCES = 1 << 58;
   }
 }
 
@@ -284,6 +336,16 @@ impl RuntimeGlobals {
       R::CREATE_FAKE_NAMESPACE_OBJECT => "__webpack_require__.t",
       R::HARMONY_MODULE_DECORATOR => "__webpack_require__.hmd",
       R::NODE_MODULE_DECORATOR => "__webpack_require__.nmd",
+      R::CURRENT_REMOTE_GET_SCOPE => "__webpack_require__.R",
+      R::SHARE_SCOPE_MAP => "__webpack_require__.S",
+      R::INITIALIZE_SHARING => "__webpack_require__.I",
+      R::PREFETCH_CHUNK => "__webpack_require__.E",
+      R::PREFETCH_CHUNK_HANDLERS => "__webpack_require__.F",
+      R::PRELOAD_CHUNK => "__webpack_require__.G",
+      R::PRELOAD_CHUNK_HANDLERS => "__webpack_require__.H",
+      R::AMD_DEFINE => "__webpack_require__.amdD",
+      R::AMD_OPTIONS => "__webpack_require__.amdO",
+      R::WASM_INSTANCES => "__webpack_require__.w",
       r => panic!(
         "Unexpected flag `{r:?}`. RuntimeGlobals should only be printed for one single flag."
       ),
@@ -398,8 +398,43 @@ impl Stats<'_> {
       assets
     });
 
-    // TODO: a placeholder for concatenation modules
-    let modules = nested_modules.then(Vec::new);
+    // the other modules concatenated into this module
+    let modules = nested_modules
+      .then(|| -> Result<_> {
+        module
+          .get_concatenated_modules()
+          .unwrap_or_default()
+          .iter()
+          .filter(|m| **m != identifier)
+          .filter_map(|m| self.compilation.module_graph.module_by_identifier(m))
+          .map(|m| self.get_module(m, reasons.is_some(), module_assets, false, source))
+          .collect::<Result<Vec<_>>>()
+      })
+      .transpose()?;
+
+    let mut optimization_bailout = mgm.optimization_bailout.clone();
+    if let Some(importers) = self
+      .compilation
+      .side_effects_skipped_modules
+      .get(&identifier)
+    {
+      let context = &self.compilation.options.context;
+      let mut importers: Vec<_> = importers
+        .iter()
+        .filter_map(|i| self.compilation.module_graph.module_by_identifier(i))
+        .map(|m| m.readable_identifier(context).to_string())
+        .collect();
+      importers.sort_unstable();
+      optimization_bailout.push(format!(
+        "Side effects free module is skipped by tree shaking, none of its exports are used by {}",
+        importers.join(", ")
+      ));
+    }
+    if let Some(reason) = self.compilation.side_effects_kept_modules.get(&identifier) {
+      optimization_bailout.push(format!(
+        "Side effects free module is kept by tree shaking, {reason}"
+      ));
+    }
 
     Ok(StatsModule {
       r#type: "module",
@@ -422,6 +457,7 @@ impl Stats<'_> {
       reasons,
       assets,
       modules,
+      optimization_bailout,
       source: source.then(|| module.original_source()).flatten(),
     })
   }
@@ -533,6 +569,7 @@ pub struct StatsModule<'a> {
   pub reasons: Option<Vec<StatsModuleReason>>,
   pub assets: Option<Vec<String>>,
   pub modules: Option<Vec<StatsModule<'a>>>,
+  pub optimization_bailout: Vec<String>,
   pub source: Option<&'a dyn Source>,
 }
 
@@ -23,10 +23,32 @@ pub struct OptimizeDependencyResult {
   pub analyze_results: IdentifierMap<OptimizeAnalyzeResult>,
   pub bail_out_module_identifiers: IdentifierMap<BailoutFlag>,
   pub side_effects_free_modules: IdentifierSet,
+  /// The side effects free modules skipped because none of their exports are used, e.g. the
+  /// modules in a side effects free re-export chain. Value is the modules importing them.
+  pub side_effects_skipped_modules: IdentifierMap<IdentifierSet>,
+  /// The side effects free modules kept by tree shaking, and why they are kept.
+  pub side_effects_kept_modules: IdentifierMap<SideEffectsKeptReason>,
   pub module_item_map: IdentifierMap<Vec<ModuleItem>>,
   pub include_module_ids: IdentifierSet,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SideEffectsKeptReason {
+  Entry,
+  Bailout,
+  ExportsUsed,
+}
+
+impl std::fmt::Display for SideEffectsKeptReason {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      SideEffectsKeptReason::Entry => write!(f, "it is an entry module"),
+      SideEffectsKeptReason::Bailout => write!(f, "it is bailed out of tree shaking"),
+      SideEffectsKeptReason::ExportsUsed => write!(f, "its exports are used"),
+    }
+  }
+}
+
 const ANALYZE_LOGGING: bool = true;
 static CARE_MODULE_ID_FROM_ENV: Lazy<Vec<String>> = Lazy::new(|| {
   let cwd = std::env::current_dir().expect("");
@@ -29,17 +29,21 @@ use super::{
   js_module::JsModule,
   symbol_graph::SymbolGraph,
   visitor::{OptimizeAnalyzeResult, SymbolRef},
-  BailoutFlag, ModuleUsedType, OptimizeDependencyResult, SideEffectType,
+  BailoutFlag, ModuleUsedType, OptimizeDependencyResult, SideEffectType, SideEffectsKeptReason,
 };
 use crate::{
-  contextify, join_string_component, tree_shaking::utils::ConvertModulePath, Compilation,
-  DependencyType, ModuleGraph, ModuleIdentifier, ModuleType, NormalModuleAstOrSource,
+  calc_hash, contextify, join_string_component, tree_shaking::utils::ConvertModulePath,
+  Compilation, DependencyType, ModuleGraph, ModuleIdentifier, ModuleSyntax, ModuleType,
+  NormalModuleAstOrSource,
 };
 
 pub struct CodeSizeOptimizer<'a> {
   compilation: &'a mut Compilation,
   bailout_modules: IdentifierMap<BailoutFlag>,
   side_effects_free_modules: IdentifierSet,
+  /// Key is a side effects free module skipped by tree shaking, value is the modules importing it
+  side_effects_skipped_modules: IdentifierMap<IdentifierSet>,
+  side_effects_kept_modules: IdentifierMap<SideEffectsKeptReason>,
   symbol_graph: SymbolGraph,
 }
 
@@ -70,6 +74,16 @@ impl ModuleEliminator {
   fn could_be_skipped(&self) -> bool {
     !self.export_used && !self.is_bailout && self.side_effects_free && !self.is_entry
   }
+
+  fn kept_reason(&self) -> SideEffectsKeptReason {
+    if self.is_entry {
+      SideEffectsKeptReason::Entry
+    } else if self.is_bailout {
+      SideEffectsKeptReason::Bailout
+    } else {
+      SideEffectsKeptReason::ExportsUsed
+    }
+  }
 }
 
 impl<'a> CodeSizeOptimizer<'a> {
@@ -78,6 +92,8 @@ impl<'a> CodeSizeOptimizer<'a> {
       bailout_modules: compilation.bailout_module_identifiers.clone(),
       symbol_graph: SymbolGraph::default(),
       side_effects_free_modules: IdentifierSet::default(),
+      side_effects_skipped_modules: IdentifierMap::default(),
+      side_effects_kept_modules: IdentifierMap::default(),
       compilation,
     }
   }
@@ -217,6 +233,8 @@ impl<'a> CodeSizeOptimizer<'a> {
         analyze_results: finalized_result_map,
         bail_out_module_identifiers: std::mem::take(&mut self.bailout_modules),
         side_effects_free_modules: std::mem::take(&mut self.side_effects_free_modules),
+        side_effects_skipped_modules: std::mem::take(&mut self.side_effects_skipped_modules),
+        side_effects_kept_modules: std::mem::take(&mut self.side_effects_kept_modules),
         module_item_map: IdentifierMap::default(),
         include_module_ids,
       }
@@ -309,19 +327,32 @@ impl<'a> CodeSizeOptimizer<'a> {
     visited_symbol_ref: &mut HashSet<SymbolRefWithMemberChain>,
     errors: &mut Vec<Error>,
   ) {
-    let bailout_entry_modules = self.bailout_modules.keys().copied().collect::<Vec<_>>();
-    for module_id in bailout_entry_modules {
-      self.collect_from_entry_like(
-        analyze_result_map,
-        module_id,
-        &mut evaluated_module_identifiers,
-        used_export_module_identifiers,
-        &inherit_export_ref_graph,
-        &mut traced_tuple,
-        EntryLikeType::Bailout,
-        visited_symbol_ref,
-        errors,
-      );
+    // A commonjs module may be bailout when marking the symbols of other bailout module
+    let mut visited_bailout_modules = IdentifierSet::default();
+    loop {
+      let bailout_entry_modules = self
+        .bailout_modules
+        .keys()
+        .filter(|module_id| !visited_bailout_modules.contains(*module_id))
+        .copied()
+        .collect::<Vec<_>>();
+      if bailout_entry_modules.is_empty() {
+        break;
+      }
+      for module_id in bailout_entry_modules {
+        visited_bailout_modules.insert(module_id);
+        self.collect_from_entry_like(
+          analyze_result_map,
+          module_id,
+          &mut evaluated_module_identifiers,
+          used_export_module_identifiers,
+          &inherit_export_ref_graph,
+          &mut traced_tuple,
+          EntryLikeType::Bailout,
+          visited_symbol_ref,
+          errors,
+        );
+      }
     }
   }
 
@@ -404,7 +435,12 @@ impl<'a> CodeSizeOptimizer<'a> {
 
         if eliminator.could_be_skipped() {
           continue;
-        } else {
+        }
+        tracing::debug!(eliminator = format!("{:?}", eliminator), "module is kept");
+        if eliminator.side_effects_free {
+          self
+            .side_effects_kept_modules
+            .insert(module_identifier, eliminator.kept_reason());
         }
 
         let mut reachable_dependency_identifier = IdentifierSet::default();
@@ -494,6 +530,11 @@ impl<'a> CodeSizeOptimizer<'a> {
             && !reachable_dependency_identifier.contains(module_identifier)
             && !need_bailout
           {
+            self
+              .side_effects_skipped_modules
+              .entry(*module_identifier)
+              .or_default()
+              .insert(mgm.module_identifier);
             continue;
           }
 
@@ -537,6 +578,10 @@ impl<'a> CodeSizeOptimizer<'a> {
             .clone(),
         );
       }
+      // A module skipped by one importer may still be included by another importer
+      self
+        .side_effects_skipped_modules
+        .retain(|module_identifier, _| !include_module_ids.contains(module_identifier));
     } else {
       *used_symbol_ref = visited_symbol_ref;
     }
@@ -789,6 +834,18 @@ impl<'a> CodeSizeOptimizer<'a> {
           }
         };
 
+        // The exports of a commonjs module are only known from `exports.xxx = ` and
+        // `module.exports = {}`, the module is bailout when the imported name is not one of them,
+        // or the default import is the whole `module.exports`.
+        if module_result.module_syntax == ModuleSyntax::COMMONJS
+          && (is_commonjs_exports_object(module_result, indirect_symbol.indirect_id())
+            || !module_result
+              .export_map
+              .contains_key(indirect_symbol.indirect_id()))
+        {
+          self.merge_bailout_modules_reason(&indirect_symbol.src, BailoutFlag::COMMONJS_EXPORTS);
+        }
+
         match module_result.export_map.get(indirect_symbol.indirect_id()) {
           Some(symbol) => match symbol {
             SymbolRef::Indirect(IndirectTopLevelSymbol {
@@ -969,11 +1026,6 @@ impl<'a> CodeSizeOptimizer<'a> {
                 //     }));
                 //   }
                 //   return;
-                // } else {
-                //   // TODO: This branch should be remove after we analyze module.exports
-                //   // If one of inherit module is a bailout module, that most probably means that module has some common js export
-                //   // which we don't analyze yet, we just pass it. It is alright because we don't modified the ast of bailout module
-                //   return;
                 // }
               }
               1 => ret[0].1.clone(),
@@ -1062,7 +1114,9 @@ impl<'a> CodeSizeOptimizer<'a> {
           StarSymbolKind::ReExportAll => (false, vec![]),
         };
         // try to access first member expr element
-        if let Some(name) = next_member_chain.get(0) {
+        if let Some(name) = next_member_chain.get(0)
+          && !is_commonjs_exports_object(analyze_refsult, name)
+        {
           if let Some(export_symbol_ref) = analyze_refsult.export_map.get(name) {
             self
               .symbol_graph
@@ -1285,6 +1339,14 @@ impl<'a> CodeSizeOptimizer<'a> {
 // };
 // }
 
+/// Without the `__esModule` flag, the default export of a commonjs module is the whole
+/// `module.exports` instead of `exports.default`
+fn is_commonjs_exports_object(analyze_result: &OptimizeAnalyzeResult, name: &JsWord) -> bool {
+  analyze_result.module_syntax == ModuleSyntax::COMMONJS
+    && !analyze_result.commonjs_es_module
+    && name == "default"
+}
+
 fn get_inherit_export_ref_graph(
   analyze_result_map: &mut std::collections::HashMap<
     Identifier,
@@ -1345,6 +1407,42 @@ fn get_inherit_export_ref_graph(
   inherit_export_ref_graph
 }
 
+/// Hash of what the analysis of a module reads besides its ast, which are the modules its
+/// dependencies are resolved to and the side effects options.
+pub(crate) fn analyze_fingerprint(
+  compilation: &Compilation,
+  module_identifier: &ModuleIdentifier,
+) -> u64 {
+  let module_graph = &compilation.module_graph;
+  let Some(mgm) = module_graph.module_graph_module_by_identifier(module_identifier) else {
+    return 0;
+  };
+  let dependencies = mgm
+    .dependencies
+    .iter()
+    .filter_map(|id| module_graph.dependency_by_id(id))
+    .map(|dependency| {
+      (
+        dependency.request(),
+        dependency.dependency_type().to_string(),
+        module_graph
+          .module_identifier_by_dependency_id(dependency.id())
+          .map(|module_identifier| module_identifier.as_str()),
+      )
+    })
+    .collect::<Vec<_>>();
+  calc_hash(&(
+    mgm
+      .build_info
+      .as_ref()
+      .and_then(|build_info| build_info.hash.as_ref())
+      .map(|hash| hash.encoded()),
+    dependencies,
+    format!("{:?}", mgm.factory_meta),
+    format!("{:?}", compilation.options.optimization.side_effects),
+  ))
+}
+
 async fn par_analyze_module(compilation: &mut Compilation) -> IdentifierMap<OptimizeAnalyzeResult> {
   let analyze_results = {
     compilation
@@ -1353,18 +1451,22 @@ async fn par_analyze_module(compilation: &mut Compilation) -> IdentifierMap<Opti
       .par_iter()
       .filter_map(|(module_identifier, mgm)| {
         let optimize_analyze_result = if mgm.module_type.is_js_like() {
-          match compilation
+          let module = compilation
             .module_graph
-            .module_by_identifier(&mgm.module_identifier)
-            .and_then(|module| module.as_normal_module().and_then(|m| m.ast()))
-            // A module can missing its AST if the module is failed to build
-            .and_then(|ast| ast.as_javascript())
-          {
-            Some(ast) => JsModule::new(ast, *module_identifier).analyze(compilation),
-            None => {
-              return None;
-            }
-          }
+            .module_by_identifier(&mgm.module_identifier)?;
+          // the analysis of an unbuilt module is served by the cache
+          compilation.cache.analyze_module_occasion.use_cache(
+            module,
+            analyze_fingerprint(compilation, module_identifier),
+            |module| {
+              module
+                .as_normal_module()
+                .and_then(|m| m.ast())
+                // A module can missing its AST if the module is failed to build
+                .and_then(|ast| ast.as_javascript())
+                .map(|ast| JsModule::new(ast, *module_identifier).analyze(compilation))
+            },
+          )?
         } else {
           AssetModule::new(*module_identifier).analyze(compilation)
         };
@@ -123,5 +123,27 @@ mod test_side_effects {
       vec!["./src/**/*.js", "./dirty.js"],
       "./clean.js"
     ));
+    assert!(get_side_effects_from_package_json_helper(
+      vec!["*.css"],
+      "src\\button\\index.css"
+    ));
+    assert!(!get_side_effects_from_package_json_helper(
+      vec!["*.css"],
+      "src\\button\\index.js"
+    ));
+  }
+
+  #[test]
+  fn parse_side_effects_field() {
+    let side_effects = SideEffects::from_value(&serde_json::json!(["*.css", 1, "./polyfill.js"]));
+    assert!(matches!(
+      side_effects,
+      Some(SideEffects::Array(patterns)) if patterns == vec!["*.css", "./polyfill.js"]
+    ));
+    assert!(matches!(
+      SideEffects::from_value(&serde_json::json!(false)),
+      Some(SideEffects::Bool(false))
+    ));
+    assert!(SideEffects::from_value(&serde_json::json!({})).is_none());
   }
 }
@@ -1,5 +1,8 @@
 use rspack_symbol::{IndirectTopLevelSymbol, StarSymbol, Symbol};
-use swc_core::common::Mark;
+use swc_core::common::{
+  comments::{CommentKind, Comments},
+  BytePos, Mark,
+};
 use swc_core::ecma::ast::{CallExpr, Callee, Expr, ExprOrSpread, Ident, Lit};
 use swc_core::ecma::atoms::{js_word, JsWord};
 
@@ -17,6 +20,15 @@ pub fn get_first_string_lit_arg(e: &CallExpr) -> Option<JsWord> {
   })
 }
 
+/// Check if the node at `pos` is annotated with `/*#__PURE__*/` or `/*@__PURE__*/`
+pub fn has_pure_annotation(comments: &dyn Comments, pos: BytePos) -> bool {
+  comments.get_leading(pos).map_or(false, |comments| {
+    comments.iter().any(|comment| {
+      comment.kind == CommentKind::Block && matches!(comment.text.trim(), "#__PURE__" | "@__PURE__")
+    })
+  })
+}
+
 pub fn get_require_literal(e: &CallExpr, unresolved_mark: Mark) -> Option<JsWord> {
   if e.args.len() == 1 {
     if match &e.callee {
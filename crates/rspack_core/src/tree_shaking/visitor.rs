@@ -10,9 +10,9 @@ use rspack_symbol::{
   SymbolExt, SymbolFlag, SymbolType,
 };
 use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
-use serde::Serialize;
-use swc_core::common::SyntaxContext;
+use serde::{Deserialize, Serialize};
 use swc_core::common::{util::take::Take, Mark, GLOBALS};
+use swc_core::common::{Span, SyntaxContext};
 use swc_core::ecma::ast::*;
 use swc_core::ecma::atoms::{js_word, JsWord};
 use swc_core::ecma::utils::{ExprCtx, ExprExt};
@@ -25,16 +25,16 @@ use super::SideEffectType;
 // use swc_ecma_ast::*;
 // use swc_ecma_visit::{noop_visit_type, Visit, VisitWith};
 use super::{
-  utils::{get_dynamic_import_string_literal, get_require_literal},
+  utils::{get_dynamic_import_string_literal, get_require_literal, has_pure_annotation},
   BailoutFlag,
 };
 use crate::needs_refactor::WorkerSyntaxList;
 use crate::{
-  CompilerOptions, Dependency, DependencyType, FactoryMeta, ModuleGraph, ModuleIdentifier,
-  ModuleSyntax,
+  BoxModuleDependency, CompilerOptions, Dependency, DependencyType, FactoryMeta, ModuleGraph,
+  ModuleIdentifier, ModuleSyntax,
 };
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum SymbolRef {
   Declaration(Symbol),
   Indirect(IndirectTopLevelSymbol),
@@ -155,6 +155,17 @@ pub(crate) struct ModuleRefAnalyze<'a> {
   unresolved_ctxt: SyntaxContext,
   pub(crate) potential_top_level_mark: HashSet<Mark>,
   worker_syntax_list: &'a WorkerSyntaxList,
+  /// The commonjs exports are only analyzed in a module without esm syntax
+  is_harmony: bool,
+  /// `exports` or `module.exports` is used in a way that can't be analyzed statically, e.g.
+  /// `exports[name] = value` or `fn(module.exports)`
+  has_dynamic_commonjs_exports: bool,
+  /// `Object.defineProperty(exports, "__esModule", { value: true })` or `exports.__esModule = true`
+  pub(crate) commonjs_es_module: bool,
+  comments: Option<&'a SwcComments>,
+  /// The imports reachable from each top level declaration, a declaration is unused if any of its
+  /// reachable imports is unused.
+  pub(crate) inner_graph: HashMap<BetterId, HashSet<SymbolRef>>,
 }
 
 impl<'a> std::fmt::Debug for ModuleRefAnalyze<'a> {
@@ -196,6 +207,13 @@ impl<'a> std::fmt::Debug for ModuleRefAnalyze<'a> {
       .field("potential_top_mark", &self.potential_top_level_mark)
       .field("comments", &"...")
       .field("worker_syntax_list", self.worker_syntax_list)
+      .field("is_harmony", &self.is_harmony)
+      .field(
+        "has_dynamic_commonjs_exports",
+        &self.has_dynamic_commonjs_exports,
+      )
+      .field("commonjs_es_module", &self.commonjs_es_module)
+      .field("inner_graph", &self.inner_graph)
       .finish()
   }
 }
@@ -220,7 +238,7 @@ impl<'a> ModuleRefAnalyze<'a> {
     uri: ModuleIdentifier,
     dep_to_module_identifier: &'a ModuleGraph,
     options: &'a Arc<CompilerOptions>,
-    _comments: Option<&'a SwcComments>,
+    comments: Option<&'a SwcComments>,
     worker_syntax_list: &'a WorkerSyntaxList,
   ) -> Self {
     Self {
@@ -247,6 +265,11 @@ impl<'a> ModuleRefAnalyze<'a> {
       unresolved_ctxt: SyntaxContext::empty(),
       potential_top_level_mark: HashSet::from_iter([mark_info.top_level_mark]),
       worker_syntax_list,
+      is_harmony: false,
+      has_dynamic_commonjs_exports: false,
+      commonjs_es_module: false,
+      comments,
+      inner_graph: HashMap::default(),
     }
   }
 
@@ -386,20 +409,197 @@ impl<'a> ModuleRefAnalyze<'a> {
   }
 
   fn check_commonjs_feature(&mut self, obj: &Ident, prop: &str) {
-    if self.state.contains(AnalyzeState::ASSIGNMENT_LHS)
-      && ((&obj.sym == "module" && prop == "exports") || &obj.sym == "exports")
-    {
+    if !((&obj.sym == "module" && prop == "exports") || &obj.sym == "exports") {
+      return;
+    }
+    if self.state.contains(AnalyzeState::ASSIGNMENT_LHS) {
       self.module_syntax.insert(ModuleSyntax::COMMONJS);
-      match self
-        .bail_out_module_identifiers
-        .entry(self.module_identifier)
-      {
-        Entry::Occupied(mut occ) => {
-          *occ.get_mut() |= BailoutFlag::COMMONJS_EXPORTS;
-        }
-        Entry::Vacant(vac) => {
-          vac.insert(BailoutFlag::COMMONJS_EXPORTS);
+      self.add_bailout(self.module_identifier, BailoutFlag::COMMONJS_EXPORTS);
+    } else if obj.span.ctxt == self.unresolved_ctxt {
+      // reading `exports.xxx` may read the export assigned dynamically
+      self.has_dynamic_commonjs_exports = true;
+    }
+  }
+
+  fn add_bailout(&mut self, module_identifier: ModuleIdentifier, flag: BailoutFlag) {
+    match self.bail_out_module_identifiers.entry(module_identifier) {
+      Entry::Occupied(mut occ) => {
+        *occ.get_mut() |= flag;
+      }
+      Entry::Vacant(vac) => {
+        vac.insert(flag);
+      }
+    }
+  }
+
+  /// The binding of a commonjs export, it is in the empty context so it can't conflict with the
+  /// bindings in the module.
+  fn commonjs_export_id(name: JsWord) -> BetterId {
+    BetterId {
+      ctxt: SyntaxContext::empty(),
+      atom: name,
+    }
+  }
+
+  /// Returns the export name if the assignment is `exports.xxx = ` or `module.exports.xxx = `
+  fn get_commonjs_export_assignment(&self, node: &AssignExpr) -> Option<JsWord> {
+    if node.op != op!("=") {
+      return None;
+    }
+    let Some(Expr::Member(member_expr)) = node.left.as_expr() else {
+      return None;
+    };
+    let is_exports_object = match &*member_expr.obj {
+      Expr::Ident(obj) => &obj.sym == "exports" && obj.span.ctxt == self.unresolved_ctxt,
+      obj => is_module_exports_member_expr(obj, self.unresolved_ctxt),
+    };
+    if is_exports_object {
+      get_static_member_prop(&member_expr.prop).cloned()
+    } else {
+      None
+    }
+  }
+
+  /// `exports.xxx = value`, the export `xxx` references everything referenced in `value`
+  fn analyze_commonjs_export(&mut self, name: JsWord, value: &Expr) {
+    self.module_syntax.insert(ModuleSyntax::COMMONJS);
+    if &name == "__esModule" {
+      self.commonjs_es_module = true;
+      value.visit_with(self);
+      return;
+    }
+    let id = Self::commonjs_export_id(name.clone());
+    self.add_export(
+      name,
+      SymbolRef::Declaration(Symbol::new(
+        self.module_identifier,
+        id.clone(),
+        SymbolType::Define,
+        None,
+      )),
+    );
+    // `exports.a = exports.b = value`, `a` references the same value as `b`
+    if let Expr::Assign(assign) = value
+      && let Some(inner_name) = self.get_commonjs_export_assignment(assign)
+    {
+      self.add_reference(
+        id.into(),
+        Part::Id(Self::commonjs_export_id(inner_name.clone())),
+        false,
+      );
+      self.analyze_commonjs_export(inner_name, &assign.right);
+      return;
+    }
+    let before_owner_extend_symbol = self.current_body_owner_symbol_ext.replace(id.into());
+    self.visit_commonjs_export_value(value);
+    self.current_body_owner_symbol_ext = before_owner_extend_symbol;
+  }
+
+  /// `module.exports = { a, b: value }`, returns `false` if any property can't be analyzed
+  fn analyze_commonjs_exports_object(&mut self, object: &ObjectLit) -> bool {
+    let is_static = object.props.iter().all(|prop| match prop {
+      PropOrSpread::Prop(box Prop::Shorthand(_)) => true,
+      PropOrSpread::Prop(box Prop::KeyValue(KeyValueProp { key, .. }))
+      | PropOrSpread::Prop(box Prop::Method(MethodProp { key, .. })) => {
+        matches!(key, PropName::Ident(_) | PropName::Str(_))
+      }
+      _ => false,
+    });
+    if !is_static {
+      return false;
+    }
+    self.module_syntax.insert(ModuleSyntax::COMMONJS);
+    for prop in object.props.iter().filter_map(|prop| prop.as_prop()) {
+      let name = match &**prop {
+        Prop::Shorthand(ident) => ident.sym.clone(),
+        Prop::KeyValue(KeyValueProp { key, .. }) | Prop::Method(MethodProp { key, .. }) => {
+          match key {
+            PropName::Ident(ident) => ident.sym.clone(),
+            PropName::Str(str) => str.value.clone(),
+            _ => unreachable!("Only static property name is analyzed"),
+          }
         }
+        _ => unreachable!("Only static property is analyzed"),
+      };
+      if &name == "__esModule" {
+        self.commonjs_es_module = true;
+        prop.visit_with(self);
+        continue;
+      }
+      let id = Self::commonjs_export_id(name.clone());
+      self.add_export(
+        name,
+        SymbolRef::Declaration(Symbol::new(
+          self.module_identifier,
+          id.clone(),
+          SymbolType::Define,
+          None,
+        )),
+      );
+      let before_owner_extend_symbol = self.current_body_owner_symbol_ext.replace(id.into());
+      match &**prop {
+        Prop::Shorthand(ident) => ident.visit_with(self),
+        Prop::KeyValue(KeyValueProp { value, .. }) => self.visit_commonjs_export_value(value),
+        Prop::Method(method) => method.function.visit_with(self),
+        _ => {}
+      }
+      self.current_body_owner_symbol_ext = before_owner_extend_symbol;
+    }
+    true
+  }
+
+  /// Function and identifier are not evaluated until the export is used, others are evaluated
+  /// immediately like the initializer of var decl.
+  fn visit_commonjs_export_value(&mut self, value: &Expr) {
+    if matches!(value, Expr::Fn(_) | Expr::Arrow(_) | Expr::Ident(_)) {
+      value.visit_with(self);
+    } else {
+      self.state.insert(AnalyzeState::ASSIGNMENT_RHS);
+      value.visit_with(self);
+      self.state.remove(AnalyzeState::ASSIGNMENT_RHS);
+    }
+  }
+
+  /// `require('./a').b` is treated like `import { b } from './a'`, so the required module is not
+  /// bailout.
+  fn analyze_require_member(&mut self, request: &JsWord, property: &JsWord) {
+    self.module_syntax.insert(ModuleSyntax::COMMONJS);
+    let Some(module_identifier) = self
+      .resolve_module_identifier(request, &DependencyType::CjsRequire)
+      .copied()
+    else {
+      // the resolution error of the request is already reported by the module factory
+      tracing::debug!(
+        "Can't resolve require {} in {}",
+        request,
+        self.module_identifier
+      );
+      return;
+    };
+    self.add_member_import(module_identifier, property);
+  }
+
+  /// Reference the export `property` of the module as if it is imported by a named import.
+  fn add_member_import(&mut self, module_identifier: ModuleIdentifier, property: &JsWord) {
+    // The member is referenced through a binding in a fresh context, like a imported binding
+    let id = BetterId {
+      ctxt: SyntaxContext::empty().apply_mark(Mark::fresh(Mark::root())),
+      atom: property.clone(),
+    };
+    self.add_import(
+      id.clone(),
+      SymbolRef::Indirect(IndirectTopLevelSymbol::new(
+        module_identifier,
+        self.module_identifier,
+        IndirectType::Import(property.clone(), None),
+      )),
+    );
+    match self.current_body_owner_symbol_ext {
+      Some(ref body_owner_symbol_ext) => {
+        self.add_reference(body_owner_symbol_ext.clone(), Part::Id(id), false);
+      }
+      None => {
+        self.used_id_set.insert(Part::Id(id));
       }
     }
   }
@@ -410,7 +610,17 @@ impl<'a> Visit for ModuleRefAnalyze<'a> {
   fn visit_program(&mut self, node: &Program) {
     assert!(GLOBALS.is_set());
     self.unresolved_ctxt = self.unresolved_ctxt.apply_mark(self.unresolved_mark);
+    self.is_harmony = match node {
+      Program::Module(module) => module
+        .body
+        .iter()
+        .any(|item| matches!(item, ModuleItem::ModuleDecl(_))),
+      Program::Script(_) => false,
+    };
     node.visit_children_with(self);
+    if !self.is_harmony && self.has_dynamic_commonjs_exports {
+      self.add_bailout(self.module_identifier, BailoutFlag::COMMONJS_EXPORTS);
+    }
     // calc reachable imports for each export symbol defined in current module
     for (_, symbol) in self.export_map.iter() {
       match symbol {
@@ -429,6 +639,19 @@ impl<'a> Visit for ModuleRefAnalyze<'a> {
         SymbolRef::Usage(..) => {}
       }
     }
+    // calc reachable imports for each top level declaration
+    let inner_graph = self
+      .maybe_lazy_reference_map
+      .keys()
+      .filter(|symbol| symbol.id().ctxt.outer() == self.top_level_mark)
+      .map(|symbol| {
+        (
+          symbol.id().clone(),
+          self.get_all_import_or_export(symbol.id().clone(), true),
+        )
+      })
+      .collect();
+    self.inner_graph = inner_graph;
     // Any var declaration has reference a symbol from other module, it is marked as used
     // Because the symbol import from other module possibly has side effect
     let side_effect_symbol_list = self
@@ -453,7 +676,8 @@ impl<'a> Visit for ModuleRefAnalyze<'a> {
               SymbolFlag::FUNCTION_EXPR
                 | SymbolFlag::ARROW_EXPR
                 | SymbolFlag::CLASS_EXPR
-                | SymbolFlag::ALIAS,
+                | SymbolFlag::ALIAS
+                | SymbolFlag::PURE,
             )
             .bits()
             .count_ones()
@@ -645,6 +869,9 @@ impl<'a> Visit for ModuleRefAnalyze<'a> {
   }
 
   fn visit_ident(&mut self, node: &Ident) {
+    if node.span.ctxt == self.unresolved_ctxt && matches!(&*node.sym, "exports" | "module") {
+      self.has_dynamic_commonjs_exports = true;
+    }
     let id: BetterId = node.to_id().into();
     let mark = id.ctxt.outer();
 
@@ -877,6 +1104,21 @@ impl<'a> Visit for ModuleRefAnalyze<'a> {
   }
 
   fn visit_assign_expr(&mut self, node: &AssignExpr) {
+    // Only the exports assigned in top level are analyzed, others make the module bailout
+    if !self.is_harmony && self.current_body_owner_symbol_ext.is_none() {
+      if let Some(name) = self.get_commonjs_export_assignment(node) {
+        self.analyze_commonjs_export(name, &node.right);
+        return;
+      }
+      if node.op == op!("=")
+        && let Some(left) = node.left.as_expr()
+        && is_module_exports_member_expr(left, self.unresolved_ctxt)
+        && let Expr::Object(object) = &*node.right
+        && self.analyze_commonjs_exports_object(object)
+      {
+        return;
+      }
+    }
     let before_owner_extend_symbol = self.current_body_owner_symbol_ext.clone();
     let target = if before_owner_extend_symbol.is_none() {
       let target = first_ident_of_assign_lhs(node);
@@ -983,6 +1225,16 @@ impl<'a> Visit for ModuleRefAnalyze<'a> {
           }
         }
       }
+      // require('./a').b
+      (Expr::Call(call_expr), prop) => {
+        if let Some(request) = get_require_literal(call_expr, self.unresolved_mark)
+          && let Some(property) = get_static_member_prop(prop)
+        {
+          self.analyze_require_member(&request, property);
+        } else {
+          node.visit_children_with(self);
+        }
+      }
       _ => {
         node.visit_children_with(self);
       }
@@ -1064,14 +1316,7 @@ impl<'a> Visit for ModuleRefAnalyze<'a> {
         .copied()
       {
         Some(module_identifier) => {
-          match self.bail_out_module_identifiers.entry(module_identifier) {
-            Entry::Occupied(mut occ) => {
-              *occ.get_mut() |= BailoutFlag::COMMONJS_REQUIRE;
-            }
-            Entry::Vacant(vac) => {
-              vac.insert(BailoutFlag::COMMONJS_REQUIRE);
-            }
-          }
+          self.add_bailout(module_identifier, BailoutFlag::COMMONJS_REQUIRE);
         }
         None => {
           eprintln!(
@@ -1081,20 +1326,25 @@ impl<'a> Visit for ModuleRefAnalyze<'a> {
         }
       };
     } else if let Some(import_str) = get_dynamic_import_string_literal(node) {
-      match self
-        .resolve_module_identifier(&import_str, &DependencyType::DynamicImport)
-        .copied()
-      {
-        Some(module_identifier) => {
-          match self.bail_out_module_identifiers.entry(module_identifier) {
-            Entry::Occupied(mut occ) => {
-              *occ.get_mut() |= BailoutFlag::DYNAMIC_IMPORT;
-            }
-            Entry::Vacant(vac) => {
-              vac.insert(BailoutFlag::DYNAMIC_IMPORT);
-            }
+      // `import(/* webpackExports: ["a"] */ './a')` only uses the listed exports
+      let resolved = self
+        .resolve_dependency(&import_str, &DependencyType::DynamicImport)
+        .map(|(dependency, module_identifier)| {
+          let referenced_exports = dependency
+            .get_referenced_exports()
+            .filter(|exports| !exports.is_empty())
+            .map(<[JsWord]>::to_vec);
+          (*module_identifier, referenced_exports)
+        });
+      match resolved {
+        Some((module_identifier, Some(referenced_exports))) => {
+          for export in &referenced_exports {
+            self.add_member_import(module_identifier, export);
           }
         }
+        Some((module_identifier, None)) => {
+          self.add_bailout(module_identifier, BailoutFlag::DYNAMIC_IMPORT);
+        }
         None => {
           eprintln!(
             "Can't resolve dynamic import {} in {}",
@@ -1102,6 +1352,9 @@ impl<'a> Visit for ModuleRefAnalyze<'a> {
           );
         }
       };
+    } else if is_define_es_module_flag(node, self.unresolved_ctxt) {
+      self.module_syntax.insert(ModuleSyntax::COMMONJS);
+      self.commonjs_es_module = true;
     } else {
       node.visit_children_with(self);
     }
@@ -1205,6 +1458,7 @@ impl<'a> Visit for ModuleRefAnalyze<'a> {
         match init {
             box Expr::Fn(_) => symbol_ext.flag.insert(SymbolFlag::FUNCTION_EXPR),
             box Expr::Arrow(_) => symbol_ext.flag.insert(SymbolFlag::ARROW_EXPR),
+            _ if self.is_pure_initializer(init) => symbol_ext.flag.insert(SymbolFlag::PURE),
             _ => {}
         };
         if is_export {
@@ -1327,14 +1581,14 @@ pub fn get_side_effects_from_package_json(
   side_effects: SideEffects,
   relative_path: PathBuf,
 ) -> bool {
+  // The globs are always matched against the posix style path, even on windows
+  let relative_path = relative_path.to_string_lossy().replace('\\', "/");
   match side_effects {
     SideEffects::Bool(s) => s,
-    SideEffects::String(s) => {
-      glob_match_with_normalized_pattern(&s, &relative_path.to_string_lossy())
-    }
+    SideEffects::String(s) => glob_match_with_normalized_pattern(&s, &relative_path),
     SideEffects::Array(patterns) => patterns
       .iter()
-      .any(|pattern| glob_match_with_normalized_pattern(pattern, &relative_path.to_string_lossy())),
+      .any(|pattern| glob_match_with_normalized_pattern(pattern, &relative_path)),
   }
 }
 
@@ -1353,7 +1607,7 @@ impl<'a> ModuleRefAnalyze<'a> {
     match ele {
       ModuleItem::ModuleDecl(module_decl) => match module_decl {
         ModuleDecl::ExportDecl(decl) => {
-          if !is_pure_decl(&decl.decl, self.unresolved_ctxt) {
+          if !self.is_pure_top_level_decl(&decl.decl) {
             self.has_side_effects_stmt = true;
           }
         }
@@ -1448,7 +1702,7 @@ impl<'a> ModuleRefAnalyze<'a> {
           }
         }
         Stmt::Decl(stmt) => {
-          if !is_pure_decl(stmt, self.unresolved_ctxt) {
+          if !self.is_pure_top_level_decl(stmt) {
             self.has_side_effects_stmt = true;
           }
         }
@@ -1459,6 +1713,44 @@ impl<'a> ModuleRefAnalyze<'a> {
       };
     }
   }
+  /// Like [is_pure_decl], but the initializers annotated with `/*#__PURE__*/` are also pure.
+  fn is_pure_top_level_decl(&self, decl: &Decl) -> bool {
+    match decl {
+      Decl::Var(var) => var.decls.iter().all(|decl| match decl.init {
+        Some(ref init) => self.is_pure_initializer(init),
+        None => true,
+      }),
+      _ => is_pure_decl(decl, self.unresolved_ctxt),
+    }
+  }
+
+  /// Align with the `isPure` of webpack `InnerGraphPlugin`, a call or new expression annotated
+  /// with `/*#__PURE__*/` is pure if all of its arguments are pure, the callee is not checked.
+  fn is_pure_initializer(&self, expr: &Expr) -> bool {
+    match expr {
+      Expr::Paren(paren) => self.is_pure_initializer(&paren.expr),
+      Expr::Call(CallExpr {
+        span,
+        callee: Callee::Expr(_),
+        args,
+        ..
+      }) if self.has_pure_annotation(span) => args
+        .iter()
+        .all(|arg| arg.spread.is_none() && self.is_pure_initializer(&arg.expr)),
+      Expr::New(NewExpr { span, args, .. }) if self.has_pure_annotation(span) => args
+        .iter()
+        .flatten()
+        .all(|arg| arg.spread.is_none() && self.is_pure_initializer(&arg.expr)),
+      _ => is_pure_expression(expr, self.unresolved_ctxt),
+    }
+  }
+
+  fn has_pure_annotation(&self, span: &Span) -> bool {
+    self
+      .comments
+      .map_or(false, |comments| has_pure_annotation(comments, span.lo))
+  }
+
   fn add_export(&mut self, id: JsWord, symbol: SymbolRef) {
     match self.export_map.entry(id) {
       Entry::Occupied(_) => {
@@ -1602,6 +1894,17 @@ impl<'a> ModuleRefAnalyze<'a> {
     src: &str,
     dependency_type: &DependencyType,
   ) -> Option<&ModuleIdentifier> {
+    self
+      .resolve_dependency(src, dependency_type)
+      .map(|(_, module_identifier)| module_identifier)
+  }
+
+  /// Like `resolve_module_identifier`, but also returns the dependency
+  fn resolve_dependency(
+    &self,
+    src: &str,
+    dependency_type: &DependencyType,
+  ) -> Option<(&BoxModuleDependency, &ModuleIdentifier)> {
     self
       .module_graph
       .module_graph_module_by_identifier(&self.module_identifier)
@@ -1615,7 +1918,7 @@ impl<'a> ModuleRefAnalyze<'a> {
             self
               .module_graph
               .module_graph_module_by_dependency_id(dep.id())
-              .map(|module| &module.module_identifier)
+              .map(|module| (dep, &module.module_identifier))
           } else {
             None
           }
@@ -1625,7 +1928,7 @@ impl<'a> ModuleRefAnalyze<'a> {
 }
 
 /// The `allow(unused)` will be removed after the Tree shaking is finished
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone)]
 #[allow(unused)]
 pub struct OptimizeAnalyzeResult {
   pub top_level_mark: Mark,
@@ -1642,6 +1945,9 @@ pub struct OptimizeAnalyzeResult {
   pub(crate) bail_out_module_identifiers: IdentifierMap<BailoutFlag>,
   pub(crate) side_effects: SideEffectType,
   pub(crate) module_syntax: ModuleSyntax,
+  pub(crate) commonjs_es_module: bool,
+  /// Key is a top level declaration, value is the imports reachable from it
+  pub inner_graph: HashMap<BetterId, HashSet<SymbolRef>>,
 }
 
 impl From<ModuleRefAnalyze<'_>> for OptimizeAnalyzeResult {
@@ -1661,6 +1967,8 @@ impl From<ModuleRefAnalyze<'_>> for OptimizeAnalyzeResult {
       bail_out_module_identifiers: analyze.bail_out_module_identifiers,
       side_effects: analyze.side_effects,
       module_syntax: analyze.module_syntax,
+      commonjs_es_module: analyze.commonjs_es_module,
+      inner_graph: analyze.inner_graph,
     }
   }
 }
@@ -1720,6 +2028,52 @@ fn is_module_exports_member_expr(expr: &Expr, unresolved_ctxt: SyntaxContext) ->
   }) if obj_span.ctxt == unresolved_ctxt && prop_sym == "exports")
 }
 
+/// Returns the property name of `obj.prop` or `obj['prop']`
+fn get_static_member_prop(prop: &MemberProp) -> Option<&JsWord> {
+  match prop {
+    MemberProp::Ident(ident) => Some(&ident.sym),
+    MemberProp::Computed(ComputedPropName {
+      expr: box Expr::Lit(Lit::Str(str)),
+      ..
+    }) => Some(&str.value),
+    _ => None,
+  }
+}
+
+/// Check if the call is `Object.defineProperty(exports, "__esModule", { value: true })`
+fn is_define_es_module_flag(call_expr: &CallExpr, unresolved_ctxt: SyntaxContext) -> bool {
+  let Callee::Expr(box Expr::Member(MemberExpr {
+    obj: box Expr::Ident(obj),
+    prop: MemberProp::Ident(prop),
+    ..
+  })) = &call_expr.callee
+  else {
+    return false;
+  };
+  if &obj.sym != "Object" || &prop.sym != "defineProperty" || call_expr.args.len() != 3 {
+    return false;
+  }
+  let is_exports_object = match &*call_expr.args[0].expr {
+    Expr::Ident(ident) => &ident.sym == "exports" && ident.span.ctxt == unresolved_ctxt,
+    expr => is_module_exports_member_expr(expr, unresolved_ctxt),
+  };
+  let is_es_module_flag =
+    matches!(&*call_expr.args[1].expr, Expr::Lit(Lit::Str(str)) if &str.value == "__esModule");
+  let is_literal_descriptor = match &*call_expr.args[2].expr {
+    Expr::Object(object) => object.props.iter().all(|prop| {
+      matches!(
+        prop,
+        PropOrSpread::Prop(box Prop::KeyValue(KeyValueProp {
+          value: box Expr::Lit(_),
+          ..
+        }))
+      )
+    }),
+    _ => false,
+  };
+  is_exports_object && is_es_module_flag && is_literal_descriptor
+}
+
 fn is_pure_decl(stmt: &Decl, unresolved_ctxt: SyntaxContext) -> bool {
   match stmt {
     Decl::Class(class) => is_pure_class(&class.class, unresolved_ctxt),
@@ -1807,24 +2161,25 @@ impl SideEffects {
       .data()
       .raw()
       .get("sideEffects")
-      .and_then(|value| {
-        if let Some(b) = value.as_bool() {
-          Some(SideEffects::Bool(b))
-        } else if let Some(s) = value.as_str() {
-          Some(SideEffects::String(s.to_owned()))
-        } else if let Some(vec) = value.as_array() {
-          let mut ans = vec![];
-          for value in vec {
-            if let Some(str) = value.as_str() {
-              ans.push(str.to_string());
-            } else {
-              return None;
-            }
-          }
-          Some(SideEffects::Array(ans))
-        } else {
-          None
-        }
+      .and_then(Self::from_value)
+  }
+
+  /// Parse the `sideEffects` field of `package.json`, the items of an array which are not string
+  /// are ignored like webpack, instead of making the whole module side effectful.
+  pub fn from_value(value: &serde_json::Value) -> Option<Self> {
+    if let Some(b) = value.as_bool() {
+      Some(SideEffects::Bool(b))
+    } else if let Some(s) = value.as_str() {
+      Some(SideEffects::String(s.to_owned()))
+    } else {
+      value.as_array().map(|vec| {
+        SideEffects::Array(
+          vec
+            .iter()
+            .filter_map(|value| value.as_str().map(|str| str.to_string()))
+            .collect(),
+        )
       })
+    }
   }
 }
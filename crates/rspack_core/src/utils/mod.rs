@@ -82,3 +82,19 @@ pub fn stringify_map(map: &HashMap<String, String>) -> String {
     })
   )
 }
+
+/// Creates an empty directory under the system temp directory which is unique to the caller,
+/// tests running in parallel should not share directories.
+#[cfg(test)]
+pub(crate) fn unique_temp_dir(name: &str) -> std::path::PathBuf {
+  use std::sync::atomic::{AtomicUsize, Ordering};
+  static COUNTER: AtomicUsize = AtomicUsize::new(0);
+  let dir = std::env::temp_dir().join(format!(
+    "{name}_{}_{}",
+    std::process::id(),
+    COUNTER.fetch_add(1, Ordering::Relaxed)
+  ));
+  let _ = std::fs::remove_dir_all(&dir);
+  std::fs::create_dir_all(&dir).expect("should create temp dir");
+  dir
+}
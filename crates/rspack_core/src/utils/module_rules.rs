@@ -2,17 +2,26 @@ use async_recursion::async_recursion;
 use rspack_error::Result;
 use rspack_loader_runner::ResourceData;
 
-use crate::{DependencyCategory, ModuleRule};
+use crate::{DependencyCategory, ImportAttributes, ModuleRule};
 
 pub async fn module_rules_matcher<'a>(
   rules: &'a [ModuleRule],
   resource_data: &ResourceData,
   issuer: Option<&'a str>,
   dependency: &DependencyCategory,
+  attributes: &ImportAttributes,
   matched_rules: &mut Vec<&'a ModuleRule>,
 ) -> Result<()> {
   for rule in rules {
-    module_rule_matcher(rule, resource_data, issuer, dependency, matched_rules).await?;
+    module_rule_matcher(
+      rule,
+      resource_data,
+      issuer,
+      dependency,
+      attributes,
+      matched_rules,
+    )
+    .await?;
   }
   Ok(())
 }
@@ -24,6 +33,7 @@ pub async fn module_rule_matcher<'a>(
   resource_data: &ResourceData,
   issuer: Option<&'a str>,
   dependency: &DependencyCategory,
+  attributes: &ImportAttributes,
   matched_rules: &mut Vec<&'a ModuleRule>,
 ) -> Result<bool> {
   // Include all modules that pass test assertion. If you supply a Rule.test option, you cannot also supply a `Rule.resource`.
@@ -121,16 +131,45 @@ pub async fn module_rule_matcher<'a>(
     }
   }
 
+  if let Some(with) = &module_rule.with {
+    for (k, matcher) in with {
+      if let Some(v) = attributes.get(k) {
+        if !matcher.try_match(v).await? {
+          return Ok(false);
+        }
+      } else {
+        return Ok(false);
+      }
+    }
+  }
+
   if let Some(one_of) = &module_rule.one_of {
     for rule in one_of {
-      if module_rule_matcher(rule, resource_data, issuer, dependency, matched_rules).await? {
+      if module_rule_matcher(
+        rule,
+        resource_data,
+        issuer,
+        dependency,
+        attributes,
+        matched_rules,
+      )
+      .await?
+      {
         break;
       }
     }
   }
 
   if let Some(rules) = &module_rule.rules {
-    module_rules_matcher(rules, resource_data, issuer, dependency, matched_rules).await?;
+    module_rules_matcher(
+      rules,
+      resource_data,
+      issuer,
+      dependency,
+      attributes,
+      matched_rules,
+    )
+    .await?;
   }
 
   matched_rules.push(module_rule);
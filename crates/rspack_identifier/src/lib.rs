@@ -3,7 +3,7 @@ use std::hash::BuildHasherDefault;
 use std::{convert::From, fmt, ops::Deref};
 
 use hashlink::{LinkedHashMap, LinkedHashSet};
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use ustr::Ustr;
 
 pub trait Identifiable {
@@ -23,7 +23,9 @@ pub type IdentifierLinkedMap<V> =
 pub type IdentifierSet = HashSet<Identifier, BuildHasherDefault<IdentifierHasher>>;
 pub type IdentifierLinkedSet = LinkedHashSet<Identifier, BuildHasherDefault<IdentifierHasher>>;
 
-#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize)]
+#[derive(
+  Debug, Default, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize,
+)]
 pub struct Identifier(Ustr);
 
 impl Deref for Identifier {
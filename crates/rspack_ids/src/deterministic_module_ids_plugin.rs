@@ -3,7 +3,7 @@ use rspack_error::Result;
 
 use crate::id_helpers::{
   assign_deterministic_ids, compare_modules_by_pre_order_index_or_identifier, get_full_module_name,
-  get_used_module_ids_and_modules,
+  get_used_module_ids_and_modules, revive_module_ids,
 };
 
 #[derive(Debug, Default)]
@@ -11,6 +11,8 @@ pub struct DeterministicModuleIdsPlugin;
 
 impl Plugin for DeterministicModuleIdsPlugin {
   fn module_ids(&self, compilation: &mut Compilation) -> Result<()> {
+    // ids are deterministic only if there is no conflict, keep ids of the previous compilation
+    revive_module_ids(compilation);
     let (mut used_ids, modules) = get_used_module_ids_and_modules(compilation, None);
 
     let module_graph = &compilation.module_graph;
@@ -9,7 +9,8 @@ use once_cell::sync::Lazy;
 use rayon::prelude::*;
 use regex::Regex;
 use rspack_core::{
-  BoxModule, Chunk, ChunkGraph, ChunkUkey, Compilation, ModuleGraph, ModuleIdentifier,
+  BoxModule, Chunk, ChunkGraph, ChunkUkey, Compilation, CompilationRecords, ModuleGraph,
+  ModuleIdentifier,
 };
 use rspack_util::{
   comparators::{compare_ids, compare_numbers},
@@ -53,6 +54,24 @@ pub fn get_used_module_ids_and_modules(
   (used_ids, modules)
 }
 
+/// Assign module ids recorded by the previous compilation, so ids stay stable between compilations.
+pub fn revive_module_ids(compilation: &mut Compilation) {
+  let records = compilation.records.clone();
+  if records.module_ids.is_empty() {
+    return;
+  }
+  let (mut used_ids, modules) = get_used_module_ids_and_modules(compilation, None);
+  for module_identifier in modules {
+    if let Some(id) = records.module_ids.get(module_identifier.as_str()) {
+      if used_ids.insert(id.clone()) {
+        compilation
+          .chunk_graph
+          .set_module_id(module_identifier, id.clone());
+      }
+    }
+  }
+}
+
 pub fn get_short_module_name(module: &BoxModule, context: &str) -> String {
   let lib_ident = module.lib_ident(rspack_core::LibIdentOptions { context });
   if let Some(lib_ident) = lib_ident {
@@ -432,6 +451,7 @@ pub fn get_used_chunk_ids(compilation: &Compilation) -> HashSet<String> {
 }
 
 pub fn assign_ascending_chunk_ids(chunks: &[ChunkUkey], compilation: &mut Compilation) {
+  revive_chunk_ids(chunks, compilation);
   let used_ids = get_used_chunk_ids(compilation);
 
   let mut next_id = 0;
@@ -464,3 +484,32 @@ pub fn assign_ascending_chunk_ids(chunks: &[ChunkUkey], compilation: &mut Compil
     }
   }
 }
+
+/// Assign chunk ids recorded by the previous compilation, so ids stay stable between compilations.
+fn revive_chunk_ids(chunks: &[ChunkUkey], compilation: &mut Compilation) {
+  let records = compilation.records.clone();
+  if records.chunk_ids.is_empty() {
+    return;
+  }
+  let mut used_ids = get_used_chunk_ids(compilation);
+  let revived = chunks
+    .iter()
+    .filter_map(|ukey| {
+      let chunk = compilation.chunk_by_ukey.get(ukey)?;
+      if chunk.id.is_some() {
+        return None;
+      }
+      let key = CompilationRecords::chunk_key(chunk, compilation)?;
+      let id = records.chunk_ids.get(&key)?;
+      used_ids.insert(id.clone()).then(|| (*ukey, id.clone()))
+    })
+    .collect::<Vec<_>>();
+  for (ukey, id) in revived {
+    let chunk = compilation
+      .chunk_by_ukey
+      .get_mut(&ukey)
+      .expect("Chunk not found");
+    chunk.id = Some(id.clone());
+    chunk.ids = vec![id];
+  }
+}
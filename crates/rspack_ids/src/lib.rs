@@ -4,6 +4,7 @@ pub use deterministic_module_ids_plugin::*;
 mod named_module_ids_plugin;
 pub use named_module_ids_plugin::*;
 pub(crate) mod id_helpers;
+pub use id_helpers::{assign_ascending_chunk_ids, revive_module_ids};
 mod named_chunk_ids_plugin;
 pub use named_chunk_ids_plugin::*;
 mod stable_named_chunk_ids_plugin;
@@ -86,7 +86,9 @@ async fn loader_test(actual: impl AsRef<Path>, expected: impl AsRef<Path>) {
           remove_available_modules: false,
           remove_empty_chunks: true,
           side_effects: SideEffectOption::False,
+          concatenate_modules: false,
         },
+        records_path: None,
       }),
       resolver_factory: Default::default(),
     },
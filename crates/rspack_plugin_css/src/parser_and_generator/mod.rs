@@ -260,6 +260,7 @@ impl ParserAndGenerator for CssParserAndGenerator {
           module,
           runtime_requirements: generate_context.runtime_requirements,
           init_fragments: &mut vec![],
+          concatenation_scope: None,
         };
 
         let mgm = compilation
@@ -10,9 +10,9 @@ use regex::Regex;
 use rspack_core::{
   contextify,
   rspack_sources::{BoxSource, ConcatSource, MapOptions, RawSource, Source, SourceExt, SourceMap},
-  AssetInfo, Compilation, CompilationAsset, JsChunkHashArgs, PathData, Plugin, PluginContext,
-  PluginJsChunkHashHookOutput, PluginProcessAssetsOutput, PluginRenderModuleContentOutput,
-  ProcessAssetsArgs, RenderModuleContentArgs, SourceType,
+  AssetInfo, Compilation, CompilationAsset, JsChunkHashArgs, ModuleIdentifier, PathData, Plugin,
+  PluginContext, PluginJsChunkHashHookOutput, PluginProcessAssetsOutput,
+  PluginRenderModuleContentOutput, ProcessAssetsArgs, RenderModuleContentArgs, SourceType,
 };
 use rspack_error::{internal_error, Error, Result};
 use rspack_util::swc::normalize_custom_filename;
@@ -21,6 +21,9 @@ use serde_json::json;
 
 static IS_CSS_FILE: Lazy<Regex> = Lazy::new(|| Regex::new(r"\.css($|\?)").expect("TODO:"));
 
+/// The same as the default `moduleFilenameTemplate` of `EvalDevToolModulePlugin` in webpack.
+const EVAL_MODULE_FILENAME_TEMPLATE: &str = "webpack://[namespace]/[resourcePath]";
+
 #[derive(Debug)]
 pub struct DevtoolPluginOptions {
   pub inline: bool,
@@ -40,6 +43,17 @@ pub struct DevtoolPlugin {
   columns: bool,
   no_sources: bool,
   public_path: Option<String>,
+  /// Rendered eval modules of the last compilation, so only changed modules are
+  /// wrapped again and have their source maps recalculated in rebuilds.
+  module_render_cache: DashMap<ModuleIdentifier, EvalModuleCache>,
+}
+
+#[derive(Debug)]
+struct EvalModuleCache {
+  /// hash of the code generation result
+  hash: String,
+  module_id: String,
+  source: BoxSource,
 }
 
 impl DevtoolPlugin {
@@ -54,11 +68,68 @@ impl DevtoolPlugin {
       columns: options.columns,
       no_sources: options.no_sources,
       public_path: options.public_path,
+      module_render_cache: Default::default(),
     }
   }
-}
 
-static MODULE_RENDER_CACHE: Lazy<DashMap<BoxSource, BoxSource>> = Lazy::new(DashMap::default);
+  fn render_eval_module(
+    &self,
+    args: &RenderModuleContentArgs,
+    module_id: &str,
+  ) -> Result<BoxSource> {
+    let compilation = args.compilation;
+    let devtool = &compilation.options.devtool;
+    let origin_source = args.module_source;
+
+    if devtool.source_map() {
+      if let Some(map) = origin_source.map(&MapOptions::new(!devtool.cheap())) {
+        return self.wrap_eval_source_map(&origin_source.source(), map, module_id, compilation);
+      }
+    }
+
+    // fallback to eval with `sourceURL` when the source has no source map
+    let resource_path = args
+      .module
+      .readable_identifier(&compilation.options.context);
+    let source_url = EVAL_MODULE_FILENAME_TEMPLATE
+      .replace("[namespace]", &self.namespace)
+      .replace("[resourcePath]", &resource_path);
+    let footer = format!("\n//# sourceURL={source_url}\n");
+    Ok(wrap_eval(&origin_source.source(), &footer))
+  }
+
+  fn wrap_eval_source_map(
+    &self,
+    source: &str,
+    mut map: SourceMap,
+    module_id: &str,
+    compilation: &Compilation,
+  ) -> Result<BoxSource> {
+    for source in map.sources_mut() {
+      let resource_path = normalize_custom_filename(source);
+      let resource_path = contextify(&compilation.options.context, resource_path);
+      *source = self
+        .module_filename_template
+        .replace("[namespace]", &self.namespace)
+        .replace("[resourcePath]", &resource_path);
+    }
+    if self.no_sources {
+      for content in map.sources_content_mut() {
+        *content = String::default();
+      }
+    }
+    let mut map_buffer = Vec::new();
+    map
+      .to_writer(&mut map_buffer)
+      .map_err(|e| internal_error!(e.to_string()))?;
+    let base64 = rspack_base64::encode_to_string(&map_buffer);
+    let footer = format!(
+      "\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,{base64}\n\
+       //# sourceURL=webpack-internal:///{module_id}\n"
+    );
+    Ok(wrap_eval(source, &footer))
+  }
+}
 
 #[async_trait::async_trait]
 impl Plugin for DevtoolPlugin {
@@ -70,18 +141,42 @@ impl Plugin for DevtoolPlugin {
     _ctx: PluginContext,
     args: &RenderModuleContentArgs,
   ) -> PluginRenderModuleContentOutput {
-    let devtool = &args.compilation.options.devtool;
-    let origin_source = args.module_source.clone();
-    if devtool.eval() && devtool.source_map() {
-      if let Some(cached) = MODULE_RENDER_CACHE.get(&origin_source) {
-        return Ok(Some(cached.value().clone()));
-      } else if let Some(map) = origin_source.map(&MapOptions::new(devtool.cheap())) {
-        let source = wrap_eval_source_map(&origin_source.source(), map, args.compilation)?;
-        MODULE_RENDER_CACHE.insert(origin_source, source.clone());
-        return Ok(Some(source));
+    if !args.compilation.options.devtool.eval() {
+      return Ok(Some(args.module_source.clone()));
+    }
+    let identifier = args.module.identifier();
+    let module_id = args
+      .compilation
+      .chunk_graph
+      .get_module_id(identifier)
+      .as_deref()
+      .unwrap_or_default();
+    let hash = args
+      .code_generation_result
+      .hash
+      .as_ref()
+      .map(|hash| hash.encoded());
+    if let Some(hash) = hash {
+      if let Some(cached) = self.module_render_cache.get(&identifier)
+        && cached.hash == hash
+        && cached.module_id == module_id
+      {
+        return Ok(Some(cached.source.clone()));
       }
     }
-    Ok(Some(origin_source))
+
+    let source = self.render_eval_module(args, module_id)?;
+    if let Some(hash) = hash {
+      self.module_render_cache.insert(
+        identifier,
+        EvalModuleCache {
+          hash: hash.to_string(),
+          module_id: module_id.to_string(),
+          source: source.clone(),
+        },
+      );
+    }
+    Ok(Some(source))
   }
 
   fn js_chunk_hash(
@@ -99,6 +194,13 @@ impl Plugin for DevtoolPlugin {
     _ctx: PluginContext,
     args: ProcessAssetsArgs<'_>,
   ) -> PluginProcessAssetsOutput {
+    if args.compilation.options.devtool.eval() {
+      // drop the modules which are removed in this compilation
+      let module_graph = &args.compilation.module_graph;
+      self
+        .module_render_cache
+        .retain(|identifier, _| module_graph.module_by_identifier(identifier).is_some());
+    }
     let no_map =
       !args.compilation.options.devtool.source_map() || args.compilation.options.devtool.eval();
     let context = args.compilation.options.context.clone();
@@ -247,28 +349,6 @@ impl Plugin for DevtoolPlugin {
   }
 }
 
-pub fn wrap_eval_source_map(
-  source: &str,
-  mut map: SourceMap,
-  compilation: &Compilation,
-) -> Result<BoxSource> {
-  for source in map.sources_mut() {
-    let resource_path = normalize_custom_filename(source);
-    let resource_path = contextify(&compilation.options.context, resource_path);
-    *source = resource_path;
-  }
-  if compilation.options.devtool.no_sources() {
-    for content in map.sources_content_mut() {
-      *content = String::default();
-    }
-  }
-  let mut map_buffer = Vec::new();
-  map
-    .to_writer(&mut map_buffer)
-    .map_err(|e| internal_error!(e.to_string()))?;
-  let base64 = rspack_base64::encode_to_string(&map_buffer);
-  let footer =
-    format!("\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,{base64}");
-  let result = RawSource::from(format!("eval({});", json!(format!("{source}{footer}")))).boxed();
-  Ok(result)
+fn wrap_eval(source: &str, footer: &str) -> BoxSource {
+  RawSource::from(format!("eval({});", json!(format!("{source}{footer}")))).boxed()
 }
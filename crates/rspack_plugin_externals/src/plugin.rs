@@ -3,9 +3,10 @@ use std::fmt::Debug;
 use once_cell::sync::Lazy;
 use regex::Regex;
 use rspack_core::{
-  ApplyContext, ExternalItem, ExternalItemFnCtx, ExternalItemValue, ExternalModule, ExternalType,
-  FactorizeArgs, ModuleDependency, ModuleExt, ModuleFactoryResult, NormalModuleFactoryContext,
-  Plugin, PluginContext, PluginFactorizeHookOutput,
+  ApplyContext, ExternalItem, ExternalItemFnCtx, ExternalItemValue, ExternalModule,
+  ExternalRequest, ExternalRequestValue, ExternalType, FactorizeArgs, ModuleDependency, ModuleExt,
+  ModuleFactoryResult, NormalModuleFactoryContext, Plugin, PluginContext,
+  PluginFactorizeHookOutput,
 };
 use rspack_error::Result;
 
@@ -47,6 +48,17 @@ impl ExternalPlugin {
         }
       }
       ExternalItemValue::Array(config) => config.to_vec(),
+      ExternalItemValue::Object(config) => {
+        let request = config
+          .iter()
+          .map(|(k, v)| (k.clone(), ExternalRequestValue(v.clone())))
+          .collect();
+        return Some(ExternalModule::new(
+          ExternalRequest::Map(request),
+          r#type.unwrap_or_else(|| self.r#type.clone()),
+          dependency.request().to_owned(),
+        ));
+      }
     };
 
     let external_module_type = r#type.unwrap_or_else(|| {
@@ -61,7 +73,7 @@ impl ExternalPlugin {
       self.r#type.clone()
     });
     Some(ExternalModule::new(
-      external_module_config,
+      ExternalRequest::Single(ExternalRequestValue(external_module_config)),
       external_module_type,
       dependency.request().to_owned(),
     ))
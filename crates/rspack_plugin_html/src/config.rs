@@ -90,6 +90,10 @@ pub struct HtmlPluginConfig {
   pub title: Option<String>,
   pub favicon: Option<String>,
   pub meta: Option<HashMap<String, HashMap<String, String>>>,
+  /// emit preload links for the async chunks preloaded by the included entries
+  /// with `webpackPreload`
+  #[serde(default)]
+  pub preload: bool,
 }
 
 fn default_filename() -> String {
@@ -117,6 +121,7 @@ impl Default for HtmlPluginConfig {
       title: None,
       favicon: None,
       meta: None,
+      preload: false,
     }
   }
 }
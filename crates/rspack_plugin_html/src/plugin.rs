@@ -8,11 +8,12 @@ use std::{
 use anyhow::Context;
 use async_trait::async_trait;
 use dojang::dojang::Dojang;
+use itertools::Itertools;
 use rayon::prelude::{IntoParallelRefMutIterator, ParallelIterator};
 use rspack_core::{
   parse_to_url,
   rspack_sources::{RawSource, SourceExt},
-  CompilationAsset, Filename, PathData, Plugin,
+  ChunkGroupOrderKey, CompilationAsset, Filename, PathData, Plugin,
 };
 use serde::Deserialize;
 use swc_html::visit::VisitMutWith;
@@ -108,7 +109,7 @@ impl Plugin for HtmlPlugin {
     if !diagnostic.is_empty() {
       compilation.push_batch_diagnostic(diagnostic);
     }
-    let included_assets = compilation
+    let included_entrypoints = compilation
       .entrypoints
       .keys()
       .filter(|&entry_name| {
@@ -122,6 +123,9 @@ impl Plugin for HtmlPlugin {
         included
       })
       .map(|entry_name| compilation.entrypoint_by_name(entry_name))
+      .collect::<Vec<_>>();
+    let included_assets = included_entrypoints
+      .iter()
       .flat_map(|entry| entry.get_files(&compilation.chunk_by_ukey))
       .map(|asset_name| {
         (
@@ -132,6 +136,41 @@ impl Plugin for HtmlPlugin {
       .collect::<Vec<_>>();
 
     let mut tags = vec![];
+    if config.preload {
+      // the async chunks preloaded by the initial chunks with `webpackPreload`
+      let preload_assets = included_entrypoints
+        .iter()
+        .flat_map(|entry| entry.chunks.iter())
+        .filter_map(|chunk_ukey| compilation.chunk_by_ukey.get(chunk_ukey))
+        .flat_map(|chunk| chunk.get_children_by_order(&ChunkGroupOrderKey::Preload, compilation))
+        .unique()
+        .filter_map(|chunk_ukey| compilation.chunk_by_ukey.get(&chunk_ukey))
+        .flat_map(|chunk| chunk.files.iter().sorted())
+        .filter_map(|asset_name| {
+          compilation
+            .assets()
+            .get(asset_name)
+            .map(|asset| (asset_name, asset))
+        })
+        .collect::<Vec<_>>();
+      for (asset_name, asset) in preload_assets {
+        let Some(extension) = Path::new(asset_name).extension() else {
+          continue;
+        };
+        let as_type = if extension.eq_ignore_ascii_case("css") {
+          "style"
+        } else if extension.eq_ignore_ascii_case("js") || extension.eq_ignore_ascii_case("mjs") {
+          "script"
+        } else {
+          continue;
+        };
+        let asset_uri = format!(
+          "{}{asset_name}",
+          config.get_public_path(compilation, &self.config.filename),
+        );
+        tags.push((HTMLPluginTag::create_preload(&asset_uri, as_type), asset));
+      }
+    }
     for (asset_name, asset) in included_assets {
       if let Some(extension) = Path::new(&asset_name).extension() {
         let asset_uri = format!(
@@ -40,6 +40,28 @@ impl HTMLPluginTag {
     }
   }
 
+  pub fn create_preload(href: &str, as_type: &str) -> HTMLPluginTag {
+    HTMLPluginTag {
+      tag_name: "link".to_string(),
+      append_to: HtmlPluginConfigInject::Head,
+      attributes: vec![
+        HtmlPluginAttribute {
+          attr_name: "href".to_string(),
+          attr_value: Some(href.to_string()),
+        },
+        HtmlPluginAttribute {
+          attr_name: "rel".to_string(),
+          attr_value: Some("preload".to_string()),
+        },
+        HtmlPluginAttribute {
+          attr_name: "as".to_string(),
+          attr_value: Some(as_type.to_string()),
+        },
+      ],
+      void_tag: true,
+    }
+  }
+
   pub fn create_script(
     src: &str,
     append_to: Option<HtmlPluginConfigInject>,
@@ -3,10 +3,15 @@ use std::sync::Arc;
 use rspack_core::{ast::javascript::Ast, ModuleType};
 use rspack_error::Error;
 use swc_core::common::comments::Comments;
+use swc_core::common::{input::SourceFileInput, BytePos};
 use swc_core::common::{FileName, SourceFile};
 use swc_core::ecma::ast::{self, EsVersion, Program};
+use swc_core::ecma::atoms::js_word;
 use swc_core::ecma::parser::{
-  self, parse_file_as_module, parse_file_as_program, parse_file_as_script, Syntax,
+  self,
+  lexer::{Lexer, TokenContexts},
+  token::{Keyword, Token, TokenAndSpan, Word},
+  Context, Parser, Syntax, Tokens,
 };
 use swc_node_comments::SwcComments;
 
@@ -22,6 +27,110 @@ fn module_type_to_is_module(value: &ModuleType) -> IsModule {
   }
 }
 
+/// Lexes the `with` of import attributes as `assert`, the parser only supports import assertions.
+///
+/// `with` is a keyword which can't follow the source of an import or export declaration otherwise,
+/// the token is replaced in place so the spans are kept.
+#[derive(Clone)]
+struct ImportAttributesTokens<I> {
+  inner: I,
+  /// the previous token is `import` or `from`
+  before_source: bool,
+  /// the previous token is the source of an import or export declaration
+  after_source: bool,
+}
+
+impl<I: Tokens> ImportAttributesTokens<I> {
+  fn new(inner: I) -> Self {
+    Self {
+      inner,
+      before_source: false,
+      after_source: false,
+    }
+  }
+}
+
+impl<I: Tokens> Iterator for ImportAttributesTokens<I> {
+  type Item = TokenAndSpan;
+
+  fn next(&mut self) -> Option<Self::Item> {
+    let mut token = self.inner.next()?;
+    if self.after_source
+      && !token.had_line_break
+      && matches!(token.token, Token::Word(Word::Keyword(Keyword::With)))
+    {
+      token.token = Token::Word(Word::Ident(js_word!("assert")));
+    }
+    self.after_source = self.before_source && matches!(token.token, Token::Str { .. });
+    self.before_source = match &token.token {
+      Token::Word(Word::Keyword(Keyword::Import)) => true,
+      Token::Word(Word::Ident(word)) => *word == js_word!("from"),
+      _ => false,
+    };
+    Some(token)
+  }
+}
+
+impl<I: Tokens> Tokens for ImportAttributesTokens<I> {
+  fn set_ctx(&mut self, ctx: Context) {
+    self.inner.set_ctx(ctx)
+  }
+
+  fn ctx(&self) -> Context {
+    self.inner.ctx()
+  }
+
+  fn syntax(&self) -> Syntax {
+    self.inner.syntax()
+  }
+
+  fn target(&self) -> EsVersion {
+    self.inner.target()
+  }
+
+  fn start_pos(&self) -> BytePos {
+    self.inner.start_pos()
+  }
+
+  fn set_expr_allowed(&mut self, allow: bool) {
+    self.inner.set_expr_allowed(allow)
+  }
+
+  fn set_next_regexp(&mut self, start: Option<BytePos>) {
+    self.inner.set_next_regexp(start)
+  }
+
+  fn token_context(&self) -> &TokenContexts {
+    self.inner.token_context()
+  }
+
+  fn token_context_mut(&mut self) -> &mut TokenContexts {
+    self.inner.token_context_mut()
+  }
+
+  fn set_token_context(&mut self, c: TokenContexts) {
+    self.inner.set_token_context(c)
+  }
+
+  fn add_error(&self, error: parser::error::Error) {
+    self.inner.add_error(error)
+  }
+
+  fn add_module_mode_error(&self, error: parser::error::Error) {
+    self.inner.add_module_mode_error(error)
+  }
+
+  fn take_errors(&mut self) -> Vec<parser::error::Error> {
+    self.inner.take_errors()
+  }
+
+  fn reset_to(&mut self, to: BytePos) {
+    self.before_source = false;
+    self.after_source = false;
+    self.inner.reset_to(to)
+  }
+}
+
 /// Why this helper function design like this?
 /// 1. `swc_ecma_parser` could return ast with some errors which are recoverable
 /// or warning (though swc defined them as errors), but the parser at here should
@@ -36,16 +145,14 @@ pub fn parse_js(
   is_module: IsModule,
   comments: Option<&dyn Comments>,
 ) -> Result<Program, Vec<parser::error::Error>> {
-  let mut errors = vec![];
+  let lexer = Lexer::new(syntax, target, SourceFileInput::from(&*fm), comments);
+  let mut parser = Parser::new_from(ImportAttributesTokens::new(lexer));
   let program_result = match is_module {
-    IsModule::Bool(true) => {
-      parse_file_as_module(&fm, syntax, target, comments, &mut errors).map(Program::Module)
-    }
-    IsModule::Bool(false) => {
-      parse_file_as_script(&fm, syntax, target, comments, &mut errors).map(Program::Script)
-    }
-    IsModule::Unknown => parse_file_as_program(&fm, syntax, target, comments, &mut errors),
+    IsModule::Bool(true) => parser.parse_module().map(Program::Module),
+    IsModule::Bool(false) => parser.parse_script().map(Program::Script),
+    IsModule::Unknown => parser.parse_program(),
   };
+  let mut errors = parser.take_errors();
 
   // Using combinator will let rustc unhappy.
   match program_result {
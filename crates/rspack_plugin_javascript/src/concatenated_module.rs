@@ -0,0 +1,850 @@
+use std::{borrow::Cow, hash::Hash, path::Path, sync::Arc};
+
+use indexmap::IndexSet;
+use rspack_core::{
+  ast::javascript::Ast,
+  rspack_sources::{
+    BoxSource, ConcatSource, MapOptions, RawSource, Source, SourceExt, SourceMap, SourceMapSource,
+    SourceMapSourceOptions,
+  },
+  to_identifier,
+  tree_shaking::visitor::SymbolRef,
+  AstOrSource, BoxModule, BuildContext, BuildResult, CodeGenerationResult, Compilation,
+  ConcatenatedModuleExports, ConcatenationScope, Context, DependencyTemplate, LibIdentOptions,
+  Module, ModuleDependency, ModuleExt, ModuleIdentifier, ModuleType, RawModule, Resolve,
+  RuntimeGlobals, SourceType,
+};
+use rspack_error::{internal_error, Result, TWithDiagnosticArray};
+use rspack_identifier::{Identifiable, IdentifierMap};
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
+use swc_core::common::{SyntaxContext, DUMMY_SP};
+use swc_core::ecma::ast::{
+  CallExpr, Callee, ComputedPropName, Expr, ExprStmt, Ident, KeyValuePatProp, KeyValueProp, Lit,
+  MemberExpr, MemberProp, Module as SwcModule, ModuleItem, Number, ObjectPatProp, ParenExpr, Pat,
+  Prop, PropName, SeqExpr, Stmt, Str,
+};
+use swc_core::ecma::atoms::JsWord;
+use swc_core::ecma::parser::Syntax;
+use swc_core::ecma::utils::DropSpan;
+use swc_core::ecma::visit::{
+  noop_visit_mut_type, noop_visit_type, Visit, VisitMut, VisitMutWith, VisitWith,
+};
+
+use crate::dependency::format_exports;
+use crate::visitors::swc_visitor::resolver;
+
+/// Expressions of raw exports are appended to the module as `__WEBPACK_RAW_EXPORT__(expr);`,
+/// so they are renamed together with the module.
+const RAW_EXPORT_MARKER: &str = "__WEBPACK_RAW_EXPORT__";
+
+// Align with https://github.com/webpack/webpack/blob/4b4ca3bb53f36a5b8fc6bc1bd976ed7af161bd80/lib/optimize/ConcatenatedModule.js
+/// Merges ESM modules into a single scope, it takes the place of the root module in the module graph,
+/// the other concatenated modules are removed from the chunks.
+#[derive(Debug)]
+pub struct ConcatenatedModule {
+  root: BoxModule,
+  /// Concatenated modules in evaluation order, the root module is the last one.
+  modules: Vec<ModuleIdentifier>,
+  size: f64,
+}
+
+impl ConcatenatedModule {
+  pub fn new(root: BoxModule, modules: Vec<ModuleIdentifier>, size: f64) -> Self {
+    Self {
+      root,
+      modules,
+      size,
+    }
+  }
+
+  pub fn root(&self) -> &BoxModule {
+    &self.root
+  }
+
+  /// Take the root module out to restore the module graph, the concatenated module should be dropped afterwards.
+  pub(crate) fn take_root(&mut self) -> BoxModule {
+    let placeholder = RawModule::new(
+      String::new(),
+      self.root.identifier(),
+      String::new(),
+      RuntimeGlobals::default(),
+    )
+    .boxed();
+    std::mem::replace(&mut self.root, placeholder)
+  }
+
+  fn module_by_identifier<'a>(
+    &'a self,
+    compilation: &'a Compilation,
+    module_identifier: &ModuleIdentifier,
+  ) -> Result<&'a BoxModule> {
+    if *module_identifier == self.root.identifier() {
+      return Ok(&self.root);
+    }
+    compilation
+      .module_graph
+      .module_by_identifier(module_identifier)
+      .ok_or_else(|| internal_error!("Failed to get concatenated module {module_identifier}"))
+  }
+}
+
+impl Identifiable for ConcatenatedModule {
+  fn identifier(&self) -> ModuleIdentifier {
+    self.root.identifier()
+  }
+}
+
+#[async_trait::async_trait]
+impl Module for ConcatenatedModule {
+  fn module_type(&self) -> &ModuleType {
+    self.root.module_type()
+  }
+
+  fn source_types(&self) -> &[SourceType] {
+    self.root.source_types()
+  }
+
+  fn original_source(&self) -> Option<&dyn Source> {
+    self.root.original_source()
+  }
+
+  fn readable_identifier(&self, context: &Context) -> Cow<str> {
+    Cow::Owned(format!(
+      "{} + {} modules",
+      self.root.readable_identifier(context),
+      self.modules.len() - 1
+    ))
+  }
+
+  fn size(&self, _source_type: &SourceType) -> f64 {
+    self.size
+  }
+
+  async fn build(
+    &mut self,
+    build_context: BuildContext<'_>,
+  ) -> Result<TWithDiagnosticArray<BuildResult>> {
+    self.root.build(build_context).await
+  }
+
+  fn is_built(&self) -> bool {
+    self.root.is_built()
+  }
+
+  #[allow(clippy::unwrap_in_result)]
+  fn code_generation(&self, compilation: &Compilation) -> Result<CodeGenerationResult> {
+    let modules: Arc<[ModuleIdentifier]> = Arc::from(self.modules.as_slice());
+    let root = self.root.identifier();
+    let mut result = CodeGenerationResult::default();
+
+    let mut infos = IdentifierMap::default();
+    for module_identifier in modules.iter() {
+      let module = self.module_by_identifier(compilation, module_identifier)?;
+      let mut scope = ConcatenationScope::new(modules.clone(), *module_identifier);
+      let generation_result = module
+        .try_as_normal_module()?
+        .code_generation_with_concatenation_scope(compilation, &mut scope)?;
+      let source = generation_result
+        .get(&SourceType::JavaScript)
+        .and_then(|result| result.ast_or_source.as_source());
+      let mut code = source
+        .map(|source| source.source().to_string())
+        .unwrap_or_default();
+      // the source map of the generated module, which maps the code back to the original module
+      let inner_source_map = compilation
+        .options
+        .devtool
+        .enabled()
+        .then(|| source.and_then(|source| source.map(&MapOptions::default())))
+        .flatten();
+      result
+        .runtime_requirements
+        .insert(generation_result.runtime_requirements);
+      result
+        .chunk_init_fragments
+        .extend(generation_result.chunk_init_fragments);
+
+      let exports = scope.take_exports();
+      for expression in exports.raw_exports.values() {
+        code.push_str(&format!("\n{RAW_EXPORT_MARKER}({expression});"));
+      }
+      let readable_identifier = module
+        .readable_identifier(&compilation.options.context)
+        .to_string();
+      infos.insert(
+        *module_identifier,
+        ModuleInfo::new(code, readable_identifier, exports, inner_source_map)?,
+      );
+    }
+
+    // Top level names used by more than one module are renamed, names used by only one module are kept,
+    // so that the references rendered into other modules are never shadowed.
+    let mut name_counts: HashMap<JsWord, usize> = HashMap::default();
+    let mut nested_names: HashSet<JsWord> = HashSet::default();
+    for info in infos.values() {
+      for name in &info.names {
+        *name_counts.entry(name.clone()).or_default() += 1;
+      }
+      nested_names.extend(info.nested_names.iter().cloned());
+    }
+    // With tree shaking, the first declaration used by the other modules keeps a colliding name as long as
+    // no module uses the name out of the top level scope, the other declarations are renamed away.
+    let used_declarations: HashSet<(ModuleIdentifier, JsWord)> =
+      if compilation.options.builtins.tree_shaking.is_true() {
+        compilation
+          .used_symbol_ref
+          .iter()
+          .filter_map(|symbol| match symbol {
+            SymbolRef::Declaration(decl) if infos.contains_key(&decl.src()) => {
+              Some((decl.src(), decl.id().atom.clone()))
+            }
+            _ => None,
+          })
+          .collect()
+      } else {
+        Default::default()
+      };
+    let mut used_names: HashSet<JsWord> = name_counts.keys().cloned().collect();
+    let mut kept_names: HashSet<JsWord> = HashSet::default();
+    for module_identifier in modules.iter() {
+      let info = infos
+        .get_mut(module_identifier)
+        .expect("should have module info");
+      for name in &info.top_level_names {
+        if name_counts.get(name).copied().unwrap_or_default() <= 1 {
+          continue;
+        }
+        if !nested_names.contains(name)
+          && used_declarations.contains(&(*module_identifier, name.clone()))
+          && kept_names.insert(name.clone())
+        {
+          continue;
+        }
+        let new_name = find_new_name(name, &info.readable_identifier, &mut used_names);
+        info.renames.insert(name.clone(), new_name);
+      }
+      info.rename();
+    }
+
+    let resolver = ExportsResolver { infos: &infos };
+    let reference_scope = ConcatenationScope::new(modules.clone(), root);
+
+    // create namespace objects for the modules referenced as a whole
+    let mut namespace_modules: IndexSet<ModuleIdentifier> = IndexSet::default();
+    let mut queue = infos
+      .values()
+      .flat_map(|info| info.references.iter())
+      .filter_map(|reference| reference_scope.match_module_reference(reference))
+      .map(|reference| resolver.resolve(&reference.module, &reference.ids).0)
+      .filter_map(|binding| match binding {
+        Binding::Namespace(module) => Some(module),
+        _ => None,
+      })
+      .collect::<Vec<_>>();
+    while let Some(module) = queue.pop() {
+      if !namespace_modules.insert(module) {
+        continue;
+      }
+      for name in resolver.export_names(&module) {
+        if let (Binding::Namespace(module), _) = resolver.resolve(&module, &[name]) {
+          queue.push(module);
+        }
+      }
+    }
+    let mut namespace_object_names: IdentifierMap<JsWord> = IdentifierMap::default();
+    for module in modules
+      .iter()
+      .filter(|module| namespace_modules.contains(*module))
+    {
+      let info = infos.get(module).expect("should have module info");
+      let name = find_new_name(
+        "namespaceObject",
+        &info.readable_identifier,
+        &mut used_names,
+      );
+      namespace_object_names.insert(*module, name);
+    }
+
+    // the exports of the root module and the namespace objects
+    let mut header = String::from("\"use strict\";\n");
+    let exports_argument = compilation
+      .module_graph
+      .module_graph_module_by_identifier(&root)
+      .ok_or_else(|| internal_error!("Failed to get module graph module of {root}"))?
+      .get_exports_argument();
+    result
+      .runtime_requirements
+      .insert(RuntimeGlobals::EXPORTS | RuntimeGlobals::MAKE_NAMESPACE_OBJECT);
+    header.push_str(&format!(
+      "{}({exports_argument});\n",
+      RuntimeGlobals::MAKE_NAMESPACE_OBJECT
+    ));
+    let root_exports = export_definitions(&resolver, &reference_scope, &root);
+    if !root_exports.is_empty() {
+      result
+        .runtime_requirements
+        .insert(RuntimeGlobals::DEFINE_PROPERTY_GETTERS);
+      header.push_str(&format!(
+        "{}({exports_argument}, {});\n",
+        RuntimeGlobals::DEFINE_PROPERTY_GETTERS,
+        format_exports(&root_exports)
+      ));
+    }
+    for module in modules.iter() {
+      let Some(name) = namespace_object_names.get(module) else {
+        continue;
+      };
+      header.push_str(&format!(
+        "// NAMESPACE OBJECT: {}\nvar {name} = {{}};\n{}({name});\n",
+        infos
+          .get(module)
+          .expect("should have module info")
+          .readable_identifier,
+        RuntimeGlobals::MAKE_NAMESPACE_OBJECT
+      ));
+      let exports = export_definitions(&resolver, &reference_scope, module);
+      if !exports.is_empty() {
+        result
+          .runtime_requirements
+          .insert(RuntimeGlobals::DEFINE_PROPERTY_GETTERS);
+        header.push_str(&format!(
+          "{}({name}, {});\n",
+          RuntimeGlobals::DEFINE_PROPERTY_GETTERS,
+          format_exports(&exports)
+        ));
+      }
+    }
+    let mut header = ModuleInfo::new(
+      header,
+      self
+        .readable_identifier(&compilation.options.context)
+        .to_string(),
+      Default::default(),
+      None,
+    )?;
+
+    let render_references = |info: &mut ModuleInfo| -> Result<BoxSource> {
+      let references = info
+        .references
+        .iter()
+        .filter_map(|reference| {
+          let module_reference = reference_scope.match_module_reference(reference)?;
+          let (binding, ids) = resolver.resolve(&module_reference.module, &module_reference.ids);
+          let expr = match binding {
+            Binding::Symbol(module, local) => {
+              let info = infos.get(&module).expect("should have module info");
+              let name = info.renames.get(&local).cloned().unwrap_or(local);
+              Expr::Ident(Ident::new(name, DUMMY_SP))
+            }
+            Binding::Namespace(module) => Expr::Ident(Ident::new(
+              namespace_object_names
+                .get(&module)
+                .cloned()
+                .expect("should have namespace object"),
+              DUMMY_SP,
+            )),
+            Binding::Raw(module, index) => infos
+              .get(&module)
+              .expect("should have module info")
+              .raw_exports
+              .get(index)
+              .cloned()
+              .expect("should have raw export"),
+            Binding::Missing => Expr::Ident(Ident::new("undefined".into(), DUMMY_SP)),
+          };
+          let expr = ids.into_iter().fold(expr, |obj, id| {
+            Expr::Member(MemberExpr {
+              span: DUMMY_SP,
+              obj: Box::new(obj),
+              prop: MemberProp::Computed(ComputedPropName {
+                span: DUMMY_SP,
+                expr: Box::new(Expr::Lit(Lit::Str(Str::from(id)))),
+              }),
+            })
+          });
+          // avoid binding `this` to the namespace object when calling
+          let expr = if module_reference.call && expr.is_member() {
+            Expr::Paren(ParenExpr {
+              span: DUMMY_SP,
+              expr: Box::new(Expr::Seq(SeqExpr {
+                span: DUMMY_SP,
+                exprs: vec![
+                  Box::new(Expr::Lit(Lit::Num(Number {
+                    span: DUMMY_SP,
+                    value: 0.0,
+                    raw: None,
+                  }))),
+                  Box::new(expr),
+                ],
+              })),
+            })
+          } else {
+            expr
+          };
+          Some((reference.clone(), expr))
+        })
+        .collect::<HashMap<_, _>>();
+      info.ast.transform(|program, _| {
+        program.visit_mut_with(&mut ReferenceReplacer {
+          references: &references,
+        })
+      });
+      let output = crate::ast::stringify(&info.ast, &compilation.options.devtool, Some(true))?;
+      match (output.map, info.inner_source_map.clone()) {
+        (Some(map), Some(inner_source_map)) => Ok(
+          SourceMapSource::new(SourceMapSourceOptions {
+            value: output.code,
+            name: info.readable_identifier.clone(),
+            source_map: SourceMap::from_json(&map).map_err(|e| internal_error!(e.to_string()))?,
+            original_source: info.original_source.clone(),
+            inner_source_map: Some(inner_source_map),
+            remove_original_source: false,
+          })
+          .boxed(),
+        ),
+        // the header is generated, there is no original source to map to
+        _ => Ok(RawSource::from(output.code).boxed()),
+      }
+    };
+
+    let mut source = ConcatSource::default();
+    source.add(render_references(&mut header)?);
+    for module_identifier in modules.iter() {
+      // take the info out as references are resolved against the other modules
+      let mut info = infos
+        .get(module_identifier)
+        .expect("should have module info")
+        .shallow_clone();
+      source.add(RawSource::from(format!(
+        "\n// CONCATENATED MODULE: {}\n",
+        info.readable_identifier
+      )));
+      source.add(render_references(&mut info)?);
+    }
+
+    result.add(SourceType::JavaScript, AstOrSource::from(source.boxed()));
+    result.set_hash(
+      &compilation.options.output.hash_function,
+      &compilation.options.output.hash_digest,
+      &compilation.options.output.hash_salt,
+    );
+    Ok(result)
+  }
+
+  fn name_for_condition(&self) -> Option<Cow<str>> {
+    self.root.name_for_condition()
+  }
+
+  fn lib_ident(&self, options: LibIdentOptions) -> Option<Cow<str>> {
+    self.root.lib_ident(options)
+  }
+
+  fn get_code_generation_dependencies(&self) -> Option<&[Box<dyn ModuleDependency>]> {
+    self.root.get_code_generation_dependencies()
+  }
+
+  fn get_presentational_dependencies(&self) -> Option<&[Box<dyn DependencyTemplate>]> {
+    self.root.get_presentational_dependencies()
+  }
+
+  fn get_resolve_options(&self) -> Option<&Resolve> {
+    self.root.get_resolve_options()
+  }
+
+  fn get_context(&self) -> Option<&Context> {
+    self.root.get_context()
+  }
+
+  fn get_concatenated_modules(&self) -> Option<&[ModuleIdentifier]> {
+    Some(&self.modules)
+  }
+}
+
+impl Hash for ConcatenatedModule {
+  fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+    "__rspack_internal__ConcatenatedModule".hash(state);
+    self.root.hash(state);
+    self.modules.hash(state);
+  }
+}
+
+impl PartialEq for ConcatenatedModule {
+  fn eq(&self, other: &Self) -> bool {
+    self.identifier() == other.identifier() && self.modules == other.modules
+  }
+}
+
+impl Eq for ConcatenatedModule {}
+
+struct ModuleInfo {
+  readable_identifier: String,
+  ast: Ast,
+  exports: ConcatenatedModuleExports,
+  /// All the names used in the module, property names excluded
+  names: HashSet<JsWord>,
+  top_level_names: IndexSet<JsWord>,
+  /// Names bound in nested scopes or referencing globals
+  nested_names: HashSet<JsWord>,
+  /// Module references rendered by the `ConcatenationScope`
+  references: IndexSet<JsWord>,
+  renames: HashMap<JsWord, JsWord>,
+  raw_exports: Vec<Expr>,
+  /// The generated code of the module, kept with its source map when the devtool is enabled
+  original_source: Option<String>,
+  inner_source_map: Option<SourceMap>,
+}
+
+impl ModuleInfo {
+  fn new(
+    code: String,
+    readable_identifier: String,
+    exports: ConcatenatedModuleExports,
+    inner_source_map: Option<SourceMap>,
+  ) -> Result<Self> {
+    let original_source = inner_source_map.as_ref().map(|_| code.clone());
+    let mut ast = crate::ast::parse(
+      code,
+      Syntax::Es(Default::default()),
+      &readable_identifier,
+      &ModuleType::JsEsm,
+    )?;
+    let collector = ast.transform(|program, context| {
+      program.visit_mut_with(&mut resolver(
+        context.unresolved_mark,
+        context.top_level_mark,
+        false,
+      ));
+      let mut collector = IdentCollector {
+        top_level_ctxt: SyntaxContext::empty().apply_mark(context.top_level_mark),
+        unresolved_ctxt: SyntaxContext::empty().apply_mark(context.unresolved_mark),
+        names: Default::default(),
+        top_level_names: Default::default(),
+        nested_names: Default::default(),
+        references: Default::default(),
+      };
+      program.visit_with(&mut collector);
+      collector
+    });
+    Ok(Self {
+      readable_identifier,
+      ast,
+      exports,
+      names: collector.names,
+      top_level_names: collector.top_level_names,
+      nested_names: collector.nested_names,
+      references: collector.references,
+      renames: Default::default(),
+      raw_exports: Default::default(),
+      original_source,
+      inner_source_map,
+    })
+  }
+
+  /// Apply the renames of top level names and take the raw export expressions out of the module.
+  fn rename(&mut self) {
+    let renames = &self.renames;
+    let raw_exports = self.ast.transform(|program, context| {
+      let mut renamer = Renamer {
+        top_level_ctxt: SyntaxContext::empty().apply_mark(context.top_level_mark),
+        renames,
+        raw_exports: vec![],
+      };
+      program.visit_mut_with(&mut renamer);
+      renamer.raw_exports
+    });
+    self.raw_exports = raw_exports;
+  }
+
+  fn shallow_clone(&self) -> Self {
+    Self {
+      readable_identifier: self.readable_identifier.clone(),
+      ast: self.ast.clone(),
+      exports: Default::default(),
+      names: Default::default(),
+      top_level_names: Default::default(),
+      nested_names: Default::default(),
+      references: self.references.clone(),
+      renames: Default::default(),
+      raw_exports: Default::default(),
+      original_source: self.original_source.clone(),
+      inner_source_map: self.inner_source_map.clone(),
+    }
+  }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Binding {
+  /// A top level binding of the module
+  Symbol(ModuleIdentifier, JsWord),
+  Namespace(ModuleIdentifier),
+  /// Index of the raw export expressions of the module
+  Raw(ModuleIdentifier, usize),
+  Missing,
+}
+
+struct ExportsResolver<'a> {
+  infos: &'a IdentifierMap<ModuleInfo>,
+}
+
+impl ExportsResolver<'_> {
+  /// Resolve the export names to a binding, returns the binding and the remaining property accesses.
+  fn resolve(&self, module: &ModuleIdentifier, ids: &[JsWord]) -> (Binding, Vec<JsWord>) {
+    self.resolve_inner(module, ids, &mut HashSet::default())
+  }
+
+  fn resolve_inner(
+    &self,
+    module: &ModuleIdentifier,
+    ids: &[JsWord],
+    visited: &mut HashSet<(ModuleIdentifier, JsWord)>,
+  ) -> (Binding, Vec<JsWord>) {
+    let Some((name, rest)) = ids.split_first() else {
+      return (Binding::Namespace(*module), vec![]);
+    };
+    let Some(info) = self.infos.get(module) else {
+      return (Binding::Missing, vec![]);
+    };
+    if !visited.insert((*module, name.clone())) {
+      return (Binding::Missing, vec![]);
+    }
+    let exports = &info.exports;
+    if let Some(local) = exports.exports.get(name) {
+      return (Binding::Symbol(*module, local.clone()), rest.to_vec());
+    }
+    if let Some((reference_module, reference_ids)) = exports.reexports.get(name) {
+      let ids = reference_ids
+        .iter()
+        .chain(rest.iter())
+        .cloned()
+        .collect::<Vec<_>>();
+      return self.resolve_inner(reference_module, &ids, visited);
+    }
+    if let Some(index) = exports.raw_exports.get_index_of(name) {
+      return (Binding::Raw(*module, index), rest.to_vec());
+    }
+    if name != "default" {
+      for star_module in &exports.star_reexports {
+        let resolved = self.resolve_inner(star_module, ids, visited);
+        if resolved.0 != Binding::Missing {
+          return resolved;
+        }
+      }
+    }
+    (Binding::Missing, vec![])
+  }
+
+  fn export_names(&self, module: &ModuleIdentifier) -> IndexSet<JsWord> {
+    let mut names = IndexSet::default();
+    self.collect_export_names(module, &mut names, &mut HashSet::default());
+    names
+  }
+
+  fn collect_export_names(
+    &self,
+    module: &ModuleIdentifier,
+    names: &mut IndexSet<JsWord>,
+    visited: &mut HashSet<ModuleIdentifier>,
+  ) {
+    let Some(info) = self.infos.get(module) else {
+      return;
+    };
+    if !visited.insert(*module) {
+      return;
+    }
+    let exports = &info.exports;
+    names.extend(exports.exports.keys().cloned());
+    names.extend(exports.reexports.keys().cloned());
+    names.extend(exports.raw_exports.keys().cloned());
+    for star_module in &exports.star_reexports {
+      let mut star_names = IndexSet::default();
+      self.collect_export_names(star_module, &mut star_names, visited);
+      names.extend(star_names.into_iter().filter(|name| name != "default"));
+    }
+  }
+}
+
+/// Getters of the exports of `module`, the values are module references resolved later.
+fn export_definitions(
+  resolver: &ExportsResolver,
+  scope: &ConcatenationScope,
+  module: &ModuleIdentifier,
+) -> Vec<(JsWord, JsWord)> {
+  resolver
+    .export_names(module)
+    .into_iter()
+    .map(|name| {
+      let reference = scope.create_module_reference(module, &[name.clone()], false);
+      (name, JsWord::from(reference))
+    })
+    .collect()
+}
+
+fn find_new_name(
+  name: &str,
+  readable_identifier: &str,
+  used_names: &mut HashSet<JsWord>,
+) -> JsWord {
+  let module_name = Path::new(readable_identifier)
+    .file_stem()
+    .map(|stem| stem.to_string_lossy().to_string())
+    .unwrap_or_default();
+  let base = to_identifier(&format!("{name}_{module_name}"));
+  let mut new_name = JsWord::from(base.as_str());
+  let mut index = 1;
+  while used_names.contains(&new_name) {
+    new_name = JsWord::from(format!("{base}_{index}"));
+    index += 1;
+  }
+  used_names.insert(new_name.clone());
+  new_name
+}
+
+struct IdentCollector {
+  top_level_ctxt: SyntaxContext,
+  unresolved_ctxt: SyntaxContext,
+  names: HashSet<JsWord>,
+  top_level_names: IndexSet<JsWord>,
+  nested_names: HashSet<JsWord>,
+  references: IndexSet<JsWord>,
+}
+
+impl Visit for IdentCollector {
+  noop_visit_type!();
+
+  fn visit_ident(&mut self, ident: &Ident) {
+    self.names.insert(ident.sym.clone());
+    if ident.span.ctxt == self.top_level_ctxt {
+      self.top_level_names.insert(ident.sym.clone());
+    } else if ident.span.ctxt == self.unresolved_ctxt
+      && ident.sym.starts_with("__WEBPACK_MODULE_REFERENCE__")
+    {
+      self.references.insert(ident.sym.clone());
+    } else {
+      self.nested_names.insert(ident.sym.clone());
+    }
+  }
+
+  fn visit_member_prop(&mut self, prop: &MemberProp) {
+    if let MemberProp::Computed(computed) = prop {
+      computed.visit_with(self);
+    }
+  }
+
+  fn visit_prop_name(&mut self, name: &PropName) {
+    if let PropName::Computed(computed) = name {
+      computed.visit_with(self);
+    }
+  }
+}
+
+struct Renamer<'a> {
+  top_level_ctxt: SyntaxContext,
+  renames: &'a HashMap<JsWord, JsWord>,
+  raw_exports: Vec<Expr>,
+}
+
+impl Renamer<'_> {
+  fn new_name(&self, ident: &Ident) -> Option<JsWord> {
+    if ident.span.ctxt != self.top_level_ctxt {
+      return None;
+    }
+    self.renames.get(&ident.sym).cloned()
+  }
+}
+
+impl VisitMut for Renamer<'_> {
+  noop_visit_mut_type!();
+
+  fn visit_mut_module(&mut self, module: &mut SwcModule) {
+    module.visit_mut_children_with(self);
+    module.body.retain_mut(|item| {
+      if let ModuleItem::Stmt(Stmt::Expr(ExprStmt { expr, .. })) = item
+        && let Expr::Call(CallExpr { callee: Callee::Expr(callee), args, .. }) = &mut **expr
+        && matches!(&**callee, Expr::Ident(ident) if &*ident.sym == RAW_EXPORT_MARKER)
+        && let Some(arg) = args.pop()
+      {
+        let mut expr = arg.expr;
+        expr.visit_mut_with(&mut DropSpan { preserve_ctxt: false });
+        self.raw_exports.push(*expr);
+        return false;
+      }
+      true
+    });
+  }
+
+  fn visit_mut_ident(&mut self, ident: &mut Ident) {
+    if let Some(name) = self.new_name(ident) {
+      ident.sym = name;
+    }
+  }
+
+  fn visit_mut_member_prop(&mut self, prop: &mut MemberProp) {
+    if let MemberProp::Computed(computed) = prop {
+      computed.visit_mut_with(self);
+    }
+  }
+
+  fn visit_mut_prop_name(&mut self, name: &mut PropName) {
+    if let PropName::Computed(computed) = name {
+      computed.visit_mut_with(self);
+    }
+  }
+
+  fn visit_mut_prop(&mut self, prop: &mut Prop) {
+    // `{ a }` -> `{ a: a_module }`
+    if let Prop::Shorthand(ident) = prop
+      && let Some(name) = self.new_name(ident)
+    {
+      *prop = Prop::KeyValue(KeyValueProp {
+        key: PropName::Ident(Ident::new(ident.sym.clone(), DUMMY_SP)),
+        value: Box::new(Expr::Ident(Ident::new(name, ident.span))),
+      });
+      return;
+    }
+    prop.visit_mut_children_with(self);
+  }
+
+  fn visit_mut_object_pat_prop(&mut self, prop: &mut ObjectPatProp) {
+    // `const { a } = b` -> `const { a: a_module } = b`
+    if let ObjectPatProp::Assign(assign) = prop
+      && let Some(name) = self.new_name(&assign.key)
+    {
+      let binding = Box::new(Pat::Ident(Ident::new(name, assign.key.span).into()));
+      let value = match assign.value.take() {
+        Some(mut default) => {
+          default.visit_mut_with(self);
+          Box::new(Pat::Assign(swc_core::ecma::ast::AssignPat {
+            span: assign.span,
+            left: binding,
+            right: default,
+          }))
+        }
+        None => binding,
+      };
+      *prop = ObjectPatProp::KeyValue(KeyValuePatProp {
+        key: PropName::Ident(Ident::new(assign.key.sym.clone(), DUMMY_SP)),
+        value,
+      });
+      return;
+    }
+    prop.visit_mut_children_with(self);
+  }
+}
+
+struct ReferenceReplacer<'a> {
+  references: &'a HashMap<JsWord, Expr>,
+}
+
+impl VisitMut for ReferenceReplacer<'_> {
+  noop_visit_mut_type!();
+
+  fn visit_mut_expr(&mut self, expr: &mut Expr) {
+    if let Expr::Ident(ident) = expr
+      && let Some(reference) = self.references.get(&ident.sym)
+    {
+      *expr = reference.clone();
+      return;
+    }
+    expr.visit_mut_children_with(self);
+  }
+}
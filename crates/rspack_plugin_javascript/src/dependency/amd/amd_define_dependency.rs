@@ -0,0 +1,121 @@
+use rspack_core::{DependencyTemplate, RuntimeGlobals, TemplateContext, TemplateReplaceSource};
+
+// Align with https://github.com/webpack/webpack/blob/4b4ca3bb53f36a5b8fc6bc1bd976ed7af161bd80/lib/dependencies/AMDDefineDependency.js
+/// AMD `define(name?, dependencies?, factory)`, the factory is called with the required
+/// values, and its result is assigned to `module.exports`.
+///
+/// The name of the module is dropped, named modules are not shared inside the module like
+/// webpack does.
+#[derive(Debug)]
+pub struct AmdDefineDependency {
+  start: u32,
+  end: u32,
+  array_range: Option<(u32, u32)>,
+  function_range: Option<(u32, u32)>,
+  object_range: Option<(u32, u32)>,
+}
+
+impl AmdDefineDependency {
+  pub fn new(
+    start: u32,
+    end: u32,
+    array_range: Option<(u32, u32)>,
+    function_range: Option<(u32, u32)>,
+    object_range: Option<(u32, u32)>,
+  ) -> Self {
+    Self {
+      start,
+      end,
+      array_range,
+      function_range,
+      object_range,
+    }
+  }
+
+  /// Returns the variable declarations and the code with `#` as the placeholders of the ranges.
+  fn definition(&self, exports: &str, module: &str) -> (&'static str, String) {
+    let require = RuntimeGlobals::REQUIRE;
+    let assign_result = format!(
+      "__WEBPACK_AMD_DEFINE_RESULT__ !== undefined && ({module}.exports = __WEBPACK_AMD_DEFINE_RESULT__)"
+    );
+    match (
+      self.array_range.is_some(),
+      self.object_range.is_some(),
+      self.function_range.is_some(),
+    ) {
+      (false, false, true) => (
+        "var __WEBPACK_AMD_DEFINE_RESULT__;",
+        format!(
+          "!(__WEBPACK_AMD_DEFINE_RESULT__ = (#).call({exports}, {require}, {exports}, {module}),\n\t\t{assign_result})"
+        ),
+      ),
+      (false, true, false) => ("", format!("!({module}.exports = #)")),
+      (false, true, true) => (
+        "var __WEBPACK_AMD_DEFINE_FACTORY__, __WEBPACK_AMD_DEFINE_RESULT__;",
+        format!(
+          "!(__WEBPACK_AMD_DEFINE_FACTORY__ = (#),\n\t\t__WEBPACK_AMD_DEFINE_RESULT__ = (typeof __WEBPACK_AMD_DEFINE_FACTORY__ === 'function' ?\n\t\t(__WEBPACK_AMD_DEFINE_FACTORY__.call({exports}, {require}, {exports}, {module})) :\n\t\t__WEBPACK_AMD_DEFINE_FACTORY__),\n\t\t{assign_result})"
+        ),
+      ),
+      (true, false, true) => (
+        "var __WEBPACK_AMD_DEFINE_ARRAY__, __WEBPACK_AMD_DEFINE_RESULT__;",
+        format!(
+          "!(__WEBPACK_AMD_DEFINE_ARRAY__ = #, __WEBPACK_AMD_DEFINE_RESULT__ = (#).apply({exports}, __WEBPACK_AMD_DEFINE_ARRAY__),\n\t\t{assign_result})"
+        ),
+      ),
+      (true, true, false) => ("", format!("!(#, {module}.exports = #)")),
+      (true, true, true) => (
+        "var __WEBPACK_AMD_DEFINE_ARRAY__, __WEBPACK_AMD_DEFINE_FACTORY__, __WEBPACK_AMD_DEFINE_RESULT__;",
+        format!(
+          "!(__WEBPACK_AMD_DEFINE_ARRAY__ = #, __WEBPACK_AMD_DEFINE_FACTORY__ = (#),\n\t\t__WEBPACK_AMD_DEFINE_RESULT__ = (typeof __WEBPACK_AMD_DEFINE_FACTORY__ === 'function' ?\n\t\t(__WEBPACK_AMD_DEFINE_FACTORY__.apply({exports}, __WEBPACK_AMD_DEFINE_ARRAY__)) : __WEBPACK_AMD_DEFINE_FACTORY__),\n\t\t{assign_result})"
+        ),
+      ),
+      // `define([...])` without a factory
+      (_, false, false) => ("", "!(#)".to_string()),
+    }
+  }
+}
+
+impl DependencyTemplate for AmdDefineDependency {
+  fn apply(
+    &self,
+    source: &mut TemplateReplaceSource,
+    code_generatable_context: &mut TemplateContext,
+  ) {
+    let TemplateContext {
+      runtime_requirements,
+      compilation,
+      module,
+      ..
+    } = code_generatable_context;
+
+    let mgm = compilation
+      .module_graph
+      .module_graph_module_by_identifier(&module.identifier())
+      .expect("should have mgm");
+    runtime_requirements.insert(RuntimeGlobals::MODULE);
+    if self.function_range.is_some() {
+      runtime_requirements.insert(RuntimeGlobals::EXPORTS);
+      if self.array_range.is_none() {
+        runtime_requirements.insert(RuntimeGlobals::REQUIRE);
+      }
+    }
+
+    let (definition, content) =
+      self.definition(mgm.get_exports_argument(), mgm.get_module_argument());
+    if !definition.is_empty() {
+      source.insert(0, definition, None);
+    }
+
+    let mut texts = content.split('#');
+    let mut current = self.start;
+    if let Some((start, end)) = self.array_range {
+      source.replace(current, start, texts.next().unwrap_or_default(), None);
+      current = end;
+    }
+    if let Some((start, end)) = self.object_range.or(self.function_range) {
+      source.replace(current, start, texts.next().unwrap_or_default(), None);
+      current = end;
+    }
+    source.replace(current, self.end, texts.next().unwrap_or_default(), None);
+  }
+}
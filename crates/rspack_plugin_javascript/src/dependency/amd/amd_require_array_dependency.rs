@@ -0,0 +1,74 @@
+use rspack_core::{
+  module_raw, DependencyId, DependencyTemplate, RuntimeGlobals, TemplateContext,
+  TemplateReplaceSource,
+};
+use swc_core::ecma::atoms::JsWord;
+
+#[derive(Debug, Clone)]
+pub enum AmdRequireArrayItem {
+  /// `"require"`, the require function
+  Require,
+  /// `"exports"`, the exports of the current module
+  Exports,
+  /// `"module"`, the current module
+  Module,
+  /// A module request of an [crate::dependency::AmdRequireItemDependency]
+  Dependency { id: DependencyId, request: JsWord },
+}
+
+/// The dependency array of AMD `define` or `require`, rendered as an array of the required values.
+#[derive(Debug)]
+pub struct AmdRequireArrayDependency {
+  start: u32,
+  end: u32,
+  items: Vec<AmdRequireArrayItem>,
+}
+
+impl AmdRequireArrayDependency {
+  pub fn new(start: u32, end: u32, items: Vec<AmdRequireArrayItem>) -> Self {
+    Self { start, end, items }
+  }
+}
+
+impl DependencyTemplate for AmdRequireArrayDependency {
+  fn apply(
+    &self,
+    source: &mut TemplateReplaceSource,
+    code_generatable_context: &mut TemplateContext,
+  ) {
+    let TemplateContext {
+      runtime_requirements,
+      compilation,
+      module,
+      ..
+    } = code_generatable_context;
+
+    let mgm = compilation
+      .module_graph
+      .module_graph_module_by_identifier(&module.identifier())
+      .expect("should have mgm");
+    let content = self
+      .items
+      .iter()
+      .map(|item| match item {
+        AmdRequireArrayItem::Require => {
+          runtime_requirements.insert(RuntimeGlobals::REQUIRE);
+          RuntimeGlobals::REQUIRE.name().to_string()
+        }
+        AmdRequireArrayItem::Exports => {
+          runtime_requirements.insert(RuntimeGlobals::EXPORTS);
+          mgm.get_exports_argument().to_string()
+        }
+        AmdRequireArrayItem::Module => {
+          runtime_requirements.insert(RuntimeGlobals::MODULE);
+          mgm.get_module_argument().to_string()
+        }
+        AmdRequireArrayItem::Dependency { id, request } => {
+          module_raw(compilation, runtime_requirements, id, request, false)
+        }
+      })
+      .collect::<Vec<_>>()
+      .join(", ");
+    source.replace(self.start, self.end, &format!("[{content}]"), None);
+  }
+}
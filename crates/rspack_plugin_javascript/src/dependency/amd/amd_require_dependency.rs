@@ -0,0 +1,108 @@
+use rspack_core::{
+  block_promise, module_id, DependencyId, DependencyTemplate, TemplateContext,
+  TemplateReplaceSource,
+};
+use swc_core::ecma::atoms::JsWord;
+
+/// Rethrow the error of loading the chunks outside of the promise chain.
+const UNCAUGHT_ERROR_HANDLER: &str = "function(err) { setTimeout(function() { throw err; }, 0); }";
+
+// Align with https://github.com/webpack/webpack/blob/4b4ca3bb53f36a5b8fc6bc1bd976ed7af161bd80/lib/dependencies/AMDRequireDependency.js
+/// AMD `require(dependencies, callback?, errorCallback?)`, the callback is called with the
+/// required values after the chunks of the modules are loaded.
+#[derive(Debug)]
+pub struct AmdRequireDependency {
+  start: u32,
+  end: u32,
+  array_range: (u32, u32),
+  function_range: Option<(u32, u32)>,
+  error_callback_range: Option<(u32, u32)>,
+  /// The module requests in the dependency array
+  items: Vec<(DependencyId, JsWord)>,
+}
+
+impl AmdRequireDependency {
+  pub fn new(
+    start: u32,
+    end: u32,
+    array_range: (u32, u32),
+    function_range: Option<(u32, u32)>,
+    error_callback_range: Option<(u32, u32)>,
+    items: Vec<(DependencyId, JsWord)>,
+  ) -> Self {
+    Self {
+      start,
+      end,
+      array_range,
+      function_range,
+      error_callback_range,
+      items,
+    }
+  }
+}
+
+impl DependencyTemplate for AmdRequireDependency {
+  fn apply(
+    &self,
+    source: &mut TemplateReplaceSource,
+    code_generatable_context: &mut TemplateContext,
+  ) {
+    let TemplateContext {
+      runtime_requirements,
+      compilation,
+      ..
+    } = code_generatable_context;
+
+    let mut promises = self
+      .items
+      .iter()
+      .map(|(id, request)| {
+        block_promise(
+          &module_id(compilation, id, request, false),
+          runtime_requirements,
+        )
+      })
+      .collect::<Vec<_>>();
+    let promise = match promises.len() {
+      0 => "Promise.resolve()".to_string(),
+      1 => promises.remove(0),
+      _ => format!("Promise.all([{}])", promises.join(", ")),
+    };
+
+    let (array_start, array_end) = self.array_range;
+    let Some((function_start, function_end)) = self.function_range else {
+      source.replace(
+        self.start,
+        array_start,
+        &format!("{promise}.then(function() {{ "),
+        None,
+      );
+      source.replace(
+        array_end,
+        self.end,
+        &format!(";}})['catch']({UNCAUGHT_ERROR_HANDLER})"),
+        None,
+      );
+      return;
+    };
+    source.replace(
+      self.start,
+      array_start,
+      &format!("{promise}.then(function() {{ var __WEBPACK_AMD_REQUIRE_ARRAY__ = "),
+      None,
+    );
+    source.replace(array_end, function_start, "; (", None);
+    let end_block = ").apply(null, __WEBPACK_AMD_REQUIRE_ARRAY__);})['catch'](";
+    if let Some((error_start, error_end)) = self.error_callback_range {
+      source.replace(function_end, error_start, end_block, None);
+      source.replace(error_end, self.end, ")", None);
+    } else {
+      source.replace(
+        function_end,
+        self.end,
+        &format!("{end_block}{UNCAUGHT_ERROR_HANDLER})"),
+        None,
+      );
+    }
+  }
+}
@@ -0,0 +1,67 @@
+use rspack_core::{
+  ChunkGroupOptions, Dependency, DependencyCategory, DependencyId, DependencyType, ErrorSpan,
+  ModuleDependency,
+};
+use swc_core::ecma::atoms::JsWord;
+
+/// A module request in the dependency array of AMD `define` or `require`, which is rendered by
+/// the [crate::dependency::AmdRequireArrayDependency] of the array.
+#[derive(Debug, Clone)]
+pub struct AmdRequireItemDependency {
+  id: DependencyId,
+  request: JsWord,
+  span: Option<ErrorSpan>,
+  /// The items of AMD `require` are loaded on demand, while the items of AMD `define` are not
+  group_options: Option<ChunkGroupOptions>,
+}
+
+impl AmdRequireItemDependency {
+  pub fn new(
+    request: JsWord,
+    span: Option<ErrorSpan>,
+    group_options: Option<ChunkGroupOptions>,
+  ) -> Self {
+    Self {
+      id: DependencyId::new(),
+      request,
+      span,
+      group_options,
+    }
+  }
+}
+
+impl Dependency for AmdRequireItemDependency {
+  fn category(&self) -> &DependencyCategory {
+    &DependencyCategory::Amd
+  }
+
+  fn dependency_type(&self) -> &DependencyType {
+    &DependencyType::AmdRequireItem
+  }
+}
+
+impl ModuleDependency for AmdRequireItemDependency {
+  fn id(&self) -> &DependencyId {
+    &self.id
+  }
+
+  fn request(&self) -> &str {
+    &self.request
+  }
+
+  fn user_request(&self) -> &str {
+    &self.request
+  }
+
+  fn span(&self) -> Option<&ErrorSpan> {
+    self.span.as_ref()
+  }
+
+  fn group_options(&self) -> Option<&ChunkGroupOptions> {
+    self.group_options.as_ref()
+  }
+
+  fn set_request(&mut self, request: String) {
+    self.request = request.into();
+  }
+}
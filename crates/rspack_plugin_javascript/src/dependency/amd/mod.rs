@@ -0,0 +1,8 @@
+mod amd_define_dependency;
+pub use amd_define_dependency::AmdDefineDependency;
+mod amd_require_array_dependency;
+pub use amd_require_array_dependency::{AmdRequireArrayDependency, AmdRequireArrayItem};
+mod amd_require_dependency;
+pub use amd_require_dependency::AmdRequireDependency;
+mod amd_require_item_dependency;
+pub use amd_require_item_dependency::AmdRequireItemDependency;
@@ -0,0 +1,85 @@
+use rspack_core::{
+  tree_shaking::visitor::SymbolRef, Compilation, DependencyTemplate, InitFragment,
+  InitFragmentStage, ModuleIdentifier, TemplateContext, TemplateReplaceSource,
+};
+use rspack_symbol::{BetterId, Symbol, SymbolType};
+use swc_core::{common::SyntaxContext, ecma::atoms::JsWord};
+
+const UNUSED_EXPORT: &str = "__webpack_unused_export__";
+
+/// Replace the left side of `exports.xxx = value` or `module.exports.xxx = value` with
+/// `__webpack_unused_export__` when the export is unused, then the value could be removed by
+/// minimizer.
+#[derive(Debug)]
+pub struct CommonJsExportsDependency {
+  start: u32,
+  end: u32,
+  name: JsWord,
+}
+
+impl CommonJsExportsDependency {
+  pub fn new(start: u32, end: u32, name: JsWord) -> Self {
+    Self { start, end, name }
+  }
+}
+
+impl DependencyTemplate for CommonJsExportsDependency {
+  fn apply(
+    &self,
+    source: &mut TemplateReplaceSource,
+    code_generatable_context: &mut TemplateContext,
+  ) {
+    let TemplateContext {
+      compilation,
+      module,
+      init_fragments,
+      ..
+    } = code_generatable_context;
+
+    if is_common_js_export_used(compilation, module.identifier(), &self.name) {
+      return;
+    }
+    source.replace(self.start, self.end, UNUSED_EXPORT, None);
+    let declaration = format!("var {UNUSED_EXPORT};\n");
+    if !init_fragments
+      .iter()
+      .any(|fragment| fragment.content == declaration)
+    {
+      init_fragments.push(InitFragment::new(
+        declaration,
+        InitFragmentStage::STAGE_CONSTANTS,
+        None,
+      ));
+    }
+  }
+}
+
+/// The commonjs exports are kept if the module is not analyzed or bailout by tree shaking.
+pub fn is_common_js_export_used(
+  compilation: &Compilation,
+  module_identifier: ModuleIdentifier,
+  name: &JsWord,
+) -> bool {
+  if !compilation.options.builtins.tree_shaking.is_true()
+    || !compilation
+      .optimize_analyze_result_map
+      .contains_key(&module_identifier)
+    || compilation
+      .bailout_module_identifiers
+      .contains_key(&module_identifier)
+  {
+    return true;
+  }
+  // The binding of commonjs export is in the empty context, see `ModuleRefAnalyze`
+  compilation
+    .used_symbol_ref
+    .contains(&SymbolRef::Declaration(Symbol::new(
+      module_identifier,
+      BetterId {
+        ctxt: SyntaxContext::empty(),
+        atom: name.clone(),
+      },
+      SymbolType::Define,
+      None,
+    )))
+}
@@ -0,0 +1,84 @@
+use rspack_core::{DependencyTemplate, TemplateContext, TemplateReplaceSource};
+use swc_core::ecma::atoms::JsWord;
+
+use super::is_common_js_export_used;
+
+#[derive(Debug)]
+pub struct CommonJsExportsObjectProperty {
+  pub name: JsWord,
+  pub start: u32,
+  pub end: u32,
+  /// Only the property without side effects could be removed, e.g. `a`, `a: b` or `a() {}`
+  pub removable: bool,
+}
+
+/// Remove the unused properties of `module.exports = { a, b: value }`.
+#[derive(Debug)]
+pub struct CommonJsExportsObjectDependency {
+  start: u32,
+  end: u32,
+  properties: Vec<CommonJsExportsObjectProperty>,
+}
+
+impl CommonJsExportsObjectDependency {
+  pub fn new(start: u32, end: u32, properties: Vec<CommonJsExportsObjectProperty>) -> Self {
+    Self {
+      start,
+      end,
+      properties,
+    }
+  }
+}
+
+impl DependencyTemplate for CommonJsExportsObjectDependency {
+  fn apply(
+    &self,
+    source: &mut TemplateReplaceSource,
+    code_generatable_context: &mut TemplateContext,
+  ) {
+    let TemplateContext {
+      compilation,
+      module,
+      ..
+    } = code_generatable_context;
+
+    let unused = self
+      .properties
+      .iter()
+      .map(|property| {
+        property.removable
+          && !is_common_js_export_used(compilation, module.identifier(), &property.name)
+      })
+      .collect::<Vec<_>>();
+    if !unused.contains(&true) {
+      return;
+    }
+    if !unused.contains(&false) {
+      source.replace(self.start, self.end, "{}", None);
+      return;
+    }
+    // Remove each run of unused properties with the commas around them, the comma before the
+    // run is removed if the run is at the end, so that the trailing comma is still valid.
+    let mut index = 0;
+    while index < unused.len() {
+      if !unused[index] {
+        index += 1;
+        continue;
+      }
+      let run_start = index;
+      while index < unused.len() && unused[index] {
+        index += 1;
+      }
+      if let Some(next) = self.properties.get(index) {
+        source.replace(self.properties[run_start].start, next.start, "", None);
+      } else {
+        source.replace(
+          self.properties[run_start - 1].end,
+          self.properties[index - 1].end,
+          "",
+          None,
+        );
+      }
+    }
+  }
+}
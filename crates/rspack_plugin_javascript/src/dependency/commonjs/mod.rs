@@ -4,3 +4,7 @@ mod require_resolve_dependency;
 pub use require_resolve_dependency::RequireResolveDependency;
 mod module_decorator_dependency;
 pub use module_decorator_dependency::ModuleDecoratorDependency;
+mod common_js_exports_dependency;
+pub use common_js_exports_dependency::*;
+mod common_js_exports_object_dependency;
+pub use common_js_exports_object_dependency::*;
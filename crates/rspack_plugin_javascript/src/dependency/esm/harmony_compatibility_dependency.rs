@@ -19,8 +19,12 @@ impl DependencyTemplate for HarmonyCompatibilityDependency {
       init_fragments,
       compilation,
       module,
-      ..
+      concatenation_scope,
     } = code_generatable_context;
+    // the exports object of the concatenated module is created by the `ConcatenatedModule`
+    if concatenation_scope.is_some() {
+      return;
+    }
     // TODO __esModule is used
     runtime_requirements.insert(RuntimeGlobals::MAKE_NAMESPACE_OBJECT);
     runtime_requirements.insert(RuntimeGlobals::EXPORTS);
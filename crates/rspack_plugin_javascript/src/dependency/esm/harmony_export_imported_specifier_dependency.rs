@@ -100,10 +100,30 @@ impl DependencyTemplate for HarmonyExportImportedSpecifierDependency {
       None
     };
 
+    let ref_module = *compilation
+      .module_graph
+      .module_identifier_by_dependency_id(dependency_id)
+      .expect("should have dependency referenced module");
+    let is_ref_module_in_scope = code_generatable_context
+      .concatenation_scope
+      .as_ref()
+      .map(|scope| scope.is_module_in_scope(&ref_module));
+
     let mut exports = vec![];
 
     for id in &self.ids {
       if used_exports.is_none() || matches!(used_exports.as_ref(), Some(x) if x.contains(&id.0)) {
+        if is_ref_module_in_scope == Some(true) {
+          // the referenced module is concatenated, reexport its binding directly
+          if let Some(scope) = code_generatable_context.concatenation_scope.as_mut() {
+            scope.register_reexport(
+              id.0.clone(),
+              ref_module,
+              id.1.clone().map(|i| vec![i]).unwrap_or_default(),
+            );
+          }
+          continue;
+        }
         exports.push((
           id.0.clone(),
           JsWord::from(export_from_import(
@@ -118,7 +138,11 @@ impl DependencyTemplate for HarmonyExportImportedSpecifierDependency {
       }
     }
 
-    if !exports.is_empty() {
+    if let Some(scope) = code_generatable_context.concatenation_scope.as_mut() {
+      for (export_name, expression) in exports {
+        scope.register_raw_export(export_name, expression.to_string());
+      }
+    } else if !exports.is_empty() {
       let TemplateContext {
         runtime_requirements,
         init_fragments,
@@ -30,14 +30,16 @@ impl DependencyTemplate for HarmonyExportSpecifierDependency {
       init_fragments,
       compilation,
       module,
-      ..
+      concatenation_scope,
     } = code_generatable_context;
     let exports_argument = compilation
       .module_graph
       .module_graph_module_by_identifier(&module.identifier())
       .expect("should have mgm")
       .get_exports_argument();
-    runtime_requirements.insert(RuntimeGlobals::EXPORTS);
+    if concatenation_scope.is_none() {
+      runtime_requirements.insert(RuntimeGlobals::EXPORTS);
+    }
 
     if !self.exports.is_empty() {
       let used_exports = if compilation.options.builtins.tree_shaking.is_true() {
@@ -79,7 +81,11 @@ impl DependencyTemplate for HarmonyExportSpecifierDependency {
           true
         })
         .collect::<Vec<_>>();
-      if !exports.is_empty() {
+      if let Some(scope) = concatenation_scope {
+        for (export_name, local) in exports {
+          scope.register_export(export_name, local);
+        }
+      } else if !exports.is_empty() {
         runtime_requirements.insert(RuntimeGlobals::DEFINE_PROPERTY_GETTERS);
         init_fragments.push(InitFragment::new(
           format!(
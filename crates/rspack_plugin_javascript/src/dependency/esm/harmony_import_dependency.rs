@@ -1,7 +1,7 @@
 use rspack_core::{
   import_statement, tree_shaking::visitor::SymbolRef, Dependency, DependencyCategory, DependencyId,
-  DependencyTemplate, DependencyType, ErrorSpan, InitFragment, InitFragmentStage, ModuleDependency,
-  RuntimeGlobals, TemplateContext, TemplateReplaceSource,
+  DependencyTemplate, DependencyType, ErrorSpan, ImportAttributes, InitFragment, InitFragmentStage,
+  ModuleDependency, RuntimeGlobals, TemplateContext, TemplateReplaceSource,
 };
 use rspack_symbol::IndirectTopLevelSymbol;
 use swc_core::ecma::atoms::JsWord;
@@ -23,6 +23,7 @@ pub struct HarmonyImportDependency {
   pub specifiers: Vec<Specifier>,
   pub dependency_type: DependencyType,
   pub export_all: bool,
+  pub attributes: Option<ImportAttributes>,
 }
 
 impl HarmonyImportDependency {
@@ -33,6 +34,7 @@ impl HarmonyImportDependency {
     specifiers: Vec<Specifier>,
     dependency_type: DependencyType,
     export_all: bool,
+    attributes: Option<ImportAttributes>,
   ) -> Self {
     Self {
       request,
@@ -41,6 +43,7 @@ impl HarmonyImportDependency {
       specifiers,
       dependency_type,
       export_all,
+      attributes,
     }
   }
 }
@@ -65,6 +68,16 @@ impl DependencyTemplate for HarmonyImportDependency {
       return;
     }
 
+    if let Some(scope) = code_generatable_context.concatenation_scope.as_mut()
+      && scope.is_module_in_scope(&ref_mgm.module_identifier)
+    {
+      // the referenced module is concatenated, its bindings are referenced directly
+      if self.export_all {
+        scope.register_star_reexport(ref_mgm.module_identifier);
+      }
+      return;
+    }
+
     if !self.export_all {
       let specifiers = self
         .specifiers
@@ -221,4 +234,12 @@ impl ModuleDependency for HarmonyImportDependency {
   fn set_request(&mut self, request: String) {
     self.request = request.into();
   }
+
+  fn get_attributes(&self) -> Option<&ImportAttributes> {
+    self.attributes.as_ref()
+  }
+
+  fn is_export_all(&self) -> bool {
+    self.export_all
+  }
 }
@@ -116,6 +116,19 @@ impl DependencyTemplate for HarmonyImportSpecifierDependency {
       return;
     }
 
+    if let Some(scope) = &code_generatable_context.concatenation_scope
+      && scope.is_module_in_scope(&reference_mgm.module_identifier)
+    {
+      let reference =
+        scope.create_module_reference(&reference_mgm.module_identifier, &self.ids, self.is_call);
+      if self.shorthand {
+        source.insert(self.end, format!(": {reference}").as_str(), None);
+      } else {
+        source.replace(self.start, self.end, reference.as_str(), None)
+      }
+      return;
+    }
+
     let import_var = code_generatable_context
       .compilation
       .module_graph
@@ -1,7 +1,7 @@
 use rspack_core::{
   module_namespace_promise, ChunkGroupOptions, Dependency, DependencyCategory, DependencyId,
-  DependencyTemplate, DependencyType, ErrorSpan, ModuleDependency, TemplateContext,
-  TemplateReplaceSource,
+  DependencyTemplate, DependencyType, ErrorSpan, ImportAttributes, ModuleDependency,
+  TemplateContext, TemplateReplaceSource,
 };
 use swc_core::ecma::atoms::JsWord;
 
@@ -14,16 +14,28 @@ pub struct ImportDependency {
   span: Option<ErrorSpan>,
   /// This is used to implement `webpackChunkName`, `webpackPrefetch` etc.
   /// for example: `import(/* webpackChunkName: "my-chunk-name", webpackPrefetch: true */ './module')`
-  pub group_options: ChunkGroupOptions,
+  ///
+  /// It's `None` in `eager` and `weak` mode, which don't create a chunk group.
+  pub group_options: Option<ChunkGroupOptions>,
+  /// `webpackMode: "weak"`, the module is not bundled by this dependency
+  weak: bool,
+  /// `webpackExports`, the exports used by the importer
+  referenced_exports: Option<Vec<JsWord>>,
+  /// `import("./a.json", { with: { type: "json" } })`
+  attributes: Option<ImportAttributes>,
 }
 
 impl ImportDependency {
+  #[allow(clippy::too_many_arguments)]
   pub fn new(
     start: u32,
     end: u32,
     request: JsWord,
     span: Option<ErrorSpan>,
-    group_options: ChunkGroupOptions,
+    group_options: Option<ChunkGroupOptions>,
+    weak: bool,
+    referenced_exports: Option<Vec<JsWord>>,
+    attributes: Option<ImportAttributes>,
   ) -> Self {
     Self {
       start,
@@ -32,6 +44,9 @@ impl ImportDependency {
       span,
       id: DependencyId::new(),
       group_options,
+      weak,
+      referenced_exports,
+      attributes,
     }
   }
 }
@@ -67,13 +82,25 @@ impl ModuleDependency for ImportDependency {
     Some(self)
   }
 
+  fn weak(&self) -> bool {
+    self.weak
+  }
+
   fn group_options(&self) -> Option<&ChunkGroupOptions> {
-    Some(&self.group_options)
+    self.group_options.as_ref()
   }
 
   fn set_request(&mut self, request: String) {
     self.request = request.into();
   }
+
+  fn get_attributes(&self) -> Option<&ImportAttributes> {
+    self.attributes.as_ref()
+  }
+
+  fn get_referenced_exports(&self) -> Option<&[JsWord]> {
+    self.referenced_exports.as_deref()
+  }
 }
 
 impl DependencyTemplate for ImportDependency {
@@ -85,7 +112,8 @@ impl DependencyTemplate for ImportDependency {
     source.replace(
       self.start,
       self.end,
-      module_namespace_promise(code_generatable_context, &self.id, &self.request, false).as_str(),
+      module_namespace_promise(code_generatable_context, &self.id, &self.request, self.weak)
+        .as_str(),
       None,
     );
   }
@@ -1,15 +1,19 @@
+mod amd;
 mod commonjs;
 mod context;
 mod esm;
 mod hmr;
 mod module_argument_dependency;
+mod pure_expression_dependency;
 mod url;
 mod worker;
+pub use amd::*;
 pub use commonjs::*;
 pub use context::*;
 pub use esm::*;
 pub use hmr::*;
 pub use module_argument_dependency::*;
+pub use pure_expression_dependency::*;
 pub use worker::*;
 
 pub use self::url::*;
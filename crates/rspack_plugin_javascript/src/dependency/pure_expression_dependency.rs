@@ -0,0 +1,75 @@
+use rspack_core::{
+  tree_shaking::visitor::SymbolRef, Compilation, DependencyTemplate, ModuleIdentifier,
+  TemplateContext, TemplateReplaceSource,
+};
+use rspack_symbol::BetterId;
+
+/// The initializer of a top level declaration annotated with `/*#__PURE__*/`, it is not evaluated
+/// when the declaration is unused, because the imports it references may be removed.
+#[derive(Debug)]
+pub struct PureExpressionDependency {
+  start: u32,
+  end: u32,
+  declaration: BetterId,
+}
+
+impl PureExpressionDependency {
+  pub fn new(start: u32, end: u32, declaration: BetterId) -> Self {
+    Self {
+      start,
+      end,
+      declaration,
+    }
+  }
+}
+
+impl DependencyTemplate for PureExpressionDependency {
+  fn apply(
+    &self,
+    source: &mut TemplateReplaceSource,
+    code_generatable_context: &mut TemplateContext,
+  ) {
+    let TemplateContext {
+      compilation,
+      module,
+      ..
+    } = code_generatable_context;
+
+    if is_declaration_used(compilation, module.identifier(), &self.declaration) {
+      return;
+    }
+    source.insert(
+      self.start,
+      "(/* unused pure expression or super */ null && (",
+      None,
+    );
+    source.insert(self.end, "))", None);
+  }
+}
+
+/// A declaration is used if all the imports reachable from it are used, see the `inner_graph` of
+/// `OptimizeAnalyzeResult`.
+fn is_declaration_used(
+  compilation: &Compilation,
+  module_identifier: ModuleIdentifier,
+  declaration: &BetterId,
+) -> bool {
+  if !compilation.options.builtins.tree_shaking.is_true()
+    || compilation
+      .bailout_module_identifiers
+      .contains_key(&module_identifier)
+  {
+    return true;
+  }
+  let Some(analyze_result) = compilation.optimize_analyze_result_map.get(&module_identifier) else {
+    return true;
+  };
+  analyze_result
+    .inner_graph
+    .get(declaration)
+    .map_or(true, |reachable_imports| {
+      reachable_imports.iter().all(|symbol| {
+        !matches!(symbol, SymbolRef::Indirect(_)) || compilation.used_symbol_ref.contains(symbol)
+      })
+    })
+}
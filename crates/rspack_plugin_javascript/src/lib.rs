@@ -1,6 +1,7 @@
 #![feature(let_chains)]
 #![feature(box_patterns)]
 
+mod concatenated_module;
 pub(crate) mod dependency;
 mod plugin;
 pub use plugin::*;
@@ -16,7 +17,9 @@ use swc_ecma_minifier::option::{
   MangleOptions,
 };
 
+pub use crate::concatenated_module::ConcatenatedModule;
 pub use crate::plugin::infer_async_modules_plugin::InferAsyncModulesPlugin;
+pub use crate::plugin::module_concatenation_plugin::ModuleConcatenationPlugin;
 
 #[derive(Debug)]
 pub struct TransformOutput {
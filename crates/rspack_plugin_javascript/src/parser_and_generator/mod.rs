@@ -1,5 +1,6 @@
 use rspack_core::rspack_sources::{
-  RawSource, ReplaceSource, Source, SourceExt, SourceMap, SourceMapSource, WithoutOriginalOptions,
+  MapOptions, RawSource, ReplaceSource, Source, SourceExt, SourceMap, SourceMapSource,
+  SourceMapSourceOptions,
 };
 use rspack_core::{
   AstOrSource, GenerateContext, GenerationResult, Module, ModuleAst, ParseContext, ParseResult,
@@ -40,14 +41,31 @@ impl ParserAndGenerator for JavaScriptParserAndGenerator {
       build_info,
       build_meta,
       module_identifier,
+      module_parser_options,
       ..
     } = parse_context;
 
+    let parser_options = module_parser_options
+      .or_else(|| {
+        compiler_options
+          .module
+          .parser
+          .as_ref()
+          .and_then(|parser| parser.get(module_type))
+      })
+      .and_then(|options| options.get_javascript(module_type));
+
     let syntax = syntax_by_module_type(
       &resource_data.resource_path,
       module_type,
       compiler_options.builtins.decorator.is_some(),
     );
+    // the source map of loaders, which maps the transformed code back to the original code
+    let inner_source_map = compiler_options
+      .devtool
+      .enabled()
+      .then(|| source.map(&MapOptions::default()))
+      .flatten();
     let source = source.source();
     let mut ast = match crate::ast::parse(
       source.to_string(),
@@ -108,24 +126,30 @@ impl ParserAndGenerator for JavaScriptParserAndGenerator {
       ));
     });
 
-    let (dependencies, presentational_dependencies) = scan_ast.visit(|program, context| {
-      scan_dependencies(
-        program,
-        context.unresolved_mark,
-        resource_data,
-        compiler_options,
-        module_type,
-        build_info,
-        build_meta,
-        module_identifier,
-      )
-    });
+    let (dependencies, presentational_dependencies, warning_diagnostics) =
+      scan_ast.visit(|program, context| {
+        scan_dependencies(
+          program,
+          context.unresolved_mark,
+          &context.source_map,
+          resource_data,
+          compiler_options,
+          module_type,
+          build_info,
+          build_meta,
+          module_identifier,
+          parser_options,
+        )
+      });
 
     let source = if let Some(map) = output.map {
-      SourceMapSource::new(WithoutOriginalOptions {
+      SourceMapSource::new(SourceMapSourceOptions {
         value: output.code,
         name: resource_data.resource_path.to_string_lossy().to_string(),
         source_map: SourceMap::from_json(&map).map_err(|e| internal_error!(e.to_string()))?,
+        original_source: inner_source_map.as_ref().map(|_| source.to_string()),
+        inner_source_map,
+        remove_original_source: false,
       })
       .boxed()
     } else {
@@ -138,7 +162,7 @@ impl ParserAndGenerator for JavaScriptParserAndGenerator {
         dependencies,
         presentational_dependencies,
       }
-      .with_empty_diagnostic(),
+      .with_diagnostic(warning_diagnostics),
     )
   }
 
@@ -161,6 +185,7 @@ impl ParserAndGenerator for JavaScriptParserAndGenerator {
         module,
         runtime_requirements: generate_context.runtime_requirements,
         init_fragments: &mut init_fragments,
+        concatenation_scope: generate_context.concatenation_scope.as_deref_mut(),
       };
 
       let mgm = compilation
@@ -1,5 +1,6 @@
 pub mod impl_plugin_for_js_plugin;
 pub mod infer_async_modules_plugin;
+pub mod module_concatenation_plugin;
 
 use std::hash::Hash;
 
@@ -0,0 +1,405 @@
+use std::collections::{HashSet, VecDeque};
+
+use indexmap::IndexSet;
+use rspack_core::{
+  tree_shaking::BailoutFlag, BuildMetaExportsType, ChunkUkey, Compilation, DependencyType,
+  ModuleDependency, ModuleExt, ModuleIdentifier, OptimizeChunksArgs, Plugin,
+  PluginThisCompilationHookOutput, SourceType, ThisCompilationArgs,
+};
+use rspack_error::{internal_error, Result};
+use rspack_identifier::{IdentifierMap, IdentifierSet};
+
+use crate::concatenated_module::ConcatenatedModule;
+
+// Align with https://github.com/webpack/webpack/blob/4b4ca3bb53f36a5b8fc6bc1bd976ed7af161bd80/lib/optimize/ModuleConcatenationPlugin.js
+/// Concatenate ESM modules which are only imported by each other in the same chunks into a single scope.
+#[derive(Debug, Default)]
+pub struct ModuleConcatenationPlugin;
+
+#[async_trait::async_trait]
+impl Plugin for ModuleConcatenationPlugin {
+  fn name(&self) -> &'static str {
+    "ModuleConcatenationPlugin"
+  }
+
+  async fn this_compilation(
+    &self,
+    args: ThisCompilationArgs<'_>,
+  ) -> PluginThisCompilationHookOutput {
+    // the module graph is reused in rebuild, restore the root modules of the last compilation
+    for module in args
+      .this_compilation
+      .module_graph
+      .modules_mut()
+      .values_mut()
+    {
+      if let Some(concatenated_module) = module.downcast_mut::<ConcatenatedModule>() {
+        let root = concatenated_module.take_root();
+        *module = root;
+      }
+    }
+    Ok(())
+  }
+
+  async fn optimize_chunk_modules(&self, args: OptimizeChunksArgs<'_>) -> Result<()> {
+    let compilation = args.compilation;
+    if !compilation.options.is_module_concatenation_enabled() {
+      return Ok(());
+    }
+
+    let mut bailouts: IdentifierMap<Vec<String>> = IdentifierMap::default();
+    let mut candidates = IdentifierSet::default();
+    for module_identifier in compilation.module_graph.modules().keys() {
+      match bailout_reason(compilation, module_identifier) {
+        Some(reason) => bailouts
+          .entry(*module_identifier)
+          .or_default()
+          .push(format!("ModuleConcatenation bailout: {reason}")),
+        None => {
+          candidates.insert(*module_identifier);
+        }
+      }
+    }
+
+    let mut roots = candidates.iter().copied().collect::<Vec<_>>();
+    roots.sort_by_key(|module_identifier| {
+      (
+        compilation
+          .module_graph
+          .get_pre_order_index(module_identifier)
+          .unwrap_or(usize::MAX),
+        *module_identifier,
+      )
+    });
+
+    let mut used_modules = IdentifierSet::default();
+    let mut groups = vec![];
+    for root in roots {
+      if used_modules.contains(&root) {
+        continue;
+      }
+      let mut builder = ConcatenationBuilder {
+        compilation,
+        candidates: &candidates,
+        used_modules: &used_modules,
+        root_chunks: module_chunks(compilation, &root),
+        group: IndexSet::from([root]),
+      };
+      let mut queue = imported_modules(compilation, &root)
+        .into_iter()
+        .collect::<VecDeque<_>>();
+      while let Some(module_identifier) = queue.pop_front() {
+        if builder.group.contains(&module_identifier) {
+          continue;
+        }
+        let backup = builder.group.clone();
+        match builder.try_add(&module_identifier) {
+          Ok(()) => {
+            for added in builder.group.iter().skip(backup.len()) {
+              queue.extend(imported_modules(compilation, added));
+            }
+          }
+          Err(reason) => {
+            builder.group = backup;
+            bailouts.entry(root).or_default().push(format!(
+              "Cannot concat with {}: {reason}",
+              readable_identifier(compilation, &module_identifier)
+            ));
+          }
+        }
+      }
+      let group = builder.group;
+      if group.len() == 1 {
+        continue;
+      }
+      let modules = evaluation_order(compilation, &root, &group);
+      if modules.len() != group.len() {
+        bailouts.entry(root).or_default().push(
+          "Cannot concat with modules which are not reachable from the root module".to_string(),
+        );
+        continue;
+      }
+      used_modules.extend(group);
+      groups.push(modules);
+    }
+
+    for (module_identifier, reasons) in bailouts {
+      if let Some(mgm) = compilation
+        .module_graph
+        .module_graph_module_by_identifier_mut(&module_identifier)
+      {
+        mgm.optimization_bailout = reasons;
+      }
+    }
+
+    for modules in groups {
+      let root = *modules.last().expect("should have root module");
+      let size = modules
+        .iter()
+        .filter_map(|module_identifier| {
+          compilation
+            .module_graph
+            .module_by_identifier(module_identifier)
+        })
+        .map(|module| module.size(&SourceType::JavaScript))
+        .sum();
+      let root_chunks = module_chunks(compilation, &root);
+      for module_identifier in modules.iter().filter(|m| **m != root) {
+        for chunk in &root_chunks {
+          compilation
+            .chunk_graph
+            .disconnect_chunk_and_module(chunk, *module_identifier);
+        }
+      }
+      let root_module = compilation
+        .module_graph
+        .modules_mut()
+        .remove(&root)
+        .ok_or_else(|| internal_error!("Failed to get module {root}"))?;
+      compilation.module_graph.modules_mut().insert(
+        root,
+        ConcatenatedModule::new(root_module, modules, size).boxed(),
+      );
+    }
+    Ok(())
+  }
+}
+
+fn bailout_reason(
+  compilation: &Compilation,
+  module_identifier: &ModuleIdentifier,
+) -> Option<String> {
+  let module_graph = &compilation.module_graph;
+  let module = module_graph.module_by_identifier(module_identifier)?;
+  if !module.module_type().is_js_like() || module.as_normal_module().is_none() {
+    return Some("Module is not a javascript module".to_string());
+  }
+  let mgm = module_graph.module_graph_module_by_identifier(module_identifier)?;
+  let build_meta = mgm.build_meta.as_ref()?;
+  if !matches!(build_meta.exports_type, BuildMetaExportsType::Namespace) {
+    return Some("Module is not an ECMAScript module".to_string());
+  }
+  if build_meta.is_async {
+    return Some("Module is async".to_string());
+  }
+  if let Some(bailout) = mgm
+    .build_info
+    .as_ref()
+    .and_then(|build_info| build_info.module_concatenation_bailout.as_ref())
+  {
+    return Some(format!("Module uses {bailout}"));
+  }
+  if !compilation.include_module_ids.contains(module_identifier) {
+    return Some("Module is not included".to_string());
+  }
+  None
+}
+
+struct ConcatenationBuilder<'a> {
+  compilation: &'a Compilation,
+  candidates: &'a IdentifierSet,
+  used_modules: &'a IdentifierSet,
+  root_chunks: HashSet<ChunkUkey>,
+  /// Modules in the group, the first one is the root module
+  group: IndexSet<ModuleIdentifier>,
+}
+
+impl ConcatenationBuilder<'_> {
+  /// Add the module with its importers and the modules it reexports all from, the group is left
+  /// partially modified on failure.
+  fn try_add(&mut self, module_identifier: &ModuleIdentifier) -> std::result::Result<(), String> {
+    if self.group.contains(module_identifier) {
+      return Ok(());
+    }
+    if !self.candidates.contains(module_identifier) {
+      return Err("Module is not a concatenation candidate".to_string());
+    }
+    if self.used_modules.contains(module_identifier) {
+      return Err("Module is already concatenated into another module".to_string());
+    }
+    if self
+      .compilation
+      .entry_module_identifiers
+      .contains(module_identifier)
+    {
+      return Err("Module is an entry point".to_string());
+    }
+    if module_chunks(self.compilation, module_identifier) != self.root_chunks {
+      return Err("Module is not in the same chunks as the root module".to_string());
+    }
+    // tree shaking bails out the modules whose exports object is accessed at runtime,
+    // which only the root module of a concatenated module keeps
+    if let Some(flag) = self
+      .compilation
+      .bailout_module_identifiers
+      .get(module_identifier)
+    {
+      return Err(format!(
+        "Module is bailed out of tree shaking: {}",
+        tree_shaking_bailout_reasons(*flag)
+      ));
+    }
+    self.group.insert(*module_identifier);
+
+    let compilation = self.compilation;
+    let module_graph = &compilation.module_graph;
+    let mgm = module_graph
+      .module_graph_module_by_identifier(module_identifier)
+      .ok_or_else(|| "Module is not in the module graph".to_string())?;
+    let importers = mgm
+      .incoming_connections_unordered(module_graph)
+      .map_err(|e| e.to_string())?
+      .filter_map(|connection| {
+        let origin = connection.original_module_identifier?;
+        compilation
+          .include_module_ids
+          .contains(&origin)
+          .then_some((origin, connection.dependency_id))
+      })
+      .collect::<Vec<_>>();
+    for (origin, dependency_id) in importers {
+      let is_esm = module_graph
+        .dependency_by_id(&dependency_id)
+        .map(|dependency| {
+          matches!(
+            dependency.dependency_type(),
+            DependencyType::EsmImport | DependencyType::EsmExport
+          )
+        })
+        .unwrap_or_default();
+      if !is_esm {
+        return Err(format!(
+          "Module is referenced by {} with a non ESM import",
+          readable_identifier(compilation, &origin)
+        ));
+      }
+      self.try_add(&origin).map_err(|reason| {
+        format!(
+          "Module is referenced by {}: {reason}",
+          readable_identifier(compilation, &origin)
+        )
+      })?;
+    }
+
+    // `export *` from the modules out of the group can't be resolved statically
+    for dependency_id in &mgm.dependencies {
+      let is_export_all = module_graph
+        .dependency_by_id(dependency_id)
+        .map(|dependency| dependency.is_export_all())
+        .unwrap_or_default();
+      if !is_export_all {
+        continue;
+      }
+      if let Some(reexported) = module_graph.module_identifier_by_dependency_id(dependency_id) {
+        self.try_add(reexported).map_err(|reason| {
+          format!(
+            "Module reexports all from {}: {reason}",
+            readable_identifier(compilation, reexported)
+          )
+        })?;
+      }
+    }
+    Ok(())
+  }
+}
+
+fn tree_shaking_bailout_reasons(flag: BailoutFlag) -> String {
+  [
+    (BailoutFlag::COMMONJS_REQUIRE, "it is required by CommonJS"),
+    (BailoutFlag::COMMONJS_EXPORTS, "it uses CommonJS exports"),
+    (BailoutFlag::DYNAMIC_IMPORT, "it is imported dynamically"),
+    (BailoutFlag::CONTEXT_MODULE, "it is in a context module"),
+  ]
+  .into_iter()
+  .filter(|(bailout, _)| flag.contains(*bailout))
+  .map(|(_, reason)| reason)
+  .collect::<Vec<_>>()
+  .join(", ")
+}
+
+fn module_chunks(
+  compilation: &Compilation,
+  module_identifier: &ModuleIdentifier,
+) -> HashSet<ChunkUkey> {
+  compilation
+    .chunk_graph
+    .chunk_graph_module_by_module_identifier
+    .get(module_identifier)
+    .map(|chunk_graph_module| chunk_graph_module.chunks.iter().copied().collect())
+    .unwrap_or_default()
+}
+
+/// Modules imported by ESM imports and reexports, in source order.
+fn imported_modules(
+  compilation: &Compilation,
+  module_identifier: &ModuleIdentifier,
+) -> Vec<ModuleIdentifier> {
+  let module_graph = &compilation.module_graph;
+  let Some(mgm) = module_graph.module_graph_module_by_identifier(module_identifier) else {
+    return vec![];
+  };
+  mgm
+    .dependencies
+    .iter()
+    .filter(|dependency_id| {
+      module_graph
+        .dependency_by_id(dependency_id)
+        .map(|dependency| {
+          matches!(
+            dependency.dependency_type(),
+            DependencyType::EsmImport | DependencyType::EsmExport
+          )
+        })
+        .unwrap_or_default()
+    })
+    .filter_map(|dependency_id| module_graph.module_identifier_by_dependency_id(dependency_id))
+    .copied()
+    .collect()
+}
+
+/// Post order of the imports from the root module, the imported modules are evaluated first.
+fn evaluation_order(
+  compilation: &Compilation,
+  root: &ModuleIdentifier,
+  group: &IndexSet<ModuleIdentifier>,
+) -> Vec<ModuleIdentifier> {
+  fn visit(
+    compilation: &Compilation,
+    module_identifier: ModuleIdentifier,
+    group: &IndexSet<ModuleIdentifier>,
+    visited: &mut IdentifierSet,
+    order: &mut Vec<ModuleIdentifier>,
+  ) {
+    if !visited.insert(module_identifier) {
+      return;
+    }
+    for imported in imported_modules(compilation, &module_identifier) {
+      if group.contains(&imported) {
+        visit(compilation, imported, group, visited, order);
+      }
+    }
+    order.push(module_identifier);
+  }
+
+  let mut order = vec![];
+  visit(
+    compilation,
+    *root,
+    group,
+    &mut IdentifierSet::default(),
+    &mut order,
+  );
+  order
+}
+
+fn readable_identifier(compilation: &Compilation, module_identifier: &ModuleIdentifier) -> String {
+  compilation
+    .module_graph
+    .module_by_identifier(module_identifier)
+    .map(|module| {
+      module
+        .readable_identifier(&compilation.options.context)
+        .to_string()
+    })
+    .unwrap_or_else(|| module_identifier.to_string())
+}
@@ -40,10 +40,15 @@ pub fn render_chunk_modules(
           .clone()
           .try_into_source()
           .expect("should be source");
+        let module = module_graph
+          .module_by_identifier(&mgm.module_identifier)
+          .expect("should have module");
         let module_source = if let Some(source) = plugin_driver
           .render_module_content(RenderModuleContentArgs {
             compilation,
             module_source: &origin_source,
+            module,
+            code_generation_result: code_gen_result,
           })
           .expect("render_module_content failed")
         {
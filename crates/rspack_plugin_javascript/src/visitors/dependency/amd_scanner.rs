@@ -0,0 +1,317 @@
+use rspack_core::{
+  ChunkGroupOptions, ConstDependency, DependencyTemplate, ModuleDependency, RuntimeGlobals, SpanExt,
+};
+use swc_core::{
+  common::{Spanned, SyntaxContext},
+  ecma::{
+    ast::{ArrayLit, CallExpr, Callee, Expr, ExprOrSpread, Id, Lit, Pat, UnaryExpr, UnaryOp},
+    visit::{noop_visit_type, Visit, VisitWith},
+  },
+};
+
+use super::{expr_matcher, is_unresolved_member_object_ident};
+use crate::dependency::{
+  AmdDefineDependency, AmdRequireArrayDependency, AmdRequireArrayItem, AmdRequireDependency,
+  AmdRequireItemDependency, CommonJsRequireDependency,
+};
+
+/// The parameters of a factory without a dependency array, like
+/// `define(function(require, exports, module) {})`.
+static DEFAULT_FACTORY_PARAMS: [AmdRequireArrayItem; 3] = [
+  AmdRequireArrayItem::Require,
+  AmdRequireArrayItem::Exports,
+  AmdRequireArrayItem::Module,
+];
+
+// Align with https://github.com/webpack/webpack/blob/4b4ca3bb53f36a5b8fc6bc1bd976ed7af161bd80/lib/dependencies/AMDPlugin.js
+/// Scans AMD `define(...)` and `require([...], callback)`.
+pub struct AmdScanner<'a> {
+  dependencies: &'a mut Vec<Box<dyn ModuleDependency>>,
+  presentational_dependencies: &'a mut Vec<Box<dyn DependencyTemplate>>,
+  unresolved_ctxt: &'a SyntaxContext,
+  /// The parameters of the factories bound to `require`, which are called like the global `require`
+  require_params: Vec<Id>,
+}
+
+impl<'a> AmdScanner<'a> {
+  pub fn new(
+    dependencies: &'a mut Vec<Box<dyn ModuleDependency>>,
+    presentational_dependencies: &'a mut Vec<Box<dyn DependencyTemplate>>,
+    unresolved_ctxt: &'a SyntaxContext,
+  ) -> Self {
+    Self {
+      dependencies,
+      presentational_dependencies,
+      unresolved_ctxt,
+      require_params: vec![],
+    }
+  }
+
+  fn is_unresolved_ident(&self, expr: &Expr, name: &str) -> bool {
+    matches!(
+      expr,
+      Expr::Ident(ident) if &*ident.sym == name && ident.span.ctxt == *self.unresolved_ctxt
+    )
+  }
+
+  fn is_require_callee(&self, call_expr: &CallExpr) -> bool {
+    match &call_expr.callee {
+      Callee::Expr(box Expr::Ident(ident)) => {
+        (&*ident.sym == "require" && ident.span.ctxt == *self.unresolved_ctxt)
+          || self.require_params.contains(&ident.to_id())
+      }
+      _ => false,
+    }
+  }
+
+  /// Parse the dependency array, `None` is returned if any item is not a string literal.
+  fn parse_array(
+    &mut self,
+    array: &ArrayLit,
+    group_options: Option<ChunkGroupOptions>,
+  ) -> Option<Vec<AmdRequireArrayItem>> {
+    let requests = array
+      .elems
+      .iter()
+      .map(|elem| match elem {
+        Some(ExprOrSpread {
+          spread: None,
+          expr: box Expr::Lit(Lit::Str(str)),
+        }) => Some(str),
+        _ => None,
+      })
+      .collect::<Option<Vec<_>>>()?;
+    let items = requests
+      .into_iter()
+      .map(|str| match str.value.as_ref() {
+        "require" => AmdRequireArrayItem::Require,
+        "exports" => AmdRequireArrayItem::Exports,
+        "module" => AmdRequireArrayItem::Module,
+        _ => {
+          let dependency = AmdRequireItemDependency::new(
+            str.value.clone(),
+            Some(str.span.into()),
+            group_options.clone(),
+          );
+          let item = AmdRequireArrayItem::Dependency {
+            id: *dependency.id(),
+            request: str.value.clone(),
+          };
+          self.dependencies.push(Box::new(dependency));
+          item
+        }
+      })
+      .collect::<Vec<_>>();
+    self
+      .presentational_dependencies
+      .push(Box::new(AmdRequireArrayDependency::new(
+        array.span.real_lo(),
+        array.span.real_hi(),
+        items.clone(),
+      )));
+    Some(items)
+  }
+
+  /// Visit the factory with its parameters bound to the items of the dependency array.
+  fn visit_factory(&mut self, factory: &Expr, items: &[AmdRequireArrayItem]) {
+    let params: Vec<&Pat> = match factory {
+      Expr::Fn(function) => function.function.params.iter().map(|p| &p.pat).collect(),
+      Expr::Arrow(arrow) => arrow.params.iter().collect(),
+      _ => vec![],
+    };
+    let require_params = params
+      .into_iter()
+      .zip(items)
+      .filter_map(|(param, item)| match (param, item) {
+        (Pat::Ident(ident), AmdRequireArrayItem::Require) => Some(ident.id.to_id()),
+        _ => None,
+      })
+      .collect::<Vec<_>>();
+    let len = self.require_params.len();
+    self.require_params.extend(require_params);
+    factory.visit_children_with(self);
+    self.require_params.truncate(len);
+  }
+
+  fn add_define(&mut self, call_expr: &CallExpr) -> bool {
+    let args = call_expr
+      .args
+      .iter()
+      .map(|arg| arg.spread.is_none().then_some(arg.expr.as_ref()))
+      .collect::<Option<Vec<_>>>();
+    // the name of the module is ignored
+    let (array, factory) = match args.as_deref() {
+      Some([factory]) => (None, *factory),
+      Some([Expr::Lit(Lit::Str(_)), factory]) => (None, *factory),
+      Some([Expr::Array(array), factory]) => (Some(array), *factory),
+      Some([Expr::Lit(Lit::Str(_)), Expr::Array(array), factory]) => (Some(array), *factory),
+      _ => return false,
+    };
+    let items = match array {
+      Some(array) => match self.parse_array(array, None) {
+        Some(items) => items,
+        None => return false,
+      },
+      None => DEFAULT_FACTORY_PARAMS.to_vec(),
+    };
+    let range = (factory.span().real_lo(), factory.span().real_hi());
+    let (function_range, object_range) = match factory {
+      Expr::Fn(_) | Expr::Arrow(_) => (Some(range), None),
+      Expr::Object(_) => (None, Some(range)),
+      _ => (Some(range), Some(range)),
+    };
+    self
+      .presentational_dependencies
+      .push(Box::new(AmdDefineDependency::new(
+        call_expr.span.real_lo(),
+        call_expr.span.real_hi(),
+        array.map(|array| (array.span.real_lo(), array.span.real_hi())),
+        function_range,
+        object_range,
+      )));
+    self.visit_factory(factory, &items);
+    true
+  }
+
+  fn add_require(&mut self, call_expr: &CallExpr) -> bool {
+    let args = call_expr
+      .args
+      .iter()
+      .map(|arg| arg.spread.is_none().then_some(arg.expr.as_ref()))
+      .collect::<Option<Vec<_>>>();
+    let (array, callback, error_callback) = match args.as_deref() {
+      Some([Expr::Array(array)]) => (array, None, None),
+      Some([Expr::Array(array), callback]) => (array, Some(*callback), None),
+      Some([Expr::Array(array), callback, error_callback]) => {
+        (array, Some(*callback), Some(*error_callback))
+      }
+      _ => return false,
+    };
+    let Some(items) = self.parse_array(array, Some(ChunkGroupOptions::default())) else {
+      return false;
+    };
+    let range = |expr: &Expr| (expr.span().real_lo(), expr.span().real_hi());
+    self
+      .presentational_dependencies
+      .push(Box::new(AmdRequireDependency::new(
+        call_expr.span.real_lo(),
+        call_expr.span.real_hi(),
+        (array.span.real_lo(), array.span.real_hi()),
+        callback.map(range),
+        error_callback.map(range),
+        items
+          .iter()
+          .filter_map(|item| match item {
+            AmdRequireArrayItem::Dependency { id, request } => Some((*id, request.clone())),
+            _ => None,
+          })
+          .collect(),
+      )));
+    if let Some(callback) = callback {
+      self.visit_factory(callback, &items);
+    }
+    if let Some(error_callback) = error_callback {
+      error_callback.visit_with(self);
+    }
+    true
+  }
+
+  /// `require("./a")` with a factory parameter bound to `require`.
+  fn add_param_require(&mut self, call_expr: &CallExpr) -> bool {
+    let Callee::Expr(box Expr::Ident(ident)) = &call_expr.callee else {
+      return false;
+    };
+    if !self.require_params.contains(&ident.to_id()) {
+      return false;
+    }
+    let [ExprOrSpread {
+      spread: None,
+      expr: box Expr::Lit(Lit::Str(str)),
+    }] = call_expr.args.as_slice()
+    else {
+      return false;
+    };
+    self
+      .dependencies
+      .push(Box::new(CommonJsRequireDependency::new(
+        str.value.clone(),
+        Some(call_expr.span.into()),
+        call_expr.span.real_lo(),
+        call_expr.span.real_hi(),
+        false,
+      )));
+    true
+  }
+}
+
+impl Visit for AmdScanner<'_> {
+  noop_visit_type!();
+
+  fn visit_call_expr(&mut self, call_expr: &CallExpr) {
+    if let Callee::Expr(callee) = &call_expr.callee && self.is_unresolved_ident(callee, "define") {
+      if self.add_define(call_expr) {
+        return;
+      }
+    } else if self.is_require_callee(call_expr)
+      && (self.add_require(call_expr) || self.add_param_require(call_expr))
+    {
+      return;
+    }
+    call_expr.visit_children_with(self);
+  }
+
+  fn visit_unary_expr(&mut self, unary_expr: &UnaryExpr) {
+    if let UnaryExpr {
+      op: UnaryOp::TypeOf,
+      arg: box expr,
+      ..
+    } = unary_expr
+    {
+      let value = if self.is_unresolved_ident(expr, "define") {
+        Some("\"function\"")
+      } else if is_unresolved_member_object_ident(expr, self.unresolved_ctxt)
+        && (expr_matcher::is_define_amd(expr) || expr_matcher::is_require_amd(expr))
+      {
+        Some("\"object\"")
+      } else {
+        None
+      };
+      if let Some(value) = value {
+        self
+          .presentational_dependencies
+          .push(Box::new(ConstDependency::new(
+            unary_expr.span.real_lo(),
+            unary_expr.span.real_hi(),
+            value.into(),
+            None,
+          )));
+        return;
+      }
+    }
+    unary_expr.visit_children_with(self);
+  }
+
+  fn visit_expr(&mut self, expr: &Expr) {
+    let runtime_global = if self.is_unresolved_ident(expr, "define") {
+      Some(RuntimeGlobals::AMD_DEFINE)
+    } else if is_unresolved_member_object_ident(expr, self.unresolved_ctxt)
+      && (expr_matcher::is_define_amd(expr) || expr_matcher::is_require_amd(expr))
+    {
+      Some(RuntimeGlobals::AMD_OPTIONS)
+    } else {
+      None
+    };
+    if let Some(runtime_global) = runtime_global {
+      self
+        .presentational_dependencies
+        .push(Box::new(ConstDependency::new(
+          expr.span().real_lo(),
+          expr.span().real_hi(),
+          runtime_global.name().into(),
+          Some(runtime_global),
+        )));
+      return;
+    }
+    expr.visit_children_with(self);
+  }
+}
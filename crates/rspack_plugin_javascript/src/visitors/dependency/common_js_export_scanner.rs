@@ -1,17 +1,24 @@
 use rspack_core::{
   BuildMeta, BuildMetaDefaultObject, BuildMetaExportsType, DependencyTemplate, ModuleType,
-  RuntimeGlobals,
+  RuntimeGlobals, SpanExt,
 };
 use swc_core::{
-  common::SyntaxContext,
+  common::{Spanned, SyntaxContext},
   ecma::{
-    ast::{Expr, Ident, ModuleItem, Program},
+    ast::{
+      op, AssignExpr, ComputedPropName, Expr, Ident, KeyValueProp, Lit, MemberProp, MethodProp,
+      ModuleItem, ObjectLit, Program, Prop, PropName, PropOrSpread,
+    },
+    atoms::JsWord,
     visit::{noop_visit_type, Visit, VisitWith},
   },
 };
 
-use super::expr_matcher;
-use crate::dependency::ModuleDecoratorDependency;
+use super::{expr_matcher, is_unresolved_member_object_ident};
+use crate::dependency::{
+  CommonJsExportsDependency, CommonJsExportsObjectDependency, CommonJsExportsObjectProperty,
+  ModuleDecoratorDependency,
+};
 
 pub struct CommonJsExportDependencyScanner<'a> {
   presentational_dependencies: &'a mut Vec<Box<dyn DependencyTemplate>>,
@@ -36,6 +43,59 @@ impl<'a> CommonJsExportDependencyScanner<'a> {
       is_harmony: false,
     }
   }
+
+  fn is_exports_object(&self, expr: &Expr) -> bool {
+    match expr {
+      Expr::Ident(ident) => &ident.sym == "exports" && ident.span.ctxt == *self.unresolved_ctxt,
+      expr => {
+        expr_matcher::is_module_exports(expr)
+          && is_unresolved_member_object_ident(expr, self.unresolved_ctxt)
+      }
+    }
+  }
+
+  fn add_exports_object_dependency(&mut self, object: &ObjectLit) {
+    let mut properties = vec![];
+    for prop in &object.props {
+      let (name, removable) = match prop {
+        PropOrSpread::Prop(box Prop::Shorthand(ident)) => (ident.sym.clone(), true),
+        PropOrSpread::Prop(box Prop::KeyValue(KeyValueProp { key, value })) => {
+          let Some(name) = get_static_prop_name(key) else {
+            return;
+          };
+          let removable = matches!(
+            &**value,
+            Expr::Fn(_) | Expr::Arrow(_) | Expr::Ident(_) | Expr::Lit(_)
+          );
+          (name, removable)
+        }
+        PropOrSpread::Prop(box Prop::Method(MethodProp { key, .. })) => {
+          let Some(name) = get_static_prop_name(key) else {
+            return;
+          };
+          (name, true)
+        }
+        // the exports can't be analyzed, the module is bailout by tree shaking
+        _ => return,
+      };
+      let span = prop.span();
+      properties.push(CommonJsExportsObjectProperty {
+        removable: removable && &name != "__esModule",
+        name,
+        start: span.real_lo(),
+        end: span.real_hi(),
+      });
+    }
+    if !properties.is_empty() {
+      self
+        .presentational_dependencies
+        .push(Box::new(CommonJsExportsObjectDependency::new(
+          object.span.real_lo(),
+          object.span.real_hi(),
+          properties,
+        )));
+    }
+  }
 }
 
 impl Visit for CommonJsExportDependencyScanner<'_> {
@@ -75,6 +135,52 @@ impl Visit for CommonJsExportDependencyScanner<'_> {
     }
     expr.visit_children_with(self);
   }
+
+  fn visit_assign_expr(&mut self, assign_expr: &AssignExpr) {
+    if !self.is_harmony
+      && assign_expr.op == op!("=")
+      && let Some(left) = assign_expr.left.as_expr()
+    {
+      if let Expr::Member(member_expr) = left
+        && self.is_exports_object(&member_expr.obj)
+        && let Some(name) = get_static_member_prop(&member_expr.prop)
+        && &name != "__esModule"
+      {
+        self
+          .presentational_dependencies
+          .push(Box::new(CommonJsExportsDependency::new(
+            left.span().real_lo(),
+            left.span().real_hi(),
+            name,
+          )));
+      } else if expr_matcher::is_module_exports(left)
+        && is_unresolved_member_object_ident(left, self.unresolved_ctxt)
+        && let Expr::Object(object) = &*assign_expr.right
+      {
+        self.add_exports_object_dependency(object);
+      }
+    }
+    assign_expr.visit_children_with(self);
+  }
+}
+
+fn get_static_member_prop(prop: &MemberProp) -> Option<JsWord> {
+  match prop {
+    MemberProp::Ident(ident) => Some(ident.sym.clone()),
+    MemberProp::Computed(ComputedPropName {
+      expr: box Expr::Lit(Lit::Str(str)),
+      ..
+    }) => Some(str.value.clone()),
+    _ => None,
+  }
+}
+
+fn get_static_prop_name(key: &PropName) -> Option<JsWord> {
+  match key {
+    PropName::Ident(ident) => Some(ident.sym.clone()),
+    PropName::Str(str) => Some(str.value.clone()),
+    _ => None,
+  }
 }
 
 fn bailout(build_meta: &mut BuildMeta) {
@@ -23,6 +23,8 @@ pub struct CommonJsImportDependencyScanner<'a> {
   dependencies: &'a mut Vec<Box<dyn ModuleDependency>>,
   presentational_dependencies: &'a mut Vec<Box<dyn DependencyTemplate>>,
   unresolved_ctxt: &'a SyntaxContext,
+  /// `require([...], callback)` is left to the AMD scanner
+  amd: bool,
   in_try: bool,
   in_if: bool,
 }
@@ -32,11 +34,13 @@ impl<'a> CommonJsImportDependencyScanner<'a> {
     dependencies: &'a mut Vec<Box<dyn ModuleDependency>>,
     presentational_dependencies: &'a mut Vec<Box<dyn DependencyTemplate>>,
     unresolved_ctxt: &'a SyntaxContext,
+    amd: bool,
   ) -> Self {
     Self {
       dependencies,
       presentational_dependencies,
       unresolved_ctxt,
+      amd,
       in_try: false,
       in_if: false,
     }
@@ -89,6 +93,15 @@ impl Visit for CommonJsImportDependencyScanner<'_> {
     if let Callee::Expr(expr) = &call_expr.callee {
       if let Expr::Ident(ident) = &**expr {
         if "require".eq(&ident.sym) && ident.span.ctxt == *self.unresolved_ctxt {
+          if self.amd
+            && call_expr
+              .args
+              .get(0)
+              .is_some_and(|arg| arg.spread.is_none() && arg.expr.is_array())
+          {
+            call_expr.args.visit_with(self);
+            return;
+          }
           {
             if let Some(expr) = call_expr.args.get(0) && call_expr.args.len() == 1 && expr.spread.is_none() {
               // TemplateLiteral String
@@ -132,6 +145,7 @@ impl Visit for CommonJsImportDependencyScanner<'_> {
                       exclude: None,
                       category: DependencyCategory::CommonJS,
                       request: context,
+                      group_options: None,
                     },
                     Some(call_expr.span.into()),
                   )));
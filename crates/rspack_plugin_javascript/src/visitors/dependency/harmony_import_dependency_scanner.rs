@@ -1,37 +1,61 @@
+use std::collections::BTreeMap;
+
 use indexmap::IndexMap;
 use rspack_core::{
-  ConstDependency, DependencyId, DependencyTemplate, DependencyType, ModuleDependency,
-  ModuleIdentifier, SpanExt,
+  ConstDependency, DependencyId, DependencyTemplate, DependencyType, ImportAttributes,
+  ModuleDependency, ModuleIdentifier, ModuleType, SpanExt,
 };
+use rspack_error::{Diagnostic, DiagnosticKind, Error, TraceableError};
 use rspack_symbol::DEFAULT_JS_WORD;
 use rustc_hash::FxHashMap;
 use swc_core::{
-  common::Span,
+  common::{SourceMap, Span},
   ecma::{
     ast::{
       Callee, ExportAll, ExportSpecifier, Expr, Id, Ident, ImportDecl, ImportSpecifier, Lit,
-      MemberExpr, MemberProp, ModuleExportName, NamedExport, Program, Prop,
+      MemberExpr, MemberProp, ModuleExportName, NamedExport, ObjectLit, Program, Prop,
     },
     atoms::JsWord,
     visit::{noop_visit_type, Visit, VisitWith},
   },
 };
 
+use super::{get_import_attributes, JSON_IMPORT_ATTRIBUTE_TYPE};
 use crate::dependency::{
   HarmonyExportImportedSpecifierDependency, HarmonyImportDependency,
   HarmonyImportSpecifierDependency, Specifier,
 };
 
+/// Imports of the same request are different dependencies when their attributes are different,
+/// the attributes are sorted to be hashable.
+pub type AttributesKey = Option<BTreeMap<String, String>>;
+
+fn attributes_key(attributes: Option<&ImportAttributes>) -> AttributesKey {
+  attributes.map(|attributes| {
+    attributes
+      .iter()
+      .map(|(key, value)| (key.clone(), value.clone()))
+      .collect()
+  })
+}
+
 pub struct ImporterReferenceInfo {
   pub request: JsWord,
+  pub attributes: AttributesKey,
   pub specifier: Specifier,
   pub names: Option<JsWord>,
 }
 
 impl ImporterReferenceInfo {
-  pub fn new(request: JsWord, specifier: Specifier, names: Option<JsWord>) -> Self {
+  pub fn new(
+    request: JsWord,
+    attributes: AttributesKey,
+    specifier: Specifier,
+    names: Option<JsWord>,
+  ) -> Self {
     Self {
       request,
+      attributes,
       specifier,
       names,
     }
@@ -44,19 +68,26 @@ pub struct ImporterInfo {
   pub span: Span,
   pub specifiers: Vec<Specifier>,
   pub exports_all: bool,
+  pub attributes: Option<ImportAttributes>,
 }
 
 impl ImporterInfo {
-  pub fn new(span: Span, specifiers: Vec<Specifier>, exports_all: bool) -> Self {
+  pub fn new(
+    span: Span,
+    specifiers: Vec<Specifier>,
+    exports_all: bool,
+    attributes: Option<ImportAttributes>,
+  ) -> Self {
     Self {
       span,
       specifiers,
       exports_all,
+      attributes,
     }
   }
 }
 
-pub type Imports = IndexMap<(JsWord, DependencyType), ImporterInfo>;
+pub type Imports = IndexMap<(JsWord, DependencyType, AttributesKey), ImporterInfo>;
 
 pub struct HarmonyImportDependencyScanner<'a> {
   pub dependencies: &'a mut Vec<Box<dyn ModuleDependency>>,
@@ -64,6 +95,9 @@ pub struct HarmonyImportDependencyScanner<'a> {
   pub import_map: &'a mut ImportMap,
   pub imports: Imports,
   pub module_identifier: ModuleIdentifier,
+  pub module_type: &'a ModuleType,
+  source_map: &'a SourceMap,
+  diagnostics: &'a mut Vec<Diagnostic>,
 }
 
 impl<'a> HarmonyImportDependencyScanner<'a> {
@@ -72,6 +106,9 @@ impl<'a> HarmonyImportDependencyScanner<'a> {
     presentational_dependencies: &'a mut Vec<Box<dyn DependencyTemplate>>,
     import_map: &'a mut ImportMap,
     module_identifier: ModuleIdentifier,
+    module_type: &'a ModuleType,
+    source_map: &'a SourceMap,
+    diagnostics: &'a mut Vec<Diagnostic>,
   ) -> Self {
     Self {
       dependencies,
@@ -79,7 +116,61 @@ impl<'a> HarmonyImportDependencyScanner<'a> {
       import_map,
       imports: Default::default(),
       module_identifier,
+      module_type,
+      source_map,
+      diagnostics,
+    }
+  }
+
+  fn add_error(&mut self, span: Span, title: &str, message: String) {
+    let source_file = self.source_map.lookup_byte_offset(span.lo).sf;
+    let error = TraceableError::from_source_file(
+      &source_file,
+      (span.lo.0 - source_file.start_pos.0) as usize,
+      (span.hi.0 - source_file.start_pos.0) as usize,
+      title.to_string(),
+      message,
+    )
+    .with_kind(DiagnosticKind::JavaScript);
+    self
+      .diagnostics
+      .extend(Vec::<Diagnostic>::from(Error::TraceableError(error)));
+  }
+
+  /// Parse the import attributes of a static import or re-export, and check that
+  /// a JSON module only provides its `default` export to a strict harmony module.
+  fn get_attributes<'b>(
+    &mut self,
+    span: Span,
+    request: &JsWord,
+    asserts: Option<&ObjectLit>,
+    imported_names: impl Iterator<Item = &'b JsWord>,
+  ) -> Option<ImportAttributes> {
+    let attributes = get_import_attributes(asserts?);
+    match attributes.get("type").map(String::as_str) {
+      Some(JSON_IMPORT_ATTRIBUTE_TYPE) => {
+        if matches!(self.module_type, ModuleType::JsEsm | ModuleType::JsxEsm) {
+          for name in imported_names.filter(|name| **name != DEFAULT_JS_WORD) {
+            self.add_error(
+              span,
+              "Import attributes error",
+              format!(
+                "Should not import the named export '{name}' from JSON module '{request}' (only default export is available)"
+              ),
+            );
+          }
+        }
+      }
+      Some(r#type) => self.add_error(
+        span,
+        "Import attributes error",
+        format!(
+          "Import attribute type '{type}' of '{request}' is not supported, only '{JSON_IMPORT_ATTRIBUTE_TYPE}' is supported"
+        ),
+      ),
+      None => {}
     }
+    Some(attributes)
   }
 }
 
@@ -90,12 +181,14 @@ impl Visit for HarmonyImportDependencyScanner<'_> {
     // collect import map info
     program.visit_children_with(self);
 
-    let mut dependency_id_map: FxHashMap<JsWord, DependencyId> = Default::default();
-    for ((request, dependency_type), importer_info) in std::mem::take(&mut self.imports).into_iter()
+    let mut dependency_id_map: FxHashMap<(JsWord, AttributesKey), DependencyId> =
+      Default::default();
+    for ((request, dependency_type, attributes), importer_info) in
+      std::mem::take(&mut self.imports).into_iter()
     {
       let id = DependencyId::new();
       if matches!(dependency_type, DependencyType::EsmImport) {
-        dependency_id_map.insert(request.clone(), id);
+        dependency_id_map.insert((request.clone(), attributes), id);
       }
       self
         .dependencies
@@ -106,6 +199,7 @@ impl Visit for HarmonyImportDependencyScanner<'_> {
           importer_info.specifiers,
           dependency_type,
           importer_info.exports_all,
+          importer_info.attributes,
         )));
     }
 
@@ -119,58 +213,76 @@ impl Visit for HarmonyImportDependencyScanner<'_> {
 
   fn visit_import_decl(&mut self, import_decl: &ImportDecl) {
     let mut specifiers = vec![];
+    // (local, specifier, names) of the references, they are keyed by the attributes parsed below
+    let mut references = vec![];
     import_decl.specifiers.iter().for_each(|s| match s {
       ImportSpecifier::Named(n) => {
-        let specifier = Specifier::Named(
-          n.local.sym.clone(),
-          match &n.imported {
-            Some(ModuleExportName::Ident(ident)) => Some(ident.sym.clone()),
-            _ => None,
-          },
-        );
-        self.import_map.insert(
+        let imported = n.imported.as_ref().map(|imported| match imported {
+          ModuleExportName::Ident(ident) => ident.sym.clone(),
+          ModuleExportName::Str(str) => str.value.clone(),
+        });
+        let specifier = Specifier::Named(n.local.sym.clone(), imported.clone());
+        references.push((
           n.local.to_id(),
-          ImporterReferenceInfo::new(
-            import_decl.src.value.clone(),
-            specifier.clone(),
-            Some(match &n.imported {
-              Some(ModuleExportName::Ident(ident)) => ident.sym.clone(),
-              _ => n.local.sym.clone(),
-            }),
-          ),
-        );
-
+          specifier.clone(),
+          Some(imported.unwrap_or_else(|| n.local.sym.clone())),
+        ));
         specifiers.push(specifier);
       }
       ImportSpecifier::Default(d) => {
         let specifier = Specifier::Default(d.local.sym.clone());
-        self.import_map.insert(
+        references.push((
           d.local.to_id(),
-          ImporterReferenceInfo::new(
-            import_decl.src.value.clone(),
-            specifier.clone(),
-            Some(DEFAULT_JS_WORD.clone()),
-          ),
-        );
+          specifier.clone(),
+          Some(DEFAULT_JS_WORD.clone()),
+        ));
         specifiers.push(specifier);
       }
       ImportSpecifier::Namespace(n) => {
         let specifier = Specifier::Namespace(n.local.sym.clone());
-        self.import_map.insert(
-          n.local.to_id(),
-          ImporterReferenceInfo::new(import_decl.src.value.clone(), specifier.clone(), None),
-        );
+        references.push((n.local.to_id(), specifier.clone(), None));
         specifiers.push(specifier);
       }
     });
 
-    let key = (import_decl.src.value.clone(), DependencyType::EsmImport);
+    let imported_names = specifiers
+      .iter()
+      .filter_map(|specifier| match specifier {
+        Specifier::Named(local, imported) => Some(imported.as_ref().unwrap_or(local)),
+        _ => None,
+      })
+      .cloned()
+      .collect::<Vec<_>>();
+    let attributes = self.get_attributes(
+      import_decl.span,
+      &import_decl.src.value,
+      import_decl.asserts.as_deref(),
+      imported_names.iter(),
+    );
+    let attributes_key = attributes_key(attributes.as_ref());
+    for (local, specifier, names) in references {
+      self.import_map.insert(
+        local,
+        ImporterReferenceInfo::new(
+          import_decl.src.value.clone(),
+          attributes_key.clone(),
+          specifier,
+          names,
+        ),
+      );
+    }
+    let key = (
+      import_decl.src.value.clone(),
+      DependencyType::EsmImport,
+      attributes_key,
+    );
     if let Some(importer_info) = self.imports.get_mut(&key) {
       importer_info.specifiers.extend(specifiers);
     } else {
-      self
-        .imports
-        .insert(key, ImporterInfo::new(import_decl.span, specifiers, false));
+      self.imports.insert(
+        key,
+        ImporterInfo::new(import_decl.span, specifiers, false, attributes),
+      );
     }
     self
       .presentational_dependencies
@@ -219,6 +331,12 @@ impl Visit for HarmonyImportDependencyScanner<'_> {
           }
         });
 
+      let attributes = self.get_attributes(
+        named_export.span,
+        &src.value,
+        named_export.asserts.as_deref(),
+        ids.iter().filter_map(|(_, orig)| orig.as_ref()),
+      );
       self.presentational_dependencies.push(Box::new(
         HarmonyExportImportedSpecifierDependency::new(
           src.value.clone(),
@@ -226,13 +344,18 @@ impl Visit for HarmonyImportDependencyScanner<'_> {
           self.module_identifier,
         ),
       ));
-      let key = (src.value.clone(), DependencyType::EsmExport);
+      let key = (
+        src.value.clone(),
+        DependencyType::EsmExport,
+        attributes_key(attributes.as_ref()),
+      );
       if let Some(importer_info) = self.imports.get_mut(&key) {
         importer_info.specifiers.extend(specifiers);
       } else {
-        self
-          .imports
-          .insert(key, ImporterInfo::new(named_export.span, specifiers, false));
+        self.imports.insert(
+          key,
+          ImporterInfo::new(named_export.span, specifiers, false, attributes),
+        );
       }
       self
         .presentational_dependencies
@@ -246,13 +369,24 @@ impl Visit for HarmonyImportDependencyScanner<'_> {
   }
 
   fn visit_export_all(&mut self, export_all: &ExportAll) {
-    let key = (export_all.src.value.clone(), DependencyType::EsmExport);
+    let attributes = self.get_attributes(
+      export_all.span,
+      &export_all.src.value,
+      export_all.asserts.as_deref(),
+      std::iter::empty(),
+    );
+    let key = (
+      export_all.src.value.clone(),
+      DependencyType::EsmExport,
+      attributes_key(attributes.as_ref()),
+    );
     if let Some(importer_info) = self.imports.get_mut(&key) {
       importer_info.exports_all = true;
     } else {
-      self
-        .imports
-        .insert(key, ImporterInfo::new(export_all.span, vec![], true));
+      self.imports.insert(
+        key,
+        ImporterInfo::new(export_all.span, vec![], true, attributes),
+      );
     }
     self
       .presentational_dependencies
@@ -269,14 +403,14 @@ pub struct HarmonyImportRefDependencyScanner<'a> {
   pub enter_callee: bool,
   pub import_map: &'a ImportMap,
   pub presentational_dependencies: &'a mut Vec<Box<dyn DependencyTemplate>>,
-  pub dependency_id_map: &'a FxHashMap<JsWord, DependencyId>,
+  pub dependency_id_map: &'a FxHashMap<(JsWord, AttributesKey), DependencyId>,
 }
 
 impl<'a> HarmonyImportRefDependencyScanner<'a> {
   pub fn new(
     import_map: &'a ImportMap,
     presentational_dependencies: &'a mut Vec<Box<dyn DependencyTemplate>>,
-    dependency_id_map: &'a FxHashMap<JsWord, DependencyId>,
+    dependency_id_map: &'a FxHashMap<(JsWord, AttributesKey), DependencyId>,
   ) -> Self {
     Self {
       import_map,
@@ -299,7 +433,7 @@ impl Visit for HarmonyImportRefDependencyScanner<'_> {
             .push(Box::new(HarmonyImportSpecifierDependency::new(
               *self
                 .dependency_id_map
-                .get(&reference.request)
+                .get(&(reference.request.clone(), reference.attributes.clone()))
                 .expect("should have dependency id"),
               reference.request.clone(),
               true,
@@ -322,7 +456,7 @@ impl Visit for HarmonyImportRefDependencyScanner<'_> {
         .push(Box::new(HarmonyImportSpecifierDependency::new(
           *self
             .dependency_id_map
-            .get(&reference.request)
+            .get(&(reference.request.clone(), reference.attributes.clone()))
             .expect("should have dependency id"),
           reference.request.clone(),
           false,
@@ -359,7 +493,7 @@ impl Visit for HarmonyImportRefDependencyScanner<'_> {
             .push(Box::new(HarmonyImportSpecifierDependency::new(
               *self
                 .dependency_id_map
-                .get(&reference.request)
+                .get(&(reference.request.clone(), reference.attributes.clone()))
                 .expect("should have dependency id"),
               reference.request.clone(),
               false,
@@ -1,25 +1,50 @@
 use rspack_core::{CompilerOptions, ConstDependency, DependencyTemplate, ResourceData, SpanExt};
-use swc_core::common::Spanned;
-use swc_core::ecma::ast::{Expr, Ident, NewExpr, UnaryExpr, UnaryOp};
-use swc_core::ecma::atoms::js_word;
+use rspack_error::{Diagnostic, DiagnosticKind, Error, Severity, TraceableError};
+use swc_core::common::{SourceMap, Span, Spanned};
+use swc_core::ecma::ast::{
+  BinExpr, BinaryOp, ComputedPropName, Expr, Ident, Lit, MemberExpr, MemberProp, MetaPropExpr,
+  MetaPropKind, NewExpr, UnaryExpr, UnaryOp,
+};
+use swc_core::ecma::atoms::{js_word, JsWord};
 use swc_core::ecma::visit::{noop_visit_type, Visit, VisitWith};
 use url::Url;
 
-use super::{
-  expr_matcher, is_member_expr_starts_with_import_meta,
-  is_member_expr_starts_with_import_meta_webpack_hot,
-};
+use super::is_member_expr_starts_with_import_meta_webpack_hot;
+
+/// The compile time value of `import.meta` or one of its properties.
+struct EvaluatedImportMeta {
+  code: String,
+  /// `None` if the type of `code` is unknown
+  type_of: Option<&'static str>,
+  /// `None` if the truthiness of `code` is unknown
+  truthy: Option<bool>,
+  warning: Option<String>,
+}
+
+impl EvaluatedImportMeta {
+  fn new(code: String, type_of: &'static str, truthy: bool) -> Self {
+    Self {
+      code,
+      type_of: Some(type_of),
+      truthy: Some(truthy),
+      warning: None,
+    }
+  }
+}
 
 // Port from https://github.com/webpack/webpack/blob/main/lib/dependencies/ImportMetaPlugin.js
-// TODO:
-// - scan `import.meta.webpack`
-// - scan `import.meta.url.indexOf("index.js")`
-// - evaluate expression. eg `import.meta.env && import.meta.env.xx` should be `false`
-// - add warning for `import.meta`
+// - `import.meta.url`, `import.meta.webpack` and the configured `import.meta.env` are replaced
+//   with their values, only the object is replaced in `import.meta.url.indexOf("index.js")`
+// - unknown properties are replaced with `undefined` with a warning
+// - `import.meta.env && import.meta.env.xx` is folded when the left side is known
 pub struct ImportMetaScanner<'a> {
   pub presentational_dependencies: &'a mut Vec<Box<dyn DependencyTemplate>>,
   pub compiler_options: &'a CompilerOptions,
   pub resource_data: &'a ResourceData,
+  source_map: &'a SourceMap,
+  warning_diagnostics: &'a mut Vec<Diagnostic>,
+  /// The right side of `import.meta.env || xx` is never evaluated, so no warning is needed
+  in_dead_branch: bool,
 }
 
 impl<'a> ImportMetaScanner<'a> {
@@ -27,95 +52,231 @@ impl<'a> ImportMetaScanner<'a> {
     presentational_dependencies: &'a mut Vec<Box<dyn DependencyTemplate>>,
     resource_data: &'a ResourceData,
     compiler_options: &'a CompilerOptions,
+    source_map: &'a SourceMap,
+    warning_diagnostics: &'a mut Vec<Diagnostic>,
   ) -> Self {
     Self {
       presentational_dependencies,
       resource_data,
       compiler_options,
+      source_map,
+      warning_diagnostics,
+      in_dead_branch: false,
+    }
+  }
+
+  fn add_warning(&mut self, span: Span, message: String) {
+    if self.in_dead_branch {
+      return;
+    }
+    let source_file = self.source_map.lookup_byte_offset(span.lo).sf;
+    let error = TraceableError::from_source_file(
+      &source_file,
+      (span.lo.0 - source_file.start_pos.0) as usize,
+      (span.hi.0 - source_file.start_pos.0) as usize,
+      "UnsupportedFeatureWarning".to_string(),
+      message,
+    )
+    .with_kind(DiagnosticKind::JavaScript)
+    .with_severity(Severity::Warn);
+    self
+      .warning_diagnostics
+      .extend(Vec::<Diagnostic>::from(Error::TraceableError(error)));
+  }
+
+  fn add_const_dependency(&mut self, start: u32, end: u32, content: String) {
+    self
+      .presentational_dependencies
+      .push(Box::new(ConstDependency::new(
+        start,
+        end,
+        content.into(),
+        None,
+      )));
+  }
+
+  /// `None` is returned if the expression is not `import.meta` or one of its properties, e.g.
+  /// `import.meta.url.length` is not evaluated, but the `import.meta.url` in it is.
+  #[allow(clippy::unwrap_in_result)]
+  fn evaluate(&self, expr: &Expr) -> Option<EvaluatedImportMeta> {
+    let members = get_import_meta_members(expr)?;
+    let env = self.compiler_options.builtins.import_meta_env.as_ref();
+    match (members.as_slice(), env) {
+      ([], _) => Some(EvaluatedImportMeta {
+        warning: Some(
+          "Accessing import.meta directly is unsupported (only property access is supported)"
+            .to_string(),
+        ),
+        ..EvaluatedImportMeta::new("({})".to_string(), "object", true)
+      }),
+      ([property, ..], _) if property == "webpackHot" => None,
+      ([property], _) if property == "url" => {
+        let url = Url::from_file_path(&self.resource_data.resource).expect("should be a path");
+        Some(EvaluatedImportMeta::new(format!("'{url}'"), "string", true))
+      }
+      ([property], _) if property == "webpack" => {
+        Some(EvaluatedImportMeta::new("5".to_string(), "number", true))
+      }
+      ([property], Some(env)) if property == "env" => {
+        let mut properties = env
+          .iter()
+          .map(|(key, value)| {
+            format!(
+              "{}: {value}",
+              serde_json::to_string(key).expect("should be a valid json string")
+            )
+          })
+          .collect::<Vec<_>>();
+        properties.sort();
+        let properties = properties.join(", ");
+        Some(EvaluatedImportMeta::new(
+          format!("({{{properties}}})"),
+          "object",
+          true,
+        ))
+      }
+      ([property, key], Some(env)) if property == "env" => match env.get(key.as_ref()) {
+        Some(value) => Some(evaluate_env_value(value)),
+        None => Some(EvaluatedImportMeta::new(
+          "undefined".to_string(),
+          "undefined",
+          false,
+        )),
+      },
+      ([_, _, ..], _) => None,
+      ([property], _) => Some(EvaluatedImportMeta {
+        warning: Some(format!(
+          "Accessing import.meta.{property} is unsupported, it's replaced with undefined"
+        )),
+        ..EvaluatedImportMeta::new(
+          format!("/* unsupported import.meta.{property} */ undefined"),
+          "undefined",
+          false,
+        )
+      }),
+    }
+  }
+}
+
+/// The property names of `import.meta.a["b"]`, `None` if the expression is not `import.meta` or
+/// its member chain, or any of the properties is computed dynamically.
+fn get_import_meta_members(mut expr: &Expr) -> Option<Vec<JsWord>> {
+  let mut members = vec![];
+  loop {
+    match expr {
+      Expr::MetaProp(MetaPropExpr {
+        kind: MetaPropKind::ImportMeta,
+        ..
+      }) => {
+        members.reverse();
+        return Some(members);
+      }
+      Expr::Member(MemberExpr { obj, prop, .. }) => {
+        members.push(match prop {
+          MemberProp::Ident(ident) => ident.sym.clone(),
+          MemberProp::Computed(ComputedPropName {
+            expr: box Expr::Lit(Lit::Str(str)),
+            ..
+          }) => str.value.clone(),
+          _ => return None,
+        });
+        expr = obj;
+      }
+      _ => return None,
     }
   }
 }
 
+/// The values of `import.meta.env` are code, the type and truthiness are known if it's a literal.
+fn evaluate_env_value(value: &str) -> EvaluatedImportMeta {
+  let (type_of, truthy) = match value.trim() {
+    "undefined" => (Some("undefined"), Some(false)),
+    value => match serde_json::from_str::<serde_json::Value>(value) {
+      Ok(serde_json::Value::Null) => (Some("object"), Some(false)),
+      Ok(serde_json::Value::Bool(value)) => (Some("boolean"), Some(value)),
+      Ok(serde_json::Value::Number(value)) => (Some("number"), Some(value.as_f64() != Some(0.0))),
+      Ok(serde_json::Value::String(value)) => (Some("string"), Some(!value.is_empty())),
+      Ok(serde_json::Value::Array(_)) => (Some("object"), Some(true)),
+      // wrapped to avoid being parsed as a block statement
+      Ok(serde_json::Value::Object(_)) => {
+        return EvaluatedImportMeta::new(format!("({value})"), "object", true)
+      }
+      Err(_) => (None, None),
+    },
+  };
+  EvaluatedImportMeta {
+    code: if type_of.is_some() {
+      value.to_string()
+    } else {
+      format!("({value})")
+    },
+    type_of,
+    truthy,
+    warning: None,
+  }
+}
+
 impl Visit for ImportMetaScanner<'_> {
   noop_visit_type!();
 
   fn visit_unary_expr(&mut self, unary_expr: &UnaryExpr) {
-    if let UnaryExpr {
-      op: UnaryOp::TypeOf,
-      arg: box expr,
-      ..
-    } = unary_expr
+    if unary_expr.op == UnaryOp::TypeOf
+      && let Some(EvaluatedImportMeta {
+        type_of: Some(type_of),
+        ..
+      }) = self.evaluate(&unary_expr.arg)
     {
-      if expr_matcher::is_import_meta(expr) {
-        self
-          .presentational_dependencies
-          .push(Box::new(ConstDependency::new(
-            unary_expr.span().real_lo(),
-            unary_expr.span().real_hi(),
-            "'object'".into(),
-            None,
-          )));
-      } else if expr_matcher::is_import_meta_url(expr) {
-        self
-          .presentational_dependencies
-          .push(Box::new(ConstDependency::new(
-            unary_expr.span().real_lo(),
-            unary_expr.span().real_hi(),
-            "'string'".into(),
-            None,
-          )));
-      } else if is_member_expr_starts_with_import_meta(expr) {
-        self
-          .presentational_dependencies
-          .push(Box::new(ConstDependency::new(
-            unary_expr.span().real_lo(),
-            unary_expr.span().real_hi(),
-            "'undefined'".into(),
-            None,
-          )));
-      }
+      self.add_const_dependency(
+        unary_expr.span().real_lo(),
+        unary_expr.span().real_hi(),
+        format!("'{type_of}'"),
+      );
     } else {
       unary_expr.visit_children_with(self);
     }
   }
 
+  fn visit_bin_expr(&mut self, bin_expr: &BinExpr) {
+    let is_and = match bin_expr.op {
+      BinaryOp::LogicalAnd => true,
+      BinaryOp::LogicalOr => false,
+      _ => return bin_expr.visit_children_with(self),
+    };
+    let Some(EvaluatedImportMeta {
+      truthy: Some(truthy),
+      ..
+    }) = self.evaluate(&bin_expr.left)
+    else {
+      return bin_expr.visit_children_with(self);
+    };
+    if truthy == is_and {
+      // `truthy && xx` and `falsy || xx` are `xx`
+      self.add_const_dependency(
+        bin_expr.left.span().real_lo(),
+        bin_expr.right.span().real_lo(),
+        String::new(),
+      );
+      bin_expr.right.visit_with(self);
+    } else {
+      // The right side is kept for the dependencies in it, but it's never evaluated
+      bin_expr.left.visit_with(self);
+      let in_dead_branch = std::mem::replace(&mut self.in_dead_branch, true);
+      bin_expr.right.visit_with(self);
+      self.in_dead_branch = in_dead_branch;
+    }
+  }
+
   fn visit_expr(&mut self, expr: &Expr) {
     // exclude import.meta.webpackHot
     if is_member_expr_starts_with_import_meta_webpack_hot(expr) {
       return;
     }
 
-    // import.meta
-    if expr_matcher::is_import_meta(expr) {
-      // TODO(underfin): add warning
-      self
-        .presentational_dependencies
-        .push(Box::new(ConstDependency::new(
-          expr.span().real_lo(),
-          expr.span().real_hi(),
-          "({})".into(),
-          None,
-        )));
-    } else if expr_matcher::is_import_meta_url(expr) {
-      // import.meta.url
-      let url = Url::from_file_path(&self.resource_data.resource).expect("should be a path");
-      self
-        .presentational_dependencies
-        .push(Box::new(ConstDependency::new(
-          expr.span().real_lo(),
-          expr.span().real_hi(),
-          format!("'{url}'").into(),
-          None,
-        )));
-    } else if is_member_expr_starts_with_import_meta(expr) {
-      self
-        .presentational_dependencies
-        .push(Box::new(ConstDependency::new(
-          expr.span().real_lo(),
-          expr.span().real_hi(),
-          "undefined".into(),
-          None,
-        )));
+    if let Some(evaluated) = self.evaluate(expr) {
+      if let Some(warning) = evaluated.warning {
+        self.add_warning(expr.span(), warning);
+      }
+      self.add_const_dependency(expr.span().real_lo(), expr.span().real_hi(), evaluated.code);
     } else {
       expr.visit_children_with(self);
     }
@@ -1,10 +1,14 @@
-use once_cell::sync::Lazy;
 use rspack_core::{
-  ChunkGroupOptions, ContextMode, ContextOptions, DependencyCategory, ModuleDependency, SpanExt,
+  ChunkGroupOptions, ContextMode, ContextOptions, DependencyCategory, ImportAttributes,
+  ModuleDependency, SpanExt,
 };
+use rspack_error::{Diagnostic, DiagnosticKind, Error, Severity, TraceableError};
 use rspack_regex::RspackRegex;
 use swc_core::{
-  common::{comments::Comments, Span},
+  common::{
+    comments::{CommentKind, Comments},
+    SourceMap, Span, Spanned,
+  },
   ecma::{
     ast::{CallExpr, Callee, Expr, Lit},
     atoms::JsWord,
@@ -13,53 +17,239 @@ use swc_core::{
 };
 
 use super::context_helper::scanner_context_module;
+use super::magic_comment::{parse_magic_comment, MagicCommentValue};
+use super::{get_dynamic_import_attributes, JSON_IMPORT_ATTRIBUTE_TYPE};
 use crate::dependency::{ImportContextDependency, ImportDependency};
 
+/// Options of `import()` given by the magic comments.
+#[derive(Debug, Default)]
+struct ImportOptions {
+  /// `webpackIgnore: true`, the `import()` is kept as is
+  ignore: bool,
+  chunk_name: Option<String>,
+  prefetch_order: Option<i32>,
+  preload_order: Option<i32>,
+  mode: Option<ContextMode>,
+  include: Option<String>,
+  exclude: Option<String>,
+  exports: Option<Vec<JsWord>>,
+  /// Given by the second argument, `import("./a.json", { with: { type: "json" } })`
+  attributes: Option<ImportAttributes>,
+}
+
+impl ImportOptions {
+  fn group_options(&self) -> ChunkGroupOptions {
+    ChunkGroupOptions {
+      name: self.chunk_name.clone(),
+      entry_options: None,
+      prefetch_order: self.prefetch_order,
+      preload_order: self.preload_order,
+    }
+  }
+}
+
 pub struct ImportScanner<'a> {
   pub dependencies: &'a mut Vec<Box<dyn ModuleDependency>>,
   pub comments: Option<&'a dyn Comments>,
+  source_map: &'a SourceMap,
+  warning_diagnostics: &'a mut Vec<Diagnostic>,
 }
 
 impl<'a> ImportScanner<'a> {
   pub fn new(
     dependencies: &'a mut Vec<Box<dyn ModuleDependency>>,
     comments: Option<&'a dyn Comments>,
+    source_map: &'a SourceMap,
+    warning_diagnostics: &'a mut Vec<Diagnostic>,
   ) -> Self {
     Self {
       dependencies,
       comments,
+      source_map,
+      warning_diagnostics,
     }
   }
 
-  fn try_extract_webpack_chunk_name(&self, first_arg_span_of_import_call: &Span) -> Option<String> {
-    use swc_core::common::comments::CommentKind;
-    static WEBPACK_CHUNK_NAME_CAPTURE_RE: Lazy<regex::Regex> = Lazy::new(|| {
-      regex::Regex::new(r#"webpackChunkName\s*:\s*("(?P<_1>(\./)?([\w0-9_\-\[\]\(\)]+/)*?[\w0-9_\-\[\]\(\)]+)"|'(?P<_2>(\./)?([\w0-9_\-\[\]\(\)]+/)*?[\w0-9_\-\[\]\(\)]+)'|`(?P<_3>(\./)?([\w0-9_\-\[\]\(\)]+/)*?[\w0-9_\-\[\]\(\)]+)`)"#)
-        .expect("invalid regex")
-    });
+  fn add_warning(&mut self, span: Span, title: &str, message: String) {
+    self.add_diagnostic(span, title, message, Severity::Warn);
+  }
+
+  fn add_diagnostic(&mut self, span: Span, title: &str, message: String, severity: Severity) {
+    let source_file = self.source_map.lookup_byte_offset(span.lo).sf;
+    let error = TraceableError::from_source_file(
+      &source_file,
+      (span.lo.0 - source_file.start_pos.0) as usize,
+      (span.hi.0 - source_file.start_pos.0) as usize,
+      title.to_string(),
+      message,
+    )
+    .with_kind(DiagnosticKind::JavaScript)
+    .with_severity(severity);
     self
+      .warning_diagnostics
+      .extend(Vec::<Diagnostic>::from(Error::TraceableError(error)));
+  }
+
+  /// Read the options from the magic comments before the first argument of `import()`.
+  fn parse_import_options(&mut self, first_arg_span_of_import_call: &Span) -> ImportOptions {
+    let mut options = ImportOptions::default();
+    let Some(comments) = self
       .comments
-      .with_leading(first_arg_span_of_import_call.lo, |comments| {
-        let ret = comments
-          .iter()
-          .rev()
-          .filter(|c| matches!(c.kind, CommentKind::Block))
-          .find_map(|comment| {
-            WEBPACK_CHUNK_NAME_CAPTURE_RE
-              .captures(&comment.text)
-              .and_then(|captures| {
-                if let Some(cap) = captures.name("_1") {
-                  Some(cap)
-                } else if let Some(cap) = captures.name("_2") {
-                  Some(cap)
-                } else {
-                  captures.name("_3")
+      .get_leading(first_arg_span_of_import_call.lo)
+    else {
+      return options;
+    };
+    for comment in comments
+      .iter()
+      .filter(|c| matches!(c.kind, CommentKind::Block))
+    {
+      let values = match parse_magic_comment(&comment.text) {
+        Some(Ok(values)) => values,
+        Some(Err(error)) => {
+          self.add_warning(
+            comment.span,
+            "CommentCompilationWarning",
+            format!(
+              "Compilation error while processing magic comment(-s): /*{}*/: {error}",
+              comment.text
+            ),
+          );
+          continue;
+        }
+        None => continue,
+      };
+      for (key, value) in values {
+        let expected = match key.as_str() {
+          "webpackIgnore" => match value {
+            MagicCommentValue::Bool(ignore) => {
+              options.ignore = ignore;
+              continue;
+            }
+            _ => "a boolean",
+          },
+          "webpackChunkName" => match value {
+            MagicCommentValue::String(ref name) => {
+              options.chunk_name = Some(name.clone());
+              continue;
+            }
+            _ => "a string",
+          },
+          "webpackPrefetch" | "webpackPreload" => {
+            let order = match value {
+              MagicCommentValue::Bool(true) => Some(Some(0)),
+              MagicCommentValue::Bool(false) => Some(None),
+              MagicCommentValue::Number(order) if order.fract() == 0.0 => Some(Some(order as i32)),
+              _ => None,
+            };
+            match order {
+              Some(order) if key == "webpackPrefetch" => {
+                options.prefetch_order = order;
+                continue;
+              }
+              Some(order) => {
+                options.preload_order = order;
+                continue;
+              }
+              None => "true or a number",
+            }
+          }
+          "webpackMode" => match value {
+            MagicCommentValue::String(ref mode) => {
+              options.mode = Some(match mode.as_str() {
+                "lazy" => ContextMode::Lazy,
+                "lazy-once" => ContextMode::LazyOnce,
+                "eager" => ContextMode::Eager,
+                "weak" => ContextMode::AsyncWeak,
+                _ => {
+                  self.add_warning(
+                    comment.span,
+                    "UnsupportedFeatureWarning",
+                    format!(
+                      "`webpackMode` expected 'lazy', 'lazy-once', 'eager' or 'weak', but received: {mode}."
+                    ),
+                  );
+                  continue;
+                }
+              });
+              continue;
+            }
+            _ => "a string",
+          },
+          "webpackInclude" | "webpackExclude" => match value {
+            MagicCommentValue::Regex {
+              ref expr,
+              ref flags,
+            } => {
+              if let Err(error) = RspackRegex::with_flags(expr, flags) {
+                self.add_warning(
+                  comment.span,
+                  "UnsupportedFeatureWarning",
+                  format!("`{key}` is not a valid regular expression: {error}"),
+                );
+                continue;
+              }
+              let regex = Some(format!("/{expr}/{flags}"));
+              if key == "webpackInclude" {
+                options.include = regex;
+              } else {
+                options.exclude = regex;
+              }
+              continue;
+            }
+            _ => "a regular expression",
+          },
+          "webpackExports" => match value {
+            MagicCommentValue::String(ref export) => {
+              options.exports = Some(vec![JsWord::from(export.as_str())]);
+              continue;
+            }
+            MagicCommentValue::Array(ref items) => {
+              let exports = items
+                .iter()
+                .map(|item| match item {
+                  MagicCommentValue::String(export) => Some(JsWord::from(export.as_str())),
+                  _ => None,
+                })
+                .collect::<Option<Vec<_>>>();
+              match exports {
+                Some(exports) => {
+                  options.exports = Some(exports);
+                  continue;
                 }
-              })
-              .map(|mat| mat.as_str().to_string())
-          });
-        ret
-      })
+                None => "a string or an array of strings",
+              }
+            }
+            _ => "a string or an array of strings",
+          },
+          // unknown options are ignored like webpack
+          _ => continue,
+        };
+        self.add_warning(
+          comment.span,
+          "UnsupportedFeatureWarning",
+          format!("`{key}` expected {expected}, but received: {value}."),
+        );
+      }
+    }
+    options
+  }
+
+  fn add_import_dependency(&mut self, node: &CallExpr, request: JsWord, options: ImportOptions) {
+    let (group_options, weak) = match options.mode {
+      Some(ContextMode::Eager) => (None, false),
+      Some(ContextMode::AsyncWeak) => (None, true),
+      _ => (Some(options.group_options()), false),
+    };
+    self.dependencies.push(Box::new(ImportDependency::new(
+      node.span.real_lo(),
+      node.span.real_hi(),
+      request,
+      Some(node.span.into()),
+      group_options,
+      weak,
+      options.exports,
+      options.attributes,
+    )));
   }
 }
 
@@ -70,19 +260,34 @@ impl Visit for ImportScanner<'_> {
     if let Callee::Import(import_call) = node.callee {
       if let Some(dyn_imported) = node.args.get(0) {
         if dyn_imported.spread.is_none() {
+          let mut options = self.parse_import_options(&dyn_imported.expr.span());
+          if options.ignore {
+            return;
+          }
+          options.attributes = node
+            .args
+            .get(1)
+            .and_then(|arg| get_dynamic_import_attributes(&arg.expr));
+          if let Some(r#type) = options
+            .attributes
+            .as_ref()
+            .and_then(|attributes| attributes.get("type"))
+            && r#type != JSON_IMPORT_ATTRIBUTE_TYPE
+          {
+            self.add_diagnostic(
+              node.span,
+              "Import attributes error",
+              format!(
+                "Import attribute type '{type}' is not supported, only '{JSON_IMPORT_ATTRIBUTE_TYPE}' is supported"
+              ),
+              Severity::Error,
+            );
+          }
           match dyn_imported.expr.as_ref() {
             Expr::Lit(Lit::Str(imported)) => {
-              let chunk_name = self.try_extract_webpack_chunk_name(&imported.span);
-              self.dependencies.push(Box::new(ImportDependency::new(
-                node.span.real_lo(),
-                node.span.real_hi(),
-                imported.value.clone(),
-                Some(node.span.into()),
-                ChunkGroupOptions::default().name_optional(chunk_name),
-              )));
+              self.add_import_dependency(node, imported.value.clone(), options);
             }
             Expr::Tpl(tpl) if tpl.quasis.len() == 1 => {
-              let chunk_name = self.try_extract_webpack_chunk_name(&tpl.span);
               let request = JsWord::from(
                 tpl
                   .quasis
@@ -91,16 +296,13 @@ impl Visit for ImportScanner<'_> {
                   .raw
                   .to_string(),
               );
-              self.dependencies.push(Box::new(ImportDependency::new(
-                node.span.real_lo(),
-                node.span.real_hi(),
-                request,
-                Some(node.span.into()),
-                ChunkGroupOptions::default().name_optional(chunk_name),
-              )));
+              self.add_import_dependency(node, request, options);
             }
             _ => {
               if let Some((context, reg)) = scanner_context_module(dyn_imported.expr.as_ref()) {
+                let mode = options.mode.clone().unwrap_or(ContextMode::Lazy);
+                let group_options = matches!(mode, ContextMode::Lazy | ContextMode::LazyOnce)
+                  .then(|| options.group_options());
                 self
                   .dependencies
                   .push(Box::new(ImportContextDependency::new(
@@ -108,14 +310,15 @@ impl Visit for ImportScanner<'_> {
                     import_call.span.real_hi(),
                     node.span.real_hi(),
                     ContextOptions {
-                      mode: ContextMode::Lazy,
+                      mode,
                       recursive: true,
                       reg_exp: RspackRegex::new(&reg).expect("reg failed"),
                       reg_str: reg,
-                      include: None,
-                      exclude: None,
+                      include: options.include,
+                      exclude: options.exclude,
                       category: DependencyCategory::Esm,
                       request: context,
+                      group_options,
                     },
                     Some(node.span.into()),
                   )));
@@ -0,0 +1,73 @@
+use rspack_core::{tree_shaking::utils::has_pure_annotation, DependencyTemplate, SpanExt};
+use swc_core::{
+  common::{comments::Comments, Spanned},
+  ecma::{
+    ast::{CallExpr, Decl, ExportDecl, Expr, ModuleDecl, ModuleItem, NewExpr, Pat, Stmt},
+    visit::{noop_visit_type, Visit},
+  },
+};
+
+use crate::dependency::PureExpressionDependency;
+
+// Port from https://github.com/webpack/webpack/blob/main/lib/optimize/InnerGraphPlugin.js
+/// Only the top level declarations are scanned, whether they are used is decided by the
+/// `inner_graph` of tree shaking.
+pub struct InnerGraphScanner<'a> {
+  presentational_dependencies: &'a mut Vec<Box<dyn DependencyTemplate>>,
+  comments: Option<&'a dyn Comments>,
+}
+
+impl<'a> InnerGraphScanner<'a> {
+  pub fn new(
+    presentational_dependencies: &'a mut Vec<Box<dyn DependencyTemplate>>,
+    comments: Option<&'a dyn Comments>,
+  ) -> Self {
+    Self {
+      presentational_dependencies,
+      comments,
+    }
+  }
+
+  fn is_pure_annotated(&self, expr: &Expr) -> bool {
+    let Some(comments) = self.comments else {
+      return false;
+    };
+    match expr {
+      Expr::Paren(paren) => self.is_pure_annotated(&paren.expr),
+      Expr::Call(CallExpr { span, .. }) | Expr::New(NewExpr { span, .. }) => {
+        has_pure_annotation(comments, span.lo)
+      }
+      _ => false,
+    }
+  }
+}
+
+impl Visit for InnerGraphScanner<'_> {
+  noop_visit_type!();
+
+  fn visit_module_item(&mut self, item: &ModuleItem) {
+    let var = match item {
+      ModuleItem::Stmt(Stmt::Decl(Decl::Var(var)))
+      | ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+        decl: Decl::Var(var),
+        ..
+      })) => var,
+      _ => return,
+    };
+    for decl in &var.decls {
+      if let Pat::Ident(ident) = &decl.name
+        && let Some(init) = &decl.init
+        && self.is_pure_annotated(init)
+      {
+        let span = init.span();
+        self
+          .presentational_dependencies
+          .push(Box::new(PureExpressionDependency::new(
+            span.real_lo(),
+            span.real_hi(),
+            ident.to_id().into(),
+          )));
+      }
+    }
+  }
+}
@@ -0,0 +1,33 @@
+use rspack_core::BuildInfo;
+use swc_core::common::SyntaxContext;
+use swc_core::ecma::ast::{CallExpr, Callee, Expr};
+use swc_core::ecma::visit::{noop_visit_type, Visit, VisitWith};
+
+// Port from https://github.com/webpack/webpack/blob/main/lib/JavascriptMetaInfoPlugin.js
+pub struct JavascriptMetaInfoScanner<'a> {
+  unresolved_ctxt: &'a SyntaxContext,
+  build_info: &'a mut BuildInfo,
+}
+
+impl<'a> JavascriptMetaInfoScanner<'a> {
+  pub fn new(unresolved_ctxt: &'a SyntaxContext, build_info: &'a mut BuildInfo) -> Self {
+    Self {
+      unresolved_ctxt,
+      build_info,
+    }
+  }
+}
+
+impl Visit for JavascriptMetaInfoScanner<'_> {
+  noop_visit_type!();
+
+  fn visit_call_expr(&mut self, call_expr: &CallExpr) {
+    if let Callee::Expr(box Expr::Ident(ident)) = &call_expr.callee
+      && &ident.sym == "eval"
+      && ident.span.ctxt == *self.unresolved_ctxt
+    {
+      self.build_info.module_concatenation_bailout = Some("eval()".to_string());
+    }
+    call_expr.visit_children_with(self);
+  }
+}
@@ -0,0 +1,370 @@
+use std::{fmt, iter::Peekable, str::CharIndices};
+
+use once_cell::sync::Lazy;
+
+// Align with https://github.com/webpack/webpack/blob/4b4ca3bb53f36a5b8fc6bc1bd976ed7af161bd80/lib/javascript/JavascriptParser.js#L3949
+/// Comments containing an option like `webpackChunkName:` are magic comments.
+static WEBPACK_MAGIC_COMMENT_RE: Lazy<regex::Regex> =
+  Lazy::new(|| regex::Regex::new(r"(^|\W)webpack[A-Z][A-Za-z]+\s*:").expect("invalid regex"));
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum MagicCommentValue {
+  String(String),
+  Number(f64),
+  Bool(bool),
+  Regex { expr: String, flags: String },
+  Array(Vec<MagicCommentValue>),
+  Null,
+  Undefined,
+}
+
+impl fmt::Display for MagicCommentValue {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      MagicCommentValue::String(s) => write!(f, "{s}"),
+      MagicCommentValue::Number(n) => write!(f, "{n}"),
+      MagicCommentValue::Bool(b) => write!(f, "{b}"),
+      MagicCommentValue::Regex { expr, flags } => write!(f, "/{expr}/{flags}"),
+      MagicCommentValue::Array(items) => {
+        for (index, item) in items.iter().enumerate() {
+          if index > 0 {
+            write!(f, ",")?;
+          }
+          write!(f, "{item}")?;
+        }
+        Ok(())
+      }
+      MagicCommentValue::Null => write!(f, "null"),
+      MagicCommentValue::Undefined => write!(f, "undefined"),
+    }
+  }
+}
+
+/// Parse the options of a magic comment like `webpackChunkName: "name", webpackPrefetch: true`,
+/// `None` is returned if the comment is not a magic comment.
+///
+/// Webpack evaluates the comment as an object literal, only the literals which are meaningful to
+/// the options are supported here: strings, numbers, booleans, regexes and arrays of them.
+pub fn parse_magic_comment(text: &str) -> Option<Result<Vec<(String, MagicCommentValue)>, String>> {
+  if !WEBPACK_MAGIC_COMMENT_RE.is_match(text) {
+    return None;
+  }
+  Some(MagicCommentParser::new(text).parse())
+}
+
+struct MagicCommentParser<'a> {
+  text: &'a str,
+  chars: Peekable<CharIndices<'a>>,
+}
+
+impl<'a> MagicCommentParser<'a> {
+  fn new(text: &'a str) -> Self {
+    Self {
+      text,
+      chars: text.char_indices().peekable(),
+    }
+  }
+
+  fn parse(mut self) -> Result<Vec<(String, MagicCommentValue)>, String> {
+    let mut options = vec![];
+    loop {
+      self.skip_whitespace();
+      if self.chars.peek().is_none() {
+        return Ok(options);
+      }
+      let key = self.parse_key()?;
+      self.skip_whitespace();
+      self.expect(':')?;
+      let value = self.parse_value()?;
+      options.push((key, value));
+      self.skip_whitespace();
+      match self.chars.next() {
+        Some((_, ',')) => continue,
+        None => return Ok(options),
+        Some((_, c)) => return Err(format!("Unexpected token '{c}'")),
+      }
+    }
+  }
+
+  fn skip_whitespace(&mut self) {
+    while self.chars.next_if(|(_, c)| c.is_whitespace()).is_some() {}
+  }
+
+  fn expect(&mut self, expected: char) -> Result<(), String> {
+    match self.chars.next() {
+      Some((_, c)) if c == expected => Ok(()),
+      Some((_, c)) => Err(format!("Unexpected token '{c}'")),
+      None => Err("Unexpected end of input".to_string()),
+    }
+  }
+
+  fn parse_identifier(&mut self) -> String {
+    let mut identifier = String::new();
+    while let Some((_, c)) = self
+      .chars
+      .next_if(|(_, c)| c.is_alphanumeric() || matches!(c, '_' | '$'))
+    {
+      identifier.push(c);
+    }
+    identifier
+  }
+
+  fn parse_key(&mut self) -> Result<String, String> {
+    match self.chars.peek() {
+      Some((_, quote @ ('"' | '\''))) => {
+        let quote = *quote;
+        self.parse_string(quote)
+      }
+      Some((_, c)) if c.is_alphabetic() || matches!(c, '_' | '$') => Ok(self.parse_identifier()),
+      Some((_, c)) => Err(format!("Unexpected token '{c}'")),
+      None => Err("Unexpected end of input".to_string()),
+    }
+  }
+
+  fn parse_value(&mut self) -> Result<MagicCommentValue, String> {
+    self.skip_whitespace();
+    match self.chars.peek().copied() {
+      Some((_, quote @ ('"' | '\'' | '`'))) => {
+        self.parse_string(quote).map(MagicCommentValue::String)
+      }
+      Some((_, '/')) => self.parse_regex(),
+      Some((_, '[')) => self.parse_array(),
+      Some((_, c)) if c.is_ascii_digit() || matches!(c, '-' | '+' | '.') => self.parse_number(),
+      Some((_, c)) if c.is_alphabetic() => match self.parse_identifier().as_str() {
+        "true" => Ok(MagicCommentValue::Bool(true)),
+        "false" => Ok(MagicCommentValue::Bool(false)),
+        "null" => Ok(MagicCommentValue::Null),
+        "undefined" => Ok(MagicCommentValue::Undefined),
+        identifier => Err(format!("{identifier} is not defined")),
+      },
+      Some((_, c)) => Err(format!("Unexpected token '{c}'")),
+      None => Err("Unexpected end of input".to_string()),
+    }
+  }
+
+  fn parse_string(&mut self, quote: char) -> Result<String, String> {
+    self.chars.next();
+    let mut value = String::new();
+    while let Some((_, c)) = self.chars.next() {
+      match c {
+        c if c == quote => return Ok(value),
+        '\\' => match self.chars.next() {
+          Some((_, 'n')) => value.push('\n'),
+          Some((_, 't')) => value.push('\t'),
+          Some((_, 'r')) => value.push('\r'),
+          Some((_, escaped)) => value.push(escaped),
+          None => break,
+        },
+        c => value.push(c),
+      }
+    }
+    Err("Invalid or unexpected token".to_string())
+  }
+
+  fn parse_number(&mut self) -> Result<MagicCommentValue, String> {
+    let start = self
+      .chars
+      .peek()
+      .map_or(self.text.len(), |(index, _)| *index);
+    let mut end = start;
+    while let Some((index, c)) = self
+      .chars
+      .next_if(|(_, c)| c.is_ascii_alphanumeric() || matches!(c, '-' | '+' | '.'))
+    {
+      end = index + c.len_utf8();
+    }
+    let number = &self.text[start..end];
+    number
+      .parse::<f64>()
+      .map(MagicCommentValue::Number)
+      .map_err(|_| format!("Invalid number {number}"))
+  }
+
+  fn parse_regex(&mut self) -> Result<MagicCommentValue, String> {
+    self.chars.next();
+    let mut expr = String::new();
+    let mut in_class = false;
+    loop {
+      match self.chars.next() {
+        Some((_, '\\')) => {
+          expr.push('\\');
+          match self.chars.next() {
+            Some((_, escaped)) => expr.push(escaped),
+            None => return Err("Invalid regular expression: missing /".to_string()),
+          }
+        }
+        Some((_, '[')) => {
+          in_class = true;
+          expr.push('[');
+        }
+        Some((_, ']')) => {
+          in_class = false;
+          expr.push(']');
+        }
+        Some((_, '/')) if !in_class => break,
+        Some((_, c)) => expr.push(c),
+        None => return Err("Invalid regular expression: missing /".to_string()),
+      }
+    }
+    let flags = self.parse_identifier();
+    Ok(MagicCommentValue::Regex { expr, flags })
+  }
+
+  fn parse_array(&mut self) -> Result<MagicCommentValue, String> {
+    self.chars.next();
+    let mut items = vec![];
+    loop {
+      self.skip_whitespace();
+      if self.chars.next_if(|(_, c)| *c == ']').is_some() {
+        return Ok(MagicCommentValue::Array(items));
+      }
+      items.push(self.parse_value()?);
+      self.skip_whitespace();
+      match self.chars.next() {
+        Some((_, ',')) => continue,
+        Some((_, ']')) => return Ok(MagicCommentValue::Array(items)),
+        Some((_, c)) => return Err(format!("Unexpected token '{c}'")),
+        None => return Err("Unexpected end of input".to_string()),
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  fn parse(text: &str) -> Result<Vec<(String, MagicCommentValue)>, String> {
+    parse_magic_comment(text).expect("should be a magic comment")
+  }
+
+  fn string(value: &str) -> MagicCommentValue {
+    MagicCommentValue::String(value.to_string())
+  }
+
+  #[test]
+  fn should_ignore_normal_comments() {
+    assert_eq!(parse_magic_comment(" some comment "), None);
+    assert_eq!(parse_magic_comment(" webpack: true "), None);
+    assert_eq!(parse_magic_comment(" mywebpackChunkName: 'a' "), None);
+  }
+
+  #[test]
+  fn should_parse_options() {
+    assert_eq!(
+      parse(r#" webpackChunkName: "chunk", 'webpackPrefetch': 1, webpackMode: `lazy`, "#),
+      Ok(vec![
+        ("webpackChunkName".to_string(), string("chunk")),
+        (
+          "webpackPrefetch".to_string(),
+          MagicCommentValue::Number(1.0)
+        ),
+        ("webpackMode".to_string(), string("lazy")),
+      ])
+    );
+    assert_eq!(
+      parse(r#" webpackChunkName: "a\"b\\c\n" "#),
+      Ok(vec![("webpackChunkName".to_string(), string("a\"b\\c\n"))])
+    );
+  }
+
+  #[test]
+  fn should_parse_booleans_and_keywords() {
+    assert_eq!(
+      parse(" webpackIgnore: true, webpackPreload: false, webpackA: null, webpackB: undefined "),
+      Ok(vec![
+        ("webpackIgnore".to_string(), MagicCommentValue::Bool(true)),
+        ("webpackPreload".to_string(), MagicCommentValue::Bool(false)),
+        ("webpackA".to_string(), MagicCommentValue::Null),
+        ("webpackB".to_string(), MagicCommentValue::Undefined),
+      ])
+    );
+  }
+
+  #[test]
+  fn should_parse_regex_literals() {
+    assert_eq!(
+      parse(r#" webpackInclude: /\.json$/i, webpackExclude: /[/\]]a\/b/ "#),
+      Ok(vec![
+        (
+          "webpackInclude".to_string(),
+          MagicCommentValue::Regex {
+            expr: r"\.json$".to_string(),
+            flags: "i".to_string(),
+          }
+        ),
+        (
+          "webpackExclude".to_string(),
+          MagicCommentValue::Regex {
+            expr: r"[/\]]a\/b".to_string(),
+            flags: String::new(),
+          }
+        ),
+      ])
+    );
+  }
+
+  #[test]
+  fn should_parse_arrays() {
+    assert_eq!(
+      parse(r#" webpackExports: ["a", 'b', [true, -1.5]], webpackEmpty: [ ] "#),
+      Ok(vec![
+        (
+          "webpackExports".to_string(),
+          MagicCommentValue::Array(vec![
+            string("a"),
+            string("b"),
+            MagicCommentValue::Array(vec![
+              MagicCommentValue::Bool(true),
+              MagicCommentValue::Number(-1.5),
+            ]),
+          ])
+        ),
+        ("webpackEmpty".to_string(), MagicCommentValue::Array(vec![])),
+      ])
+    );
+    assert_eq!(
+      parse(r#" webpackExports: ["a", "b",] "#),
+      Ok(vec![(
+        "webpackExports".to_string(),
+        MagicCommentValue::Array(vec![string("a"), string("b")])
+      )])
+    );
+  }
+
+  #[test]
+  fn should_report_malformed_comments() {
+    assert_eq!(
+      parse(r#" webpackChunkName: "chunk"#),
+      Err("Invalid or unexpected token".to_string())
+    );
+    assert_eq!(
+      parse(r#" webpackChunkName: "a" "b" "#),
+      Err("Unexpected token '\"'".to_string())
+    );
+    assert_eq!(
+      parse(" webpackChunkName: name "),
+      Err("name is not defined".to_string())
+    );
+    assert_eq!(
+      parse(" webpackChunkName: "),
+      Err("Unexpected end of input".to_string())
+    );
+    assert_eq!(
+      parse(" webpackPrefetch: 1x "),
+      Err("Invalid number 1x".to_string())
+    );
+    assert_eq!(
+      parse(r" webpackInclude: /a\/ "),
+      Err("Invalid regular expression: missing /".to_string())
+    );
+    assert_eq!(
+      parse(r#" webpackExports: ["a" "b"] "#),
+      Err("Unexpected token '\"'".to_string())
+    );
+    assert_eq!(
+      parse(r#" webpackExports: ["a", "#),
+      Err("Unexpected end of input".to_string())
+    );
+  }
+}
@@ -1,3 +1,4 @@
+mod amd_scanner;
 mod api_scanner;
 mod common_js_export_scanner;
 mod common_js_import_dependency_scanner;
@@ -10,6 +11,9 @@ mod harmony_import_dependency_scanner;
 mod hot_module_replacement_scanner;
 mod import_meta_scanner;
 mod import_scanner;
+mod inner_graph_scanner;
+mod javascript_meta_info_scanner;
+mod magic_comment;
 mod node_stuff_scanner;
 mod require_context_scanner;
 mod url_scanner;
@@ -17,13 +21,16 @@ mod util;
 mod worker_scanner;
 use rspack_core::{
   ast::javascript::Program, BuildInfo, BuildMeta, BuildMetaExportsType, CompilerOptions,
-  DependencyTemplate, ModuleDependency, ModuleIdentifier, ModuleType, ResourceData,
+  DependencyTemplate, JavascriptParserOptions, ModuleDependency, ModuleIdentifier, ModuleType,
+  ResourceData,
 };
-use swc_core::common::{comments::Comments, Mark, SyntaxContext};
+use rspack_error::Diagnostic;
+use swc_core::common::{comments::Comments, Mark, SourceMap, SyntaxContext};
 pub use util::*;
 
 use self::{
-  api_scanner::ApiScanner, common_js_export_scanner::CommonJsExportDependencyScanner,
+  amd_scanner::AmdScanner, api_scanner::ApiScanner,
+  common_js_export_scanner::CommonJsExportDependencyScanner,
   common_js_import_dependency_scanner::CommonJsImportDependencyScanner,
   common_js_scanner::CommonJsScanner, compatibility_scanner::CompatibilityScanner,
   harmony_detection_scanner::HarmonyDetectionScanner,
@@ -31,6 +38,7 @@ use self::{
   harmony_import_dependency_scanner::HarmonyImportDependencyScanner,
   hot_module_replacement_scanner::HotModuleReplacementScanner,
   import_meta_scanner::ImportMetaScanner, import_scanner::ImportScanner,
+  inner_graph_scanner::InnerGraphScanner, javascript_meta_info_scanner::JavascriptMetaInfoScanner,
   node_stuff_scanner::NodeStuffScanner, require_context_scanner::RequireContextScanner,
   url_scanner::UrlScanner, worker_scanner::WorkerScanner,
 };
@@ -38,23 +46,29 @@ use self::{
 pub type ScanDependenciesResult = (
   Vec<Box<dyn ModuleDependency>>,
   Vec<Box<dyn DependencyTemplate>>,
+  Vec<Diagnostic>,
 );
 
 #[allow(clippy::too_many_arguments)]
 pub fn scan_dependencies(
   program: &Program,
   unresolved_mark: Mark,
+  source_map: &SourceMap,
   resource_data: &ResourceData,
   compiler_options: &CompilerOptions,
   module_type: &ModuleType,
   build_info: &mut BuildInfo,
   build_meta: &mut BuildMeta,
   module_identifier: ModuleIdentifier,
+  parser_options: Option<&JavascriptParserOptions>,
 ) -> ScanDependenciesResult {
   let mut dependencies: Vec<Box<dyn ModuleDependency>> = vec![];
   let mut presentational_dependencies: Vec<Box<dyn DependencyTemplate>> = vec![];
+  let mut warning_diagnostics: Vec<Diagnostic> = vec![];
   let unresolved_ctxt = SyntaxContext::empty().apply_mark(unresolved_mark);
   let comments = program.comments.clone();
+  let amd = parser_options.map_or(false, |options| options.amd)
+    && (module_type.is_js_auto() || module_type.is_js_dynamic());
 
   program.visit_with(&mut ApiScanner::new(
     &unresolved_ctxt,
@@ -67,13 +81,18 @@ pub fn scan_dependencies(
     &unresolved_ctxt,
   ));
 
-  // TODO it should enable at js/auto or js/dynamic, but builtins provider will inject require at esm
-  program.visit_with(&mut CommonJsImportDependencyScanner::new(
-    &mut dependencies,
-    &mut presentational_dependencies,
+  program.visit_with(&mut JavascriptMetaInfoScanner::new(
     &unresolved_ctxt,
+    build_info,
   ));
+
   if module_type.is_js_auto() || module_type.is_js_dynamic() {
+    program.visit_with(&mut CommonJsImportDependencyScanner::new(
+      &mut dependencies,
+      &mut presentational_dependencies,
+      &unresolved_ctxt,
+      amd,
+    ));
     // TODO webpack scan it at CommonJsExportsParserPlugin
     // use `Dynamic` as workaround
     build_meta.exports_type = BuildMetaExportsType::Dynamic;
@@ -82,6 +101,13 @@ pub fn scan_dependencies(
       &unresolved_ctxt,
     ));
     program.visit_with(&mut RequireContextScanner::new(&mut dependencies));
+    if amd {
+      program.visit_with(&mut AmdScanner::new(
+        &mut dependencies,
+        &mut presentational_dependencies,
+        &unresolved_ctxt,
+      ));
+    }
     program.visit_with(&mut CommonJsExportDependencyScanner::new(
       &mut presentational_dependencies,
       &unresolved_ctxt,
@@ -102,6 +128,8 @@ pub fn scan_dependencies(
   program.visit_with(&mut ImportScanner::new(
     &mut dependencies,
     comments.as_ref().map(|c| c as &dyn Comments),
+    source_map,
+    &mut warning_diagnostics,
   ));
 
   if module_type.is_js_auto() || module_type.is_js_esm() {
@@ -117,6 +145,9 @@ pub fn scan_dependencies(
       &mut presentational_dependencies,
       &mut import_map,
       module_identifier,
+      module_type,
+      source_map,
+      &mut warning_diagnostics,
     ));
     program.visit_with(&mut HarmonyExportDependencyScanner::new(
       &mut dependencies,
@@ -142,7 +173,15 @@ pub fn scan_dependencies(
       &mut presentational_dependencies,
       resource_data,
       compiler_options,
+      source_map,
+      &mut warning_diagnostics,
     ));
+    if compiler_options.builtins.tree_shaking.is_true() {
+      program.visit_with(&mut InnerGraphScanner::new(
+        &mut presentational_dependencies,
+        comments.as_ref().map(|c| c as &dyn Comments),
+      ));
+    }
   }
 
   if compiler_options.dev_server.hot {
@@ -153,5 +192,9 @@ pub fn scan_dependencies(
     ));
   }
 
-  (dependencies, presentational_dependencies)
+  (
+    dependencies,
+    presentational_dependencies,
+    warning_diagnostics,
+  )
 }
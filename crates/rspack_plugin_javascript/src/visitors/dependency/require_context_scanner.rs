@@ -71,6 +71,7 @@ impl Visit for RequireContextScanner<'_> {
               exclude: None,
               category: DependencyCategory::CommonJS,
               request: str.value.to_string(),
+              group_options: None,
             },
             Some(node.span.into()),
           )));
@@ -1,7 +1,8 @@
+use rspack_core::ImportAttributes;
 use swc_core::{
   common::{pass::AstNodePath, SyntaxContext},
   ecma::{
-    ast::{CallExpr, Expr, MemberExpr},
+    ast::{CallExpr, Expr, Lit, MemberExpr, ObjectLit, Prop, PropName, PropOrSpread},
     visit::{AstParentKind, AstParentNodeRef},
   },
 };
@@ -68,11 +69,12 @@ pub(crate) mod expr_matcher {
     is_module_loaded: "module.loaded",
     is_module_exports: "module.exports",
     is_require_cache: "require.cache",
+    is_require_amd: "require.amd",
+    is_define_amd: "define.amd",
     is_webpack_module_id: "__webpack_module__.id",
     is_import_meta_webpack_hot: "import.meta.webpackHot",
     is_import_meta_webpack_hot_accept: "import.meta.webpackHot.accept",
     is_import_meta_webpack_hot_decline: "import.meta.webpackHot.decline",
-    is_import_meta_url: "import.meta.url",
     is_import_meta: "import.meta",
   });
 }
@@ -216,3 +218,53 @@ pub fn is_unresolved_member_object_ident(expr: &Expr, unresolved_ctxt: &SyntaxCo
   }
   false
 }
+
+/// The only supported value of the `type` import attribute.
+pub const JSON_IMPORT_ATTRIBUTE_TYPE: &str = "json";
+
+/// Get the import attributes of `import ... assert { type: "json" }`,
+/// entries with a non string key or value are ignored.
+pub fn get_import_attributes(attributes: &ObjectLit) -> ImportAttributes {
+  attributes
+    .props
+    .iter()
+    .filter_map(|prop| {
+      let PropOrSpread::Prop(box Prop::KeyValue(key_value)) = prop else {
+        return None;
+      };
+      let key = match &key_value.key {
+        PropName::Ident(ident) => ident.sym.to_string(),
+        PropName::Str(str) => str.value.to_string(),
+        _ => return None,
+      };
+      let Expr::Lit(Lit::Str(value)) = &*key_value.value else {
+        return None;
+      };
+      Some((key, value.value.to_string()))
+    })
+    .collect()
+}
+
+/// Get the import attributes of `import("./a.json", { with: { type: "json" } })`,
+/// `assert` is accepted as the legacy name of `with`.
+pub fn get_dynamic_import_attributes(options: &Expr) -> Option<ImportAttributes> {
+  let Expr::Object(options) = options else {
+    return None;
+  };
+  options.props.iter().find_map(|prop| {
+    let PropOrSpread::Prop(box Prop::KeyValue(key_value)) = prop else {
+      return None;
+    };
+    let key = match &key_value.key {
+      PropName::Ident(ident) => &ident.sym,
+      PropName::Str(str) => &str.value,
+      _ => return None,
+    };
+    match &*key_value.value {
+      Expr::Object(attributes) if key == "with" || key == "assert" => {
+        Some(get_import_attributes(attributes))
+      }
+      _ => None,
+    }
+  })
+}
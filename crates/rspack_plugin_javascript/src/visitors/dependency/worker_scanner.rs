@@ -73,7 +73,10 @@ impl<'a> WorkerScanner<'a> {
           public_path: None,
           base_uri: None,
           filename: None,
+          library: None,
         }),
+        prefetch_order: None,
+        preload_order: None,
       },
     )));
     if let Some(range) = range {
@@ -40,7 +40,7 @@ pub fn run_before_pass(
   ast: &mut Ast,
   options: &CompilerOptions,
   syntax: Syntax,
-  _build_info: &mut BuildInfo,
+  build_info: &mut BuildInfo,
   module_type: &ModuleType,
   source: &str,
 ) -> Result<()> {
@@ -51,6 +51,7 @@ pub fn run_before_pass(
   let cm = ast.get_context().source_map.clone();
   // TODO: should use react-loader to get exclude/include
   let should_transform_by_react = module_type.is_jsx_like();
+  let definitions = options.builtins.definitions();
   ast.transform_with_handler(cm.clone(), |_handler, program, context| {
     let top_level_mark = context.top_level_mark;
     let unresolved_mark = context.unresolved_mark;
@@ -126,11 +127,15 @@ pub fn run_before_pass(
       // enable if configurable
       // swc_visitor::const_modules(cm, globals),
       Optional::new(
-        swc_visitor::define(&options.builtins.define),
-        !options.builtins.define.is_empty()
+        swc_visitor::define(
+          &definitions,
+          unresolved_mark,
+          &mut build_info.value_dependencies
+        ),
+        !definitions.is_empty()
       ),
       Optional::new(
-        swc_visitor::provide_builtin(&options.builtins.provide, unresolved_mark),
+        swc_visitor::provide_builtin(&options.builtins.provide, unresolved_mark, module_type),
         !options.builtins.provide.is_empty()
       ),
       Optional::new(
@@ -1,52 +1,244 @@
+use std::collections::BTreeMap;
 use std::sync::Arc;
 
 use rspack_core::Define;
 use swc_core::common::collections::AHashMap;
+use swc_core::common::{chain, Mark, DUMMY_SP};
+use swc_core::ecma::ast::{
+  op, ComputedPropName, Expr, Ident, KeyValueProp, Lit, MemberExpr, MemberProp, ParenExpr, Prop,
+  PropName, PropOrSpread, Str, UnaryExpr,
+};
+use swc_core::ecma::atoms::{js_word, JsWord};
 use swc_core::ecma::parser::EsConfig;
 use swc_core::ecma::transforms::optimization::inline_globals2;
 use swc_core::ecma::utils::NodeIgnoringSpan;
-use swc_core::ecma::visit::Fold;
+use swc_core::ecma::visit::{as_folder, noop_visit_mut_type, Fold, VisitMut, VisitMutWith};
 use swc_core::{
   common::FileName,
   ecma::parser::{parse_file_as_expr, Syntax},
 };
 
-pub fn define(opts: &Define) -> impl Fold {
+type Definitions<T> = AHashMap<NodeIgnoringSpan<'static, Expr>, T>;
+
+/// Replace the definitions, the keys and values of the used definitions are recorded into
+/// `value_dependencies`, so the module could be rebuilt when any of them changes.
+pub fn define<'a>(
+  opts: &Define,
+  unresolved_mark: Mark,
+  value_dependencies: &'a mut BTreeMap<String, String>,
+) -> impl Fold + 'a {
   let cm: Arc<swc_core::common::SourceMap> = Default::default();
-  let defs = opts
+  let parse = |code: &String| {
+    let fm = cm.new_source_file(FileName::Anon, code.clone());
+    parse_file_as_expr(
+      &fm,
+      Syntax::Es(EsConfig::default()),
+      rspack_core::EsVersion::EsNext,
+      None,
+      &mut vec![],
+    )
+    .unwrap_or_else(|_| panic!("builtins.define: Failed to parse {:?}", code))
+  };
+
+  // The deeper definitions go later to override the properties expanded from their parents,
+  // e.g. `X.a.b` defined by `X.a` overrides the one defined by `X`
+  let mut origins = opts.iter().collect::<Vec<_>>();
+  origins.sort_by_key(|(key, _)| key.len());
+  let mut definitions = vec![];
+  let mut explicit = vec![];
+  for (index, (key, value)) in origins.iter().enumerate() {
+    let target = *parse(key);
+    let replacement = *parse(value);
+    expand_object(&target, &replacement, index, &mut definitions);
+    explicit.push((target, replacement, index));
+  }
+  definitions.extend(explicit);
+
+  // `typeof X` is evaluated if the type of the value is known, `typeof X` and `typeof X.a` are
+  // `"object"` if `X.a.b` is defined
+  let mut typeofs = Definitions::default();
+  for (target, _, index) in &definitions {
+    let mut target = target;
+    while let Expr::Member(MemberExpr { obj, .. }) = target {
+      target = obj;
+      typeofs
+        .entry(NodeIgnoringSpan::owned(target.clone()))
+        .or_insert((JsWord::from("object"), *index));
+    }
+  }
+  for (target, replacement, index) in &definitions {
+    let target = NodeIgnoringSpan::owned(target.clone());
+    match type_of(replacement) {
+      Some(type_of) => typeofs.insert(target, (JsWord::from(type_of), *index)),
+      None => typeofs.remove(&target),
+    };
+  }
+
+  let usage = DefinitionUsage {
+    unresolved_mark,
+    definitions: definitions
+      .iter()
+      .map(|(target, _, index)| (NodeIgnoringSpan::owned(target.clone()), *index))
+      .collect(),
+    typeofs,
+    origins: origins
+      .into_iter()
+      .map(|(key, value)| (key.clone(), value.clone()))
+      .collect(),
+    value_dependencies,
+  };
+  let replacements = definitions
+    .into_iter()
+    .map(|(target, replacement, _)| (NodeIgnoringSpan::owned(target), replacement))
+    .collect();
+
+  chain!(
+    as_folder(usage),
+    inline_globals2(
+      Default::default(),
+      Default::default(),
+      Arc::new(replacements),
+      Default::default(),
+    )
+  )
+}
+
+/// `X: { a: 1 }` defines `X.a` too, so `X.a` is replaced with `1` instead of `{ a: 1 }.a`.
+fn expand_object(
+  target: &Expr,
+  replacement: &Expr,
+  index: usize,
+  definitions: &mut Vec<(Expr, Expr, usize)>,
+) {
+  let object = match replacement {
+    Expr::Paren(ParenExpr { expr, .. }) => return expand_object(target, expr, index, definitions),
+    Expr::Object(object) => object,
+    _ => return,
+  };
+  // The properties could be overridden by spread elements or computed at runtime
+  let Some(props) = object
+    .props
     .iter()
-    .map(|(target, replacement)| {
-      let target = {
-        let fm = cm.new_source_file(FileName::Anon, target.clone());
-        parse_file_as_expr(
-          &fm,
-          Syntax::Es(EsConfig::default()),
-          rspack_core::EsVersion::EsNext,
-          None,
-          &mut vec![],
-        )
-        .unwrap_or_else(|_| panic!("builtins.define: Failed to parse {:?}", target))
-      };
-      let replacement = {
-        let fm = cm.new_source_file(FileName::Anon, replacement.clone());
-        parse_file_as_expr(
-          &fm,
-          Syntax::Es(EsConfig::default()),
-          rspack_core::EsVersion::EsNext,
-          None,
-          &mut vec![],
-        )
-        .unwrap_or_else(|_| panic!("builtins.define: Failed to parse {:?}", target))
-      };
-
-      (NodeIgnoringSpan::owned(*target), *replacement)
+    .map(|prop| match prop {
+      PropOrSpread::Prop(box Prop::KeyValue(prop)) => Some(prop),
+      _ => None,
     })
-    .collect::<AHashMap<_, _>>();
+    .collect::<Option<Vec<_>>>()
+  else {
+    return;
+  };
+  for KeyValueProp { key, value } in props {
+    let prop = match key {
+      PropName::Ident(ident) => MemberProp::Ident(ident.clone()),
+      PropName::Str(str) if Ident::verify_symbol(&str.value).is_ok() => {
+        MemberProp::Ident(Ident::new(str.value.clone(), DUMMY_SP))
+      }
+      PropName::Str(str) => MemberProp::Computed(ComputedPropName {
+        span: DUMMY_SP,
+        expr: Box::new(Expr::Lit(Lit::Str(str.clone()))),
+      }),
+      _ => continue,
+    };
+    let target = Expr::Member(MemberExpr {
+      span: DUMMY_SP,
+      obj: Box::new(target.clone()),
+      prop,
+    });
+    expand_object(&target, value, index, definitions);
+    definitions.push((target, *value.clone(), index));
+  }
+}
 
-  inline_globals2(
-    Default::default(),
-    Default::default(),
-    Arc::new(defs),
-    Default::default(),
-  )
+/// The result of `typeof` of the value, `None` if it's unknown at compile time.
+fn type_of(expr: &Expr) -> Option<&'static str> {
+  Some(match expr {
+    Expr::Paren(ParenExpr { expr, .. }) => return type_of(expr),
+    Expr::Lit(Lit::Str(_)) | Expr::Tpl(_) => "string",
+    Expr::Lit(Lit::Num(_)) => "number",
+    Expr::Lit(Lit::Bool(_)) => "boolean",
+    Expr::Lit(Lit::BigInt(_)) => "bigint",
+    Expr::Lit(Lit::Null(_) | Lit::Regex(_)) | Expr::Object(_) | Expr::Array(_) => "object",
+    Expr::Fn(_) | Expr::Arrow(_) | Expr::Class(_) => "function",
+    Expr::Ident(Ident {
+      sym: js_word!("undefined"),
+      ..
+    }) => "undefined",
+    Expr::Unary(UnaryExpr { op, arg, .. }) => match op {
+      op!("void") => "undefined",
+      op!("typeof") => "string",
+      op!("!") => "boolean",
+      op!(unary, "+") => "number",
+      op!(unary, "-") => match type_of(arg)? {
+        "bigint" => "bigint",
+        _ => "number",
+      },
+      _ => return None,
+    },
+    _ => return None,
+  })
+}
+
+/// Evaluate `typeof` of the definitions and record the used definitions, the definitions are
+/// replaced by `inline_globals2` later.
+struct DefinitionUsage<'a> {
+  unresolved_mark: Mark,
+  definitions: Definitions<usize>,
+  typeofs: Definitions<(JsWord, usize)>,
+  /// The keys and values of the definitions, indexed by the values of the maps above
+  origins: Vec<(String, String)>,
+  value_dependencies: &'a mut BTreeMap<String, String>,
+}
+
+impl DefinitionUsage<'_> {
+  /// The definitions are not applied to the local variables, e.g. `const X = 1; X`
+  fn is_global(&self, mut expr: &Expr) -> bool {
+    loop {
+      match expr {
+        Expr::Member(MemberExpr { obj, .. }) => expr = obj,
+        Expr::Ident(ident) => return ident.span.ctxt.outer() == self.unresolved_mark,
+        _ => return true,
+      }
+    }
+  }
+
+  fn get<T: Clone>(definitions: &Definitions<T>, expr: &Expr) -> Option<T> {
+    Ident::within_ignored_ctxt(|| definitions.get(&NodeIgnoringSpan::borrowed(expr)).cloned())
+  }
+
+  fn add_value_dependency(&mut self, index: usize) {
+    let (key, value) = &self.origins[index];
+    self.value_dependencies.insert(key.clone(), value.clone());
+  }
+}
+
+impl VisitMut for DefinitionUsage<'_> {
+  noop_visit_mut_type!();
+
+  fn visit_mut_expr(&mut self, expr: &mut Expr) {
+    if let Expr::Unary(UnaryExpr {
+      span,
+      op: op!("typeof"),
+      arg,
+    }) = expr
+      && self.is_global(arg)
+      && let Some((type_of, index)) = Self::get(&self.typeofs, arg)
+    {
+      self.add_value_dependency(index);
+      *expr = Expr::Lit(Lit::Str(Str {
+        span: *span,
+        value: type_of,
+        raw: None,
+      }));
+      return;
+    }
+
+    if self.is_global(expr)
+      && let Some(index) = Self::get(&self.definitions, expr)
+    {
+      self.add_value_dependency(index);
+      return;
+    }
+
+    expr.visit_mut_children_with(self);
+  }
 }
@@ -1,35 +1,53 @@
 use indexmap::IndexSet;
-use rspack_core::Provide;
+use rspack_core::{ModuleType, Provide};
 use swc_core::common::util::take::Take;
 use swc_core::common::Span;
 use swc_core::common::{Mark, DUMMY_SP};
 use swc_core::ecma::ast::{
-  BindingIdent, CallExpr, Callee, ComputedPropName, Expr, ExprOrSpread, Ident, Lit, MemberExpr,
-  MemberProp, ModuleItem, PropOrSpread, Stmt, Str, VarDecl, VarDeclarator,
+  BindingIdent, CallExpr, Callee, ComputedPropName, Expr, ExprOrSpread, Ident, ImportDecl,
+  ImportDefaultSpecifier, ImportNamedSpecifier, ImportSpecifier, ImportStarAsSpecifier, Lit,
+  MemberExpr, MemberProp, ModuleDecl, ModuleExportName, ModuleItem, PropOrSpread, Stmt, Str,
+  VarDecl, VarDeclarator,
 };
 use swc_core::ecma::visit::{as_folder, Fold, VisitMut, VisitMutWith};
 
-pub fn provide_builtin(opts: &Provide, unresolved_mark: Mark) -> impl Fold + '_ {
-  as_folder(ProvideBuiltin::new(opts, unresolved_mark))
+pub fn provide_builtin<'a>(
+  opts: &'a Provide,
+  unresolved_mark: Mark,
+  module_type: &'a ModuleType,
+) -> impl Fold + 'a {
+  as_folder(ProvideBuiltin::new(opts, unresolved_mark, module_type))
 }
 static SOURCE_DOT: &str = r#"."#;
 static MODULE_DOT: &str = r#"_dot_"#;
+static PROVIDED_PREFIX: &str = r#"__webpack_provided_"#;
 pub struct ProvideBuiltin<'a> {
   opts: &'a Provide,
   unresolved_mark: Mark,
+  module_type: &'a ModuleType,
   // the order should be stable to ensure the generated code is stable
   current_import_provide: IndexSet<String>,
 }
 
 impl<'a> ProvideBuiltin<'a> {
-  pub fn new(opts: &'a Provide, unresolved_mark: Mark) -> Self {
+  pub fn new(opts: &'a Provide, unresolved_mark: Mark, module_type: &'a ModuleType) -> Self {
     ProvideBuiltin {
       opts,
       unresolved_mark,
+      module_type,
       current_import_provide: IndexSet::new(),
     }
   }
 
+  fn is_strict_harmony_module(&self) -> bool {
+    matches!(self.module_type, ModuleType::JsEsm | ModuleType::JsxEsm)
+  }
+
+  fn is_harmony_module(&self, program: &swc_core::ecma::ast::Program) -> bool {
+    self.is_strict_harmony_module()
+      || matches!(program, swc_core::ecma::ast::Program::Module(module) if module.body.iter().any(|s| matches!(s, ModuleItem::ModuleDecl(_))))
+  }
+
   fn handle_ident(&mut self, ident: &Ident) {
     if ident.span.has_mark(self.unresolved_mark) && self.opts.get(&ident.sym.to_string()).is_some()
     {
@@ -38,6 +56,9 @@ impl<'a> ProvideBuiltin<'a> {
   }
 
   fn handle_member_expr(&mut self, member_expr: &MemberExpr) -> Option<Ident> {
+    if !self.is_unresolved_root(member_expr) {
+      return None;
+    }
     let identifier_name = ProvideBuiltin::get_nested_identifier_name(member_expr)?;
     if self.opts.get(&identifier_name).is_some() {
       self.current_import_provide.insert(identifier_name.clone());
@@ -50,6 +71,15 @@ impl<'a> ProvideBuiltin<'a> {
     None
   }
 
+  /// `Buffer.from` is only provided when `Buffer` is not declared in the module.
+  fn is_unresolved_root(&self, member_expr: &MemberExpr) -> bool {
+    match &*member_expr.obj {
+      Expr::Member(nested_member_expr) => self.is_unresolved_root(nested_member_expr),
+      Expr::Ident(ident) => ident.span.has_mark(self.unresolved_mark),
+      _ => true,
+    }
+  }
+
   fn get_nested_identifier_name(member_expr: &MemberExpr) -> Option<String> {
     let mut obj: String = match &*member_expr.obj {
       Expr::Member(nested_member_expr) => {
@@ -92,47 +122,132 @@ impl<'a> ProvideBuiltin<'a> {
             }],
             type_args: Default::default(),
           };
-          let mut obj_expr = Expr::Call(call);
-          // [""]
-          for provide_module_member in provide_module_path.iter().skip(1) {
-            let member_expr = MemberExpr {
-              span: DUMMY_SP.apply_mark(self.unresolved_mark),
-              obj: Box::new(obj_expr),
-              prop: MemberProp::Computed(ComputedPropName {
-                span: DUMMY_SP.apply_mark(self.unresolved_mark),
-                expr: Box::new(Expr::Lit(Lit::Str(Str {
-                  span: DUMMY_SP.apply_mark(self.unresolved_mark),
-                  value: provide_module_member.to_string().into(),
-                  raw: None,
-                }))),
-              }),
-            };
+          // var {provide_module_name} = require(provide_module_path)?[provide_args]
+          stmt_item_vec.push(self.create_var_decl(
+            provide_module_name.replace(SOURCE_DOT, MODULE_DOT),
+            self.create_member_chain(Expr::Call(call), provide_module_path.iter().skip(1)),
+          ));
+        }
+      });
+    stmt_item_vec
+  }
 
-            obj_expr = Expr::Member(member_expr);
+  fn create_provide_import(&self) -> Vec<ModuleItem> {
+    let mut import_item_vec = Vec::new();
+    let mut stmt_item_vec = Vec::new();
+    self
+      .current_import_provide
+      .iter()
+      .for_each(|provide_module_name| {
+        let Some(provide_module_path) = self.opts.get(provide_module_name) else {
+          return;
+        };
+        let local_name = provide_module_name.replace(SOURCE_DOT, MODULE_DOT);
+        let specifier = match provide_module_path.get(1) {
+          // import {provide_module_name} from "{provide_module_path}"
+          // a strict harmony module only sees the `module.exports` of a CommonJS module as default
+          None if self.is_strict_harmony_module() => {
+            ImportSpecifier::Default(ImportDefaultSpecifier {
+              span: DUMMY_SP.apply_mark(self.unresolved_mark),
+              local: self.create_ident(local_name),
+            })
           }
-          // var {provide_module_name} = require(provide_module_path)?[provide_args]
-          let stmt_item = Stmt::Decl(swc_core::ecma::ast::Decl::Var(Box::new(VarDecl {
+          // import * as {provide_module_name} from "{provide_module_path}"
+          None => ImportSpecifier::Namespace(ImportStarAsSpecifier {
             span: DUMMY_SP.apply_mark(self.unresolved_mark),
-            declare: false,
-            kind: swc_core::ecma::ast::VarDeclKind::Var,
-            decls: vec![VarDeclarator {
-              span: DUMMY_SP.apply_mark(self.unresolved_mark),
-              definite: false,
-              init: Some(Box::new(obj_expr)),
-              name: swc_core::ecma::ast::Pat::Ident(BindingIdent {
-                id: Ident::new(
-                  provide_module_name.replace(SOURCE_DOT, MODULE_DOT).into(),
-                  DUMMY_SP.apply_mark(self.unresolved_mark),
+            local: self.create_ident(local_name),
+          }),
+          // import { {provide_args[0]} as {provide_module_name} } from "{provide_module_path}"
+          Some(imported) => {
+            let local = if provide_module_path.len() > 2 {
+              let import_local_name = format!("{PROVIDED_PREFIX}{local_name}");
+              // var {provide_module_name} = __webpack_provided_{provide_module_name}[provide_args]
+              stmt_item_vec.push(self.create_var_decl(
+                local_name,
+                self.create_member_chain(
+                  Expr::Ident(self.create_ident(import_local_name.clone())),
+                  provide_module_path.iter().skip(2),
                 ),
-                type_ann: None,
-              }),
-            }],
-          })));
-
-          stmt_item_vec.push(stmt_item);
-        }
+              ));
+              import_local_name
+            } else {
+              local_name
+            };
+            let imported = if Ident::verify_symbol(imported).is_ok() || imported == "default" {
+              ModuleExportName::Ident(self.create_ident(imported.clone()))
+            } else {
+              ModuleExportName::Str(self.create_str(imported.clone()))
+            };
+            ImportSpecifier::Named(ImportNamedSpecifier {
+              span: DUMMY_SP.apply_mark(self.unresolved_mark),
+              local: self.create_ident(local),
+              imported: Some(imported),
+              is_type_only: false,
+            })
+          }
+        };
+        import_item_vec.push(ModuleItem::ModuleDecl(ModuleDecl::Import(ImportDecl {
+          span: DUMMY_SP.apply_mark(self.unresolved_mark),
+          specifiers: vec![specifier],
+          src: Box::new(self.create_str(provide_module_path[0].clone())),
+          type_only: false,
+          asserts: None,
+        })));
       });
-    stmt_item_vec
+    import_item_vec.extend(stmt_item_vec.into_iter().map(ModuleItem::Stmt));
+    import_item_vec
+  }
+
+  fn create_member_chain<'b>(
+    &self,
+    mut obj_expr: Expr,
+    members: impl Iterator<Item = &'b String>,
+  ) -> Expr {
+    // [""]
+    for provide_module_member in members {
+      let member_expr = MemberExpr {
+        span: DUMMY_SP.apply_mark(self.unresolved_mark),
+        obj: Box::new(obj_expr),
+        prop: MemberProp::Computed(ComputedPropName {
+          span: DUMMY_SP.apply_mark(self.unresolved_mark),
+          expr: Box::new(Expr::Lit(Lit::Str(
+            self.create_str(provide_module_member.to_string()),
+          ))),
+        }),
+      };
+
+      obj_expr = Expr::Member(member_expr);
+    }
+    obj_expr
+  }
+
+  fn create_var_decl(&self, name: String, init: Expr) -> Stmt {
+    Stmt::Decl(swc_core::ecma::ast::Decl::Var(Box::new(VarDecl {
+      span: DUMMY_SP.apply_mark(self.unresolved_mark),
+      declare: false,
+      kind: swc_core::ecma::ast::VarDeclKind::Var,
+      decls: vec![VarDeclarator {
+        span: DUMMY_SP.apply_mark(self.unresolved_mark),
+        definite: false,
+        init: Some(Box::new(init)),
+        name: swc_core::ecma::ast::Pat::Ident(BindingIdent {
+          id: self.create_ident(name),
+          type_ann: None,
+        }),
+      }],
+    })))
+  }
+
+  fn create_ident(&self, sym: String) -> Ident {
+    Ident::new(sym.into(), DUMMY_SP.apply_mark(self.unresolved_mark))
+  }
+
+  fn create_str(&self, value: String) -> Str {
+    Str {
+      span: DUMMY_SP.apply_mark(self.unresolved_mark),
+      value: value.into(),
+      raw: None,
+    }
   }
 }
 
@@ -162,11 +277,22 @@ impl VisitMut for ProvideBuiltin<'_> {
 
   fn visit_mut_program(&mut self, program: &mut swc_core::ecma::ast::Program) {
     program.visit_mut_children_with(self);
-    let mut stmt_vec = self.create_provide_require();
+    let is_harmony_module = self.is_harmony_module(program);
 
     match program {
+      // harmony modules import provided modules, so that the exports type is kept
+      swc_core::ecma::ast::Program::Module(module) if is_harmony_module => {
+        let new_body = self
+          .create_provide_import()
+          .into_iter()
+          .chain(module.body.take())
+          .collect();
+
+        module.body = new_body;
+      }
       swc_core::ecma::ast::Program::Module(module) => {
-        let new_body = stmt_vec
+        let new_body = self
+          .create_provide_require()
           .into_iter()
           .map(ModuleItem::Stmt)
           .chain(module.body.take())
@@ -175,6 +301,7 @@ impl VisitMut for ProvideBuiltin<'_> {
         module.body = new_body;
       }
       swc_core::ecma::ast::Program::Script(script) => {
+        let mut stmt_vec = self.create_provide_require();
         stmt_vec.extend(script.body.take());
         script.body = stmt_vec;
       }
@@ -103,47 +103,14 @@ assert.deepStrictEqual({
     }
 });
 assert.deepStrictEqual({
-    UNDEFINED: undefined,
-    REGEXP: /def/i,
-    STR: "string",
-    OBJ: {
-        NUM: 1
-    }
-}.OBJ, {
+    NUM: 1
+}, {
     NUM: 1
 });
-assert.deepStrictEqual({
-    UNDEFINED: undefined,
-    REGEXP: /def/i,
-    STR: "string",
-    OBJ: {
-        NUM: 1
-    }
-}.OBJ.NUM, 1);
-assert.deepStrictEqual({
-    UNDEFINED: undefined,
-    REGEXP: /def/i,
-    STR: "string",
-    OBJ: {
-        NUM: 1
-    }
-}.UNDEFINED, undefined);
-assert.deepStrictEqual({
-    UNDEFINED: undefined,
-    REGEXP: /def/i,
-    STR: "string",
-    OBJ: {
-        NUM: 1
-    }
-}.REGEXP, /def/i);
-assert.deepStrictEqual({
-    UNDEFINED: undefined,
-    REGEXP: /def/i,
-    STR: "string",
-    OBJ: {
-        NUM: 1
-    }
-}.STR, "string");
+assert.deepStrictEqual(1, 1);
+assert.deepStrictEqual(undefined, undefined);
+assert.deepStrictEqual(/def/i, /def/i);
+assert.deepStrictEqual("string", "string");
 assert.deepStrictEqual({
     UNDEFINED: undefined,
     REGEXP: /def/i,
@@ -0,0 +1,2 @@
+const a = "b";
+export { a as b };
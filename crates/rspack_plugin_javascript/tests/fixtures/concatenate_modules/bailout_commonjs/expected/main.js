@@ -0,0 +1,23 @@
+(self['webpackChunkwebpack'] = self['webpackChunkwebpack'] || []).push([["main"], {
+"./a.js": function (module, exports, __webpack_require__) {
+module.exports.a = "a";
+},
+"./index.js": function (__unused_webpack_module, __webpack_exports__, __webpack_require__) {
+"use strict";
+__webpack_require__.r(__webpack_exports__);
+
+// CONCATENATED MODULE: ./b.js
+const a = "b";
+
+// CONCATENATED MODULE: ./index.js
+/* harmony import */ var _a__WEBPACK_IMPORTED_MODULE_0_ = __webpack_require__(/* ./a */ "./a.js");
+/* harmony import */ var _a__WEBPACK_IMPORTED_MODULE_0__default = /*#__PURE__*/ __webpack_require__.n(_a__WEBPACK_IMPORTED_MODULE_0_);
+console.log(_a__WEBPACK_IMPORTED_MODULE_0_["a"], a);
+},
+
+},function(__webpack_require__) {
+var __webpack_exec__ = function(moduleId) { return __webpack_require__(__webpack_require__.s = moduleId) }
+var __webpack_exports__ = (__webpack_exec__("./index.js"));
+
+}
+]);
\ No newline at end of file
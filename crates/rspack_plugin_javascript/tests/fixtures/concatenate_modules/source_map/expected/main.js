@@ -0,0 +1,22 @@
+(self['webpackChunkwebpack'] = self['webpackChunkwebpack'] || []).push([["main"], {
+"./index.js": function (__unused_webpack_module, __webpack_exports__, __webpack_require__) {
+"use strict";
+__webpack_require__.r(__webpack_exports__);
+
+// CONCATENATED MODULE: ./a.js
+const a_a = "a";
+
+// CONCATENATED MODULE: ./b.js
+const a_b = "b";
+
+// CONCATENATED MODULE: ./index.js
+console.log(a_a, a_b);
+},
+
+},function(__webpack_require__) {
+var __webpack_exec__ = function(moduleId) { return __webpack_require__(__webpack_require__.s = moduleId) }
+var __webpack_exports__ = (__webpack_exec__("./index.js"));
+
+}
+]);
+//# sourceMappingURL=main.js.map
\ No newline at end of file
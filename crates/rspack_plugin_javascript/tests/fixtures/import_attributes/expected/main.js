@@ -0,0 +1,19 @@
+(self['webpackChunkwebpack'] = self['webpackChunkwebpack'] || []).push([["main"], {
+"./index.js": function (__unused_webpack_module, __webpack_exports__, __webpack_require__) {
+'use strict';
+__webpack_require__.r(__webpack_exports__);
+/* harmony import */var _data_json__WEBPACK_IMPORTED_MODULE_0_ = __webpack_require__(/* ./data.json */"./data.json");
+
+
+console.log(_data_json__WEBPACK_IMPORTED_MODULE_0_.name, _data_json__WEBPACK_IMPORTED_MODULE_0_.name);
+},
+"./data.json": function (module, exports, __webpack_require__) {
+module.exports = { "name": "data" }
+;},
+
+},function(__webpack_require__) {
+var __webpack_exec__ = function(moduleId) { return __webpack_require__(__webpack_require__.s = moduleId) }
+var __webpack_exports__ = (__webpack_exec__("./index.js"));
+
+}
+]);
\ No newline at end of file
@@ -0,0 +1,4 @@
+import withData from "./data.json" with { type: "json" };
+import assertData from "./data.json" assert { type: "json" };
+
+console.log(withData.name, assertData.name);
@@ -11,7 +11,7 @@ use rspack_core::{
 };
 use rspack_error::internal_error;
 
-use crate::utils::property_access;
+use crate::utils::{get_options_for_chunk, property_access};
 
 const COMMON_LIBRARY_NAME_MESSAGE: &str = "Common configuration options that specific library names are 'output.library[.name]', 'entry.xyz.library[.name]', 'ModuleFederationPlugin.name' and 'ModuleFederationPlugin.library[.name]'.";
 
@@ -48,8 +48,18 @@ impl AssignLibraryPlugin {
     Self { options }
   }
 
+  /// The library options of the chunk, `None` if the chunk is not exposed by a library of this type.
+  fn parse_options<'a>(
+    &self,
+    compilation: &'a Compilation,
+    chunk: &'a Chunk,
+  ) -> Option<&'a LibraryOptions> {
+    get_options_for_chunk(compilation, chunk)
+      .filter(|library| library.library_type == self.options.library_type)
+  }
+
   pub fn get_resolved_full_name(&self, compilation: &Compilation, chunk: &Chunk) -> Vec<String> {
-    if let Some(library) = &compilation.options.output.library {
+    if let Some(library) = get_options_for_chunk(compilation, chunk) {
       if let Some(name) = &library.name {
         if let Some(root) = &name.root {
           let mut prefix = self.options.prefix.clone();
@@ -83,6 +93,9 @@ impl Plugin for AssignLibraryPlugin {
   }
 
   fn render(&self, _ctx: PluginContext, args: &RenderArgs) -> PluginRenderHookOutput {
+    if self.parse_options(args.compilation, args.chunk()).is_none() {
+      return Ok(None);
+    }
     if self.options.declare {
       let base = &self.get_resolved_full_name(args.compilation, args.chunk())[0];
       if !is_name_valid(base) {
@@ -104,18 +117,15 @@ impl Plugin for AssignLibraryPlugin {
     _ctx: PluginContext,
     args: &RenderStartupArgs,
   ) -> PluginRenderStartupHookOutput {
+    let Some(library) = self.parse_options(args.compilation, args.chunk()) else {
+      return Ok(None);
+    };
     let mut source = ConcatSource::default();
     source.add(args.source.clone());
-    // TODO: respect entryOptions.library
-    let library = &args.compilation.options.output.library;
-    let is_copy = if let Some(library) = library {
-      if library.name.is_some() {
-        matches!(self.options.named, Some(Named::Copy))
-      } else {
-        matches!(self.options.unnamed, Unnamed::Copy)
-      }
+    let is_copy = if library.name.is_some() {
+      matches!(self.options.named, Some(Named::Copy))
     } else {
-      false
+      matches!(self.options.unnamed, Unnamed::Copy)
     };
     let full_name_resolved = self.get_resolved_full_name(args.compilation, args.chunk());
     let export_access = property_library(library);
@@ -170,24 +180,20 @@ impl Plugin for AssignLibraryPlugin {
     args: &mut JsChunkHashArgs,
   ) -> PluginJsChunkHashHookOutput {
     self.name().hash(&mut args.hasher);
-    args
-      .compilation
-      .options
-      .output
-      .library
-      .hash(&mut args.hasher);
+    let compilation = args.compilation;
+    let chunk = compilation.chunk_by_ukey.expect_get(args.chunk_ukey);
+    get_options_for_chunk(compilation, chunk).hash(&mut args.hasher);
     Ok(())
   }
 }
 
 #[inline]
-fn property_library(library: &Option<LibraryOptions>) -> String {
-  if let Some(library) = library {
-    if let Some(export) = &library.export {
-      return property_access(export);
-    }
-  }
-  String::default()
+fn property_library(library: &LibraryOptions) -> String {
+  library
+    .export
+    .as_ref()
+    .map(property_access)
+    .unwrap_or_default()
 }
 
 fn access_with_init(accessor: &Vec<String>, existing_length: usize, init_last: bool) -> String {
@@ -50,7 +50,7 @@ impl Plugin for SystemLibraryPlugin {
           .and_then(|m| (m.get_external_type() == "system").then_some(m))
       })
       .collect::<Vec<&ExternalModule>>();
-    let external_deps_array = external_system_dep_array(&modules);
+    let external_deps_array = external_system_dep_array(&modules)?;
     let external_arguments = external_module_names(&modules, compilation);
 
     // The name of the variable provided by System for exporting
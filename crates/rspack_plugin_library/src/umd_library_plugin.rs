@@ -8,7 +8,10 @@ use rspack_core::{
   PluginRenderHookOutput, RenderArgs, RuntimeGlobals, SourceType,
 };
 
-use super::utils::{external_arguments, external_dep_array};
+use super::utils::{
+  accessor_to_object_access, external_arguments, external_dep_array, external_root_array,
+  externals_require_array,
+};
 
 #[derive(Debug)]
 pub struct UmdLibraryPlugin {
@@ -84,12 +87,12 @@ impl Plugin for UmdLibraryPlugin {
     let define = if let (Some(amd), Some(_)) = &(amd, umd_named_define) {
       format!(
         "define({amd}, {}, {amd_factory});\n",
-        external_dep_array(&required_externals)
+        external_dep_array(&required_externals)?
       )
     } else {
       format!(
         "define({}, {amd_factory});\n",
-        external_dep_array(&required_externals)
+        external_dep_array(&required_externals)?
       )
     };
 
@@ -105,7 +108,7 @@ impl Plugin for UmdLibraryPlugin {
             .clone()
             .map(|root| library_name(&root, chunk, compilation)))
           .unwrap_or_default(),
-        externals_require_array("commonjs", &externals),
+        externals_require_array("commonjs", &externals)?,
       );
       let root_code = format!(
         "{}
@@ -122,7 +125,7 @@ impl Plugin for UmdLibraryPlugin {
           chunk,
           compilation,
         ),
-        external_root_array(&externals)
+        external_root_array(&externals)?
       );
       format!(
         "}} else if(typeof exports === 'object'){{\n
@@ -137,8 +140,8 @@ impl Plugin for UmdLibraryPlugin {
       } else {
         format!(
           "var a = typeof exports === 'object' ? factory({}) : factory({});\n",
-          externals_require_array("commonjs", &externals),
-          external_root_array(&externals)
+          externals_require_array("commonjs", &externals)?,
+          external_root_array(&externals)?
         )
       };
       format!(
@@ -159,7 +162,7 @@ impl Plugin for UmdLibraryPlugin {
             module.exports = factory({});
         }}"#,
       get_auxiliary_comment("commonjs2", auxiliary_comment),
-      externals_require_array("commonjs2", &externals)
+      externals_require_array("commonjs2", &externals)?
     )));
     source.add(RawSource::from(format!(
       "else if(typeof define === 'function' && define.amd) {{
@@ -211,40 +214,6 @@ fn replace_keys(v: String, chunk: &Chunk, compilation: &Compilation) -> String {
   )
 }
 
-fn externals_require_array(_t: &str, externals: &[&ExternalModule]) -> String {
-  externals
-    .iter()
-    .map(|m| {
-      let request = &m.request;
-      // TODO: check if external module is optional
-      format!("require('{}')", request.as_str())
-    })
-    .collect::<Vec<_>>()
-    .join(", ")
-}
-
-fn external_root_array(modules: &[&ExternalModule]) -> String {
-  modules
-    .iter()
-    .map(|m| {
-      let request = &m.request;
-      format!(
-        "root{}",
-        accessor_to_object_access(&[request.as_str().to_owned()])
-      )
-    })
-    .collect::<Vec<_>>()
-    .join(", ")
-}
-
-fn accessor_to_object_access(accessor: &[String]) -> String {
-  accessor
-    .iter()
-    .map(|s| format!("['{s}']"))
-    .collect::<Vec<_>>()
-    .join("")
-}
-
 fn accessor_access(base: Option<&str>, accessor: &Vec<String>) -> String {
   accessor
     .iter()
@@ -1,30 +1,88 @@
-use rspack_core::{to_identifier, Compilation, ExternalModule, LibraryName, LibraryOptions};
+use rspack_core::{
+  to_identifier, Chunk, Compilation, ExternalModule, ExternalRequestValue, LibraryName,
+  LibraryOptions,
+};
 use rspack_error::Result;
 use rspack_identifier::Identifiable;
 
-pub fn external_dep_array(modules: &[&ExternalModule]) -> String {
+/// The request of the external module for the given type, externals in the object form
+/// like `{ root: "React", amd: "react" }` resolve to the entry of the type.
+fn external_request<'a>(m: &'a ExternalModule, t: &str) -> Result<&'a ExternalRequestValue> {
+  m.request.for_type(t)
+}
+
+pub fn external_dep_array(modules: &[&ExternalModule]) -> Result<String> {
   let value = modules
     .iter()
-    .map(|m| serde_json::to_string(&m.request.as_str()).expect("invalid json to_string"))
-    .collect::<Vec<_>>()
+    .map(|m| {
+      Ok(
+        serde_json::to_string(external_request(m, "amd")?.as_str())
+          .expect("invalid json to_string"),
+      )
+    })
+    .collect::<Result<Vec<_>>>()?
     .join(", ");
-  format!("[{value}]")
+  Ok(format!("[{value}]"))
 }
 
-pub fn external_system_dep_array(modules: &[&ExternalModule]) -> String {
+pub fn external_system_dep_array(modules: &[&ExternalModule]) -> Result<String> {
   let value = modules
     .iter()
     .map(|m| {
-      m.request
-        .0
-        .iter()
-        .map(|r| format!("\"{r}\""))
-        .collect::<Vec<_>>()
-        .join(",")
+      Ok(
+        external_request(m, "amd")?
+          .as_array()
+          .iter()
+          .map(|r| format!("\"{r}\""))
+          .collect::<Vec<_>>()
+          .join(","),
+      )
     })
-    .collect::<Vec<_>>()
+    .collect::<Result<Vec<_>>>()?
     .join(", ");
-  format!("[{value}]")
+  Ok(format!("[{value}]"))
+}
+
+pub fn externals_require_array(t: &str, externals: &[&ExternalModule]) -> Result<String> {
+  Ok(
+    externals
+      .iter()
+      .map(|m| {
+        let request = external_request(m, t)?.as_array();
+        // TODO: check if external module is optional
+        Ok(format!(
+          "require('{}'){}",
+          request[0],
+          accessor_to_object_access(&request[1..])
+        ))
+      })
+      .collect::<Result<Vec<_>>>()?
+      .join(", "),
+  )
+}
+
+pub fn external_root_array(modules: &[&ExternalModule]) -> Result<String> {
+  Ok(
+    modules
+      .iter()
+      .map(|m| {
+        let request = external_request(m, "root")?;
+        Ok(format!(
+          "root{}",
+          accessor_to_object_access(request.as_array())
+        ))
+      })
+      .collect::<Result<Vec<_>>>()?
+      .join(", "),
+  )
+}
+
+pub fn accessor_to_object_access(accessor: &[String]) -> String {
+  accessor
+    .iter()
+    .map(|s| format!("['{s}']"))
+    .collect::<Vec<_>>()
+    .join("")
 }
 
 fn inner_external_arguments(modules: &[&ExternalModule], compilation: &Compilation) -> Vec<String> {
@@ -79,3 +137,14 @@ pub fn property_access(o: &Vec<String>) -> String {
   }
   str
 }
+
+/// The library options of the entry of the chunk, falls back to `output.library`.
+pub fn get_options_for_chunk<'a>(
+  compilation: &'a Compilation,
+  chunk: &'a Chunk,
+) -> Option<&'a LibraryOptions> {
+  chunk
+    .get_entry_options(&compilation.chunk_group_by_ukey)
+    .and_then(|options| options.library.as_ref())
+    .or(compilation.options.output.library.as_ref())
+}
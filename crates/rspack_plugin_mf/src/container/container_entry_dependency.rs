@@ -0,0 +1,59 @@
+use rspack_core::{
+  Dependency, DependencyCategory, DependencyId, DependencyType, ErrorSpan, ModuleDependency,
+};
+
+use crate::ExposeOptions;
+
+#[derive(Debug, Clone)]
+pub struct ContainerEntryDependency {
+  id: DependencyId,
+  pub name: String,
+  pub exposes: Vec<(String, ExposeOptions)>,
+  pub share_scope: String,
+  resource_identifier: String,
+}
+
+impl ContainerEntryDependency {
+  pub fn new(name: String, exposes: Vec<(String, ExposeOptions)>, share_scope: String) -> Self {
+    let resource_identifier = format!("container-entry-{name}");
+    Self {
+      id: DependencyId::new(),
+      name,
+      exposes,
+      share_scope,
+      resource_identifier,
+    }
+  }
+}
+
+impl Dependency for ContainerEntryDependency {
+  fn category(&self) -> &DependencyCategory {
+    &DependencyCategory::Esm
+  }
+
+  fn dependency_type(&self) -> &DependencyType {
+    &DependencyType::ContainerEntry
+  }
+}
+
+impl ModuleDependency for ContainerEntryDependency {
+  fn id(&self) -> &DependencyId {
+    &self.id
+  }
+
+  fn request(&self) -> &str {
+    &self.resource_identifier
+  }
+
+  fn user_request(&self) -> &str {
+    &self.resource_identifier
+  }
+
+  fn span(&self) -> Option<&ErrorSpan> {
+    None
+  }
+
+  fn set_request(&mut self, request: String) {
+    self.resource_identifier = request;
+  }
+}
@@ -0,0 +1,267 @@
+use std::borrow::Cow;
+use std::hash::Hash;
+
+use rspack_core::{
+  block_promise, module_raw,
+  rspack_sources::{RawSource, Source, SourceExt},
+  throw_missing_module_error_block, AstOrSource, BuildContext, BuildInfo, BuildResult,
+  CodeGenerationResult, Compilation, Context, GenerationResult, LibIdentOptions, Module,
+  ModuleDependency, ModuleType, RuntimeGlobals, SourceType,
+};
+use rspack_error::{internal_error, IntoTWithDiagnosticArray, Result, TWithDiagnosticArray};
+use rspack_hash::RspackHash;
+use rspack_identifier::{Identifiable, Identifier};
+
+use crate::{ContainerExposedDependency, ExposeOptions};
+
+static CONTAINER_ENTRY_MODULE_SOURCE_TYPES: &[SourceType] = &[SourceType::JavaScript];
+
+#[derive(Debug)]
+pub struct ContainerEntryModule {
+  id: Identifier,
+  name: String,
+  exposes: Vec<(String, ExposeOptions)>,
+  share_scope: String,
+}
+
+impl ContainerEntryModule {
+  pub fn new(name: String, exposes: Vec<(String, ExposeOptions)>, share_scope: String) -> Self {
+    Self {
+      id: Identifier::from(format!("container entry ({share_scope}) {exposes:?}")),
+      name,
+      exposes,
+      share_scope,
+    }
+  }
+
+  /// The getter of each exposed request, which loads the chunks of the exposed modules and
+  /// returns a factory of the module exports.
+  fn get_module_map(
+    &self,
+    compilation: &Compilation,
+    runtime_requirements: &mut RuntimeGlobals,
+  ) -> Result<Vec<String>> {
+    let module_graph = &compilation.module_graph;
+    let mgm = module_graph
+      .module_graph_module_by_identifier(&self.identifier())
+      .ok_or_else(|| internal_error!("Failed to get container entry module {}", self.id))?;
+    let exposed_dependencies = mgm
+      .dependencies
+      .iter()
+      .filter_map(|dependency_id| module_graph.dependency_by_id(dependency_id))
+      .filter_map(|dependency| {
+        (**dependency)
+          .as_any()
+          .downcast_ref::<ContainerExposedDependency>()
+      })
+      .collect::<Vec<_>>();
+
+    let mut getters = vec![];
+    for (exposed_name, _) in &self.exposes {
+      let dependencies = exposed_dependencies
+        .iter()
+        .filter(|dependency| &dependency.exposed_name == exposed_name)
+        .collect::<Vec<_>>();
+      let module_ids = dependencies
+        .iter()
+        .map(|dependency| {
+          module_graph
+            .module_identifier_by_dependency_id(dependency.id())
+            .and_then(|module_identifier| {
+              compilation
+                .chunk_graph
+                .get_module_id(*module_identifier)
+                .as_deref()
+            })
+        })
+        .collect::<Option<Vec<_>>>();
+      let body = match module_ids {
+        Some(module_ids) if !module_ids.is_empty() => {
+          let promises = module_ids
+            .iter()
+            .map(|module_id| {
+              Ok(block_promise(
+                &serde_json::to_string(module_id).map_err(|e| internal_error!(e.to_string()))?,
+                runtime_requirements,
+              ))
+            })
+            .collect::<Result<Vec<_>>>()?;
+          let modules = dependencies
+            .iter()
+            .map(|dependency| {
+              module_raw(
+                compilation,
+                runtime_requirements,
+                dependency.id(),
+                dependency.user_request(),
+                false,
+              )
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+          format!(
+            "return Promise.all([{}]).then(function() {{ return function() {{ return ({modules}); }}; }});",
+            promises.join(", ")
+          )
+        }
+        _ => throw_missing_module_error_block(
+          &dependencies
+            .iter()
+            .map(|dependency| dependency.user_request())
+            .collect::<Vec<_>>()
+            .join(", "),
+        ),
+      };
+      getters.push(format!(
+        "{}: function() {{\n{body}\n}}",
+        serde_json::to_string(exposed_name).map_err(|e| internal_error!(e.to_string()))?
+      ));
+    }
+    Ok(getters)
+  }
+}
+
+impl Identifiable for ContainerEntryModule {
+  fn identifier(&self) -> Identifier {
+    self.id
+  }
+}
+
+#[async_trait::async_trait]
+impl Module for ContainerEntryModule {
+  fn module_type(&self) -> &ModuleType {
+    &ModuleType::Js
+  }
+
+  fn source_types(&self) -> &[SourceType] {
+    CONTAINER_ENTRY_MODULE_SOURCE_TYPES
+  }
+
+  fn original_source(&self) -> Option<&dyn Source> {
+    None
+  }
+
+  fn readable_identifier(&self, _context: &Context) -> Cow<str> {
+    Cow::Borrowed("container entry")
+  }
+
+  fn size(&self, _source_type: &SourceType) -> f64 {
+    // copied from webpack `ContainerEntryModule`
+    42.0
+  }
+
+  async fn build(
+    &mut self,
+    build_context: BuildContext<'_>,
+  ) -> Result<TWithDiagnosticArray<BuildResult>> {
+    let mut hasher = RspackHash::from(&build_context.compiler_options.output);
+    self.update_hash(&mut hasher);
+
+    let build_info = BuildInfo {
+      hash: Some(hasher.digest(&build_context.compiler_options.output.hash_digest)),
+      cacheable: true,
+      strict: true,
+      ..Default::default()
+    };
+
+    let mut dependencies: Vec<Box<dyn ModuleDependency>> = vec![];
+    for (exposed_name, options) in &self.exposes {
+      for request in &options.import {
+        dependencies.push(Box::new(ContainerExposedDependency::new(
+          exposed_name.clone(),
+          request.clone(),
+          options.name.clone(),
+        )));
+      }
+    }
+
+    Ok(
+      BuildResult {
+        build_info,
+        build_meta: Default::default(),
+        dependencies,
+      }
+      .with_empty_diagnostic(),
+    )
+  }
+
+  fn code_generation(&self, compilation: &Compilation) -> Result<CodeGenerationResult> {
+    let mut runtime_requirements = RuntimeGlobals::DEFINE_PROPERTY_GETTERS
+      | RuntimeGlobals::HAS_OWN_PROPERTY
+      | RuntimeGlobals::CURRENT_REMOTE_GET_SCOPE
+      | RuntimeGlobals::SHARE_SCOPE_MAP
+      | RuntimeGlobals::INITIALIZE_SHARING;
+    let getters = self.get_module_map(compilation, &mut runtime_requirements)?;
+    let source = format!(
+      r#"var moduleMap = {{
+{module_map}
+}};
+var get = function(module, getScope) {{
+  {current_remote_get_scope} = getScope;
+  getScope = (
+    {has_own_property}(moduleMap, module)
+      ? moduleMap[module]()
+      : Promise.resolve().then(function() {{
+        throw new Error('Module "' + module + '" does not exist in container.');
+      }})
+  );
+  {current_remote_get_scope} = undefined;
+  return getScope;
+}};
+var init = function(shareScope, initScope) {{
+  if (!{share_scope_map}) return;
+  var name = {share_scope};
+  var oldScope = {share_scope_map}[name];
+  if (oldScope && oldScope !== shareScope) throw new Error("Container initialization failed as it has already been initialized with a different share scope");
+  {share_scope_map}[name] = shareScope;
+  return {initialize_sharing}(name, initScope);
+}};
+
+// This exports getters to disallow modifications
+{define_property_getters}(exports, {{
+  get: function() {{ return get; }},
+  init: function() {{ return init; }}
+}});"#,
+      module_map = getters.join(",\n"),
+      current_remote_get_scope = RuntimeGlobals::CURRENT_REMOTE_GET_SCOPE,
+      has_own_property = RuntimeGlobals::HAS_OWN_PROPERTY,
+      share_scope_map = RuntimeGlobals::SHARE_SCOPE_MAP,
+      share_scope =
+        serde_json::to_string(&self.share_scope).map_err(|e| internal_error!(e.to_string()))?,
+      initialize_sharing = RuntimeGlobals::INITIALIZE_SHARING,
+      define_property_getters = RuntimeGlobals::DEFINE_PROPERTY_GETTERS,
+    );
+
+    let mut cgr = CodeGenerationResult::default();
+    cgr.add(
+      SourceType::JavaScript,
+      GenerationResult::from(AstOrSource::from(RawSource::from(source).boxed())),
+    );
+    cgr.runtime_requirements.insert(runtime_requirements);
+    cgr.set_hash(
+      &compilation.options.output.hash_function,
+      &compilation.options.output.hash_digest,
+      &compilation.options.output.hash_salt,
+    );
+    Ok(cgr)
+  }
+
+  fn lib_ident(&self, _options: LibIdentOptions) -> Option<Cow<str>> {
+    Some(Cow::Owned(format!("webpack/container/entry/{}", self.name)))
+  }
+}
+
+impl Hash for ContainerEntryModule {
+  fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+    "__rspack_internal__ContainerEntryModule".hash(state);
+    self.identifier().hash(state);
+  }
+}
+
+impl PartialEq for ContainerEntryModule {
+  fn eq(&self, other: &Self) -> bool {
+    self.identifier() == other.identifier()
+  }
+}
+
+impl Eq for ContainerEntryModule {}
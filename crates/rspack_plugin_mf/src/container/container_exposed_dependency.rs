@@ -0,0 +1,60 @@
+use rspack_core::{
+  ChunkGroupOptions, Dependency, DependencyCategory, DependencyId, DependencyType, ErrorSpan,
+  ModuleDependency,
+};
+
+#[derive(Debug, Clone)]
+pub struct ContainerExposedDependency {
+  id: DependencyId,
+  request: String,
+  /// The key in `exposes`, e.g. `./Button`
+  pub exposed_name: String,
+  group_options: ChunkGroupOptions,
+}
+
+impl ContainerExposedDependency {
+  pub fn new(exposed_name: String, request: String, name: Option<String>) -> Self {
+    Self {
+      id: DependencyId::new(),
+      request,
+      exposed_name,
+      group_options: ChunkGroupOptions::default().name_optional(name),
+    }
+  }
+}
+
+impl Dependency for ContainerExposedDependency {
+  fn category(&self) -> &DependencyCategory {
+    &DependencyCategory::Esm
+  }
+
+  fn dependency_type(&self) -> &DependencyType {
+    &DependencyType::ContainerExposed
+  }
+}
+
+impl ModuleDependency for ContainerExposedDependency {
+  fn id(&self) -> &DependencyId {
+    &self.id
+  }
+
+  fn request(&self) -> &str {
+    &self.request
+  }
+
+  fn user_request(&self) -> &str {
+    &self.request
+  }
+
+  fn span(&self) -> Option<&ErrorSpan> {
+    None
+  }
+
+  fn set_request(&mut self, request: String) {
+    self.request = request;
+  }
+
+  fn group_options(&self) -> Option<&ChunkGroupOptions> {
+    Some(&self.group_options)
+  }
+}
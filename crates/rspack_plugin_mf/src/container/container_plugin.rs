@@ -0,0 +1,99 @@
+use rspack_core::{
+  Compilation, EntryOptions, FactorizeArgs, Filename, LibraryOptions, MakeParam, ModuleDependency,
+  ModuleExt, ModuleFactoryResult, NormalModuleFactoryContext, Plugin, PluginContext,
+  PluginFactorizeHookOutput, PluginMakeHookOutput,
+};
+
+use crate::{ContainerEntryDependency, ContainerEntryModule};
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct ExposeOptions {
+  /// Name of the chunk of the exposed module
+  pub name: Option<String>,
+  pub import: Vec<String>,
+}
+
+#[derive(Debug)]
+pub struct ContainerPluginOptions {
+  /// Name of the container entry
+  pub name: String,
+  pub share_scope: String,
+  pub library: LibraryOptions,
+  pub runtime: Option<String>,
+  pub filename: Option<Filename>,
+  /// Exposed requests, in the order of the config
+  pub exposes: Vec<(String, ExposeOptions)>,
+}
+
+// Align with https://github.com/webpack/webpack/blob/4b4ca3bb53f36a5b8fc6bc1bd976ed7af161bd80/lib/container/ContainerPlugin.js
+/// Adds a container entry which exposes modules to other builds through `get` and `init`.
+#[derive(Debug)]
+pub struct ContainerPlugin {
+  options: ContainerPluginOptions,
+}
+
+impl ContainerPlugin {
+  pub fn new(options: ContainerPluginOptions) -> Self {
+    Self { options }
+  }
+}
+
+#[async_trait::async_trait]
+impl Plugin for ContainerPlugin {
+  fn name(&self) -> &'static str {
+    "ContainerPlugin"
+  }
+
+  async fn make(
+    &self,
+    _ctx: PluginContext,
+    compilation: &mut Compilation,
+    param: &mut MakeParam,
+  ) -> PluginMakeHookOutput {
+    if let Some(state) = compilation.options.get_incremental_rebuild_make_state() && !state.is_first() {
+      return Ok(());
+    }
+    let dependency = Box::new(ContainerEntryDependency::new(
+      self.options.name.clone(),
+      self.options.exposes.clone(),
+      self.options.share_scope.clone(),
+    ));
+    let dependency_id = dependency.id();
+    compilation.add_entry(
+      *dependency_id,
+      self.options.name.clone(),
+      EntryOptions {
+        runtime: self.options.runtime.clone(),
+        filename: self.options.filename.clone(),
+        library: Some(self.options.library.clone()),
+        ..Default::default()
+      },
+    );
+    param.add_force_build_dependency(*dependency_id, None);
+    compilation.module_graph.add_dependency(dependency);
+    Ok(())
+  }
+
+  async fn factorize(
+    &self,
+    _ctx: PluginContext,
+    args: FactorizeArgs<'_>,
+    _job_ctx: &mut NormalModuleFactoryContext,
+  ) -> PluginFactorizeHookOutput {
+    let Some(dependency) = args
+      .dependency
+      .as_any()
+      .downcast_ref::<ContainerEntryDependency>()
+    else {
+      return Ok(None);
+    };
+    Ok(Some(ModuleFactoryResult::new(
+      ContainerEntryModule::new(
+        dependency.name.clone(),
+        dependency.exposes.clone(),
+        dependency.share_scope.clone(),
+      )
+      .boxed(),
+    )))
+  }
+}
@@ -0,0 +1,179 @@
+use rspack_core::{
+  AdditionalChunkRuntimeRequirementsArgs, Chunk, Compilation, DependencyType, ExternalModule,
+  ExternalRequest, ExternalRequestValue, ExternalType, FactorizeArgs, ModuleExt,
+  ModuleFactoryResult, NormalModuleFactoryContext, Plugin,
+  PluginAdditionalChunkRuntimeRequirementsOutput, PluginContext, PluginFactorizeHookOutput,
+  RuntimeGlobals, RuntimeModuleExt, SourceType,
+};
+
+use crate::{FallbackDependency, FallbackModule, RemoteModule, RemoteRuntimeModule};
+
+const CONTAINER_REFERENCE_PREFIX: &str = "webpack/container/reference/";
+
+#[derive(Debug, Clone)]
+pub struct RemoteOptions {
+  /// Requests of the container, the others are the fallbacks tried in order when
+  /// the first one fails to load
+  pub external: Vec<String>,
+  pub share_scope: String,
+}
+
+#[derive(Debug)]
+pub struct ContainerReferencePluginOptions {
+  /// The external type of the remote containers
+  pub remote_type: ExternalType,
+  /// Remote containers, in the order of the config
+  pub remotes: Vec<(String, RemoteOptions)>,
+}
+
+// Align with https://github.com/webpack/webpack/blob/4b4ca3bb53f36a5b8fc6bc1bd976ed7af161bd80/lib/container/ContainerReferencePlugin.js
+/// Resolves requests prefixed with a remote key to modules of the remote container,
+/// which are loaded along with the chunks containing them.
+#[derive(Debug)]
+pub struct ContainerReferencePlugin {
+  options: ContainerReferencePluginOptions,
+}
+
+impl ContainerReferencePlugin {
+  pub fn new(options: ContainerReferencePluginOptions) -> Self {
+    Self { options }
+  }
+}
+
+/// Requests of the container and its fallbacks, `internal <request>` refers to a module
+/// of the current build instead of an external.
+fn external_requests(key: &str, remote: &RemoteOptions) -> Vec<String> {
+  remote
+    .external
+    .iter()
+    .enumerate()
+    .map(
+      |(index, external)| match external.strip_prefix("internal ") {
+        Some(request) => request.to_string(),
+        None if index == 0 => format!("{CONTAINER_REFERENCE_PREFIX}{key}"),
+        None => format!("{CONTAINER_REFERENCE_PREFIX}{key}/fallback-{index}"),
+      },
+    )
+    .collect()
+}
+
+/// Whether the chunk loads remote modules in its async chunks.
+fn has_remote_modules(compilation: &Compilation, chunk: &Chunk) -> bool {
+  chunk
+    .get_all_async_chunks(&compilation.chunk_group_by_ukey)
+    .iter()
+    .any(|chunk_ukey| {
+      compilation
+        .chunk_graph
+        .get_chunk_modules_iterable_by_source_type(
+          chunk_ukey,
+          SourceType::Remote,
+          &compilation.module_graph,
+        )
+        .next()
+        .is_some()
+    })
+}
+
+#[async_trait::async_trait]
+impl Plugin for ContainerReferencePlugin {
+  fn name(&self) -> &'static str {
+    "ContainerReferencePlugin"
+  }
+
+  async fn factorize(
+    &self,
+    _ctx: PluginContext,
+    args: FactorizeArgs<'_>,
+    _job_ctx: &mut NormalModuleFactoryContext,
+  ) -> PluginFactorizeHookOutput {
+    let request = args.dependency.request();
+    if let Some(dependency) = args
+      .dependency
+      .as_any()
+      .downcast_ref::<FallbackDependency>()
+    {
+      return Ok(Some(ModuleFactoryResult::new(
+        FallbackModule::new(dependency.requests.clone()).boxed(),
+      )));
+    }
+    if matches!(
+      args.dependency.dependency_type(),
+      DependencyType::RemoteToExternal
+    ) {
+      let Some(reference) = request.strip_prefix(CONTAINER_REFERENCE_PREFIX) else {
+        return Ok(None);
+      };
+      // `<remote key>` or `<remote key>/fallback-<index>`
+      let Some(external) = self.options.remotes.iter().find_map(|(key, remote)| {
+        let index = match reference.strip_prefix(key.as_str())? {
+          "" => 0,
+          rest => rest.strip_prefix("/fallback-")?.parse::<usize>().ok()?,
+        };
+        remote.external.get(index)
+      }) else {
+        return Ok(None);
+      };
+      return Ok(Some(ModuleFactoryResult::new(
+        ExternalModule::new(
+          ExternalRequest::Single(ExternalRequestValue(vec![external.clone()])),
+          self.options.remote_type.clone(),
+          request.to_string(),
+        )
+        .boxed(),
+      )));
+    }
+
+    if request.contains('!') {
+      return Ok(None);
+    }
+    for (key, remote) in &self.options.remotes {
+      if let Some(internal_request) = request.strip_prefix(key.as_str())
+        && (internal_request.is_empty() || internal_request.starts_with('/'))
+      {
+        return Ok(Some(ModuleFactoryResult::new(
+          RemoteModule::new(
+            request.to_string(),
+            external_requests(key, remote),
+            format!(".{internal_request}"),
+            remote.share_scope.clone(),
+          )
+          .boxed(),
+        )));
+      }
+    }
+    Ok(None)
+  }
+
+  fn additional_tree_runtime_requirements(
+    &self,
+    _ctx: PluginContext,
+    args: &mut AdditionalChunkRuntimeRequirementsArgs,
+  ) -> PluginAdditionalChunkRuntimeRequirementsOutput {
+    if has_remote_modules(args.compilation, args.chunk()) {
+      args.runtime_requirements.insert(
+        RuntimeGlobals::ENSURE_CHUNK_HANDLERS
+          | RuntimeGlobals::MODULE
+          | RuntimeGlobals::MODULE_FACTORIES_ADD_ONLY
+          | RuntimeGlobals::HAS_OWN_PROPERTY
+          | RuntimeGlobals::INITIALIZE_SHARING
+          | RuntimeGlobals::SHARE_SCOPE_MAP,
+      );
+    }
+    Ok(())
+  }
+
+  fn runtime_requirements_in_tree(
+    &self,
+    _ctx: PluginContext,
+    args: &mut AdditionalChunkRuntimeRequirementsArgs,
+  ) -> PluginAdditionalChunkRuntimeRequirementsOutput {
+    if has_remote_modules(args.compilation, args.chunk()) {
+      args.compilation.add_runtime_module(
+        args.chunk,
+        RuntimeModuleExt::boxed(RemoteRuntimeModule::default()),
+      );
+    }
+    Ok(())
+  }
+}
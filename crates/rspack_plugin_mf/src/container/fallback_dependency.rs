@@ -0,0 +1,56 @@
+use rspack_core::{
+  Dependency, DependencyCategory, DependencyId, DependencyType, ErrorSpan, ModuleDependency,
+};
+
+/// Points a remote module to the [crate::FallbackModule] of its containers,
+/// which are tried in order until one of them is loaded.
+#[derive(Debug, Clone)]
+pub struct FallbackDependency {
+  id: DependencyId,
+  /// `fallback <requests>`, only used to identify the dependency
+  resource_identifier: String,
+  /// Requests of the containers, e.g. `webpack/container/reference/app1/fallback-1`
+  pub requests: Vec<String>,
+}
+
+impl FallbackDependency {
+  pub fn new(requests: Vec<String>) -> Self {
+    Self {
+      id: DependencyId::new(),
+      resource_identifier: format!("fallback {}", requests.join(" ")),
+      requests,
+    }
+  }
+}
+
+impl Dependency for FallbackDependency {
+  fn category(&self) -> &DependencyCategory {
+    &DependencyCategory::Esm
+  }
+
+  fn dependency_type(&self) -> &DependencyType {
+    &DependencyType::RemoteFallback
+  }
+}
+
+impl ModuleDependency for FallbackDependency {
+  fn id(&self) -> &DependencyId {
+    &self.id
+  }
+
+  fn request(&self) -> &str {
+    &self.resource_identifier
+  }
+
+  fn user_request(&self) -> &str {
+    &self.resource_identifier
+  }
+
+  fn span(&self) -> Option<&ErrorSpan> {
+    None
+  }
+
+  fn set_request(&mut self, request: String) {
+    self.resource_identifier = request;
+  }
+}
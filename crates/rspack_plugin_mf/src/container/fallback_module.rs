@@ -0,0 +1,186 @@
+use std::borrow::Cow;
+use std::hash::Hash;
+
+use rspack_core::{
+  rspack_sources::{RawSource, Source, SourceExt},
+  AstOrSource, BuildContext, BuildInfo, BuildResult, ChunkUkey, CodeGenerationResult, Compilation,
+  Context, GenerationResult, LibIdentOptions, Module, ModuleDependency, ModuleType, RuntimeGlobals,
+  SourceType,
+};
+use rspack_error::{internal_error, IntoTWithDiagnosticArray, Result, TWithDiagnosticArray};
+use rspack_hash::RspackHash;
+use rspack_identifier::{Identifiable, Identifier};
+
+use crate::RemoteToExternalDependency;
+
+static FALLBACK_MODULE_SOURCE_TYPES: &[SourceType] = &[SourceType::JavaScript];
+
+// Align with https://github.com/webpack/webpack/blob/4b4ca3bb53f36a5b8fc6bc1bd976ed7af161bd80/lib/container/FallbackModule.js
+/// Loads the containers of a remote in order, exports the first one which is loaded
+/// successfully, or throws the last error when all of them fail.
+#[derive(Debug)]
+pub struct FallbackModule {
+  id: Identifier,
+  requests: Vec<String>,
+}
+
+impl FallbackModule {
+  pub fn new(requests: Vec<String>) -> Self {
+    Self {
+      id: Identifier::from(format!("fallback {}", requests.join(" "))),
+      requests,
+    }
+  }
+}
+
+impl Identifiable for FallbackModule {
+  fn identifier(&self) -> Identifier {
+    self.id
+  }
+}
+
+#[async_trait::async_trait]
+impl Module for FallbackModule {
+  fn module_type(&self) -> &ModuleType {
+    &ModuleType::Js
+  }
+
+  fn source_types(&self) -> &[SourceType] {
+    FALLBACK_MODULE_SOURCE_TYPES
+  }
+
+  fn has_chunk_condition(&self) -> bool {
+    true
+  }
+
+  fn chunk_condition(&self, chunk_key: &ChunkUkey, compilation: &Compilation) -> bool {
+    compilation
+      .chunk_graph
+      .get_number_of_entry_modules(chunk_key)
+      > 0
+  }
+
+  fn original_source(&self) -> Option<&dyn Source> {
+    None
+  }
+
+  fn readable_identifier(&self, _context: &Context) -> Cow<str> {
+    Cow::Borrowed(self.id.as_str())
+  }
+
+  fn size(&self, _source_type: &SourceType) -> f64 {
+    // copied from webpack `FallbackModule`
+    self.requests.len() as f64 * 5.0 + 42.0
+  }
+
+  async fn build(
+    &mut self,
+    build_context: BuildContext<'_>,
+  ) -> Result<TWithDiagnosticArray<BuildResult>> {
+    let mut hasher = RspackHash::from(&build_context.compiler_options.output);
+    self.update_hash(&mut hasher);
+
+    let build_info = BuildInfo {
+      hash: Some(hasher.digest(&build_context.compiler_options.output.hash_digest)),
+      cacheable: true,
+      strict: true,
+      ..Default::default()
+    };
+
+    Ok(
+      BuildResult {
+        build_info,
+        build_meta: Default::default(),
+        dependencies: self
+          .requests
+          .iter()
+          .map(|request| {
+            Box::new(RemoteToExternalDependency::new(request.clone())) as Box<dyn ModuleDependency>
+          })
+          .collect(),
+      }
+      .with_empty_diagnostic(),
+    )
+  }
+
+  fn code_generation(&self, compilation: &Compilation) -> Result<CodeGenerationResult> {
+    let module_graph = &compilation.module_graph;
+    let ids = module_graph
+      .module_graph_module_by_identifier(&self.identifier())
+      .ok_or_else(|| internal_error!("Failed to get fallback module {}", self.id))?
+      .dependencies
+      .iter()
+      .filter_map(|dependency_id| module_graph.module_identifier_by_dependency_id(dependency_id))
+      .filter_map(|module_identifier| {
+        compilation
+          .chunk_graph
+          .get_module_id(*module_identifier)
+          .as_deref()
+      })
+      .collect::<Vec<_>>();
+    let source = format!(
+      r#"var ids = {ids};
+var error, result, i = 0;
+var loop = function(next) {{
+  while (i < ids.length) {{
+    try {{ next = {require}(ids[i++]); }} catch (e) {{ return handleError(e); }}
+    if (next) return next.then ? next.then(handleResult, handleError) : handleResult(next);
+  }}
+  if (error) throw error;
+}};
+var handleResult = function(result) {{
+  if (result) return result;
+  return loop();
+}};
+var handleError = function(e) {{
+  error = e;
+  return loop();
+}};
+module.exports = loop();"#,
+      ids = serde_json::to_string(&ids).map_err(|e| internal_error!(e.to_string()))?,
+      require = RuntimeGlobals::REQUIRE,
+    );
+
+    let mut cgr = CodeGenerationResult::default();
+    cgr.add(
+      SourceType::JavaScript,
+      GenerationResult::from(AstOrSource::from(RawSource::from(source).boxed())),
+    );
+    cgr
+      .runtime_requirements
+      .insert(RuntimeGlobals::MODULE | RuntimeGlobals::REQUIRE);
+    cgr.set_hash(
+      &compilation.options.output.hash_function,
+      &compilation.options.output.hash_digest,
+      &compilation.options.output.hash_salt,
+    );
+    Ok(cgr)
+  }
+
+  fn lib_ident(&self, _options: LibIdentOptions) -> Option<Cow<str>> {
+    Some(Cow::Owned(format!(
+      "webpack/container/fallback/{}/and {} more",
+      self
+        .requests
+        .first()
+        .map(String::as_str)
+        .unwrap_or_default(),
+      self.requests.len().saturating_sub(1)
+    )))
+  }
+}
+
+impl Hash for FallbackModule {
+  fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+    "__rspack_internal__FallbackModule".hash(state);
+    self.identifier().hash(state);
+  }
+}
+
+impl PartialEq for FallbackModule {
+  fn eq(&self, other: &Self) -> bool {
+    self.identifier() == other.identifier()
+  }
+}
+
+impl Eq for FallbackModule {}
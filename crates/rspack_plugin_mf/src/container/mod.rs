@@ -0,0 +1,23 @@
+mod container_entry_dependency;
+mod container_entry_module;
+mod container_exposed_dependency;
+mod container_plugin;
+mod container_reference_plugin;
+mod fallback_dependency;
+mod fallback_module;
+mod remote_module;
+mod remote_runtime_module;
+mod remote_to_external_dependency;
+
+pub use container_entry_dependency::ContainerEntryDependency;
+pub use container_entry_module::ContainerEntryModule;
+pub use container_exposed_dependency::ContainerExposedDependency;
+pub use container_plugin::{ContainerPlugin, ContainerPluginOptions, ExposeOptions};
+pub use container_reference_plugin::{
+  ContainerReferencePlugin, ContainerReferencePluginOptions, RemoteOptions,
+};
+pub use fallback_dependency::FallbackDependency;
+pub use fallback_module::FallbackModule;
+pub use remote_module::RemoteModule;
+pub use remote_runtime_module::RemoteRuntimeModule;
+pub use remote_to_external_dependency::RemoteToExternalDependency;
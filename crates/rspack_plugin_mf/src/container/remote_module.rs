@@ -0,0 +1,167 @@
+use std::borrow::Cow;
+use std::hash::Hash;
+
+use rspack_core::{
+  rspack_sources::{RawSource, Source, SourceExt},
+  AstOrSource, BuildContext, BuildInfo, BuildResult, CodeGenerationDataShareInit,
+  CodeGenerationResult, Compilation, Context, GenerationResult, LibIdentOptions, Module,
+  ModuleDependency, ModuleType, ShareInitData, SourceType,
+};
+use rspack_error::{IntoTWithDiagnosticArray, Result, TWithDiagnosticArray};
+use rspack_hash::RspackHash;
+use rspack_identifier::{Identifiable, Identifier};
+
+use crate::{FallbackDependency, RemoteToExternalDependency};
+
+static REMOTE_MODULE_SOURCE_TYPES: &[SourceType] = &[SourceType::Remote, SourceType::ShareInit];
+
+/// A module loaded from a remote container at runtime, it is not rendered into the chunk,
+/// the factory is set by the remotes runtime when the chunk is loaded.
+#[derive(Debug)]
+pub struct RemoteModule {
+  id: Identifier,
+  /// Request of the remote module, e.g. `app1/Button`
+  request: String,
+  /// Requests of the container and its fallbacks in order, e.g. `webpack/container/reference/app1`
+  /// and `webpack/container/reference/app1/fallback-1`
+  external_requests: Vec<String>,
+  /// Request of the module in the container, e.g. `./Button`
+  pub internal_request: String,
+  pub share_scope: String,
+}
+
+impl RemoteModule {
+  pub fn new(
+    request: String,
+    external_requests: Vec<String>,
+    internal_request: String,
+    share_scope: String,
+  ) -> Self {
+    Self {
+      id: Identifier::from(format!(
+        "remote ({share_scope}) {} {internal_request}",
+        external_requests.join(" ")
+      )),
+      request,
+      external_requests,
+      internal_request,
+      share_scope,
+    }
+  }
+}
+
+impl Identifiable for RemoteModule {
+  fn identifier(&self) -> Identifier {
+    self.id
+  }
+}
+
+#[async_trait::async_trait]
+impl Module for RemoteModule {
+  fn module_type(&self) -> &ModuleType {
+    &ModuleType::Js
+  }
+
+  fn source_types(&self) -> &[SourceType] {
+    REMOTE_MODULE_SOURCE_TYPES
+  }
+
+  fn original_source(&self) -> Option<&dyn Source> {
+    None
+  }
+
+  fn readable_identifier(&self, _context: &Context) -> Cow<str> {
+    Cow::Owned(format!("remote {}", self.request))
+  }
+
+  fn size(&self, _source_type: &SourceType) -> f64 {
+    // copied from webpack `RemoteModule`
+    6.0
+  }
+
+  async fn build(
+    &mut self,
+    build_context: BuildContext<'_>,
+  ) -> Result<TWithDiagnosticArray<BuildResult>> {
+    let mut hasher = RspackHash::from(&build_context.compiler_options.output);
+    self.update_hash(&mut hasher);
+
+    let build_info = BuildInfo {
+      hash: Some(hasher.digest(&build_context.compiler_options.output.hash_digest)),
+      cacheable: true,
+      strict: true,
+      ..Default::default()
+    };
+
+    let dependency: Box<dyn ModuleDependency> = match self.external_requests.as_slice() {
+      [external_request] => Box::new(RemoteToExternalDependency::new(external_request.clone())),
+      external_requests => Box::new(FallbackDependency::new(external_requests.to_vec())),
+    };
+
+    Ok(
+      BuildResult {
+        build_info,
+        build_meta: Default::default(),
+        dependencies: vec![dependency],
+      }
+      .with_empty_diagnostic(),
+    )
+  }
+
+  fn code_generation(&self, compilation: &Compilation) -> Result<CodeGenerationResult> {
+    let module_graph = &compilation.module_graph;
+    let external_module_id = module_graph
+      .module_graph_module_by_identifier(&self.identifier())
+      .and_then(|mgm| mgm.dependencies.first())
+      .and_then(|dependency_id| module_graph.module_identifier_by_dependency_id(dependency_id))
+      .and_then(|module_identifier| {
+        compilation
+          .chunk_graph
+          .get_module_id(*module_identifier)
+          .as_ref()
+      });
+
+    let mut cgr = CodeGenerationResult::default();
+    cgr.add(
+      SourceType::Remote,
+      GenerationResult::from(AstOrSource::from(RawSource::from("").boxed())),
+    );
+    cgr.data.insert(CodeGenerationDataShareInit {
+      items: vec![ShareInitData {
+        share_scope: self.share_scope.clone(),
+        init_stage: 20,
+        init: external_module_id
+          .map(|id| {
+            format!(
+              "initExternal({});",
+              serde_json::to_string(id).expect("invalid json to_string")
+            )
+          })
+          .unwrap_or_default(),
+      }],
+    });
+    Ok(cgr)
+  }
+
+  fn lib_ident(&self, _options: LibIdentOptions) -> Option<Cow<str>> {
+    Some(Cow::Owned(format!(
+      "webpack/container/remote/{}",
+      self.request
+    )))
+  }
+}
+
+impl Hash for RemoteModule {
+  fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+    "__rspack_internal__RemoteModule".hash(state);
+    self.identifier().hash(state);
+  }
+}
+
+impl PartialEq for RemoteModule {
+  fn eq(&self, other: &Self) -> bool {
+    self.identifier() == other.identifier()
+  }
+}
+
+impl Eq for RemoteModule {}
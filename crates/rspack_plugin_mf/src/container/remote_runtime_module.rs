@@ -0,0 +1,113 @@
+use std::collections::BTreeMap;
+
+use rspack_core::{
+  rspack_sources::{BoxSource, RawSource, SourceExt},
+  ChunkUkey, Compilation, RuntimeModule, SourceType, RUNTIME_MODULE_STAGE_ATTACH,
+};
+use rspack_identifier::{Identifiable, Identifier};
+use rspack_plugin_runtime::impl_runtime_module;
+
+use crate::RemoteModule;
+
+#[derive(Debug, Eq)]
+pub struct RemoteRuntimeModule {
+  id: Identifier,
+  chunk: Option<ChunkUkey>,
+}
+
+impl Default for RemoteRuntimeModule {
+  fn default() -> Self {
+    Self {
+      id: Identifier::from("webpack/runtime/remotes_loading"),
+      chunk: None,
+    }
+  }
+}
+
+impl RuntimeModule for RemoteRuntimeModule {
+  fn name(&self) -> Identifier {
+    self.id
+  }
+
+  fn generate(&self, compilation: &Compilation) -> BoxSource {
+    let chunk = compilation
+      .chunk_by_ukey
+      .get(&self.chunk.expect("The chunk should be attached"))
+      .expect("should have chunk");
+    let module_graph = &compilation.module_graph;
+
+    // chunk id -> ids of the remote modules in the chunk
+    let mut chunk_mapping: BTreeMap<&str, Vec<&str>> = BTreeMap::new();
+    // remote module id -> [share scope, request in the container, container module id]
+    let mut id_to_external_and_name_mapping: BTreeMap<&str, [&str; 3]> = BTreeMap::new();
+    for chunk_ukey in chunk.get_all_async_chunks(&compilation.chunk_group_by_ukey) {
+      let async_chunk = compilation
+        .chunk_by_ukey
+        .get(&chunk_ukey)
+        .expect("should have chunk");
+      let mut remotes = compilation
+        .chunk_graph
+        .get_chunk_modules_iterable_by_source_type(&chunk_ukey, SourceType::Remote, module_graph)
+        .filter_map(|module| module.downcast_ref::<RemoteModule>())
+        .collect::<Vec<_>>();
+      remotes.sort_unstable_by_key(|module| module.identifier());
+      for remote in remotes {
+        let Some(id) = compilation
+          .chunk_graph
+          .get_module_id(remote.identifier())
+          .as_deref()
+        else {
+          continue;
+        };
+        let Some(external_module_id) = module_graph
+          .module_graph_module_by_identifier(&remote.identifier())
+          .and_then(|mgm| mgm.dependencies.first())
+          .and_then(|dependency_id| module_graph.module_identifier_by_dependency_id(dependency_id))
+          .and_then(|module_identifier| {
+            compilation
+              .chunk_graph
+              .get_module_id(*module_identifier)
+              .as_deref()
+          })
+        else {
+          continue;
+        };
+        chunk_mapping
+          .entry(async_chunk.expect_id())
+          .or_default()
+          .push(id);
+        id_to_external_and_name_mapping.insert(
+          id,
+          [
+            remote.share_scope.as_str(),
+            remote.internal_request.as_str(),
+            external_module_id,
+          ],
+        );
+      }
+    }
+
+    RawSource::from(
+      include_str!("runtime/remotes.js")
+        .replace(
+          "$CHUNK_MAPPING$",
+          &serde_json::to_string(&chunk_mapping).expect("invalid json to_string"),
+        )
+        .replace(
+          "$ID_TO_EXTERNAL_AND_NAME_MAPPING$",
+          &serde_json::to_string(&id_to_external_and_name_mapping).expect("invalid json to_string"),
+        ),
+    )
+    .boxed()
+  }
+
+  fn attach(&mut self, chunk: ChunkUkey) {
+    self.chunk = Some(chunk);
+  }
+
+  fn stage(&self) -> u8 {
+    RUNTIME_MODULE_STAGE_ATTACH
+  }
+}
+
+impl_runtime_module!(RemoteRuntimeModule);
@@ -0,0 +1,52 @@
+use rspack_core::{
+  Dependency, DependencyCategory, DependencyId, DependencyType, ErrorSpan, ModuleDependency,
+};
+
+/// Points a remote module to the external module of its container,
+/// the request is `webpack/container/reference/<remote key>`.
+#[derive(Debug, Clone)]
+pub struct RemoteToExternalDependency {
+  id: DependencyId,
+  request: String,
+}
+
+impl RemoteToExternalDependency {
+  pub fn new(request: String) -> Self {
+    Self {
+      id: DependencyId::new(),
+      request,
+    }
+  }
+}
+
+impl Dependency for RemoteToExternalDependency {
+  fn category(&self) -> &DependencyCategory {
+    &DependencyCategory::Esm
+  }
+
+  fn dependency_type(&self) -> &DependencyType {
+    &DependencyType::RemoteToExternal
+  }
+}
+
+impl ModuleDependency for RemoteToExternalDependency {
+  fn id(&self) -> &DependencyId {
+    &self.id
+  }
+
+  fn request(&self) -> &str {
+    &self.request
+  }
+
+  fn user_request(&self) -> &str {
+    &self.request
+  }
+
+  fn span(&self) -> Option<&ErrorSpan> {
+    None
+  }
+
+  fn set_request(&mut self, request: String) {
+    self.request = request;
+  }
+}
@@ -0,0 +1,7 @@
+#![feature(let_chains)]
+
+mod container;
+mod sharing;
+
+pub use container::*;
+pub use sharing::*;
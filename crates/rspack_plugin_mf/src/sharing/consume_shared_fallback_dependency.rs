@@ -0,0 +1,59 @@
+use rspack_core::{
+  ChunkGroupOptions, Dependency, DependencyCategory, DependencyId, DependencyType, ErrorSpan,
+  ModuleDependency,
+};
+
+#[derive(Debug, Clone)]
+pub struct ConsumeSharedFallbackDependency {
+  id: DependencyId,
+  request: String,
+  /// Eager fallbacks are placed in the chunk of the consume shared module,
+  /// otherwise they are split into an async chunk
+  group_options: Option<ChunkGroupOptions>,
+}
+
+impl ConsumeSharedFallbackDependency {
+  pub fn new(request: String, eager: bool) -> Self {
+    Self {
+      id: DependencyId::new(),
+      request,
+      group_options: (!eager).then(ChunkGroupOptions::default),
+    }
+  }
+}
+
+impl Dependency for ConsumeSharedFallbackDependency {
+  fn category(&self) -> &DependencyCategory {
+    &DependencyCategory::Esm
+  }
+
+  fn dependency_type(&self) -> &DependencyType {
+    &DependencyType::ConsumeSharedFallback
+  }
+}
+
+impl ModuleDependency for ConsumeSharedFallbackDependency {
+  fn id(&self) -> &DependencyId {
+    &self.id
+  }
+
+  fn request(&self) -> &str {
+    &self.request
+  }
+
+  fn user_request(&self) -> &str {
+    &self.request
+  }
+
+  fn span(&self) -> Option<&ErrorSpan> {
+    None
+  }
+
+  fn set_request(&mut self, request: String) {
+    self.request = request;
+  }
+
+  fn group_options(&self) -> Option<&ChunkGroupOptions> {
+    self.group_options.as_ref()
+  }
+}
@@ -0,0 +1,231 @@
+use std::borrow::Cow;
+use std::hash::Hash;
+
+use rspack_core::{
+  async_module_factory,
+  rspack_sources::{RawSource, Source, SourceExt},
+  sync_module_factory, AstOrSource, BuildContext, BuildInfo, BuildResult, CodeGenerationResult,
+  Compilation, Context, GenerationResult, LibIdentOptions, Module, ModuleDependency, ModuleType,
+  RuntimeGlobals, SourceType,
+};
+use rspack_error::{internal_error, IntoTWithDiagnosticArray, Result, TWithDiagnosticArray};
+use rspack_hash::RspackHash;
+use rspack_identifier::{Identifiable, Identifier};
+
+use crate::{ConsumeOptions, ConsumeSharedFallbackDependency, ConsumeVersion, SemverRange};
+
+static CONSUME_SHARED_MODULE_SOURCE_TYPES: &[SourceType] = &[SourceType::ConsumeShared];
+
+/// A module consumed from the share scope, it is not rendered into the chunk, the factory is set
+/// by the consumes runtime with the shared module or the fallback.
+#[derive(Debug)]
+pub struct ConsumeSharedModule {
+  id: Identifier,
+  /// Context of the issuer, the fallback is resolved from it
+  context: Context,
+  options: ConsumeOptions,
+}
+
+impl ConsumeSharedModule {
+  pub fn new(context: Context, options: ConsumeOptions) -> Self {
+    Self {
+      id: Identifier::from(format!(
+        "consume-shared-module|{}|{}|{}|{}|{}|{}|{}",
+        options.share_scope,
+        options.share_key,
+        options
+          .required_version
+          .as_ref()
+          .map_or_else(|| "undefined".to_string(), ToString::to_string),
+        options.strict_version,
+        options.import.as_deref().unwrap_or("undefined"),
+        options.singleton,
+        options.eager
+      )),
+      context,
+      options,
+    }
+  }
+}
+
+impl Identifiable for ConsumeSharedModule {
+  fn identifier(&self) -> Identifier {
+    self.id
+  }
+}
+
+#[async_trait::async_trait]
+impl Module for ConsumeSharedModule {
+  fn module_type(&self) -> &ModuleType {
+    &ModuleType::Js
+  }
+
+  fn source_types(&self) -> &[SourceType] {
+    CONSUME_SHARED_MODULE_SOURCE_TYPES
+  }
+
+  fn original_source(&self) -> Option<&dyn Source> {
+    None
+  }
+
+  fn readable_identifier(&self, _context: &Context) -> Cow<str> {
+    let required_version = match &self.options.required_version {
+      Some(ConsumeVersion::Version(version)) => version.as_str(),
+      _ => "*",
+    };
+    Cow::Owned(format!(
+      "consume shared module ({}) {}@{required_version}{}{}{}{}",
+      self.options.share_scope,
+      self.options.share_key,
+      if self.options.strict_version {
+        " (strict)"
+      } else {
+        ""
+      },
+      if self.options.singleton {
+        " (singleton)"
+      } else {
+        ""
+      },
+      self
+        .options
+        .import
+        .as_ref()
+        .map(|import| format!(" (fallback: {import})"))
+        .unwrap_or_default(),
+      if self.options.eager { " (eager)" } else { "" }
+    ))
+  }
+
+  fn size(&self, _source_type: &SourceType) -> f64 {
+    // copied from webpack `ConsumeSharedModule`
+    42.0
+  }
+
+  async fn build(
+    &mut self,
+    build_context: BuildContext<'_>,
+  ) -> Result<TWithDiagnosticArray<BuildResult>> {
+    let mut hasher = RspackHash::from(&build_context.compiler_options.output);
+    self.update_hash(&mut hasher);
+
+    let build_info = BuildInfo {
+      hash: Some(hasher.digest(&build_context.compiler_options.output.hash_digest)),
+      cacheable: true,
+      strict: true,
+      ..Default::default()
+    };
+
+    let mut dependencies: Vec<Box<dyn ModuleDependency>> = vec![];
+    if let Some(import) = &self.options.import {
+      dependencies.push(Box::new(ConsumeSharedFallbackDependency::new(
+        import.clone(),
+        self.options.eager,
+      )));
+    }
+
+    Ok(
+      BuildResult {
+        build_info,
+        build_meta: Default::default(),
+        dependencies,
+      }
+      .with_empty_diagnostic(),
+    )
+  }
+
+  fn code_generation(&self, compilation: &Compilation) -> Result<CodeGenerationResult> {
+    let mut runtime_requirements = RuntimeGlobals::SHARE_SCOPE_MAP;
+    let fallback = self.options.import.as_ref().and_then(|import| {
+      let dependency_id = compilation
+        .module_graph
+        .module_graph_module_by_identifier(&self.identifier())
+        .and_then(|mgm| mgm.dependencies.first())?;
+      Some(if self.options.eager {
+        sync_module_factory(
+          compilation,
+          &mut runtime_requirements,
+          dependency_id,
+          import,
+        )
+      } else {
+        async_module_factory(
+          compilation,
+          &mut runtime_requirements,
+          dependency_id,
+          import,
+        )
+      })
+    });
+
+    let mut function = String::from("load");
+    let mut args = vec![
+      serde_json::to_string(&self.options.share_scope)
+        .map_err(|e| internal_error!(e.to_string()))?,
+      serde_json::to_string(&self.options.share_key).map_err(|e| internal_error!(e.to_string()))?,
+    ];
+    if let Some(ConsumeVersion::Version(required_version)) = &self.options.required_version {
+      if self.options.strict_version {
+        function.push_str("Strict");
+      }
+      if self.options.singleton {
+        function.push_str("Singleton");
+      }
+      args.push(SemverRange::parse(required_version).to_string());
+      function.push_str("VersionCheck");
+    } else if self.options.singleton {
+      function.push_str("Singleton");
+    }
+    if let Some(fallback) = fallback {
+      function.push_str("Fallback");
+      args.push(fallback);
+    }
+    let source = format!("function() {{ return {function}({}); }}", args.join(", "));
+
+    let mut cgr = CodeGenerationResult::default();
+    cgr.add(
+      SourceType::ConsumeShared,
+      GenerationResult::from(AstOrSource::from(RawSource::from(source).boxed())),
+    );
+    cgr.runtime_requirements.insert(runtime_requirements);
+    cgr.set_hash(
+      &compilation.options.output.hash_function,
+      &compilation.options.output.hash_digest,
+      &compilation.options.output.hash_salt,
+    );
+    Ok(cgr)
+  }
+
+  fn lib_ident(&self, _options: LibIdentOptions) -> Option<Cow<str>> {
+    Some(Cow::Owned(format!(
+      "webpack/sharing/consume/{}/{}{}",
+      self.options.share_scope,
+      self.options.share_key,
+      self
+        .options
+        .import
+        .as_ref()
+        .map(|import| format!("/{import}"))
+        .unwrap_or_default()
+    )))
+  }
+
+  fn get_context(&self) -> Option<&Context> {
+    Some(&self.context)
+  }
+}
+
+impl Hash for ConsumeSharedModule {
+  fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+    "__rspack_internal__ConsumeSharedModule".hash(state);
+    self.identifier().hash(state);
+  }
+}
+
+impl PartialEq for ConsumeSharedModule {
+  fn eq(&self, other: &Self) -> bool {
+    self.identifier() == other.identifier()
+  }
+}
+
+impl Eq for ConsumeSharedModule {}
@@ -0,0 +1,334 @@
+use std::fmt;
+use std::path::Path;
+
+use rspack_core::{
+  AdditionalChunkRuntimeRequirementsArgs, ChunkUkey, Compilation, DependencyType, FactorizeArgs,
+  MakeParam, ModuleExt, ModuleFactoryResult, NormalModuleFactoryContext, Plugin,
+  PluginAdditionalChunkRuntimeRequirementsOutput, PluginContext, PluginFactorizeHookOutput,
+  PluginMakeHookOutput, RuntimeGlobals, RuntimeModuleExt, SourceType,
+};
+use rspack_error::Diagnostic;
+use rspack_plugin_runtime::ConsumeSharedRuntimeModule;
+
+use crate::{is_required_version, ConsumeSharedModule};
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub enum ConsumeVersion {
+  Version(String),
+  /// `false` in the config, any version of the shared module is accepted
+  False,
+}
+
+impl fmt::Display for ConsumeVersion {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      ConsumeVersion::Version(version) => write!(f, "{version}"),
+      ConsumeVersion::False => write!(f, "false"),
+    }
+  }
+}
+
+#[derive(Debug, Clone)]
+pub struct ConsumeOptions {
+  /// Name of the shared module in the share scope
+  pub share_key: String,
+  pub share_scope: String,
+  /// Request of the fallback module, which is used when there is no shared module in the share scope
+  pub import: Option<String>,
+  /// Read from the description file of the issuer if not given
+  pub required_version: Option<ConsumeVersion>,
+  /// Package name to look up the required version in the description file, defaults to the package
+  /// name of the request
+  pub package_name: Option<String>,
+  pub strict_version: bool,
+  pub singleton: bool,
+  pub eager: bool,
+}
+
+#[derive(Debug)]
+pub struct ConsumeSharedPluginOptions {
+  /// Consumed requests, in the order of the config
+  pub consumes: Vec<(String, ConsumeOptions)>,
+}
+
+// Align with https://github.com/webpack/webpack/blob/4b4ca3bb53f36a5b8fc6bc1bd976ed7af161bd80/lib/sharing/ConsumeSharedPlugin.js
+/// Replaces the consumed requests with modules loaded from the share scope, falling back to the
+/// module of the `import` request.
+#[derive(Debug)]
+pub struct ConsumeSharedPlugin {
+  options: ConsumeSharedPluginOptions,
+}
+
+impl ConsumeSharedPlugin {
+  pub fn new(options: ConsumeSharedPluginOptions) -> Self {
+    Self { options }
+  }
+}
+
+fn is_absolute_request(request: &str) -> bool {
+  request.starts_with('/')
+    || request.starts_with("\\\\")
+    || (request.len() >= 2
+      && request.as_bytes()[0].is_ascii_alphabetic()
+      && request.as_bytes()[1] == b':')
+}
+
+fn is_relative_request(request: &str) -> bool {
+  matches!(request, "." | "..") || request.starts_with("./") || request.starts_with("../")
+}
+
+/// The package name of the request, e.g. `@scope/name` of `@scope/name/lib/index.js`.
+fn get_package_name(request: &str) -> Option<&str> {
+  let mut parts = request.splitn(3, ['/', '\\']);
+  let first = parts.next().filter(|first| !first.is_empty())?;
+  if first.starts_with('@') {
+    let second = parts.next().filter(|second| !second.is_empty())?;
+    Some(&request[..first.len() + 1 + second.len()])
+  } else {
+    Some(first)
+  }
+}
+
+/// Find the version of the package in the dependencies of the description file.
+fn get_required_version_from_description_file(
+  data: &serde_json::Value,
+  package_name: &str,
+) -> Option<String> {
+  [
+    "optionalDependencies",
+    "dependencies",
+    "peerDependencies",
+    "devDependencies",
+  ]
+  .into_iter()
+  .find_map(|field| {
+    data
+      .get(field)
+      .and_then(|dependencies| dependencies.get(package_name))
+      .and_then(|version| version.as_str())
+  })
+  .filter(|version| is_required_version(version))
+  .map(ToString::to_string)
+}
+
+/// Whether there are consume shared modules in the chunks.
+fn has_consume_shared_modules(
+  compilation: &Compilation,
+  chunks: impl IntoIterator<Item = ChunkUkey>,
+) -> bool {
+  chunks.into_iter().any(|chunk_ukey| {
+    compilation
+      .chunk_graph
+      .get_chunk_modules_iterable_by_source_type(
+        &chunk_ukey,
+        SourceType::ConsumeShared,
+        &compilation.module_graph,
+      )
+      .next()
+      .is_some()
+  })
+}
+
+impl ConsumeSharedPlugin {
+  /// Fill in the required version from the description file of the issuer if it is not configured.
+  fn resolve_required_version(
+    &self,
+    request: &str,
+    mut options: ConsumeOptions,
+    job_ctx: &NormalModuleFactoryContext,
+    diagnostics: &mut Vec<Diagnostic>,
+  ) -> ConsumeOptions {
+    if options.required_version.is_some() {
+      return options;
+    }
+    let package_name = match &options.package_name {
+      Some(package_name) => package_name.as_str(),
+      None => {
+        if is_absolute_request(request) {
+          return options;
+        }
+        let Some(package_name) = get_package_name(request) else {
+          diagnostics.push(Diagnostic::warn(
+            "ConsumeSharedPlugin".to_string(),
+            format!("Unable to extract the package name from request {request}."),
+            0,
+            0,
+          ));
+          return options;
+        };
+        package_name
+      }
+    };
+    let Some(description) = &job_ctx.issuer_description else {
+      diagnostics.push(Diagnostic::warn(
+        "ConsumeSharedPlugin".to_string(),
+        format!(
+          "Unable to find description file of the issuer {}.",
+          job_ctx.issuer.as_deref().unwrap_or(request)
+        ),
+        0,
+        0,
+      ));
+      return options;
+    };
+    match get_required_version_from_description_file(description.data().raw(), package_name) {
+      Some(required_version) => {
+        options.required_version = Some(ConsumeVersion::Version(required_version));
+      }
+      None => diagnostics.push(Diagnostic::warn(
+        "ConsumeSharedPlugin".to_string(),
+        format!(
+          "Unable to find required version for \"{package_name}\" in description file ({}). It need to be specified manually.",
+          Path::join(description.dir().as_ref(), "package.json").display()
+        ),
+        0,
+        0,
+      )),
+    }
+    options
+  }
+}
+
+#[async_trait::async_trait]
+impl Plugin for ConsumeSharedPlugin {
+  fn name(&self) -> &'static str {
+    "ConsumeSharedPlugin"
+  }
+
+  async fn make(
+    &self,
+    _ctx: PluginContext,
+    compilation: &mut Compilation,
+    _param: &mut MakeParam,
+  ) -> PluginMakeHookOutput {
+    if let Some(state) = compilation.options.get_incremental_rebuild_make_state() && !state.is_first() {
+      return Ok(());
+    }
+    let diagnostics = self
+      .options
+      .consumes
+      .iter()
+      .filter(|(key, _)| is_relative_request(key) || is_absolute_request(key))
+      .map(|(key, _)| {
+        Diagnostic::warn(
+          "ConsumeSharedPlugin".to_string(),
+          format!(
+            "Consuming the resolved module of the relative or absolute request {key} is not supported yet, consume it by a module request instead."
+          ),
+          0,
+          0,
+        )
+      })
+      .collect();
+    compilation.push_batch_diagnostic(diagnostics);
+    Ok(())
+  }
+
+  async fn factorize(
+    &self,
+    _ctx: PluginContext,
+    args: FactorizeArgs<'_>,
+    job_ctx: &mut NormalModuleFactoryContext,
+  ) -> PluginFactorizeHookOutput {
+    // the fallback and the provided modules are the actual modules of the shared module
+    if matches!(
+      args.dependency.dependency_type(),
+      DependencyType::ConsumeSharedFallback | DependencyType::ProvideModuleForShared
+    ) {
+      return Ok(None);
+    }
+    let request = args.dependency.request();
+    // relative and absolute requests are reported as unsupported in `make`
+    let mut consumes = self
+      .options
+      .consumes
+      .iter()
+      .filter(|(key, _)| !is_relative_request(key) && !is_absolute_request(key));
+    let matched = consumes
+      .clone()
+      .find(|(key, _)| key == request)
+      .map(|(_, options)| options.clone())
+      .or_else(|| {
+        consumes.find_map(|(key, options)| {
+          let remainder = request.strip_prefix(key.as_str())?;
+          key.ends_with('/').then(|| ConsumeOptions {
+            import: options
+              .import
+              .as_ref()
+              .map(|import| format!("{import}{remainder}")),
+            share_key: format!("{}{remainder}", options.share_key),
+            ..options.clone()
+          })
+        })
+      });
+    let Some(options) = matched else {
+      return Ok(None);
+    };
+
+    let mut diagnostics = vec![];
+    let options = self.resolve_required_version(request, options, job_ctx, &mut diagnostics);
+    if !diagnostics.is_empty() {
+      args
+        .plugin_driver
+        .diagnostics
+        .lock()
+        .expect("should lock diagnostics")
+        .extend(diagnostics);
+    }
+    Ok(Some(ModuleFactoryResult::new(
+      ConsumeSharedModule::new(args.context.clone(), options).boxed(),
+    )))
+  }
+
+  fn additional_tree_runtime_requirements(
+    &self,
+    _ctx: PluginContext,
+    args: &mut AdditionalChunkRuntimeRequirementsArgs,
+  ) -> PluginAdditionalChunkRuntimeRequirementsOutput {
+    let chunk = args.chunk();
+    let chunk_group_by_ukey = &args.compilation.chunk_group_by_ukey;
+    let has_async_consumes = has_consume_shared_modules(
+      args.compilation,
+      chunk.get_all_async_chunks(chunk_group_by_ukey),
+    );
+    if has_async_consumes
+      || has_consume_shared_modules(
+        args.compilation,
+        chunk.get_all_initial_chunks(chunk_group_by_ukey),
+      )
+    {
+      args.runtime_requirements.insert(
+        RuntimeGlobals::MODULE
+          | RuntimeGlobals::MODULE_CACHE
+          | RuntimeGlobals::MODULE_FACTORIES_ADD_ONLY
+          | RuntimeGlobals::SHARE_SCOPE_MAP
+          | RuntimeGlobals::INITIALIZE_SHARING
+          | RuntimeGlobals::HAS_OWN_PROPERTY,
+      );
+    }
+    if has_async_consumes {
+      args
+        .runtime_requirements
+        .insert(RuntimeGlobals::ENSURE_CHUNK_HANDLERS);
+    }
+    Ok(())
+  }
+
+  fn runtime_requirements_in_tree(
+    &self,
+    _ctx: PluginContext,
+    args: &mut AdditionalChunkRuntimeRequirementsArgs,
+  ) -> PluginAdditionalChunkRuntimeRequirementsOutput {
+    let chunk = args.chunk();
+    if has_consume_shared_modules(
+      args.compilation,
+      chunk.get_all_referenced_chunks(&args.compilation.chunk_group_by_ukey),
+    ) {
+      args.compilation.add_runtime_module(
+        args.chunk,
+        RuntimeModuleExt::boxed(ConsumeSharedRuntimeModule::default()),
+      );
+    }
+    Ok(())
+  }
+}
@@ -0,0 +1,21 @@
+mod consume_shared_fallback_dependency;
+mod consume_shared_module;
+mod consume_shared_plugin;
+mod provide_for_shared_dependency;
+mod provide_shared_dependency;
+mod provide_shared_module;
+mod provide_shared_plugin;
+mod semver;
+
+pub use consume_shared_fallback_dependency::ConsumeSharedFallbackDependency;
+pub use consume_shared_module::ConsumeSharedModule;
+pub use consume_shared_plugin::{
+  ConsumeOptions, ConsumeSharedPlugin, ConsumeSharedPluginOptions, ConsumeVersion,
+};
+pub use provide_for_shared_dependency::ProvideForSharedDependency;
+pub use provide_shared_dependency::ProvideSharedDependency;
+pub use provide_shared_module::ProvideSharedModule;
+pub use provide_shared_plugin::{
+  ProvideOptions, ProvideSharedPlugin, ProvideSharedPluginOptions, ProvideVersion,
+};
+pub use semver::{is_required_version, SemverRange};
@@ -0,0 +1,81 @@
+use rspack_core::{
+  Dependency, DependencyCategory, DependencyId, DependencyType, ErrorSpan, ModuleDependency,
+};
+
+use crate::ProvideVersion;
+
+#[derive(Debug, Clone)]
+pub struct ProvideSharedDependency {
+  id: DependencyId,
+  pub share_scope: String,
+  /// Name of the shared module in the share scope, e.g. `react`
+  pub name: String,
+  /// The version from the config, it is read from the description file of the provided module
+  /// if not given
+  pub version: Option<ProvideVersion>,
+  /// Request of the provided module
+  pub request: String,
+  pub eager: bool,
+  resource_identifier: String,
+}
+
+impl ProvideSharedDependency {
+  pub fn new(
+    share_scope: String,
+    name: String,
+    version: Option<ProvideVersion>,
+    request: String,
+    eager: bool,
+  ) -> Self {
+    let resource_identifier = format!(
+      "provide module ({share_scope}) {request} as {name} @ {}{}",
+      version
+        .as_ref()
+        .map_or_else(|| "auto".to_string(), ToString::to_string),
+      if eager { " (eager)" } else { "" }
+    );
+    Self {
+      id: DependencyId::new(),
+      share_scope,
+      name,
+      version,
+      request,
+      eager,
+      resource_identifier,
+    }
+  }
+}
+
+impl Dependency for ProvideSharedDependency {
+  fn category(&self) -> &DependencyCategory {
+    &DependencyCategory::Esm
+  }
+
+  fn dependency_type(&self) -> &DependencyType {
+    &DependencyType::ProvideSharedModule
+  }
+}
+
+impl ModuleDependency for ProvideSharedDependency {
+  fn id(&self) -> &DependencyId {
+    &self.id
+  }
+
+  // the identifier is the request, so the provided module is created once for the same options
+  #[allow(clippy::misnamed_getters)]
+  fn request(&self) -> &str {
+    &self.resource_identifier
+  }
+
+  fn user_request(&self) -> &str {
+    &self.resource_identifier
+  }
+
+  fn span(&self) -> Option<&ErrorSpan> {
+    None
+  }
+
+  fn set_request(&mut self, request: String) {
+    self.resource_identifier = request;
+  }
+}
@@ -0,0 +1,208 @@
+use std::borrow::Cow;
+use std::hash::Hash;
+
+use rspack_core::{
+  async_module_factory,
+  rspack_sources::{RawSource, Source, SourceExt},
+  sync_module_factory, AstOrSource, BuildContext, BuildInfo, BuildResult,
+  CodeGenerationDataShareInit, CodeGenerationResult, Compilation, Context, GenerationResult,
+  LibIdentOptions, Module, ModuleType, RuntimeGlobals, ShareInitData, SourceType,
+};
+use rspack_error::{internal_error, IntoTWithDiagnosticArray, Result, TWithDiagnosticArray};
+use rspack_hash::RspackHash;
+use rspack_identifier::{Identifiable, Identifier};
+
+use crate::{ProvideForSharedDependency, ProvideVersion};
+
+static PROVIDE_SHARED_MODULE_SOURCE_TYPES: &[SourceType] = &[SourceType::ShareInit];
+
+/// Registers the provided module to the share scope when the share scope is initialized,
+/// it is not rendered into the chunk.
+#[derive(Debug)]
+pub struct ProvideSharedModule {
+  id: Identifier,
+  share_scope: String,
+  name: String,
+  /// Read from the description file of the provided module in `finish_modules` if not configured
+  version: Option<ProvideVersion>,
+  request: String,
+  eager: bool,
+}
+
+impl ProvideSharedModule {
+  pub fn new(
+    share_scope: String,
+    name: String,
+    version: Option<ProvideVersion>,
+    request: String,
+    eager: bool,
+  ) -> Self {
+    Self {
+      id: Identifier::from(format!(
+        "provide shared module ({share_scope}) {name} = {request}{}",
+        if eager { " (eager)" } else { "" }
+      )),
+      share_scope,
+      name,
+      version,
+      request,
+      eager,
+    }
+  }
+
+  pub fn name(&self) -> &str {
+    &self.name
+  }
+
+  pub fn version(&self) -> Option<&ProvideVersion> {
+    self.version.as_ref()
+  }
+
+  pub fn set_version(&mut self, version: ProvideVersion) {
+    self.version = Some(version);
+  }
+}
+
+impl Identifiable for ProvideSharedModule {
+  fn identifier(&self) -> Identifier {
+    self.id
+  }
+}
+
+#[async_trait::async_trait]
+impl Module for ProvideSharedModule {
+  fn module_type(&self) -> &ModuleType {
+    &ModuleType::Js
+  }
+
+  fn source_types(&self) -> &[SourceType] {
+    PROVIDE_SHARED_MODULE_SOURCE_TYPES
+  }
+
+  fn original_source(&self) -> Option<&dyn Source> {
+    None
+  }
+
+  fn readable_identifier(&self, _context: &Context) -> Cow<str> {
+    Cow::Owned(format!(
+      "provide shared module ({}) {}@{} = {}",
+      self.share_scope,
+      self.name,
+      self
+        .version
+        .as_ref()
+        .map_or_else(|| "auto".to_string(), ToString::to_string),
+      self.request
+    ))
+  }
+
+  fn size(&self, _source_type: &SourceType) -> f64 {
+    // copied from webpack `ProvideSharedModule`
+    42.0
+  }
+
+  async fn build(
+    &mut self,
+    build_context: BuildContext<'_>,
+  ) -> Result<TWithDiagnosticArray<BuildResult>> {
+    let mut hasher = RspackHash::from(&build_context.compiler_options.output);
+    self.update_hash(&mut hasher);
+
+    let build_info = BuildInfo {
+      hash: Some(hasher.digest(&build_context.compiler_options.output.hash_digest)),
+      cacheable: true,
+      strict: true,
+      ..Default::default()
+    };
+
+    Ok(
+      BuildResult {
+        build_info,
+        build_meta: Default::default(),
+        dependencies: vec![Box::new(ProvideForSharedDependency::new(
+          self.request.clone(),
+          self.eager,
+        ))],
+      }
+      .with_empty_diagnostic(),
+    )
+  }
+
+  fn code_generation(&self, compilation: &Compilation) -> Result<CodeGenerationResult> {
+    let dependency_id = compilation
+      .module_graph
+      .module_graph_module_by_identifier(&self.identifier())
+      .and_then(|mgm| mgm.dependencies.first())
+      .ok_or_else(|| internal_error!("Failed to get the provided module of {}", self.id))?;
+
+    let mut runtime_requirements = RuntimeGlobals::INITIALIZE_SHARING;
+    let factory = if self.eager {
+      sync_module_factory(
+        compilation,
+        &mut runtime_requirements,
+        dependency_id,
+        &self.request,
+      )
+    } else {
+      async_module_factory(
+        compilation,
+        &mut runtime_requirements,
+        dependency_id,
+        &self.request,
+      )
+    };
+    let version = match &self.version {
+      Some(ProvideVersion::Version(version)) => version.as_str(),
+      _ => "0",
+    };
+    let init = format!(
+      "register({}, {}, {factory}{});",
+      serde_json::to_string(&self.name).map_err(|e| internal_error!(e.to_string()))?,
+      serde_json::to_string(version).map_err(|e| internal_error!(e.to_string()))?,
+      if self.eager { ", 1" } else { "" }
+    );
+
+    let mut cgr = CodeGenerationResult::default();
+    // the init code is also added as the source, so that it is taken into account by the hash
+    cgr.add(
+      SourceType::ShareInit,
+      GenerationResult::from(AstOrSource::from(RawSource::from(init.clone()).boxed())),
+    );
+    cgr.data.insert(CodeGenerationDataShareInit {
+      items: vec![ShareInitData {
+        share_scope: self.share_scope.clone(),
+        init_stage: 10,
+        init,
+      }],
+    });
+    cgr.runtime_requirements.insert(runtime_requirements);
+    cgr.set_hash(
+      &compilation.options.output.hash_function,
+      &compilation.options.output.hash_digest,
+      &compilation.options.output.hash_salt,
+    );
+    Ok(cgr)
+  }
+
+  fn lib_ident(&self, _options: LibIdentOptions) -> Option<Cow<str>> {
+    Some(Cow::Owned(format!(
+      "webpack/sharing/provide/{}/{}",
+      self.share_scope, self.name
+    )))
+  }
+}
+
+impl Hash for ProvideSharedModule {
+  fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+    "__rspack_internal__ProvideSharedModule".hash(state);
+    self.identifier().hash(state);
+  }
+}
+
+impl PartialEq for ProvideSharedModule {
+  fn eq(&self, other: &Self) -> bool {
+    self.identifier() == other.identifier()
+  }
+}
+
+impl Eq for ProvideSharedModule {}
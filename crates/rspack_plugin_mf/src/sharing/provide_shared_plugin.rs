@@ -0,0 +1,201 @@
+use std::fmt;
+use std::path::Path;
+
+use rspack_core::{
+  Compilation, FactorizeArgs, MakeParam, ModuleDependency, ModuleExt, ModuleFactoryResult,
+  ModuleIdentifier, NormalModuleFactoryContext, Plugin, PluginContext, PluginFactorizeHookOutput,
+  PluginMakeHookOutput,
+};
+use rspack_error::{Diagnostic, Result};
+
+use crate::{ProvideSharedDependency, ProvideSharedModule};
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub enum ProvideVersion {
+  Version(String),
+  /// `false` in the config, the module is provided without a version
+  False,
+}
+
+impl fmt::Display for ProvideVersion {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      ProvideVersion::Version(version) => write!(f, "{version}"),
+      ProvideVersion::False => write!(f, "false"),
+    }
+  }
+}
+
+#[derive(Debug, Clone)]
+pub struct ProvideOptions {
+  /// Name of the shared module in the share scope
+  pub share_key: String,
+  pub share_scope: String,
+  /// Read from the description file of the provided module if not given
+  pub version: Option<ProvideVersion>,
+  pub eager: bool,
+}
+
+#[derive(Debug)]
+pub struct ProvideSharedPluginOptions {
+  /// Provided requests, in the order of the config
+  pub provides: Vec<(String, ProvideOptions)>,
+}
+
+// Align with https://github.com/webpack/webpack/blob/4b4ca3bb53f36a5b8fc6bc1bd976ed7af161bd80/lib/sharing/ProvideSharedPlugin.js
+/// Provides modules to the share scope, which is initialized with all the provided modules
+/// reachable from the runtime.
+///
+/// Unlike webpack, the provided modules are added to all entries whether they are requested by
+/// the build or not.
+#[derive(Debug)]
+pub struct ProvideSharedPlugin {
+  options: ProvideSharedPluginOptions,
+}
+
+impl ProvideSharedPlugin {
+  pub fn new(options: ProvideSharedPluginOptions) -> Self {
+    Self { options }
+  }
+}
+
+#[async_trait::async_trait]
+impl Plugin for ProvideSharedPlugin {
+  fn name(&self) -> &'static str {
+    "ProvideSharedPlugin"
+  }
+
+  async fn make(
+    &self,
+    _ctx: PluginContext,
+    compilation: &mut Compilation,
+    param: &mut MakeParam,
+  ) -> PluginMakeHookOutput {
+    if let Some(state) = compilation.options.get_incremental_rebuild_make_state() && !state.is_first() {
+      return Ok(());
+    }
+    let mut diagnostics = vec![];
+    for (request, options) in &self.options.provides {
+      if request.ends_with('/') {
+        diagnostics.push(Diagnostic::warn(
+          "ProvideSharedPlugin".to_string(),
+          format!(
+            "Providing modules by the prefix {request} is not supported yet, provide each module of the prefix instead."
+          ),
+          0,
+          0,
+        ));
+        continue;
+      }
+      let dependency = Box::new(ProvideSharedDependency::new(
+        options.share_scope.clone(),
+        options.share_key.clone(),
+        options.version.clone(),
+        request.clone(),
+        options.eager,
+      ));
+      let dependency_id = *dependency.id();
+      compilation.add_include(dependency_id);
+      param.add_force_build_dependency(dependency_id, None);
+      compilation.module_graph.add_dependency(dependency);
+    }
+    compilation.push_batch_diagnostic(diagnostics);
+    Ok(())
+  }
+
+  async fn factorize(
+    &self,
+    _ctx: PluginContext,
+    args: FactorizeArgs<'_>,
+    _job_ctx: &mut NormalModuleFactoryContext,
+  ) -> PluginFactorizeHookOutput {
+    let Some(dependency) = args
+      .dependency
+      .as_any()
+      .downcast_ref::<ProvideSharedDependency>()
+    else {
+      return Ok(None);
+    };
+    Ok(Some(ModuleFactoryResult::new(
+      ProvideSharedModule::new(
+        dependency.share_scope.clone(),
+        dependency.name.clone(),
+        dependency.version.clone(),
+        dependency.request.clone(),
+        dependency.eager,
+      )
+      .boxed(),
+    )))
+  }
+
+  async fn finish_modules(&self, compilation: &mut Compilation) -> Result<()> {
+    let module_graph = &compilation.module_graph;
+    // the version of the provided module is read from its description file when it is not configured
+    let mut versions: Vec<(ModuleIdentifier, ProvideVersion)> = vec![];
+    let mut diagnostics = vec![];
+    for (module_identifier, module) in module_graph.modules() {
+      let Some(provide_module) = module.downcast_ref::<ProvideSharedModule>() else {
+        continue;
+      };
+      if provide_module.version().is_some() {
+        continue;
+      }
+      let Some(provided_module) = module_graph
+        .module_graph_module_by_identifier(module_identifier)
+        .and_then(|mgm| mgm.dependencies.first())
+        .and_then(|dependency_id| module_graph.module_identifier_by_dependency_id(dependency_id))
+        .and_then(|provided| module_graph.module_by_identifier(provided))
+        .and_then(|provided| provided.as_normal_module())
+      else {
+        continue;
+      };
+      let resource_data = provided_module.resource_resolved_data();
+      let Some(description) = &resource_data.resource_description else {
+        diagnostics.push(Diagnostic::warn(
+          "ProvideSharedPlugin".to_string(),
+          format!(
+            "No description file (usually package.json) found for {}. Add description file with name and version, or manually specify version in shared config.",
+            resource_data.resource
+          ),
+          0,
+          0,
+        ));
+        continue;
+      };
+      let Some(version) = description
+        .data()
+        .raw()
+        .get("version")
+        .and_then(|version| version.as_str())
+      else {
+        diagnostics.push(Diagnostic::warn(
+          "ProvideSharedPlugin".to_string(),
+          format!(
+            "No version in description file (usually package.json) of {}. Add version to description file {}, or manually specify version in shared config.",
+            provide_module.name(),
+            Path::join(description.dir().as_ref(), "package.json").display()
+          ),
+          0,
+          0,
+        ));
+        continue;
+      };
+      versions.push((
+        *module_identifier,
+        ProvideVersion::Version(version.to_string()),
+      ));
+    }
+
+    for (module_identifier, version) in versions {
+      if let Some(provide_module) = compilation
+        .module_graph
+        .module_by_identifier_mut(&module_identifier)
+        .and_then(|module| module.downcast_mut::<ProvideSharedModule>())
+      {
+        provide_module.set_version(version);
+      }
+    }
+    compilation.push_batch_diagnostic(diagnostics);
+    Ok(())
+  }
+}
@@ -0,0 +1,298 @@
+use std::fmt;
+
+// Align with https://github.com/webpack/webpack/blob/4b4ca3bb53f36a5b8fc6bc1bd976ed7af161bd80/lib/util/semver.js
+
+/// Whether the string looks like a version range rather than a request, e.g. `^1.0.0` or `*`.
+pub fn is_required_version(version: &str) -> bool {
+  let mut chars = version.chars();
+  match chars.next() {
+    Some(c) if c.is_ascii_digit() || matches!(c, '^' | '=' | 'v' | '<' | '>' | '~') => true,
+    Some('*' | 'x' | 'X') => chars.next().is_none(),
+    _ => false,
+  }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VersionPart {
+  Number(u64),
+  String(String),
+  /// Marks the start of the prerelease parts, rendered as a hole in the array
+  Prerelease,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RangeItem {
+  Range(SemverRange),
+  Not,
+  Or,
+  And,
+}
+
+/// A parsed version range, which is rendered as the array checked by `satisfy` in the consumes
+/// runtime.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SemverRange {
+  /// The version parts with the count of the leading parts which should equal to the version.
+  /// A count of 0 means `>=` and a negative count means `<`.
+  Primitive {
+    fix_count: i64,
+    parts: Vec<VersionPart>,
+  },
+  /// Ranges combined by the operators in reverse polish notation.
+  Compound(Vec<RangeItem>),
+}
+
+impl SemverRange {
+  pub fn parse(range: &str) -> Self {
+    let ranges = range
+      .split("||")
+      .map(|range| parse_range(range.trim()))
+      .collect();
+    combine(ranges, RangeItem::Or)
+  }
+}
+
+impl fmt::Display for SemverRange {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      SemverRange::Primitive { fix_count, parts } => {
+        write!(f, "[{fix_count}")?;
+        for part in parts {
+          match part {
+            VersionPart::Number(n) => write!(f, ",{n}")?,
+            VersionPart::String(s) => {
+              write!(f, ",{}", serde_json::to_string(s).map_err(|_| fmt::Error)?)?
+            }
+            VersionPart::Prerelease => write!(f, ",")?,
+          }
+        }
+        write!(f, "]")
+      }
+      SemverRange::Compound(items) => {
+        write!(f, "[")?;
+        for item in items {
+          match item {
+            RangeItem::Range(range) => write!(f, ",{range}")?,
+            RangeItem::Not => write!(f, ",0")?,
+            RangeItem::Or => write!(f, ",1")?,
+            RangeItem::And => write!(f, ",2")?,
+          }
+        }
+        write!(f, "]")
+      }
+    }
+  }
+}
+
+fn split_and_convert(version: &str) -> impl Iterator<Item = VersionPart> + '_ {
+  version.split('.').map(|item| match item.parse::<u64>() {
+    Ok(n) if n.to_string() == item => VersionPart::Number(n),
+    _ => VersionPart::String(item.to_string()),
+  })
+}
+
+/// Parse a partial version like `1.2.x` or `1.0.0-beta.1`, the trailing any matchers are removed.
+fn parse_partial(version: &str) -> Vec<VersionPart> {
+  // the build metadata is ignored in ranges
+  let version = version.split_once('+').map_or(version, |(v, _)| v);
+  let (release, prerelease) = match version.split_once('-') {
+    Some((release, prerelease)) => (release, Some(prerelease)),
+    None => (version, None),
+  };
+  let mut parts = vec![];
+  if !release.is_empty() {
+    parts.extend(split_and_convert(release));
+  }
+  if let Some(prerelease) = prerelease {
+    parts.push(VersionPart::Prerelease);
+    parts.extend(split_and_convert(prerelease));
+  }
+  while let Some(last) = parts.last() {
+    match last {
+      VersionPart::Prerelease => {}
+      VersionPart::String(s) if matches!(s.as_str(), "*" | "x" | "X") => {}
+      _ => break,
+    }
+    parts.pop();
+  }
+  parts
+}
+
+fn to_fixed(parts: Vec<VersionPart>) -> SemverRange {
+  let fix_count = match parts.len() {
+    // `*` is `x.x.x`, `1` is `1.x.x` and `1.2` is `1.2.x`
+    len @ (0 | 1 | 2) => len as i64,
+    len => len as i64 + 1,
+  };
+  SemverRange::Primitive { fix_count, parts }
+}
+
+fn negate(parts: Vec<VersionPart>) -> SemverRange {
+  SemverRange::Primitive {
+    fix_count: -1,
+    parts,
+  }
+}
+
+fn parse_simple(range: &str) -> SemverRange {
+  let operator = ["^", "~", "<=", "<", ">=", ">", "=", "v", "!"]
+    .into_iter()
+    .find(|operator| range.starts_with(operator))
+    .unwrap_or_default();
+  let parts = parse_partial(range[operator.len()..].trim());
+  match operator {
+    "^" => {
+      let fix_count = match parts.as_slice() {
+        [VersionPart::Number(0), VersionPart::Number(0), ..] => 3,
+        [VersionPart::Number(0), ..] => 2,
+        _ => 1,
+      };
+      SemverRange::Primitive { fix_count, parts }
+    }
+    "~" => SemverRange::Primitive {
+      fix_count: 2,
+      parts,
+    },
+    ">=" => SemverRange::Primitive {
+      fix_count: 0,
+      parts,
+    },
+    "<" => negate(parts),
+    // and(>=, not(=))
+    ">" => SemverRange::Compound(vec![
+      RangeItem::Range(to_fixed(parts.clone())),
+      RangeItem::Not,
+      RangeItem::Range(SemverRange::Primitive {
+        fix_count: 0,
+        parts,
+      }),
+      RangeItem::And,
+    ]),
+    // or(<, =)
+    "<=" => SemverRange::Compound(vec![
+      RangeItem::Range(to_fixed(parts.clone())),
+      RangeItem::Range(negate(parts)),
+      RangeItem::Or,
+    ]),
+    // not(=)
+    "!" => SemverRange::Compound(vec![RangeItem::Range(to_fixed(parts)), RangeItem::Not]),
+    _ => to_fixed(parts),
+  }
+}
+
+fn combine(ranges: Vec<SemverRange>, operator: RangeItem) -> SemverRange {
+  if ranges.len() == 1 {
+    return ranges.into_iter().next().expect("should have range");
+  }
+  let count = ranges.len();
+  let mut items = vec![];
+  for range in ranges.into_iter().rev() {
+    match range {
+      SemverRange::Compound(compound_items) => items.extend(compound_items),
+      primitive => items.push(RangeItem::Range(primitive)),
+    }
+  }
+  items.extend(std::iter::repeat(operator).take(count - 1));
+  SemverRange::Compound(items)
+}
+
+/// Split the range by the whitespaces following a version, e.g. `>= 1.0.0 <2` is `>= 1.0.0` and `<2`.
+fn split_simple_ranges(range: &str) -> Vec<&str> {
+  let mut ranges = vec![];
+  let mut start = 0;
+  let mut prev: Option<char> = None;
+  for (index, c) in range.char_indices() {
+    if c.is_whitespace()
+      && let Some(prev) = prev
+      && (prev.is_ascii_alphanumeric() || prev == '-')
+    {
+      ranges.push(&range[start..index]);
+      start = index;
+    }
+    prev = Some(c);
+  }
+  ranges.push(&range[start..]);
+  ranges
+    .into_iter()
+    .map(str::trim)
+    .filter(|range| !range.is_empty())
+    .collect()
+}
+
+fn parse_range(range: &str) -> SemverRange {
+  // hyphen range `a - b` is and(>=a, or(<b, =b))
+  let tokens = range.split_whitespace().collect::<Vec<_>>();
+  if let Some(hyphen) = tokens.iter().position(|token| *token == "-") {
+    let from = parse_partial(&tokens[..hyphen].join(" "));
+    let to = tokens[hyphen + 1..]
+      .iter()
+      .take_while(|token| **token != "-")
+      .copied()
+      .collect::<Vec<_>>()
+      .join(" ");
+    let to = parse_partial(&to);
+    return SemverRange::Compound(vec![
+      RangeItem::Range(to_fixed(to.clone())),
+      RangeItem::Range(negate(to)),
+      RangeItem::Or,
+      RangeItem::Range(SemverRange::Primitive {
+        fix_count: 0,
+        parts: from,
+      }),
+      RangeItem::And,
+    ]);
+  }
+  let simple_ranges = split_simple_ranges(range);
+  if simple_ranges.is_empty() {
+    return parse_simple("");
+  }
+  combine(
+    simple_ranges.into_iter().map(parse_simple).collect(),
+    RangeItem::And,
+  )
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  fn parse(range: &str) -> String {
+    SemverRange::parse(range).to_string()
+  }
+
+  #[test]
+  fn parse_primitive_ranges() {
+    assert_eq!(parse("*"), "[0]");
+    assert_eq!(parse("1.2.3"), "[4,1,2,3]");
+    assert_eq!(parse("1.x"), "[1,1]");
+    assert_eq!(parse("^1.2.3"), "[1,1,2,3]");
+    assert_eq!(parse("^0.2.3"), "[2,0,2,3]");
+    assert_eq!(parse("^0.0.3"), "[3,0,0,3]");
+    assert_eq!(parse("~1.2.3"), "[2,1,2,3]");
+    assert_eq!(parse(">=1.2.3"), "[0,1,2,3]");
+    assert_eq!(parse("<1.2.3"), "[-1,1,2,3]");
+    assert_eq!(parse("^1.0.0-beta.1"), "[1,1,0,0,,\"beta\",1]");
+  }
+
+  #[test]
+  fn parse_compound_ranges() {
+    assert_eq!(parse(">1.2.3"), "[,[4,1,2,3],0,[0,1,2,3],2]");
+    assert_eq!(parse("<=1.2.3"), "[,[4,1,2,3],[-1,1,2,3],1]");
+    assert_eq!(parse(">=1.0.0 <2.0.0"), "[,[-1,2,0,0],[0,1,0,0],2]");
+    assert_eq!(parse(">= 1.0.0 < 2.0.0"), "[,[-1,2,0,0],[0,1,0,0],2]");
+    assert_eq!(parse("^1.0.0 || ^2.0.0"), "[,[1,2,0,0],[1,1,0,0],1]");
+    assert_eq!(
+      parse("1.0.0 - 2.0.0"),
+      "[,[4,2,0,0],[-1,2,0,0],1,[0,1,0,0],2]"
+    );
+  }
+
+  #[test]
+  fn required_version() {
+    assert!(is_required_version("^1.0.0"));
+    assert!(is_required_version("1.0.0"));
+    assert!(is_required_version("*"));
+    assert!(!is_required_version("x-package"));
+    assert!(!is_required_version("react"));
+  }
+}
@@ -0,0 +1,126 @@
+use async_trait::async_trait;
+use rspack_core::{
+  AdditionalChunkRuntimeRequirementsArgs, ChunkGroupOrderKey, Plugin,
+  PluginAdditionalChunkRuntimeRequirementsOutput, PluginContext, RuntimeGlobals, RuntimeModuleExt,
+};
+
+use crate::runtime_module::{
+  ChunkPrefetchPreloadFunctionRuntimeModule, ChunkPrefetchStartupRuntimeModule,
+  ChunkPrefetchTriggerRuntimeModule, ChunkPreloadTriggerRuntimeModule,
+};
+
+/// Prefetches and preloads the chunks of `import()` with `webpackPrefetch` and `webpackPreload`,
+/// the children are loaded when their parent chunk is loaded, and the prefetched children of the
+/// entry chunks are loaded after startup.
+#[derive(Debug)]
+pub struct ChunkPrefetchPreloadPlugin;
+
+#[async_trait]
+impl Plugin for ChunkPrefetchPreloadPlugin {
+  fn name(&self) -> &'static str {
+    "ChunkPrefetchPreloadPlugin"
+  }
+
+  fn additional_chunk_runtime_requirements(
+    &self,
+    _ctx: PluginContext,
+    args: &mut AdditionalChunkRuntimeRequirementsArgs,
+  ) -> PluginAdditionalChunkRuntimeRequirementsOutput {
+    let compilation = &mut args.compilation;
+    let chunk_ukey = args.chunk;
+    if compilation
+      .chunk_graph
+      .get_number_of_entry_modules(chunk_ukey)
+      == 0
+    {
+      return Ok(());
+    }
+    let chunk = compilation.chunk_by_ukey.expect_get(chunk_ukey);
+    if let Some(startup_child_chunks) =
+      chunk.get_children_of_type_in_order(&ChunkGroupOrderKey::Prefetch, compilation)
+    {
+      args
+        .runtime_requirements
+        .insert(RuntimeGlobals::PREFETCH_CHUNK);
+      args
+        .runtime_requirements
+        .insert(RuntimeGlobals::ON_CHUNKS_LOADED);
+      compilation.add_runtime_module(
+        chunk_ukey,
+        ChunkPrefetchStartupRuntimeModule::new(startup_child_chunks).boxed(),
+      );
+    }
+    Ok(())
+  }
+
+  fn additional_tree_runtime_requirements(
+    &self,
+    _ctx: PluginContext,
+    args: &mut AdditionalChunkRuntimeRequirementsArgs,
+  ) -> PluginAdditionalChunkRuntimeRequirementsOutput {
+    let compilation = &mut args.compilation;
+    let chunk = compilation.chunk_by_ukey.expect_get(args.chunk);
+    let prefetch_map =
+      chunk.get_child_ids_by_orders_map(&ChunkGroupOrderKey::Prefetch, compilation);
+    let preload_map = chunk.get_child_ids_by_orders_map(&ChunkGroupOrderKey::Preload, compilation);
+    let runtime_requirements = &mut args.runtime_requirements;
+
+    if !prefetch_map.is_empty() {
+      runtime_requirements.insert(RuntimeGlobals::PREFETCH_CHUNK);
+      compilation.add_runtime_module(
+        args.chunk,
+        ChunkPrefetchTriggerRuntimeModule::new(prefetch_map.into_iter().collect()).boxed(),
+      );
+    }
+    if !preload_map.is_empty() {
+      runtime_requirements.insert(RuntimeGlobals::PRELOAD_CHUNK);
+      compilation.add_runtime_module(
+        args.chunk,
+        ChunkPreloadTriggerRuntimeModule::new(preload_map.into_iter().collect()).boxed(),
+      );
+    }
+    // the handlers are required here, so the chunk loading plugins can provide them in
+    // `runtime_requirements_in_tree`
+    if runtime_requirements.contains(RuntimeGlobals::PREFETCH_CHUNK) {
+      runtime_requirements.insert(RuntimeGlobals::PREFETCH_CHUNK_HANDLERS);
+    }
+    if runtime_requirements.contains(RuntimeGlobals::PRELOAD_CHUNK) {
+      runtime_requirements.insert(RuntimeGlobals::PRELOAD_CHUNK_HANDLERS);
+    }
+    Ok(())
+  }
+
+  fn runtime_requirements_in_tree(
+    &self,
+    _ctx: PluginContext,
+    args: &mut AdditionalChunkRuntimeRequirementsArgs,
+  ) -> PluginAdditionalChunkRuntimeRequirementsOutput {
+    let compilation = &mut args.compilation;
+    let chunk = args.chunk;
+    let runtime_requirements = &mut args.runtime_requirements;
+
+    if runtime_requirements.contains(RuntimeGlobals::PREFETCH_CHUNK) {
+      compilation.add_runtime_module(
+        chunk,
+        ChunkPrefetchPreloadFunctionRuntimeModule::new(
+          "prefetch",
+          RuntimeGlobals::PREFETCH_CHUNK,
+          RuntimeGlobals::PREFETCH_CHUNK_HANDLERS,
+        )
+        .boxed(),
+      );
+    }
+    if runtime_requirements.contains(RuntimeGlobals::PRELOAD_CHUNK) {
+      compilation.add_runtime_module(
+        chunk,
+        ChunkPrefetchPreloadFunctionRuntimeModule::new(
+          "preload",
+          RuntimeGlobals::PRELOAD_CHUNK,
+          RuntimeGlobals::PRELOAD_CHUNK_HANDLERS,
+        )
+        .boxed(),
+      );
+    }
+    Ok(())
+  }
+}
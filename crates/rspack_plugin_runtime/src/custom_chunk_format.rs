@@ -0,0 +1,77 @@
+use std::hash::Hash;
+
+use async_trait::async_trait;
+use rspack_core::{
+  AdditionalChunkRuntimeRequirementsArgs, ChunkFormat, Compilation, JsChunkHashArgs, Plugin,
+  PluginAdditionalChunkRuntimeRequirementsOutput, PluginContext, PluginJsChunkHashHookOutput,
+  PluginRenderChunkHookOutput, RenderChunkArgs,
+};
+use rspack_error::{internal_error, Result};
+
+/// Renders the chunks by the chunk format registered by `ApplyContext::register_chunk_format` for a
+/// custom chunk format.
+#[derive(Debug)]
+pub struct CustomChunkFormatPlugin {
+  chunk_format: String,
+}
+
+impl CustomChunkFormatPlugin {
+  pub fn new(chunk_format: String) -> Self {
+    Self { chunk_format }
+  }
+
+  fn get_chunk_format<'a>(&self, compilation: &'a Compilation) -> Result<&'a dyn ChunkFormat> {
+    compilation
+      .plugin_driver
+      .registered_chunk_format
+      .get(&self.chunk_format)
+      .map(AsRef::as_ref)
+      .ok_or_else(|| {
+        internal_error!(
+          "Unsupported chunk format {}, plugins providing custom chunk formats should register it by `ApplyContext::register_chunk_format`",
+          self.chunk_format
+        )
+      })
+  }
+}
+
+#[async_trait]
+impl Plugin for CustomChunkFormatPlugin {
+  fn name(&self) -> &'static str {
+    "CustomChunkFormatPlugin"
+  }
+
+  fn additional_chunk_runtime_requirements(
+    &self,
+    _ctx: PluginContext,
+    args: &mut AdditionalChunkRuntimeRequirementsArgs,
+  ) -> PluginAdditionalChunkRuntimeRequirementsOutput {
+    let plugin_driver = args.compilation.plugin_driver.clone();
+    let Some(chunk_format) = plugin_driver.registered_chunk_format.get(&self.chunk_format) else {
+      // reported by `get_chunk_format` when the chunk is rendered
+      return Ok(());
+    };
+    chunk_format.additional_chunk_runtime_requirements(args)
+  }
+
+  fn js_chunk_hash(
+    &self,
+    _ctx: PluginContext,
+    args: &mut JsChunkHashArgs,
+  ) -> PluginJsChunkHashHookOutput {
+    self.name().hash(&mut args.hasher);
+    self.chunk_format.hash(&mut args.hasher);
+    self.get_chunk_format(args.compilation)?.js_chunk_hash(args)
+  }
+
+  async fn render_chunk(
+    &self,
+    _ctx: PluginContext,
+    args: &RenderChunkArgs,
+  ) -> PluginRenderChunkHookOutput {
+    self
+      .get_chunk_format(args.compilation)?
+      .render_chunk(args)
+      .map(Some)
+  }
+}
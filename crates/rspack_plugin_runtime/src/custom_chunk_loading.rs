@@ -0,0 +1,63 @@
+use async_trait::async_trait;
+use rspack_core::{
+  AdditionalChunkRuntimeRequirementsArgs, ChunkLoading, ChunkLoadingType, Plugin,
+  PluginAdditionalChunkRuntimeRequirementsOutput, PluginContext, RuntimeGlobals,
+};
+use rspack_error::internal_error;
+
+use crate::runtime_module::is_enabled_for_chunk;
+
+/// Adds the chunk loading runtime module registered by
+/// `ApplyContext::register_chunk_loading_runtime_module_builder` for a custom chunk loading type.
+#[derive(Debug)]
+pub struct CustomChunkLoadingPlugin {
+  chunk_loading_type: String,
+}
+
+impl CustomChunkLoadingPlugin {
+  pub fn new(chunk_loading_type: String) -> Self {
+    Self { chunk_loading_type }
+  }
+}
+
+#[async_trait]
+impl Plugin for CustomChunkLoadingPlugin {
+  fn name(&self) -> &'static str {
+    "CustomChunkLoadingPlugin"
+  }
+
+  fn runtime_requirements_in_tree(
+    &self,
+    _ctx: PluginContext,
+    args: &mut AdditionalChunkRuntimeRequirementsArgs,
+  ) -> PluginAdditionalChunkRuntimeRequirementsOutput {
+    let compilation = &mut args.compilation;
+    let chunk = args.chunk;
+    let chunk_loading_value =
+      ChunkLoading::Enable(ChunkLoadingType::Custom(self.chunk_loading_type.clone()));
+    if !is_enabled_for_chunk(chunk, &chunk_loading_value, compilation)
+      || !args
+        .runtime_requirements
+        .contains(RuntimeGlobals::ENSURE_CHUNK_HANDLERS)
+    {
+      return Ok(());
+    }
+
+    let plugin_driver = compilation.plugin_driver.clone();
+    let Some(runtime_module_builder) = plugin_driver
+      .registered_chunk_loading_runtime_module_builder
+      .get(&self.chunk_loading_type)
+    else {
+      return Err(internal_error!(
+        "Unsupported chunk loading type {}, plugins providing custom chunk loading types should register its runtime module by `ApplyContext::register_chunk_loading_runtime_module_builder`",
+        self.chunk_loading_type
+      ));
+    };
+    let runtime_requirements = &mut args.runtime_requirements;
+    runtime_requirements.insert(RuntimeGlobals::MODULE_FACTORIES_ADD_ONLY);
+    runtime_requirements.insert(RuntimeGlobals::HAS_OWN_PROPERTY);
+    let runtime_module = runtime_module_builder(runtime_requirements);
+    compilation.add_runtime_module(chunk, runtime_module);
+    Ok(())
+  }
+}
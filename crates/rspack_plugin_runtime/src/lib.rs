@@ -25,9 +25,19 @@ pub use module_chunk_loading::ModuleChunkLoadingPlugin;
 mod import_scripts_chunk_loading;
 pub use import_scripts_chunk_loading::ImportScriptsChunkLoadingPlugin;
 mod runtime_module;
+pub use runtime_module::{is_enabled_for_chunk, ConsumeSharedRuntimeModule};
 mod startup_chunk_dependencies;
 pub use startup_chunk_dependencies::StartupChunkDependenciesPlugin;
+mod chunk_prefetch_preload;
+pub use chunk_prefetch_preload::ChunkPrefetchPreloadPlugin;
+mod custom_chunk_loading;
+pub use custom_chunk_loading::CustomChunkLoadingPlugin;
+mod custom_chunk_format;
+pub use custom_chunk_format::CustomChunkFormatPlugin;
 
+/// Applies the plugins implementing a chunk loading type. The runtime module of a custom chunk
+/// loading type is provided by the plugin introducing it, see
+/// `ApplyContext::register_chunk_loading_runtime_module_builder`.
 pub fn enable_chunk_loading_plugin(loading_type: ChunkLoadingType, plugins: &mut Vec<BoxPlugin>) {
   match loading_type {
     ChunkLoadingType::Jsonp => {
@@ -62,5 +72,8 @@ pub fn enable_chunk_loading_plugin(loading_type: ChunkLoadingType, plugins: &mut
       plugins.push(ImportScriptsChunkLoadingPlugin.boxed());
     }
     ChunkLoadingType::Import => plugins.push(ModuleChunkLoadingPlugin.boxed()),
+    ChunkLoadingType::Custom(chunk_loading_type) => {
+      plugins.push(CustomChunkLoadingPlugin::new(chunk_loading_type).boxed())
+    }
   }
 }
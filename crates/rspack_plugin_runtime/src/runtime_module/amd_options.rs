@@ -0,0 +1,32 @@
+use rspack_core::{
+  rspack_sources::{BoxSource, RawSource, SourceExt},
+  Compilation, RuntimeModule,
+};
+use rspack_identifier::Identifier;
+
+use crate::impl_runtime_module;
+
+#[derive(Debug, Eq)]
+pub struct AmdOptionsRuntimeModule {
+  id: Identifier,
+}
+
+impl Default for AmdOptionsRuntimeModule {
+  fn default() -> Self {
+    Self {
+      id: Identifier::from("webpack/runtime/amd_options"),
+    }
+  }
+}
+
+impl RuntimeModule for AmdOptionsRuntimeModule {
+  fn name(&self) -> Identifier {
+    self.id
+  }
+
+  fn generate(&self, _compilation: &Compilation) -> BoxSource {
+    RawSource::from(include_str!("runtime/amd_options.js")).boxed()
+  }
+}
+
+impl_runtime_module!(AmdOptionsRuntimeModule);
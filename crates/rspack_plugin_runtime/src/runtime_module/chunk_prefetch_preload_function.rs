@@ -0,0 +1,51 @@
+use rspack_core::{
+  rspack_sources::{BoxSource, RawSource, SourceExt},
+  Compilation, RuntimeGlobals, RuntimeModule,
+};
+use rspack_identifier::Identifier;
+
+use crate::impl_runtime_module;
+
+#[derive(Debug, Eq)]
+pub struct ChunkPrefetchPreloadFunctionRuntimeModule {
+  id: Identifier,
+  runtime_function: RuntimeGlobals,
+  runtime_handlers: RuntimeGlobals,
+}
+
+impl ChunkPrefetchPreloadFunctionRuntimeModule {
+  pub fn new(
+    child_type: &str,
+    runtime_function: RuntimeGlobals,
+    runtime_handlers: RuntimeGlobals,
+  ) -> Self {
+    Self {
+      id: Identifier::from(format!("webpack/runtime/chunk_{child_type}_function")),
+      runtime_function,
+      runtime_handlers,
+    }
+  }
+}
+
+impl RuntimeModule for ChunkPrefetchPreloadFunctionRuntimeModule {
+  fn name(&self) -> Identifier {
+    self.id
+  }
+
+  fn generate(&self, _compilation: &Compilation) -> BoxSource {
+    RawSource::from(format!(
+      r#"{handlers} = {{}};
+{function} = function(chunkId) {{
+  Object.keys({handlers}).map(function(key) {{
+    {handlers}[key](chunkId);
+  }});
+}};
+"#,
+      handlers = self.runtime_handlers,
+      function = self.runtime_function,
+    ))
+    .boxed()
+  }
+}
+
+impl_runtime_module!(ChunkPrefetchPreloadFunctionRuntimeModule);
@@ -0,0 +1,94 @@
+use rspack_core::{
+  rspack_sources::{BoxSource, RawSource, SourceExt},
+  ChunkUkey, Compilation, RuntimeGlobals, RuntimeModule, RUNTIME_MODULE_STAGE_TRIGGER,
+};
+use rspack_identifier::Identifier;
+use rspack_plugin_javascript::runtime::stringify_array;
+
+use crate::impl_runtime_module;
+
+#[derive(Debug, Eq)]
+pub struct ChunkPrefetchStartupRuntimeModule {
+  id: Identifier,
+  /// The prefetched chunks with the chunks which should be loaded before prefetching
+  startup_chunks: Vec<(Vec<ChunkUkey>, Vec<ChunkUkey>)>,
+  chunk: Option<ChunkUkey>,
+}
+
+impl ChunkPrefetchStartupRuntimeModule {
+  pub fn new(startup_chunks: Vec<(Vec<ChunkUkey>, Vec<ChunkUkey>)>) -> Self {
+    Self {
+      id: Identifier::from("webpack/runtime/chunk_prefetch_startup"),
+      startup_chunks,
+      chunk: None,
+    }
+  }
+}
+
+impl RuntimeModule for ChunkPrefetchStartupRuntimeModule {
+  fn name(&self) -> Identifier {
+    self.id
+  }
+
+  fn generate(&self, compilation: &Compilation) -> BoxSource {
+    let chunk_ukey = self
+      .chunk
+      .expect("should have chunk for ChunkPrefetchStartupRuntimeModule");
+    let chunk_id = |chunk_ukey: &ChunkUkey| {
+      compilation
+        .chunk_by_ukey
+        .get(chunk_ukey)
+        .expect("Chunk not found")
+        .expect_id()
+        .to_string()
+    };
+    let source = self
+      .startup_chunks
+      .iter()
+      .map(|(on_chunks, chunks)| {
+        // only wait for the current chunk, so prefetching starts after it has been fully loaded
+        let on_chunk_ids = on_chunks
+          .iter()
+          .filter(|on_chunk| **on_chunk == chunk_ukey)
+          .map(chunk_id)
+          .collect::<Vec<_>>();
+        let chunk_ids = chunks.iter().map(chunk_id).collect::<Vec<_>>();
+        let body = if chunk_ids.len() < 3 {
+          chunk_ids
+            .iter()
+            .map(|chunk_id| {
+              format!(
+                "{}({});",
+                RuntimeGlobals::PREFETCH_CHUNK,
+                serde_json::to_string(chunk_id).expect("invalid json to_string")
+              )
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+        } else {
+          format!(
+            "{}.map({});",
+            stringify_array(&chunk_ids),
+            RuntimeGlobals::PREFETCH_CHUNK
+          )
+        };
+        format!(
+          "{}(0, {}, function() {{\n{body}\n}}, 5);\n",
+          RuntimeGlobals::ON_CHUNKS_LOADED,
+          stringify_array(&on_chunk_ids)
+        )
+      })
+      .collect::<String>();
+    RawSource::from(source).boxed()
+  }
+
+  fn attach(&mut self, chunk: ChunkUkey) {
+    self.chunk = Some(chunk);
+  }
+
+  fn stage(&self) -> u8 {
+    RUNTIME_MODULE_STAGE_TRIGGER
+  }
+}
+
+impl_runtime_module!(ChunkPrefetchStartupRuntimeModule);
@@ -0,0 +1,53 @@
+use std::collections::BTreeMap;
+
+use rspack_core::{
+  rspack_sources::{BoxSource, RawSource, SourceExt},
+  Compilation, RuntimeGlobals, RuntimeModule, RUNTIME_MODULE_STAGE_TRIGGER,
+};
+use rspack_identifier::Identifier;
+
+use crate::impl_runtime_module;
+
+#[derive(Debug, Eq)]
+pub struct ChunkPrefetchTriggerRuntimeModule {
+  id: Identifier,
+  chunk_map: BTreeMap<String, Vec<String>>,
+}
+
+impl ChunkPrefetchTriggerRuntimeModule {
+  pub fn new(chunk_map: BTreeMap<String, Vec<String>>) -> Self {
+    Self {
+      id: Identifier::from("webpack/runtime/chunk_prefetch_trigger"),
+      chunk_map,
+    }
+  }
+}
+
+impl RuntimeModule for ChunkPrefetchTriggerRuntimeModule {
+  fn name(&self) -> Identifier {
+    self.id
+  }
+
+  fn generate(&self, _compilation: &Compilation) -> BoxSource {
+    RawSource::from(format!(
+      r#"var chunkToChildrenMap = {chunk_map};
+{ensure_chunk_handlers}.prefetch = function(chunkId, promises) {{
+  return Promise.all(promises).then(function() {{
+    var chunks = chunkToChildrenMap[chunkId];
+    Array.isArray(chunks) && chunks.map({prefetch_chunk});
+  }});
+}};
+"#,
+      chunk_map = serde_json::to_string(&self.chunk_map).expect("invalid json to_string"),
+      ensure_chunk_handlers = RuntimeGlobals::ENSURE_CHUNK_HANDLERS,
+      prefetch_chunk = RuntimeGlobals::PREFETCH_CHUNK,
+    ))
+    .boxed()
+  }
+
+  fn stage(&self) -> u8 {
+    RUNTIME_MODULE_STAGE_TRIGGER
+  }
+}
+
+impl_runtime_module!(ChunkPrefetchTriggerRuntimeModule);
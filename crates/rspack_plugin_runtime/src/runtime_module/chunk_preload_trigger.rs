@@ -0,0 +1,51 @@
+use std::collections::BTreeMap;
+
+use rspack_core::{
+  rspack_sources::{BoxSource, RawSource, SourceExt},
+  Compilation, RuntimeGlobals, RuntimeModule, RUNTIME_MODULE_STAGE_TRIGGER,
+};
+use rspack_identifier::Identifier;
+
+use crate::impl_runtime_module;
+
+#[derive(Debug, Eq)]
+pub struct ChunkPreloadTriggerRuntimeModule {
+  id: Identifier,
+  chunk_map: BTreeMap<String, Vec<String>>,
+}
+
+impl ChunkPreloadTriggerRuntimeModule {
+  pub fn new(chunk_map: BTreeMap<String, Vec<String>>) -> Self {
+    Self {
+      id: Identifier::from("webpack/runtime/chunk_preload_trigger"),
+      chunk_map,
+    }
+  }
+}
+
+impl RuntimeModule for ChunkPreloadTriggerRuntimeModule {
+  fn name(&self) -> Identifier {
+    self.id
+  }
+
+  fn generate(&self, _compilation: &Compilation) -> BoxSource {
+    RawSource::from(format!(
+      r#"var chunkToChildrenMap = {chunk_map};
+{ensure_chunk_handlers}.preload = function(chunkId) {{
+  var chunks = chunkToChildrenMap[chunkId];
+  Array.isArray(chunks) && chunks.map({preload_chunk});
+}};
+"#,
+      chunk_map = serde_json::to_string(&self.chunk_map).expect("invalid json to_string"),
+      ensure_chunk_handlers = RuntimeGlobals::ENSURE_CHUNK_HANDLERS,
+      preload_chunk = RuntimeGlobals::PRELOAD_CHUNK,
+    ))
+    .boxed()
+  }
+
+  fn stage(&self) -> u8 {
+    RUNTIME_MODULE_STAGE_TRIGGER
+  }
+}
+
+impl_runtime_module!(ChunkPreloadTriggerRuntimeModule);
@@ -0,0 +1,152 @@
+use std::collections::BTreeMap;
+
+use rspack_core::{
+  rspack_sources::{BoxSource, RawSource, Source, SourceExt},
+  ChunkUkey, Compilation, RuntimeModule, SourceType, RUNTIME_MODULE_STAGE_ATTACH,
+};
+use rspack_identifier::Identifier;
+
+use crate::impl_runtime_module;
+
+#[derive(Debug, Eq)]
+pub struct ConsumeSharedRuntimeModule {
+  id: Identifier,
+  chunk: Option<ChunkUkey>,
+}
+
+impl Default for ConsumeSharedRuntimeModule {
+  fn default() -> Self {
+    Self {
+      id: Identifier::from("webpack/runtime/consumes_loading"),
+      chunk: None,
+    }
+  }
+}
+
+impl ConsumeSharedRuntimeModule {
+  /// Collect the ids of the consume shared modules in the chunk, along with the code which
+  /// returns the factory of each module.
+  fn add_modules<'a>(
+    compilation: &'a Compilation,
+    chunk_ukey: &ChunkUkey,
+    ids: &mut Vec<&'a str>,
+    module_id_to_handler: &mut BTreeMap<&'a str, String>,
+  ) {
+    let chunk = compilation
+      .chunk_by_ukey
+      .get(chunk_ukey)
+      .expect("should have chunk");
+    let mut modules = compilation
+      .chunk_graph
+      .get_chunk_modules_iterable_by_source_type(
+        chunk_ukey,
+        SourceType::ConsumeShared,
+        &compilation.module_graph,
+      )
+      .map(|module| module.identifier())
+      .collect::<Vec<_>>();
+    modules.sort_unstable();
+    for module_identifier in modules {
+      let Some(id) = compilation
+        .chunk_graph
+        .get_module_id(module_identifier)
+        .as_deref()
+      else {
+        continue;
+      };
+      let Some(handler) = compilation
+        .code_generation_results
+        .get(&module_identifier, Some(&chunk.runtime))
+        .ok()
+        .and_then(|result| result.get(&SourceType::ConsumeShared))
+        .and_then(|result| result.ast_or_source.as_source())
+      else {
+        continue;
+      };
+      ids.push(id);
+      module_id_to_handler.insert(id, handler.source().to_string());
+    }
+  }
+}
+
+impl RuntimeModule for ConsumeSharedRuntimeModule {
+  fn name(&self) -> Identifier {
+    self.id
+  }
+
+  fn generate(&self, compilation: &Compilation) -> BoxSource {
+    let chunk = compilation
+      .chunk_by_ukey
+      .get(&self.chunk.expect("The chunk should be attached"))
+      .expect("should have chunk");
+
+    let mut module_id_to_handler = BTreeMap::new();
+    // chunk id -> ids of the consume shared modules in the chunk
+    let mut chunk_mapping: BTreeMap<&str, Vec<&str>> = BTreeMap::new();
+    for chunk_ukey in chunk.get_all_async_chunks(&compilation.chunk_group_by_ukey) {
+      let mut ids = vec![];
+      Self::add_modules(
+        compilation,
+        &chunk_ukey,
+        &mut ids,
+        &mut module_id_to_handler,
+      );
+      if !ids.is_empty() {
+        let async_chunk = compilation
+          .chunk_by_ukey
+          .get(&chunk_ukey)
+          .expect("should have chunk");
+        chunk_mapping.insert(async_chunk.expect_id(), ids);
+      }
+    }
+    let mut initial_consumes = vec![];
+    for chunk_ukey in chunk.get_all_initial_chunks(&compilation.chunk_group_by_ukey) {
+      Self::add_modules(
+        compilation,
+        &chunk_ukey,
+        &mut initial_consumes,
+        &mut module_id_to_handler,
+      );
+    }
+    initial_consumes.sort_unstable();
+    initial_consumes.dedup();
+
+    let module_to_handler_mapping = module_id_to_handler
+      .into_iter()
+      .map(|(id, handler)| {
+        format!(
+          "{}: {handler}",
+          serde_json::to_string(id).expect("invalid json to_string")
+        )
+      })
+      .collect::<Vec<_>>()
+      .join(",\n");
+    let mut source = include_str!("runtime/consumes_common.js").replace(
+      "$MODULE_TO_HANDLER_MAPPING$",
+      &format!("{{\n{module_to_handler_mapping}\n}}"),
+    );
+    if !initial_consumes.is_empty() {
+      source.push_str(&include_str!("runtime/consumes_initial.js").replace(
+        "$INITIAL_CONSUMES$",
+        &serde_json::to_string(&initial_consumes).expect("invalid json to_string"),
+      ));
+    }
+    if !chunk_mapping.is_empty() {
+      source.push_str(&include_str!("runtime/consumes_loading.js").replace(
+        "$CHUNK_MAPPING$",
+        &serde_json::to_string(&chunk_mapping).expect("invalid json to_string"),
+      ));
+    }
+    RawSource::from(source).boxed()
+  }
+
+  fn attach(&mut self, chunk: ChunkUkey) {
+    self.chunk = Some(chunk);
+  }
+
+  fn stage(&self) -> u8 {
+    RUNTIME_MODULE_STAGE_ATTACH
+  }
+}
+
+impl_runtime_module!(ConsumeSharedRuntimeModule);
@@ -81,6 +81,30 @@ impl RuntimeModule for JsonpChunkLoadingRuntimeModule {
       ));
     }
 
+    if self
+      .runtime_requirements
+      .contains(RuntimeGlobals::PREFETCH_CHUNK_HANDLERS)
+    {
+      source.add(RawSource::from(
+        include_str!("runtime/jsonp_chunk_loading_with_prefetch.js").replace(
+          "__CROSS_ORIGIN_LOADING_PLACEHOLDER__",
+          &compilation.options.output.cross_origin_loading.to_string(),
+        ),
+      ));
+    }
+
+    if self
+      .runtime_requirements
+      .contains(RuntimeGlobals::PRELOAD_CHUNK_HANDLERS)
+    {
+      source.add(RawSource::from(
+        include_str!("runtime/jsonp_chunk_loading_with_preload.js").replace(
+          "__CROSS_ORIGIN_LOADING_PLACEHOLDER__",
+          &compilation.options.output.cross_origin_loading.to_string(),
+        ),
+      ));
+    }
+
     if self
       .runtime_requirements
       .contains(RuntimeGlobals::HMR_DOWNLOAD_UPDATE_HANDLERS)
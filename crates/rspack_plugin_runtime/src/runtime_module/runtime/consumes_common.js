@@ -0,0 +1,402 @@
+var parseVersion = function (str) {
+	var splitAndConvert = function (str) {
+		return str.split(".").map(function (item) {
+			return +item == item ? +item : item;
+		});
+	};
+	var match = /^([^-+]+)?(?:-([^+]+))?(?:\+(.+))?$/.exec(str);
+	var ver = match[1] ? splitAndConvert(match[1]) : [];
+	if (match[2]) {
+		ver.length++;
+		ver.push.apply(ver, splitAndConvert(match[2]));
+	}
+	if (match[3]) {
+		ver.push([]);
+		ver.push.apply(ver, splitAndConvert(match[3]));
+	}
+	return ver;
+};
+var versionLt = function (a, b) {
+	a = parseVersion(a);
+	b = parseVersion(b);
+	var i = 0;
+	for (;;) {
+		if (i >= a.length) return i < b.length && (typeof b[i])[0] != "u";
+		var aValue = a[i];
+		var aType = (typeof aValue)[0];
+		if (i >= b.length) return aType == "u";
+		var bValue = b[i];
+		var bType = (typeof bValue)[0];
+		if (aType == bType) {
+			if (aType != "o" && aType != "u" && aValue != bValue) {
+				return aValue < bValue;
+			}
+			i++;
+		} else {
+			if (aType == "o" && bType == "n") return true;
+			return bType == "s" || aType == "u";
+		}
+	}
+};
+var rangeToString = function (range) {
+	var fixCount = range[0];
+	var str = "";
+	if (range.length === 1) {
+		return "*";
+	} else if (fixCount + 0.5) {
+		str +=
+			fixCount == 0
+				? ">="
+				: fixCount == -1
+				? "<"
+				: fixCount == 1
+				? "^"
+				: fixCount == 2
+				? "~"
+				: fixCount > 0
+				? "="
+				: "!=";
+		var needDot = 1;
+		for (var i = 1; i < range.length; i++) {
+			var item = range[i];
+			var t = (typeof item)[0];
+			needDot--;
+			str +=
+				t == "u"
+					? "-"
+					: (needDot > 0 ? "." : "") + ((needDot = 2), item);
+		}
+		return str;
+	} else {
+		var stack = [];
+		for (var i = 1; i < range.length; i++) {
+			var item = range[i];
+			stack.push(
+				item === 0
+					? "not(" + pop() + ")"
+					: item === 1
+					? "(" + pop() + " || " + pop() + ")"
+					: item === 2
+					? stack.pop() + " " + stack.pop()
+					: rangeToString(item)
+			);
+		}
+		return pop();
+	}
+	function pop() {
+		return stack.pop().replace(/^\((.+)\)$/, "$1");
+	}
+};
+var satisfy = function (range, version) {
+	if (0 in range) {
+		version = parseVersion(version);
+		var fixCount = range[0];
+		// when negated is set it is checked for < instead of >=
+		var negated = fixCount < 0;
+		if (negated) fixCount = -fixCount - 1;
+		for (var i = 0, j = 1, isEqual = true; ; j++, i++) {
+			var rangeType = j < range.length ? (typeof range[j])[0] : "";
+			var versionValue;
+			var versionType;
+			// end of the version or build metadata
+			if (
+				i >= version.length ||
+				((versionValue = version[i]),
+				(versionType = (typeof versionValue)[0]) == "o")
+			) {
+				if (!isEqual) return true;
+				if (rangeType == "u") return j > fixCount && !negated;
+				return (rangeType == "") != negated;
+			}
+			// prerelease marker of the version
+			if (versionType == "u") {
+				if (!isEqual || rangeType != "u") {
+					return false;
+				}
+			} else if (isEqual) {
+				if (rangeType == versionType) {
+					if (j <= fixCount) {
+						if (versionValue != range[j]) {
+							return false;
+						}
+					} else {
+						if (negated ? versionValue > range[j] : versionValue < range[j]) {
+							return false;
+						}
+						if (versionValue != range[j]) isEqual = false;
+					}
+				} else if (rangeType != "s" && rangeType != "n") {
+					if (negated || j <= fixCount) return false;
+					isEqual = false;
+					j--;
+				} else if (j <= fixCount || versionType < rangeType != negated) {
+					return false;
+				} else {
+					isEqual = false;
+				}
+			} else {
+				if (rangeType != "s" && rangeType != "n") {
+					isEqual = false;
+					j--;
+				}
+			}
+		}
+	}
+	// logical combination of the ranges: 0 is not, 1 is or, 2 is and
+	var stack = [];
+	var p = stack.pop.bind(stack);
+	for (var i = 1; i < range.length; i++) {
+		var item = range[i];
+		stack.push(
+			item == 1
+				? p() | p()
+				: item == 2
+				? p() & p()
+				: item
+				? satisfy(item, version)
+				: !p()
+		);
+	}
+	return !!p();
+};
+var ensureExistence = function (scopeName, key) {
+	var scope = __webpack_require__.S[scopeName];
+	if (!scope || !__webpack_require__.o(scope, key))
+		throw new Error(
+			"Shared module " + key + " doesn't exist in shared scope " + scopeName
+		);
+	return scope;
+};
+var findVersion = function (scope, key) {
+	var versions = scope[key];
+	var key = Object.keys(versions).reduce(function (a, b) {
+		return !a || versionLt(a, b) ? b : a;
+	}, 0);
+	return key && versions[key];
+};
+var findSingletonVersionKey = function (scope, key) {
+	var versions = scope[key];
+	return Object.keys(versions).reduce(function (a, b) {
+		return !a || (!versions[a].loaded && versionLt(a, b)) ? b : a;
+	}, 0);
+};
+var getInvalidSingletonVersionMessage = function (
+	scope,
+	key,
+	version,
+	requiredVersion
+) {
+	return (
+		"Unsatisfied version " +
+		version +
+		" from " +
+		(version && scope[key][version].from) +
+		" of shared singleton module " +
+		key +
+		" (required " +
+		rangeToString(requiredVersion) +
+		")"
+	);
+};
+var getSingleton = function (scope, scopeName, key) {
+	var version = findSingletonVersionKey(scope, key);
+	return get(scope[key][version]);
+};
+var getSingletonVersion = function (scope, scopeName, key, requiredVersion) {
+	var version = findSingletonVersionKey(scope, key);
+	if (!satisfy(requiredVersion, version))
+		typeof console !== "undefined" &&
+			console.warn &&
+			console.warn(
+				getInvalidSingletonVersionMessage(scope, key, version, requiredVersion)
+			);
+	return get(scope[key][version]);
+};
+var getStrictSingletonVersion = function (
+	scope,
+	scopeName,
+	key,
+	requiredVersion
+) {
+	var version = findSingletonVersionKey(scope, key);
+	if (!satisfy(requiredVersion, version))
+		throw new Error(
+			getInvalidSingletonVersionMessage(scope, key, version, requiredVersion)
+		);
+	return get(scope[key][version]);
+};
+var findValidVersion = function (scope, key, requiredVersion) {
+	var versions = scope[key];
+	var key = Object.keys(versions).reduce(function (a, b) {
+		if (!satisfy(requiredVersion, b)) return a;
+		return !a || versionLt(a, b) ? b : a;
+	}, 0);
+	return key && versions[key];
+};
+var getInvalidVersionMessage = function (
+	scope,
+	scopeName,
+	key,
+	requiredVersion
+) {
+	var versions = scope[key];
+	return (
+		"No satisfying version (" +
+		rangeToString(requiredVersion) +
+		") of shared module " +
+		key +
+		" found in shared scope " +
+		scopeName +
+		".\n" +
+		"Available versions: " +
+		Object.keys(versions)
+			.map(function (key) {
+				return key + " from " + versions[key].from;
+			})
+			.join(", ")
+	);
+};
+var getValidVersion = function (scope, scopeName, key, requiredVersion) {
+	var entry = findValidVersion(scope, key, requiredVersion);
+	if (entry) return get(entry);
+	throw new Error(
+		getInvalidVersionMessage(scope, scopeName, key, requiredVersion)
+	);
+};
+var warnInvalidVersion = function (scope, scopeName, key, requiredVersion) {
+	typeof console !== "undefined" &&
+		console.warn &&
+		console.warn(
+			getInvalidVersionMessage(scope, scopeName, key, requiredVersion)
+		);
+};
+var get = function (entry) {
+	entry.loaded = 1;
+	return entry.get();
+};
+var init = function (fn) {
+	return function (scopeName, a, b, c) {
+		var promise = __webpack_require__.I(scopeName);
+		if (promise && promise.then)
+			return promise.then(
+				fn.bind(fn, scopeName, __webpack_require__.S[scopeName], a, b, c)
+			);
+		return fn(scopeName, __webpack_require__.S[scopeName], a, b, c);
+	};
+};
+var load = /*#__PURE__*/ init(function (scopeName, scope, key) {
+	ensureExistence(scopeName, key);
+	return get(findVersion(scope, key));
+});
+var loadFallback = /*#__PURE__*/ init(function (
+	scopeName,
+	scope,
+	key,
+	fallback
+) {
+	return scope && __webpack_require__.o(scope, key)
+		? get(findVersion(scope, key))
+		: fallback();
+});
+var loadVersionCheck = /*#__PURE__*/ init(function (
+	scopeName,
+	scope,
+	key,
+	version
+) {
+	ensureExistence(scopeName, key);
+	return get(
+		findValidVersion(scope, key, version) ||
+			warnInvalidVersion(scope, scopeName, key, version) ||
+			findVersion(scope, key)
+	);
+});
+var loadSingleton = /*#__PURE__*/ init(function (scopeName, scope, key) {
+	ensureExistence(scopeName, key);
+	return getSingleton(scope, scopeName, key);
+});
+var loadSingletonVersionCheck = /*#__PURE__*/ init(function (
+	scopeName,
+	scope,
+	key,
+	version
+) {
+	ensureExistence(scopeName, key);
+	return getSingletonVersion(scope, scopeName, key, version);
+});
+var loadStrictVersionCheck = /*#__PURE__*/ init(function (
+	scopeName,
+	scope,
+	key,
+	version
+) {
+	ensureExistence(scopeName, key);
+	return getValidVersion(scope, scopeName, key, version);
+});
+var loadStrictSingletonVersionCheck = /*#__PURE__*/ init(function (
+	scopeName,
+	scope,
+	key,
+	version
+) {
+	ensureExistence(scopeName, key);
+	return getStrictSingletonVersion(scope, scopeName, key, version);
+});
+var loadVersionCheckFallback = /*#__PURE__*/ init(function (
+	scopeName,
+	scope,
+	key,
+	version,
+	fallback
+) {
+	if (!scope || !__webpack_require__.o(scope, key)) return fallback();
+	return get(
+		findValidVersion(scope, key, version) ||
+			warnInvalidVersion(scope, scopeName, key, version) ||
+			findVersion(scope, key)
+	);
+});
+var loadSingletonFallback = /*#__PURE__*/ init(function (
+	scopeName,
+	scope,
+	key,
+	fallback
+) {
+	if (!scope || !__webpack_require__.o(scope, key)) return fallback();
+	return getSingleton(scope, scopeName, key);
+});
+var loadSingletonVersionCheckFallback = /*#__PURE__*/ init(function (
+	scopeName,
+	scope,
+	key,
+	version,
+	fallback
+) {
+	if (!scope || !__webpack_require__.o(scope, key)) return fallback();
+	return getSingletonVersion(scope, scopeName, key, version);
+});
+var loadStrictVersionCheckFallback = /*#__PURE__*/ init(function (
+	scopeName,
+	scope,
+	key,
+	version,
+	fallback
+) {
+	var entry =
+		scope &&
+		__webpack_require__.o(scope, key) &&
+		findValidVersion(scope, key, version);
+	return entry ? get(entry) : fallback();
+});
+var loadStrictSingletonVersionCheckFallback = /*#__PURE__*/ init(function (
+	scopeName,
+	scope,
+	key,
+	version,
+	fallback
+) {
+	if (!scope || !__webpack_require__.o(scope, key)) return fallback();
+	return getStrictSingletonVersion(scope, scopeName, key, version);
+});
+var installedModules = {};
+var moduleToHandlerMapping = $MODULE_TO_HANDLER_MAPPING$;
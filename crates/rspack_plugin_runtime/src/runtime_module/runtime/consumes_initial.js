@@ -0,0 +1,14 @@
+var initialConsumes = $INITIAL_CONSUMES$;
+initialConsumes.forEach(function (id) {
+	__webpack_require__.m[id] = function (module) {
+		// Handle case when module is used sync
+		installedModules[id] = 0;
+		delete __webpack_require__.c[id];
+		var factory = moduleToHandlerMapping[id]();
+		if (typeof factory !== "function")
+			throw new Error(
+				"Shared module is not available for eager consumption: " + id
+			);
+		module.exports = factory();
+	};
+});
@@ -0,0 +1,33 @@
+var chunkMapping = $CHUNK_MAPPING$;
+__webpack_require__.f.consumes = function (chunkId, promises) {
+	if (__webpack_require__.o(chunkMapping, chunkId)) {
+		chunkMapping[chunkId].forEach(function (id) {
+			if (__webpack_require__.o(installedModules, id))
+				return promises.push(installedModules[id]);
+			var onFactory = function (factory) {
+				installedModules[id] = 0;
+				__webpack_require__.m[id] = function (module) {
+					delete __webpack_require__.c[id];
+					module.exports = factory();
+				};
+			};
+			var onError = function (error) {
+				delete installedModules[id];
+				__webpack_require__.m[id] = function (module) {
+					delete __webpack_require__.c[id];
+					throw error;
+				};
+			};
+			try {
+				var promise = moduleToHandlerMapping[id]();
+				if (promise.then) {
+					promises.push(
+						(installedModules[id] = promise.then(onFactory)["catch"](onError))
+					);
+				} else onFactory(promise);
+			} catch (e) {
+				onError(e);
+			}
+		});
+	}
+};
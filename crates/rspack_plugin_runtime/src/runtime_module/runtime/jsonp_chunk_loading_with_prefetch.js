@@ -0,0 +1,16 @@
+__webpack_require__.F.j = function (chunkId) {
+	if (
+		!__webpack_require__.o(installedChunks, chunkId) ||
+		installedChunks[chunkId] === undefined
+	) {
+		installedChunks[chunkId] = null;
+		var link = document.createElement("link");
+		if (__CROSS_ORIGIN_LOADING_PLACEHOLDER__) {
+			link.crossOrigin = __CROSS_ORIGIN_LOADING_PLACEHOLDER__;
+		}
+		link.rel = "prefetch";
+		link.as = "script";
+		link.href = __webpack_require__.p + __webpack_require__.u(chunkId);
+		document.head.appendChild(link);
+	}
+};
@@ -0,0 +1,17 @@
+__webpack_require__.H.j = function (chunkId) {
+	if (
+		!__webpack_require__.o(installedChunks, chunkId) ||
+		installedChunks[chunkId] === undefined
+	) {
+		installedChunks[chunkId] = null;
+		var link = document.createElement("link");
+		link.charset = "utf-8";
+		link.rel = "preload";
+		link.as = "script";
+		link.href = __webpack_require__.p + __webpack_require__.u(chunkId);
+		if (__CROSS_ORIGIN_LOADING_PLACEHOLDER__) {
+			link.crossOrigin = __CROSS_ORIGIN_LOADING_PLACEHOLDER__;
+		}
+		document.head.appendChild(link);
+	}
+};
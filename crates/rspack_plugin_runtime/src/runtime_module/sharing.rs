@@ -0,0 +1,95 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use rspack_core::{
+  rspack_sources::{BoxSource, RawSource, SourceExt},
+  ChunkUkey, CodeGenerationDataShareInit, Compilation, RuntimeModule,
+};
+use rspack_identifier::Identifier;
+
+use crate::impl_runtime_module;
+
+#[derive(Debug, Eq)]
+pub struct ShareRuntimeModule {
+  id: Identifier,
+  chunk: Option<ChunkUkey>,
+}
+
+impl Default for ShareRuntimeModule {
+  fn default() -> Self {
+    Self {
+      id: Identifier::from("webpack/runtime/sharing"),
+      chunk: None,
+    }
+  }
+}
+
+impl RuntimeModule for ShareRuntimeModule {
+  fn name(&self) -> Identifier {
+    self.id
+  }
+
+  fn generate(&self, compilation: &Compilation) -> BoxSource {
+    let chunk = compilation
+      .chunk_by_ukey
+      .get(&self.chunk.expect("The chunk should be attached"))
+      .expect("should have chunk");
+
+    // share scope -> init stage -> init snippets
+    let mut init_per_scope: BTreeMap<&str, BTreeMap<i8, BTreeSet<&str>>> = BTreeMap::new();
+    for chunk_ukey in chunk.get_all_referenced_chunks(&compilation.chunk_group_by_ukey) {
+      for module_identifier in compilation
+        .chunk_graph
+        .get_chunk_module_identifiers(&chunk_ukey)
+      {
+        let Some(data) = compilation
+          .code_generation_results
+          .get(module_identifier, Some(&chunk.runtime))
+          .ok()
+          .and_then(|result| result.data.get::<CodeGenerationDataShareInit>())
+        else {
+          continue;
+        };
+        for item in &data.items {
+          init_per_scope
+            .entry(item.share_scope.as_str())
+            .or_default()
+            .entry(item.init_stage)
+            .or_default()
+            .insert(item.init.as_str());
+        }
+      }
+    }
+
+    let init_code = init_per_scope
+      .into_iter()
+      .map(|(share_scope, stages)| {
+        let snippets = stages
+          .into_values()
+          .flatten()
+          .map(|init| format!("{init}\n"))
+          .collect::<String>();
+        format!(
+          "case {}: {{\n{snippets}}}\nbreak;\n",
+          serde_json::to_string(share_scope).expect("invalid json to_string")
+        )
+      })
+      .collect::<String>();
+
+    RawSource::from(
+      include_str!("runtime/sharing.js")
+        .replace(
+          "$UNIQUE_NAME$",
+          &serde_json::to_string(&compilation.options.output.unique_name)
+            .expect("invalid json to_string"),
+        )
+        .replace("$INIT_CODE$", &format!("switch (name) {{\n{init_code}}}")),
+    )
+    .boxed()
+  }
+
+  fn attach(&mut self, chunk: ChunkUkey) {
+    self.chunk = Some(chunk);
+  }
+}
+
+impl_runtime_module!(ShareRuntimeModule);
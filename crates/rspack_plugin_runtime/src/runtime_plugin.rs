@@ -9,15 +9,15 @@ use rspack_core::{
 use rspack_error::Result;
 
 use crate::runtime_module::{
-  is_enabled_for_chunk, AsyncRuntimeModule, BaseUriRuntimeModule,
-  CompatGetDefaultExportRuntimeModule, CreateFakeNamespaceObjectRuntimeModule,
-  CreateScriptUrlRuntimeModule, DefinePropertyGettersRuntimeModule, EnsureChunkRuntimeModule,
-  GetChunkFilenameRuntimeModule, GetChunkUpdateFilenameRuntimeModule, GetFullHashRuntimeModule,
-  GetMainFilenameRuntimeModule, GetTrustedTypesPolicyRuntimeModule, GlobalRuntimeModule,
-  HarmonyModuleDecoratorRuntimeModule, HasOwnPropertyRuntimeModule,
-  LoadChunkWithModuleRuntimeModule, LoadScriptRuntimeModule, MakeNamespaceObjectRuntimeModule,
-  NodeModuleDecoratorRuntimeModule, NormalRuntimeModule, OnChunkLoadedRuntimeModule,
-  PublicPathRuntimeModule,
+  is_enabled_for_chunk, AmdDefineRuntimeModule, AmdOptionsRuntimeModule, AsyncRuntimeModule,
+  BaseUriRuntimeModule, CompatGetDefaultExportRuntimeModule,
+  CreateFakeNamespaceObjectRuntimeModule, CreateScriptUrlRuntimeModule,
+  DefinePropertyGettersRuntimeModule, EnsureChunkRuntimeModule, GetChunkFilenameRuntimeModule,
+  GetChunkUpdateFilenameRuntimeModule, GetFullHashRuntimeModule, GetMainFilenameRuntimeModule,
+  GetTrustedTypesPolicyRuntimeModule, GlobalRuntimeModule, HarmonyModuleDecoratorRuntimeModule,
+  HasOwnPropertyRuntimeModule, LoadChunkWithModuleRuntimeModule, LoadScriptRuntimeModule,
+  MakeNamespaceObjectRuntimeModule, NodeModuleDecoratorRuntimeModule, NormalRuntimeModule,
+  OnChunkLoadedRuntimeModule, PublicPathRuntimeModule, ShareRuntimeModule,
 };
 
 #[derive(Debug)]
@@ -119,6 +119,14 @@ impl Plugin for RuntimePlugin {
       runtime_requirements.insert(RuntimeGlobals::HAS_OWN_PROPERTY);
     }
 
+    if runtime_requirements.contains(RuntimeGlobals::INITIALIZE_SHARING) {
+      runtime_requirements.insert(RuntimeGlobals::SHARE_SCOPE_MAP);
+    }
+
+    if runtime_requirements.contains(RuntimeGlobals::SHARE_SCOPE_MAP) {
+      runtime_requirements.insert(RuntimeGlobals::HAS_OWN_PROPERTY);
+    }
+
     if runtime_requirements.contains(RuntimeGlobals::HARMONY_MODULE_DECORATOR)
       || runtime_requirements.contains(RuntimeGlobals::NODE_MODULE_DECORATOR)
     {
@@ -225,6 +233,15 @@ impl Plugin for RuntimePlugin {
         RuntimeGlobals::NODE_MODULE_DECORATOR => {
           compilation.add_runtime_module(chunk, NodeModuleDecoratorRuntimeModule::default().boxed())
         }
+        RuntimeGlobals::SHARE_SCOPE_MAP => {
+          compilation.add_runtime_module(chunk, ShareRuntimeModule::default().boxed())
+        }
+        RuntimeGlobals::AMD_DEFINE => {
+          compilation.add_runtime_module(chunk, AmdDefineRuntimeModule::default().boxed())
+        }
+        RuntimeGlobals::AMD_OPTIONS => {
+          compilation.add_runtime_module(chunk, AmdOptionsRuntimeModule::default().boxed())
+        }
         _ => {}
       }
     }
@@ -1,6 +1,7 @@
 use rspack_core::{
   Dependency, DependencyCategory, DependencyId, DependencyType, ErrorSpan, ModuleDependency,
 };
+use wasmparser::TypeRef;
 
 use crate::WasmNode;
 
@@ -30,6 +31,22 @@ impl WasmImportDependency {
   pub fn name(&self) -> &str {
     &self.name
   }
+
+  /// Globals, memories and tables can't be wrapped by a function, so they can only be imported
+  /// from the instance of another wasm module.
+  pub fn is_only_direct_import(&self) -> bool {
+    !matches!(self.desc, TypeRef::Func(_))
+  }
+
+  pub fn desc_kind(&self) -> &'static str {
+    match self.desc {
+      TypeRef::Func(_) => "Func",
+      TypeRef::Table(_) => "Table",
+      TypeRef::Memory(_) => "Memory",
+      TypeRef::Global(_) => "Global",
+      TypeRef::Tag(_) => "Tag",
+    }
+  }
 }
 
 impl Dependency for WasmImportDependency {
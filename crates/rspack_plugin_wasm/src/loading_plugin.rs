@@ -1,16 +1,141 @@
 use rspack_core::{
-  AdditionalChunkRuntimeRequirementsArgs, BoxPlugin, Plugin,
+  AdditionalChunkRuntimeRequirementsArgs, BoxPlugin, ChunkUkey, Compilation, ModuleType, Plugin,
   PluginAdditionalChunkRuntimeRequirementsOutput, PluginContext, PluginExt, RuntimeGlobals,
   RuntimeModuleExt, WasmLoadingType,
 };
+use rspack_error::internal_error;
 
-use crate::AsyncWasmLoadingRuntimeModule;
+use crate::{AsyncWasmLoadingRuntimeModule, WasmChunkLoadingRuntimeModule};
 
-pub fn enable_wasm_loading_plugin(wasm_loading_type: WasmLoadingType) -> BoxPlugin {
+pub fn enable_wasm_loading_plugin(
+  wasm_loading_type: WasmLoadingType,
+  plugins: &mut Vec<BoxPlugin>,
+) {
   match wasm_loading_type {
-    WasmLoadingType::Fetch => FetchCompileAsyncWasmPlugin.boxed(),
-    WasmLoadingType::AsyncNode => ReadFileCompileAsyncWasmPlugin::new(false).boxed(),
-    WasmLoadingType::AsyncNodeModule => ReadFileCompileAsyncWasmPlugin::new(true).boxed(),
+    WasmLoadingType::Fetch => {
+      plugins.push(FetchCompileWasmPlugin.boxed());
+      plugins.push(FetchCompileAsyncWasmPlugin.boxed());
+    }
+    WasmLoadingType::AsyncNode => {
+      plugins.push(ReadFileCompileWasmPlugin::new(false).boxed());
+      plugins.push(ReadFileCompileAsyncWasmPlugin::new(false).boxed());
+    }
+    WasmLoadingType::AsyncNodeModule => {
+      plugins.push(ReadFileCompileWasmPlugin::new(true).boxed());
+      plugins.push(ReadFileCompileAsyncWasmPlugin::new(true).boxed());
+    }
+    WasmLoadingType::Universal => {
+      plugins.push(UniversalCompileAsyncWasmPlugin.boxed());
+    }
+    WasmLoadingType::Custom(wasm_loading_type) => {
+      plugins.push(CustomWasmLoadingPlugin::new(wasm_loading_type).boxed());
+    }
+  }
+}
+
+fn read_file_compile_wasm_code(import: bool) -> String {
+  if import {
+    include_str!("runtime/read_file_compile_async_wasm_with_import.js").to_string()
+  } else {
+    include_str!("runtime/read_file_compile_async_wasm.js").to_string()
+  }
+}
+
+fn universal_compile_wasm_code() -> String {
+  let read_file = read_file_compile_wasm_code(true).replace("$PATH", "wasmUrl");
+  format!(
+    "(useFetch\n  ? fetch(new URL(wasmUrl, import.meta.url))\n  : {})",
+    read_file.trim_end().trim_end_matches(';')
+  )
+}
+
+/// Whether sync wasm modules are loaded by the async chunks of the chunk.
+fn has_sync_wasm_modules(chunk: &ChunkUkey, compilation: &Compilation) -> bool {
+  compilation
+    .chunk_by_ukey
+    .get(chunk)
+    .map(|chunk| chunk.get_all_async_chunks(&compilation.chunk_group_by_ukey))
+    .unwrap_or_default()
+    .iter()
+    .any(|chunk| {
+      compilation
+        .chunk_graph
+        .get_chunk_modules(chunk, &compilation.module_graph)
+        .iter()
+        .any(|module| *module.module_type() == ModuleType::WasmSync)
+    })
+}
+
+#[derive(Debug)]
+pub struct FetchCompileWasmPlugin;
+
+#[async_trait::async_trait]
+impl Plugin for FetchCompileWasmPlugin {
+  fn name(&self) -> &'static str {
+    "FetchCompileWasmPlugin"
+  }
+
+  fn runtime_requirements_in_tree(
+    &self,
+    _ctx: PluginContext,
+    args: &mut AdditionalChunkRuntimeRequirementsArgs,
+  ) -> PluginAdditionalChunkRuntimeRequirementsOutput {
+    let runtime_requirements = &mut args.runtime_requirements;
+
+    if runtime_requirements.contains(RuntimeGlobals::ENSURE_CHUNK_HANDLERS)
+      && has_sync_wasm_modules(args.chunk, args.compilation)
+    {
+      runtime_requirements.insert(RuntimeGlobals::MODULE_CACHE);
+      runtime_requirements.insert(RuntimeGlobals::PUBLIC_PATH);
+      args.compilation.add_runtime_module(
+        args.chunk,
+        WasmChunkLoadingRuntimeModule::new(
+          format!("fetch({} + $PATH)", RuntimeGlobals::PUBLIC_PATH),
+          true,
+        )
+        .boxed(),
+      );
+    }
+
+    Ok(())
+  }
+}
+
+#[derive(Debug)]
+pub struct ReadFileCompileWasmPlugin {
+  import: bool,
+}
+
+impl ReadFileCompileWasmPlugin {
+  fn new(import: bool) -> Self {
+    Self { import }
+  }
+}
+
+#[async_trait::async_trait]
+impl Plugin for ReadFileCompileWasmPlugin {
+  fn name(&self) -> &'static str {
+    "ReadFileCompileWasmPlugin"
+  }
+
+  fn runtime_requirements_in_tree(
+    &self,
+    _ctx: PluginContext,
+    args: &mut AdditionalChunkRuntimeRequirementsArgs,
+  ) -> PluginAdditionalChunkRuntimeRequirementsOutput {
+    let runtime_requirements = &mut args.runtime_requirements;
+
+    if runtime_requirements.contains(RuntimeGlobals::ENSURE_CHUNK_HANDLERS)
+      && has_sync_wasm_modules(args.chunk, args.compilation)
+    {
+      runtime_requirements.insert(RuntimeGlobals::MODULE_CACHE);
+      args.compilation.add_runtime_module(
+        args.chunk,
+        WasmChunkLoadingRuntimeModule::new(read_file_compile_wasm_code(self.import), false).boxed(),
+      );
+    }
+
+    Ok(())
   }
 }
 
@@ -74,15 +199,101 @@ impl Plugin for ReadFileCompileAsyncWasmPlugin {
       runtime_requirements.insert(RuntimeGlobals::PUBLIC_PATH);
       args.compilation.add_runtime_module(
         args.chunk,
-        AsyncWasmLoadingRuntimeModule::new(
-          if self.import {
-            include_str!("runtime/read_file_compile_async_wasm_with_import.js").to_string()
-          } else {
-            include_str!("runtime/read_file_compile_async_wasm.js").to_string()
-          },
-          false,
-        )
-        .boxed(),
+        AsyncWasmLoadingRuntimeModule::new(read_file_compile_wasm_code(self.import), false).boxed(),
+      );
+    }
+
+    Ok(())
+  }
+}
+
+#[derive(Debug)]
+pub struct UniversalCompileAsyncWasmPlugin;
+
+#[async_trait::async_trait]
+impl Plugin for UniversalCompileAsyncWasmPlugin {
+  fn name(&self) -> &'static str {
+    "UniversalCompileAsyncWasmPlugin"
+  }
+
+  fn runtime_requirements_in_tree(
+    &self,
+    _ctx: PluginContext,
+    args: &mut AdditionalChunkRuntimeRequirementsArgs,
+  ) -> PluginAdditionalChunkRuntimeRequirementsOutput {
+    let runtime_requirements = &mut args.runtime_requirements;
+
+    if runtime_requirements.contains(RuntimeGlobals::INSTANTIATE_WASM) {
+      args.compilation.add_runtime_module(
+        args.chunk,
+        AsyncWasmLoadingRuntimeModule::new(universal_compile_wasm_code(), true)
+          .with_before_code(
+            "var useFetch = typeof document !== 'undefined' || typeof self !== 'undefined';\nvar wasmUrl = $PATH;".to_string(),
+            "if (!useFetch) {\n  return fallback();\n}".to_string(),
+          )
+          .boxed(),
+      );
+    }
+
+    Ok(())
+  }
+}
+
+/// Adds the wasm loading runtime modules with the code registered by
+/// `ApplyContext::register_wasm_loading_code_builder` for a custom wasm loading type.
+#[derive(Debug)]
+pub struct CustomWasmLoadingPlugin {
+  wasm_loading_type: String,
+}
+
+impl CustomWasmLoadingPlugin {
+  pub fn new(wasm_loading_type: String) -> Self {
+    Self { wasm_loading_type }
+  }
+}
+
+#[async_trait::async_trait]
+impl Plugin for CustomWasmLoadingPlugin {
+  fn name(&self) -> &'static str {
+    "CustomWasmLoadingPlugin"
+  }
+
+  fn runtime_requirements_in_tree(
+    &self,
+    _ctx: PluginContext,
+    args: &mut AdditionalChunkRuntimeRequirementsArgs,
+  ) -> PluginAdditionalChunkRuntimeRequirementsOutput {
+    let runtime_requirements = &mut args.runtime_requirements;
+    let load_sync_wasm = runtime_requirements.contains(RuntimeGlobals::ENSURE_CHUNK_HANDLERS)
+      && has_sync_wasm_modules(args.chunk, args.compilation);
+    let load_async_wasm = runtime_requirements.contains(RuntimeGlobals::INSTANTIATE_WASM);
+    if !load_sync_wasm && !load_async_wasm {
+      return Ok(());
+    }
+
+    let plugin_driver = args.compilation.plugin_driver.clone();
+    let Some(wasm_loading_code_builder) = plugin_driver
+      .registered_wasm_loading_code_builder
+      .get(&self.wasm_loading_type)
+    else {
+      return Err(internal_error!(
+        "Unsupported wasm loading type {}, plugins providing custom wasm loading types should register its loading code by `ApplyContext::register_wasm_loading_code_builder`",
+        self.wasm_loading_type
+      ));
+    };
+    let code = wasm_loading_code_builder(runtime_requirements);
+    if load_sync_wasm {
+      runtime_requirements.insert(RuntimeGlobals::MODULE_CACHE);
+      args.compilation.add_runtime_module(
+        args.chunk,
+        WasmChunkLoadingRuntimeModule::new(code.load_binary_code.clone(), code.supports_streaming)
+          .boxed(),
+      );
+    }
+    if load_async_wasm {
+      args.compilation.add_runtime_module(
+        args.chunk,
+        AsyncWasmLoadingRuntimeModule::new(code.load_binary_code, code.supports_streaming).boxed(),
       );
     }
 
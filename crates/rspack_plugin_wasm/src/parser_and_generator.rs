@@ -1,25 +1,23 @@
 use std::collections::hash_map::DefaultHasher;
 use std::hash::{Hash, Hasher};
 
-use dashmap::DashMap;
+use indexmap::IndexMap;
 use rspack_core::rspack_sources::{RawSource, Source, SourceExt};
 use rspack_core::DependencyType::WasmImport;
 use rspack_core::{
-  AssetInfo, AstOrSource, BuildMetaExportsType, Compilation, Dependency, Filename, GenerateContext,
-  GenerationResult, Module, ModuleDependency, ModuleIdentifier, NormalModule, ParseContext,
-  ParseResult, ParserAndGenerator, PathData, RuntimeGlobals, SourceType,
+  AssetInfo, AstOrSource, BuildMetaExportsType, CodeGenerationDataAssetInfo,
+  CodeGenerationDataFilename, Compilation, Dependency, Filename, GenerateContext, GenerationResult,
+  Module, ModuleDependency, ModuleIdentifier, NormalModule, ParseContext, ParseResult,
+  ParserAndGenerator, PathData, RuntimeGlobals, SourceType,
 };
 use rspack_error::{Diagnostic, IntoTWithDiagnosticArray, Result, TWithDiagnosticArray};
 use rspack_identifier::Identifier;
 use wasmparser::{Import, Parser, Payload};
 
 use crate::dependency::WasmImportDependency;
-use crate::ModuleIdToFileName;
 
 #[derive(Debug)]
-pub struct AsyncWasmParserAndGenerator {
-  pub(crate) module_id_to_filename: ModuleIdToFileName,
-}
+pub struct AsyncWasmParserAndGenerator;
 
 pub(crate) static WASM_SOURCE_TYPE: &[SourceType; 2] = &[SourceType::Wasm, SourceType::JavaScript];
 
@@ -34,57 +32,7 @@ impl ParserAndGenerator for AsyncWasmParserAndGenerator {
     parse_context.build_meta.exports_type = BuildMetaExportsType::Namespace;
 
     let source = parse_context.source;
-
-    let mut exports = Vec::with_capacity(1);
-    let mut dependencies = Vec::with_capacity(1);
-    let mut diagnostic = Vec::with_capacity(1);
-
-    for payload in Parser::new(0).parse_all(&source.buffer()) {
-      match payload {
-        Ok(payload) => match payload {
-          Payload::ExportSection(s) => {
-            for export in s {
-              match export {
-                Ok(export) => exports.push(export.name.to_string()),
-                Err(err) => diagnostic.push(Diagnostic::error(
-                  "Wasm Export Parse Error".into(),
-                  err.to_string(),
-                  0,
-                  0,
-                )),
-              };
-            }
-          }
-          Payload::ImportSection(s) => {
-            for import in s {
-              match import {
-                Ok(Import { module, name, ty }) => {
-                  let dep = Box::new(WasmImportDependency::new(module.into(), name.into(), ty))
-                    as Box<dyn ModuleDependency>;
-
-                  dependencies.push(dep);
-                }
-                Err(err) => diagnostic.push(Diagnostic::error(
-                  "Wasm Import Parse Error".into(),
-                  err.to_string(),
-                  0,
-                  0,
-                )),
-              }
-            }
-          }
-          _ => {}
-        },
-        Err(err) => {
-          diagnostic.push(Diagnostic::error(
-            "Wasm Parse Error".into(),
-            err.to_string(),
-            0,
-            0,
-          ));
-        }
-      }
-    }
+    let (dependencies, diagnostic) = parse_wasm(&source.buffer());
 
     // FIXME: marking StatsExportDependency as a ModuleDependency is not correct
     // dependencies
@@ -126,12 +74,15 @@ impl ParserAndGenerator for AsyncWasmParserAndGenerator {
     let normal_module = module
       .as_normal_module()
       .expect("module should be a NormalModule in AsyncWasmParserAndGenerator::generate");
-    let wasm_path_with_info =
+    let (wasm_filename, wasm_asset_info) =
       render_wasm_name(compilation, normal_module, wasm_filename_template, hash);
 
-    self
-      .module_id_to_filename
-      .insert(module.identifier(), wasm_path_with_info.clone());
+    generate_context
+      .data
+      .insert(CodeGenerationDataFilename::new(wasm_filename.clone()));
+    generate_context
+      .data
+      .insert(CodeGenerationDataAssetInfo::new(wasm_asset_info));
 
     match generate_context.requested_source_type {
       SourceType::JavaScript => {
@@ -140,8 +91,9 @@ impl ParserAndGenerator for AsyncWasmParserAndGenerator {
         runtime_requirements.insert(RuntimeGlobals::MODULE_ID);
         runtime_requirements.insert(RuntimeGlobals::INSTANTIATE_WASM);
 
-        let dep_modules = DashMap::<ModuleIdentifier, (String, &str)>::new();
-        let wasm_deps_by_request = DashMap::<&str, Vec<(Identifier, String)>>::new();
+        // keep the order of the imports of the binary, so the output is deterministic
+        let mut dep_modules = IndexMap::<ModuleIdentifier, (String, &str)>::new();
+        let mut wasm_deps_by_request = IndexMap::<&str, Vec<(Identifier, String)>>::new();
         let mut promises: Vec<String> = vec![];
 
         let module_graph = &compilation.module_graph;
@@ -181,18 +133,14 @@ impl ParserAndGenerator for AsyncWasmParserAndGenerator {
                 let dep_name = serde_json::to_string(dep.name()).expect("should be ok.");
                 let request = dep.request();
                 let val = (mgm.module_identifier, dep_name);
-                if let Some(deps) = &mut wasm_deps_by_request.get_mut(&request) {
-                  deps.value_mut().push(val);
-                } else {
-                  wasm_deps_by_request.insert(request, vec![val]);
-                }
+                wasm_deps_by_request.entry(request).or_default().push(val);
               }
             })
         }
 
         let imports_code = dep_modules
-          .iter()
-          .map(|val| render_import_stmt(&val.value().0, val.value().1))
+          .values()
+          .map(|(import_var, module_id)| render_import_stmt(import_var, module_id))
           .collect::<Vec<_>>()
           .join("");
 
@@ -202,8 +150,7 @@ impl ParserAndGenerator for AsyncWasmParserAndGenerator {
             let deps = deps
               .into_iter()
               .map(|(id, name)| {
-                let import_var = dep_modules.get(&id).expect("should be ok");
-                let import_var = &import_var.value().0;
+                let (import_var, _) = dep_modules.get(&id).expect("should be ok");
                 format!("{name}: {import_var}[{name}]")
               })
               .collect::<Vec<_>>()
@@ -228,7 +175,7 @@ impl ParserAndGenerator for AsyncWasmParserAndGenerator {
         let instantiate_call = format!(
           "{}(exports, module.id, {} {})",
           RuntimeGlobals::INSTANTIATE_WASM,
-          serde_json::to_string(&wasm_path_with_info.0).expect("should be ok"),
+          serde_json::to_string(&wasm_filename).expect("should be ok"),
           imports_obj.unwrap_or_default()
         );
 
@@ -272,7 +219,63 @@ impl ParserAndGenerator for AsyncWasmParserAndGenerator {
   }
 }
 
-fn render_wasm_name(
+/// Collects the import dependencies of a wasm binary, exports are only validated.
+pub(crate) fn parse_wasm(buffer: &[u8]) -> (Vec<Box<dyn ModuleDependency>>, Vec<Diagnostic>) {
+  let mut exports = Vec::with_capacity(1);
+  let mut dependencies = Vec::with_capacity(1);
+  let mut diagnostic = Vec::with_capacity(1);
+
+  for payload in Parser::new(0).parse_all(buffer) {
+    match payload {
+      Ok(payload) => match payload {
+        Payload::ExportSection(s) => {
+          for export in s {
+            match export {
+              Ok(export) => exports.push(export.name.to_string()),
+              Err(err) => diagnostic.push(Diagnostic::error(
+                "Wasm Export Parse Error".into(),
+                err.to_string(),
+                0,
+                0,
+              )),
+            };
+          }
+        }
+        Payload::ImportSection(s) => {
+          for import in s {
+            match import {
+              Ok(Import { module, name, ty }) => {
+                let dep = Box::new(WasmImportDependency::new(module.into(), name.into(), ty))
+                  as Box<dyn ModuleDependency>;
+
+                dependencies.push(dep);
+              }
+              Err(err) => diagnostic.push(Diagnostic::error(
+                "Wasm Import Parse Error".into(),
+                err.to_string(),
+                0,
+                0,
+              )),
+            }
+          }
+        }
+        _ => {}
+      },
+      Err(err) => {
+        diagnostic.push(Diagnostic::error(
+          "Wasm Parse Error".into(),
+          err.to_string(),
+          0,
+          0,
+        ));
+      }
+    }
+  }
+
+  (dependencies, diagnostic)
+}
+
+pub(crate) fn render_wasm_name(
   compilation: &Compilation,
   normal_module: &NormalModule,
   wasm_filename_template: &Filename,
@@ -287,12 +290,12 @@ fn render_wasm_name(
   )
 }
 
-fn render_import_stmt(import_var: &str, module_id: &str) -> String {
+pub(crate) fn render_import_stmt(import_var: &str, module_id: &str) -> String {
   let module_id = serde_json::to_string(&module_id).expect("TODO");
   format!("var {import_var} = __webpack_require__({module_id});\n",)
 }
 
-fn hash_for_ast_or_source(ast_or_source: &AstOrSource) -> String {
+pub(crate) fn hash_for_ast_or_source(ast_or_source: &AstOrSource) -> String {
   let mut hasher = DefaultHasher::new();
   ast_or_source.hash(&mut hasher);
   format!("{:016x}", hasher.finish())
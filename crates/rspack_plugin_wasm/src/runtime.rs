@@ -1,11 +1,21 @@
+use std::collections::BTreeMap;
+
+use indexmap::IndexMap;
 use rspack_core::rspack_sources::{BoxSource, RawSource, SourceExt};
-use rspack_core::{Compilation, RuntimeModule, RUNTIME_MODULE_STAGE_ATTACH};
+use rspack_core::{
+  ChunkUkey, CodeGenerationDataFilename, Compilation, ModuleDependency, ModuleIdentifier,
+  ModuleType, RuntimeGlobals, RuntimeModule, RUNTIME_MODULE_STAGE_ATTACH,
+};
 use rspack_identifier::Identifier;
 use rspack_plugin_runtime::impl_runtime_module;
 
+use crate::dependency::WasmImportDependency;
+
 #[derive(Debug, Eq)]
 pub struct AsyncWasmLoadingRuntimeModule {
   generate_load_binary_code: String,
+  generate_before_load_binary_code: Option<String>,
+  generate_before_instantiate_streaming: Option<String>,
   id: Identifier,
   supports_streaming: bool,
 }
@@ -14,10 +24,24 @@ impl AsyncWasmLoadingRuntimeModule {
   pub fn new(generate_load_binary_code: String, supports_streaming: bool) -> Self {
     Self {
       generate_load_binary_code,
+      generate_before_load_binary_code: None,
+      generate_before_instantiate_streaming: None,
       id: Identifier::from("rspack/runtime/wasm loading"),
       supports_streaming,
     }
   }
+
+  /// Code that runs before the binary is loaded, and code that runs right before the
+  /// streaming compilation, which can `return fallback()` to skip it.
+  pub fn with_before_code(
+    mut self,
+    generate_before_load_binary_code: String,
+    generate_before_instantiate_streaming: String,
+  ) -> Self {
+    self.generate_before_load_binary_code = Some(generate_before_load_binary_code);
+    self.generate_before_instantiate_streaming = Some(generate_before_instantiate_streaming);
+    self
+  }
 }
 
 impl RuntimeModule for AsyncWasmLoadingRuntimeModule {
@@ -27,7 +51,16 @@ impl RuntimeModule for AsyncWasmLoadingRuntimeModule {
   fn generate(&self, _compilation: &Compilation) -> BoxSource {
     let path = "wasmModuleHash";
     RawSource::from(get_async_wasm_loading(
+      &self
+        .generate_before_load_binary_code
+        .as_ref()
+        .map(|code| code.replace("$PATH", path))
+        .unwrap_or_default(),
       &self.generate_load_binary_code.replace("$PATH", path),
+      self
+        .generate_before_instantiate_streaming
+        .as_deref()
+        .unwrap_or_default(),
       self.supports_streaming,
     ))
     .boxed()
@@ -40,25 +73,344 @@ impl RuntimeModule for AsyncWasmLoadingRuntimeModule {
 
 impl_runtime_module!(AsyncWasmLoadingRuntimeModule);
 
-fn get_async_wasm_loading(req: &str, supports_streaming: bool) -> String {
-  let streaming_code = if supports_streaming {
-    r#"if (typeof WebAssembly.instantiateStreaming === "function") {
-  return WebAssembly.instantiateStreaming(req, importsObj).then(
-    res => Object.assign(exports, res.instance.exports)
-  );
-}"#
+fn get_async_wasm_loading(
+  before_load: &str,
+  req: &str,
+  before_instantiate_streaming: &str,
+  supports_streaming: bool,
+) -> String {
+  let before_load = if before_load.is_empty() {
+    String::new()
   } else {
-    "// no support for streaming compilation"
+    format!("\n  {}", before_load.replace('\n', "\n  "))
   };
-  format!(
-    r#"__webpack_require__.v = (exports, wasmModuleId, wasmModuleHash, importsObj) => {{
+  if !supports_streaming {
+    return format!(
+      r#"__webpack_require__.v = (exports, wasmModuleId, wasmModuleHash, importsObj) => {{{before_load}
   var req = {req};
-  {streaming_code}
+  // no support for streaming compilation
   return req
     .then(x => x.arrayBuffer())
     .then(bytes => WebAssembly.instantiate(bytes, importsObj))
     .then(res => Object.assign(exports, res.instance.exports));
 }};
 "#
+    );
+  }
+  let before_instantiate_streaming = if before_instantiate_streaming.is_empty() {
+    String::new()
+  } else {
+    format!(
+      "\n      {}",
+      before_instantiate_streaming.replace('\n', "\n      ")
+    )
+  };
+  // servers that don't respond with the `application/wasm` MIME type make the streaming
+  // compilation fail, fall back to compiling the array buffer in this case
+  format!(
+    r#"__webpack_require__.v = (exports, wasmModuleId, wasmModuleHash, importsObj) => {{{before_load}
+  var req = {req};
+  var fallback = () => req
+    .then(x => x.arrayBuffer())
+    .then(bytes => WebAssembly.instantiate(bytes, importsObj))
+    .then(res => Object.assign(exports, res.instance.exports));
+  return req.then(res => {{
+    if (typeof WebAssembly.instantiateStreaming === "function") {{{before_instantiate_streaming}
+      return WebAssembly.instantiateStreaming(res, importsObj).then(
+        res => Object.assign(exports, res.instance.exports),
+        e => {{
+          if (res.headers.get("Content-Type") !== "application/wasm") {{
+            console.warn("`WebAssembly.instantiateStreaming` failed because your server does not serve wasm with `application/wasm` MIME type. Falling back to `WebAssembly.instantiate` which is slower. Original error:\n", e);
+            return fallback();
+          }}
+          throw e;
+        }}
+      );
+    }}
+    return fallback();
+  }});
+}};
+"#
+  )
+}
+
+#[derive(Debug, Eq)]
+pub struct WasmChunkLoadingRuntimeModule {
+  generate_load_binary_code: String,
+  id: Identifier,
+  chunk: Option<ChunkUkey>,
+  supports_streaming: bool,
+}
+
+impl WasmChunkLoadingRuntimeModule {
+  pub fn new(generate_load_binary_code: String, supports_streaming: bool) -> Self {
+    Self {
+      generate_load_binary_code,
+      id: Identifier::from("rspack/runtime/wasm chunk loading"),
+      chunk: None,
+      supports_streaming,
+    }
+  }
+}
+
+impl RuntimeModule for WasmChunkLoadingRuntimeModule {
+  fn name(&self) -> Identifier {
+    self.id
+  }
+
+  fn generate(&self, compilation: &Compilation) -> BoxSource {
+    let chunk = compilation
+      .chunk_by_ukey
+      .get(&self.chunk.expect("The chunk should be attached"))
+      .expect("should have chunk");
+
+    let mut wasm_module_map = BTreeMap::<String, Vec<String>>::new();
+    let mut wasm_module_filenames = BTreeMap::<String, String>::new();
+    let mut wasm_modules = BTreeMap::<String, ModuleIdentifier>::new();
+    for chunk_ukey in chunk.get_all_async_chunks(&compilation.chunk_group_by_ukey) {
+      let async_chunk = compilation
+        .chunk_by_ukey
+        .get(&chunk_ukey)
+        .expect("should have chunk");
+      let mut module_ids = compilation
+        .chunk_graph
+        .get_chunk_modules(&chunk_ukey, &compilation.module_graph)
+        .into_iter()
+        .filter(|m| *m.module_type() == ModuleType::WasmSync)
+        .filter_map(|m| {
+          let module_id = compilation
+            .chunk_graph
+            .get_module_id(m.identifier())
+            .clone()?;
+          let filename = compilation
+            .code_generation_results
+            .get(&m.identifier(), Some(&async_chunk.runtime))
+            .ok()
+            .and_then(|result| result.data.get::<CodeGenerationDataFilename>())
+            .map(|filename| filename.inner().to_string())?;
+          wasm_module_filenames.insert(module_id.clone(), filename);
+          wasm_modules.insert(module_id.clone(), m.identifier());
+          Some(module_id)
+        })
+        .collect::<Vec<_>>();
+      if !module_ids.is_empty() {
+        module_ids.sort_unstable();
+        wasm_module_map.insert(async_chunk.expect_id().to_string(), module_ids);
+      }
+    }
+
+    let mut declarations = vec![];
+    let import_objects = wasm_modules
+      .iter()
+      .map(|(module_id, identifier)| {
+        generate_import_object(compilation, module_id, identifier, &mut declarations)
+      })
+      .collect::<Vec<_>>()
+      .join("\n");
+
+    RawSource::from(get_wasm_chunk_loading(
+      &self
+        .generate_load_binary_code
+        .replace("$PATH", "wasmModuleFilenames[wasmModuleId]"),
+      self.supports_streaming,
+      &declarations.join("\n"),
+      &import_objects,
+      &serde_json::to_string(&wasm_module_map).expect("should be ok"),
+      &serde_json::to_string(&wasm_module_filenames).expect("should be ok"),
+    ))
+    .boxed()
+  }
+
+  fn attach(&mut self, chunk: ChunkUkey) {
+    self.chunk = Some(chunk);
+  }
+
+  fn stage(&self) -> u8 {
+    RUNTIME_MODULE_STAGE_ATTACH
+  }
+}
+
+impl_runtime_module!(WasmChunkLoadingRuntimeModule);
+
+/// Renders the factory of the imports object of a sync wasm module. Functions imported from
+/// js modules are wrapped so the js modules don't need to be executed before instantiation,
+/// while other imports need the instance of the imported wasm module.
+fn generate_import_object(
+  compilation: &Compilation,
+  module_id: &str,
+  identifier: &ModuleIdentifier,
+  declarations: &mut Vec<String>,
+) -> String {
+  let module_graph = &compilation.module_graph;
+  let mut wait_for_instances = vec![];
+  let mut properties = IndexMap::<&str, Vec<String>>::new();
+
+  let dependencies = module_graph
+    .module_graph_module_by_identifier(identifier)
+    .map(|mgm| mgm.dependencies.as_slice())
+    .unwrap_or_default();
+  for dep in dependencies
+    .iter()
+    .filter_map(|id| module_graph.dependency_by_id(id))
+    .filter_map(|dep| dep.as_any().downcast_ref::<WasmImportDependency>())
+  {
+    let Some(imported_module) = module_graph.module_graph_module_by_dependency_id(dep.id()) else {
+      continue;
+    };
+    let imported_module_id = compilation
+      .chunk_graph
+      .get_module_id(imported_module.module_identifier)
+      .as_deref()
+      .unwrap_or_default();
+    let imported_module_id = serde_json::to_string(imported_module_id).expect("should be ok");
+    let name = serde_json::to_string(dep.name()).expect("should be ok");
+
+    let value = if dep.is_only_direct_import() {
+      let instance_var = format!("m{}", wait_for_instances.len());
+      let value = format!("{instance_var}[{name}]");
+      wait_for_instances.push((instance_var, imported_module_id));
+      value
+    } else {
+      let module = format!("{}[{imported_module_id}]", RuntimeGlobals::MODULE_CACHE);
+      let module_exports = format!("{module}.exports");
+      let cache = format!("wasmImportedFuncCache{}", declarations.len());
+      declarations.push(format!("var {cache};"));
+      let prefix = if imported_module.module_type.is_wasm_like() {
+        format!("{module} ? {module_exports}[{name}] : ")
+      } else {
+        String::new()
+      };
+      format!(
+        r#"{prefix}function() {{
+  if({cache} === undefined) {cache} = {module_exports};
+  return {cache}[{name}].apply({cache}, arguments);
+}}"#
+      )
+    };
+    properties
+      .entry(dep.request())
+      .or_default()
+      .push(format!("{name}: {value}"));
+  }
+
+  let import_object = format!(
+    "return {{\n{}\n}};",
+    properties
+      .iter()
+      .map(|(request, values)| {
+        format!(
+          "{}: {{\n{}\n}}",
+          serde_json::to_string(request).expect("should be ok"),
+          values.join(",\n")
+        )
+      })
+      .collect::<Vec<_>>()
+      .join(",\n")
+  );
+  let module_id = serde_json::to_string(module_id).expect("should be ok");
+
+  match wait_for_instances.as_slice() {
+    [] => format!("{module_id}: function() {{\n{import_object}\n}},"),
+    [(instance_var, imported_module_id)] => format!(
+      r#"{module_id}: function() {{
+return promiseResolve().then(function() {{ return installedWasmModules[{imported_module_id}]; }}).then(function({instance_var}) {{
+{import_object}
+}});
+}},"#
+    ),
+    instances => {
+      let promises = instances
+        .iter()
+        .map(|(_, imported_module_id)| format!("installedWasmModules[{imported_module_id}]"))
+        .collect::<Vec<_>>()
+        .join(", ");
+      let variables = instances
+        .iter()
+        .enumerate()
+        .map(|(index, (instance_var, _))| format!("{instance_var} = array[{index}]"))
+        .collect::<Vec<_>>()
+        .join(", ");
+      format!(
+        r#"{module_id}: function() {{
+return promiseResolve().then(function() {{ return Promise.all([{promises}]); }}).then(function(array) {{
+var {variables};
+{import_object}
+}});
+}},"#
+      )
+    }
+  }
+}
+
+fn get_wasm_chunk_loading(
+  req: &str,
+  supports_streaming: bool,
+  declarations: &str,
+  import_objects: &str,
+  wasm_module_map: &str,
+  wasm_module_filenames: &str,
+) -> String {
+  let streaming_code = if supports_streaming {
+    r#"if(importObject && typeof importObject.then === 'function' && typeof WebAssembly.compileStreaming === 'function') {
+        promise = Promise.all([WebAssembly.compileStreaming(req), importObject]).then(function(items) {
+          return WebAssembly.instantiate(items[0], items[1]);
+        });
+      } else if(typeof WebAssembly.instantiateStreaming === 'function') {
+        promise = WebAssembly.instantiateStreaming(req, importObject);"#
+  } else {
+    r#"if(importObject && typeof importObject.then === 'function') {
+        var bytesPromise = req.then(function(x) { return x.arrayBuffer(); });
+        promise = Promise.all([
+          bytesPromise.then(function(bytes) { return WebAssembly.compile(bytes); }),
+          importObject
+        ]).then(function(items) {
+          return WebAssembly.instantiate(items[0], items[1]);
+        });"#
+  };
+  format!(
+    r#"// object to store loaded and loading wasm modules
+var installedWasmModules = {{}};
+
+function promiseResolve() {{ return Promise.resolve(); }}
+
+{declarations}
+var wasmImportObjects = {{
+{import_objects}
+}};
+
+var wasmModuleMap = {wasm_module_map};
+var wasmModuleFilenames = {wasm_module_filenames};
+
+// object with all WebAssembly.instance exports
+{wasm_instances} = {{}};
+
+// Fetch + compile chunk loading for webassembly
+{ensure_chunk_handlers}.wasm = function(chunkId, promises) {{
+  var wasmModules = wasmModuleMap[chunkId] || [];
+  wasmModules.forEach(function(wasmModuleId, idx) {{
+    var installedWasmModuleData = installedWasmModules[wasmModuleId];
+    // a Promise means "currently loading" or "already loaded".
+    if(installedWasmModuleData)
+      promises.push(installedWasmModuleData);
+    else {{
+      var importObject = wasmImportObjects[wasmModuleId]();
+      var req = {req};
+      var promise;
+      {streaming_code}
+      }} else {{
+        var bytesPromise = req.then(function(x) {{ return x.arrayBuffer(); }});
+        promise = bytesPromise.then(function(bytes) {{
+          return WebAssembly.instantiate(bytes, importObject);
+        }});
+      }}
+      promises.push(installedWasmModules[wasmModuleId] = promise.then(function(res) {{
+        return {wasm_instances}[wasmModuleId] = (res.instance || res).exports;
+      }}));
+    }}
+  }});
+}};
+"#,
+    wasm_instances = RuntimeGlobals::WASM_INSTANCES,
+    ensure_chunk_handlers = RuntimeGlobals::ENSURE_CHUNK_HANDLERS,
   )
 }
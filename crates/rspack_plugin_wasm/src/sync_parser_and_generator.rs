@@ -0,0 +1,139 @@
+use rspack_core::rspack_sources::{RawSource, Source, SourceExt};
+use rspack_core::DependencyType::WasmImport;
+use rspack_core::{
+  AstOrSource, BuildMetaExportsType, CodeGenerationDataAssetInfo, CodeGenerationDataFilename,
+  GenerateContext, GenerationResult, Module, ModuleIdentifier, ParseContext, ParseResult,
+  ParserAndGenerator, RuntimeGlobals, SourceType,
+};
+use rspack_error::{IntoTWithDiagnosticArray, Result, TWithDiagnosticArray};
+
+use crate::{
+  hash_for_ast_or_source, parse_wasm, render_import_stmt, render_wasm_name, WASM_SOURCE_TYPE,
+};
+
+/// The parser and generator of `webassembly/sync` modules, the wasm instance is created during
+/// chunk loading by the `WasmChunkLoadingRuntimeModule`, so the module only copies its exports.
+#[derive(Debug)]
+pub struct SyncWasmParserAndGenerator;
+
+impl ParserAndGenerator for SyncWasmParserAndGenerator {
+  fn source_types(&self) -> &[SourceType] {
+    WASM_SOURCE_TYPE
+  }
+
+  fn parse(&mut self, parse_context: ParseContext) -> Result<TWithDiagnosticArray<ParseResult>> {
+    parse_context.build_info.strict = true;
+    parse_context.build_meta.exports_type = BuildMetaExportsType::Namespace;
+
+    let source = parse_context.source;
+    let (dependencies, diagnostic) = parse_wasm(&source.buffer());
+
+    Ok(
+      ParseResult {
+        dependencies,
+        presentational_dependencies: vec![],
+        ast_or_source: source.into(),
+      }
+      .with_diagnostic(diagnostic),
+    )
+  }
+
+  fn size(&self, module: &dyn Module, source_type: &SourceType) -> f64 {
+    match source_type {
+      SourceType::JavaScript => {
+        95.0
+          + module
+            .get_presentational_dependencies()
+            .map_or(0.0, |i| i.len() as f64 * 10.0)
+      }
+      SourceType::Wasm => module.original_source().map_or(0, |source| source.size()) as f64,
+      _ => 0.0,
+    }
+  }
+
+  #[allow(clippy::unwrap_in_result)]
+  fn generate(
+    &self,
+    ast_or_source: &AstOrSource,
+    module: &dyn Module,
+    generate_context: &mut GenerateContext,
+  ) -> Result<GenerationResult> {
+    let compilation = generate_context.compilation;
+    let wasm_filename_template = &compilation.options.output.webassembly_module_filename;
+    let hash = hash_for_ast_or_source(ast_or_source);
+    let normal_module = module
+      .as_normal_module()
+      .expect("module should be a NormalModule in SyncWasmParserAndGenerator::generate");
+    let (wasm_filename, wasm_asset_info) =
+      render_wasm_name(compilation, normal_module, wasm_filename_template, hash);
+
+    generate_context
+      .data
+      .insert(CodeGenerationDataFilename::new(wasm_filename));
+    generate_context
+      .data
+      .insert(CodeGenerationDataAssetInfo::new(wasm_asset_info));
+
+    match generate_context.requested_source_type {
+      SourceType::JavaScript => {
+        let runtime_requirements = &mut generate_context.runtime_requirements;
+        runtime_requirements.insert(RuntimeGlobals::MODULE);
+        runtime_requirements.insert(RuntimeGlobals::MODULE_ID);
+        runtime_requirements.insert(RuntimeGlobals::REQUIRE);
+        runtime_requirements.insert(RuntimeGlobals::MAKE_NAMESPACE_OBJECT);
+        runtime_requirements.insert(RuntimeGlobals::WASM_INSTANCES);
+
+        let module_graph = &compilation.module_graph;
+        let chunk_graph = &compilation.chunk_graph;
+
+        // the imported modules are executed after the exports are copied, so circular
+        // dependencies can access them, and in the order of the wasm imports
+        let mut dep_modules: Vec<(ModuleIdentifier, String)> = vec![];
+        if let Some(dependencies) = module_graph
+          .module_graph_module_by_identifier(&module.identifier())
+          .map(|mgm| &mgm.dependencies)
+        {
+          for id in dependencies {
+            let Some(dep) = module_graph.dependency_by_id(id) else {
+              continue;
+            };
+            if dep.dependency_type() != &WasmImport {
+              continue;
+            }
+            if let Some(mgm) = module_graph.module_graph_module_by_dependency_id(id)
+              && !dep_modules.iter().any(|(identifier, _)| identifier == &mgm.module_identifier)
+            {
+              dep_modules.push((mgm.module_identifier, mgm.id(chunk_graph).to_string()));
+            }
+          }
+        }
+
+        let imports_code = dep_modules
+          .iter()
+          .enumerate()
+          .map(|(index, (_, module_id))| {
+            render_import_stmt(&format!("WEBPACK_IMPORTED_MODULE_{index}"), module_id)
+          })
+          .collect::<Vec<_>>()
+          .join("");
+
+        let source = format!(
+          r#"// Instantiate WebAssembly module
+var wasmExports = {}[module.id];
+{}(exports);
+// export exports from WebAssembly module
+for(var name in wasmExports) if(name) exports[name] = wasmExports[name];
+// exec imports from WebAssembly module (for esm order)
+{imports_code}"#,
+          RuntimeGlobals::WASM_INSTANCES,
+          RuntimeGlobals::MAKE_NAMESPACE_OBJECT,
+        );
+
+        Ok(GenerationResult {
+          ast_or_source: RawSource::from(source).boxed().into(),
+        })
+      }
+      _ => Ok(ast_or_source.clone().into()),
+    }
+  }
+}
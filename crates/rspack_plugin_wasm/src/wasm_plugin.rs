@@ -2,25 +2,24 @@ use std::fmt::Debug;
 
 use rayon::prelude::*;
 use rspack_core::{
-  ApplyContext, ModuleType, ParserAndGenerator, Plugin, PluginContext,
-  PluginRenderManifestHookOutput, RenderManifestArgs, RenderManifestEntry, SourceType,
+  ApplyContext, CodeGenerationDataAssetInfo, CodeGenerationDataFilename, ModuleDependency,
+  ModuleType, OptimizeChunksArgs, ParserAndGenerator, Plugin, PluginContext,
+  PluginOptimizeChunksOutput, PluginRenderManifestHookOutput, RenderManifestArgs,
+  RenderManifestEntry, SourceType,
 };
-use rspack_error::Result;
+use rspack_error::{Diagnostic, Result};
 
-use crate::{AsyncWasmParserAndGenerator, ModuleIdToFileName};
+use crate::dependency::WasmImportDependency;
+use crate::{AsyncWasmParserAndGenerator, SyncWasmParserAndGenerator};
 
 pub struct EnableWasmLoadingPlugin;
 
 #[derive(Debug, Default)]
-pub struct AsyncWasmPlugin {
-  pub module_id_to_filename_without_ext: ModuleIdToFileName,
-}
+pub struct AsyncWasmPlugin;
 
 impl AsyncWasmPlugin {
   pub fn new() -> AsyncWasmPlugin {
-    Self {
-      module_id_to_filename_without_ext: Default::default(),
-    }
+    Self
   }
 }
 
@@ -31,15 +30,7 @@ impl Plugin for AsyncWasmPlugin {
   }
 
   fn apply(&self, ctx: PluginContext<&mut ApplyContext>) -> Result<()> {
-    let module_id_to_filename_without_ext = self.module_id_to_filename_without_ext.clone();
-
-    let builder = move || {
-      Box::new({
-        AsyncWasmParserAndGenerator {
-          module_id_to_filename: module_id_to_filename_without_ext.clone(),
-        }
-      }) as Box<dyn ParserAndGenerator>
-    };
+    let builder = || Box::new(AsyncWasmParserAndGenerator) as Box<dyn ParserAndGenerator>;
 
     ctx
       .context
@@ -57,42 +48,167 @@ impl Plugin for AsyncWasmPlugin {
     _ctx: PluginContext,
     args: RenderManifestArgs<'_>,
   ) -> PluginRenderManifestHookOutput {
+    render_wasm_manifest(args, ModuleType::WasmAsync)
+  }
+}
+
+#[derive(Debug, Default)]
+pub struct SyncWasmPlugin;
+
+impl SyncWasmPlugin {
+  pub fn new() -> SyncWasmPlugin {
+    Self
+  }
+}
+
+#[async_trait::async_trait]
+impl Plugin for SyncWasmPlugin {
+  fn name(&self) -> &'static str {
+    "WebAssemblyModulesPlugin"
+  }
+
+  fn apply(&self, ctx: PluginContext<&mut ApplyContext>) -> Result<()> {
+    let builder = || Box::new(SyncWasmParserAndGenerator) as Box<dyn ParserAndGenerator>;
+
+    ctx
+      .context
+      .register_parser_and_generator_builder(ModuleType::WasmSync, Box::new(builder));
+
+    Ok(())
+  }
+
+  async fn optimize_chunks(
+    &self,
+    _ctx: PluginContext,
+    args: OptimizeChunksArgs<'_>,
+  ) -> PluginOptimizeChunksOutput {
     let compilation = args.compilation;
-    let chunk = args.chunk();
     let module_graph = &compilation.module_graph;
+    let context = &compilation.options.context;
+    let mut diagnostics = vec![];
+
+    // WebAssembly download and compilation happen in the chunk loading, which initial chunks skip
+    for chunk in compilation.chunk_by_ukey.values() {
+      if !chunk.can_be_initial(&compilation.chunk_group_by_ukey) {
+        continue;
+      }
+      for module in compilation
+        .chunk_graph
+        .get_ordered_chunk_modules(&chunk.ukey, module_graph)
+      {
+        if *module.module_type() != ModuleType::WasmSync {
+          continue;
+        }
+        let mut import_chain = vec![module.readable_identifier(context).to_string()];
+        let mut issuer = module_graph.get_issuer(module);
+        while let Some(module) = issuer {
+          import_chain.push(module.readable_identifier(context).to_string());
+          issuer = module_graph.get_issuer(module);
+        }
+        import_chain.reverse();
+        diagnostics.push(Diagnostic::error(
+          "WebAssemblyInInitialChunkError".into(),
+          format!(
+            "WebAssembly module is included in initial chunk.\n\
+            This is not allowed, because WebAssembly download and compilation must happen asynchronous.\n\
+            Add an async split point (i. e. import()) somewhere between your entrypoint and the WebAssembly module:\n\
+            * {}",
+            import_chain.join(" --> ")
+          ),
+          0,
+          0,
+        ));
+      }
+    }
+
+    // only functions can be wrapped when imported from a javascript module
+    for mgm in module_graph.module_graph_modules().values() {
+      if mgm.module_type != ModuleType::WasmSync {
+        continue;
+      }
+      for dep in mgm
+        .dependencies
+        .iter()
+        .filter_map(|id| module_graph.dependency_by_id(id))
+        .filter_map(|dep| dep.as_any().downcast_ref::<WasmImportDependency>())
+        .filter(|dep| dep.is_only_direct_import())
+      {
+        if let Some(imported_module) = module_graph.module_graph_module_by_dependency_id(dep.id())
+          && !imported_module.module_type.is_wasm_like()
+        {
+          diagnostics.push(Diagnostic::error(
+            "UnsupportedWebAssemblyFeatureError".into(),
+            format!(
+              "Import \"{}\" from \"{}\" with {} can only be used for direct wasm to wasm dependencies",
+              dep.name(),
+              dep.request(),
+              dep.desc_kind()
+            ),
+            0,
+            0,
+          ));
+        }
+      }
+    }
 
-    let ordered_modules = compilation
-      .chunk_graph
-      .get_chunk_modules(&args.chunk_ukey, module_graph);
-
-    let files = ordered_modules
-      .par_iter()
-      .filter(|m| *m.module_type() == ModuleType::WasmAsync)
-      .map(|m| {
-        let code_gen_result = compilation
-          .code_generation_results
-          .get(&m.identifier(), Some(&chunk.runtime))?;
-
-        let result = code_gen_result
-          .get(&SourceType::Wasm)
-          .map(|result| result.ast_or_source.clone().try_into_source())
-          .transpose()?
-          .map(|source| {
-            let (output_path, asset_info) = self
-              .module_id_to_filename_without_ext
-              .get(&m.identifier())
-              .map(|s| s.clone())
-              .expect("should have wasm_filename");
-            RenderManifestEntry::new(source, output_path, asset_info)
-          });
-
-        Ok(result)
-      })
-      .collect::<Result<Vec<Option<RenderManifestEntry>>>>()?
-      .into_iter()
-      .flatten()
-      .collect::<Vec<RenderManifestEntry>>();
-
-    Ok(files)
+    compilation.push_batch_diagnostic(diagnostics);
+    Ok(())
+  }
+
+  async fn render_manifest(
+    &self,
+    _ctx: PluginContext,
+    args: RenderManifestArgs<'_>,
+  ) -> PluginRenderManifestHookOutput {
+    render_wasm_manifest(args, ModuleType::WasmSync)
   }
 }
+
+/// Emits the wasm binaries of the modules of `module_type` in the chunk.
+fn render_wasm_manifest(
+  args: RenderManifestArgs<'_>,
+  module_type: ModuleType,
+) -> PluginRenderManifestHookOutput {
+  let compilation = args.compilation;
+  let chunk = args.chunk();
+  let module_graph = &compilation.module_graph;
+
+  let ordered_modules = compilation
+    .chunk_graph
+    .get_chunk_modules(&args.chunk_ukey, module_graph);
+
+  let files = ordered_modules
+    .par_iter()
+    .filter(|m| *m.module_type() == module_type)
+    .map(|m| {
+      let code_gen_result = compilation
+        .code_generation_results
+        .get(&m.identifier(), Some(&chunk.runtime))?;
+
+      let result = code_gen_result
+        .get(&SourceType::Wasm)
+        .map(|result| result.ast_or_source.clone().try_into_source())
+        .transpose()?
+        .map(|source| {
+          let output_path = code_gen_result
+            .data
+            .get::<CodeGenerationDataFilename>()
+            .expect("should have wasm_filename")
+            .inner();
+          let asset_info = code_gen_result
+            .data
+            .get::<CodeGenerationDataAssetInfo>()
+            .expect("should have asset_info")
+            .inner();
+          RenderManifestEntry::new(source, output_path.to_owned(), asset_info.to_owned())
+        });
+
+      Ok(result)
+    })
+    .collect::<Result<Vec<Option<RenderManifestEntry>>>>()?
+    .into_iter()
+    .flatten()
+    .collect::<Vec<RenderManifestEntry>>();
+
+  Ok(files)
+}
@@ -0,0 +1,39 @@
+(self['webpackChunkwebpack'] = self['webpackChunkwebpack'] || []).push([["module_js"], {
+"./module.js": function (__unused_webpack_module, __webpack_exports__, __webpack_require__) {
+'use strict';
+__webpack_require__.r(__webpack_exports__);
+__webpack_require__.d(__webpack_exports__, {
+  'result': function() { return result; },
+  'getNumber': function() { return getNumber; }
+});
+/* harmony import */var _wasm_wasm__WEBPACK_IMPORTED_MODULE_0_ = __webpack_require__(/* ./wasm.wasm */"./wasm.wasm");
+
+ var result = (0, _wasm_wasm__WEBPACK_IMPORTED_MODULE_0_["getResult"])(1);
+ function getNumber() {
+    return 20;
+}
+},
+"./module2.js": function (__unused_webpack_module, __webpack_exports__, __webpack_require__) {
+'use strict';
+__webpack_require__.r(__webpack_exports__);
+__webpack_require__.d(__webpack_exports__, {
+  'getNumber': function() { return getNumber; }
+});
+/* harmony import */var _wasm_wasm__WEBPACK_IMPORTED_MODULE_0_ = __webpack_require__(/* ./wasm.wasm */"./wasm.wasm");
+
+ function getNumber() {
+    return (0, _wasm_wasm__WEBPACK_IMPORTED_MODULE_0_["getNumber"])();
+}
+},
+"./wasm.wasm": function (module, exports, __webpack_require__) {
+// Instantiate WebAssembly module
+var wasmExports = __webpack_require__.w[module.id];
+__webpack_require__.r(exports);
+// export exports from WebAssembly module
+for(var name in wasmExports) if(name) exports[name] = wasmExports[name];
+// exec imports from WebAssembly module (for esm order)
+var WEBPACK_IMPORTED_MODULE_0 = __webpack_require__("./module.js");
+var WEBPACK_IMPORTED_MODULE_1 = __webpack_require__("./module2.js");
+},
+
+}]);
\ No newline at end of file
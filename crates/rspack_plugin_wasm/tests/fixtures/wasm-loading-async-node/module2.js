@@ -0,0 +1,5 @@
+import { getNumber as getN } from "./wasm.wasm";
+
+export function getNumber() {
+	return getN();
+}
@@ -11,6 +11,6 @@ pub fn worker_plugin(
     enable_chunk_loading_plugin(loading_type, plugins);
   }
   if let WasmLoading::Enable(loading_type) = worker_wasm_loading {
-    plugins.push(enable_wasm_loading_plugin(loading_type));
+    enable_wasm_loading_plugin(loading_type, plugins);
   }
 }
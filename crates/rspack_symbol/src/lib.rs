@@ -14,10 +14,13 @@ bitflags! {
         const FUNCTION_EXPR = 1 << 4;
         const CLASS_EXPR = 1 << 5;
         const ALIAS = 1 << 6;
+        /// The initializer is side effects free, e.g. `/*#__PURE__*/ fn()`, it is evaluated
+        /// lazily like a function expression.
+        const PURE = 1 << 7;
         const EXPORT_DEFAULT = Self::DEFAULT.bits | Self::EXPORT.bits;
     }
 }
-#[derive(Debug, Hash, Clone, PartialEq, Eq, Serialize)]
+#[derive(Debug, Hash, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Symbol {
   pub(crate) src: Identifier,
   /// id means a local binding or a declaration in top level scope
@@ -28,7 +31,7 @@ pub struct Symbol {
   pub(crate) ty: SymbolType,
 }
 
-#[derive(Debug, Hash, Clone, PartialEq, Eq, Copy, Serialize)]
+#[derive(Debug, Hash, Clone, PartialEq, Eq, Copy, Serialize, Deserialize)]
 pub enum SymbolType {
   Define,
   Temp,
@@ -68,7 +71,7 @@ impl Symbol {
   }
 }
 
-#[derive(Debug, Clone, Eq, PartialEq, Hash, Serialize)]
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
 pub enum IndirectType {
   Temp(JsWord),
   /// first argument is original, second argument is exported
@@ -119,7 +122,7 @@ pub static DEFAULT_JS_WORD: JsWord = js_word!("default");
 ///   reexporter: "a.js"
 /// }
 /// ```
-#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize)]
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
 pub struct StarSymbol {
   pub src: Identifier,
   pub binding: JsWord,
@@ -127,7 +130,7 @@ pub struct StarSymbol {
   pub ty: StarSymbolKind,
 }
 
-#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, Serialize)]
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, Serialize, Deserialize)]
 pub enum StarSymbolKind {
   ReExportAllAs,
   ImportAllAs,
@@ -174,7 +177,7 @@ impl StarSymbol {
   }
 }
 
-#[derive(Debug, Clone, Eq, PartialEq, Hash, Serialize)]
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
 pub struct IndirectTopLevelSymbol {
   pub src: Identifier,
   pub ty: IndirectType,
@@ -253,7 +256,7 @@ impl IndirectTopLevelSymbol {
 /// `BetterId.debug()` -> `xxxxxxx|#10`
 /// debug of [swc_ecma_ast::Id] -> `(#1, atom: Atom('b' type=static))`
 /// We don't care the kind of inter of the [JsWord]
-#[derive(Hash, Clone, PartialEq, Eq, Default, Serialize)]
+#[derive(Hash, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
 pub struct BetterId {
   pub ctxt: SyntaxContext,
   pub atom: JsWord,
@@ -1,7 +1,11 @@
 mod eval_raw;
 mod run_fixture;
+mod temp_fixture;
 mod test_config;
 pub use eval_raw::evaluate_to_json;
-pub use run_fixture::{apply_from_fixture, test_fixture, test_rebuild_fixture};
+pub use run_fixture::{
+  apply_from_fixture, test_fixture, test_fixture_with_plugins, test_rebuild_fixture, watch_fixture,
+};
+pub use temp_fixture::TempFixture;
 pub use test_config::TestConfig;
 pub use testing_macros::{self, fixture};
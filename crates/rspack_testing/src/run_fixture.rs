@@ -3,7 +3,8 @@ use std::path::{Path, PathBuf};
 
 use cargo_rst::{helper::make_relative_from, rst::RstBuilder};
 use rspack_binding_options::{JsLoaderRunner, RawOptions, RawOptionsApply};
-use rspack_core::{BoxPlugin, Compiler, CompilerOptions};
+use rspack_core::{BoxPlugin, Compilation, Compiler, CompilerOptions, WatchOptions};
+use rspack_error::Result;
 use rspack_fs::AsyncNativeFileSystem;
 use rspack_tracing::enable_tracing_by_env;
 
@@ -25,11 +26,21 @@ pub fn apply_from_fixture(fixture_path: &Path) -> (CompilerOptions, Vec<BoxPlugi
   test_config.apply(fixture_path.to_path_buf())
 }
 
+pub fn test_fixture(fixture_path: &Path) -> Compiler<AsyncNativeFileSystem> {
+  test_fixture_with_plugins(fixture_path, vec![])
+}
+
+/// Like [test_fixture], with `extra_plugins` applied after the plugins of the fixture config, e.g.
+/// plugins providing the custom chunk loading or wasm loading type used by the fixture.
 #[tokio::main]
-pub async fn test_fixture(fixture_path: &Path) -> Compiler<AsyncNativeFileSystem> {
+pub async fn test_fixture_with_plugins(
+  fixture_path: &Path,
+  extra_plugins: Vec<BoxPlugin>,
+) -> Compiler<AsyncNativeFileSystem> {
   enable_tracing_by_env();
 
-  let (options, plugins) = apply_from_fixture(fixture_path);
+  let (options, mut plugins) = apply_from_fixture(fixture_path);
+  plugins.extend(extra_plugins);
   // clean output
   if options.output.path.exists() {
     std::fs::remove_dir_all(&options.output.path).expect("should remove output");
@@ -71,6 +82,36 @@ pub async fn test_fixture(fixture_path: &Path) -> Compiler<AsyncNativeFileSystem
   compiler
 }
 
+/// Watches the fixture, `edits` of `(relative path, content)` are written one by one after each
+/// compilation, watching stops after the compilation of the last edit.
+///
+/// `check` is called with the index and the result of each compilation, the fixture should be a
+/// [crate::TempFixture] since it's edited.
+pub async fn watch_fixture(
+  fixture_path: &Path,
+  edits: &[(&str, &str)],
+  mut check: impl FnMut(usize, Result<&Compilation>),
+) {
+  enable_tracing_by_env();
+
+  let (options, plugins) = apply_from_fixture(fixture_path);
+  let mut compiler = Compiler::new(options, plugins, AsyncNativeFileSystem);
+  let mut index = 0;
+  compiler
+    .watch(WatchOptions::default(), |result| {
+      check(index, result);
+      let Some((path, content)) = edits.get(index) else {
+        return false;
+      };
+      std::fs::write(fixture_path.join(path), content).expect("should write file");
+      index += 1;
+      true
+    })
+    .await
+    .unwrap_or_else(|e| panic!("failed to watch fixture {fixture_path:?}, {e:#?}"));
+  assert_eq!(index, edits.len(), "should compile after each edit");
+}
+
 fn read_dir_reverse(path: &PathBuf) -> Vec<String> {
   let mut result = vec![];
   if let Ok(changed_dir) = std::fs::read_dir(path) {
@@ -0,0 +1,61 @@
+use std::{
+  ffi::OsStr,
+  path::{Path, PathBuf},
+  sync::atomic::{AtomicUsize, Ordering},
+};
+
+/// A copy of a fixture in a temporary directory unique to the caller, removed when dropped.
+///
+/// Tests editing the fixture before a rebuild, or building it several times with different options,
+/// should work on a copy, so they don't race with the other tests of the same fixture.
+#[derive(Debug)]
+pub struct TempFixture {
+  path: PathBuf,
+}
+
+impl TempFixture {
+  /// Copies the fixture, the `dist` and `expected` directories of snapshot fixtures are skipped.
+  pub fn new(fixture_path: &Path) -> Self {
+    static COUNTER: AtomicUsize = AtomicUsize::new(0);
+    let name = fixture_path
+      .file_name()
+      .expect("fixture path should have a name")
+      .to_string_lossy();
+    let path = std::env::temp_dir().join(format!(
+      "rspack_testing_{name}_{}_{}",
+      std::process::id(),
+      COUNTER.fetch_add(1, Ordering::Relaxed)
+    ));
+    let _ = std::fs::remove_dir_all(&path);
+    copy_dir(fixture_path, &path);
+    Self { path }
+  }
+
+  pub fn path(&self) -> &Path {
+    &self.path
+  }
+}
+
+impl Drop for TempFixture {
+  fn drop(&mut self) {
+    let _ = std::fs::remove_dir_all(&self.path);
+  }
+}
+
+fn copy_dir(from: &Path, to: &Path) {
+  std::fs::create_dir_all(to).expect("should create dir");
+  for entry in std::fs::read_dir(from).expect("should read fixture") {
+    let path = entry.expect("should read fixture").path();
+    let target = to.join(path.file_name().expect("should have a file name"));
+    if path.is_dir() {
+      if !matches!(
+        path.file_name().and_then(OsStr::to_str),
+        Some("dist" | "expected")
+      ) {
+        copy_dir(&path, &target);
+      }
+    } else {
+      std::fs::copy(&path, &target).expect("should copy file");
+    }
+  }
+}
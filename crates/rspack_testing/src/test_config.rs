@@ -29,7 +29,7 @@ fn default_entry() -> HashMap<String, EntryItem> {
     "main".to_string(),
     EntryItem {
       import: vec!["./index".to_string()],
-      runtime: None,
+      runtime: enable_runtime_by_default(),
     },
   );
   map
@@ -50,6 +50,18 @@ fn enable_runtime_by_default() -> Option<String> {
   Some("runtime".to_string())
 }
 
+fn default_chunk_format() -> String {
+  "array-push".to_string()
+}
+
+fn default_chunk_loading() -> String {
+  "jsonp".to_string()
+}
+
+fn default_wasm_loading() -> String {
+  "fetch".to_string()
+}
+
 fn default_js_filename() -> String {
   "[name].js".to_string()
 }
@@ -105,6 +117,8 @@ pub struct Experiments {
   // True by default to reduce code in snapshots.
   #[serde(default = "true_by_default")]
   pub async_web_assembly: bool,
+  #[serde(default)]
+  pub sync_web_assembly: bool,
 }
 
 #[derive(Debug, JsonSchema, Deserialize)]
@@ -119,6 +133,8 @@ pub struct Optimization {
   pub module_ids: String,
   #[serde(default = "default_optimization_side_effects")]
   pub side_effects: String,
+  #[serde(default)]
+  pub concatenate_modules: bool,
 }
 
 #[derive(Debug, JsonSchema, Deserialize)]
@@ -162,6 +178,8 @@ pub struct Builtins {
   #[serde(default)]
   pub define: HashMap<String, String>,
   #[serde(default)]
+  pub import_meta_env: Option<HashMap<String, String>>,
+  #[serde(default)]
   pub provide: HashMap<String, Vec<String>>,
   #[serde(default)]
   pub postcss: Postcss,
@@ -258,6 +276,12 @@ pub struct Output {
   pub source_map_filename: String,
   #[serde(default)]
   pub library: Option<LibraryOptions>,
+  #[serde(default = "default_chunk_format")]
+  pub chunk_format: String,
+  #[serde(default = "default_chunk_loading")]
+  pub chunk_loading: String,
+  #[serde(default = "default_wasm_loading")]
+  pub wasm_loading: String,
 }
 
 #[derive(Debug, JsonSchema, Deserialize)]
@@ -412,12 +436,16 @@ impl TestConfig {
         hot_update_main_filename: c::Filename::from_str("[runtime].[fullhash].hot-update.json")
           .expect("Should exist"),
         asset_module_filename: c::Filename::from_str("[hash][ext][query]").expect("Should exist"),
-        wasm_loading: c::WasmLoading::Enable(c::WasmLoadingType::from("fetch")),
+        wasm_loading: c::WasmLoading::Enable(c::WasmLoadingType::from(
+          self.output.wasm_loading.as_str(),
+        )),
         webassembly_module_filename: c::Filename::from_str("[hash].module.wasm")
           .expect("Should exist"),
         public_path: c::PublicPath::String("/".to_string()),
         unique_name: "__rspack_test__".to_string(),
-        chunk_loading: c::ChunkLoading::Enable(c::ChunkLoadingType::Jsonp),
+        chunk_loading: c::ChunkLoading::Enable(c::ChunkLoadingType::from(
+          self.output.chunk_loading.as_str(),
+        )),
         chunk_loading_global: "webpackChunkwebpack".to_string(),
         path: context.join("dist"),
         library: self.output.library.map(|l| c::LibraryOptions {
@@ -460,6 +488,7 @@ impl TestConfig {
       },
       builtins: c::Builtins {
         define: self.builtins.define,
+        import_meta_env: self.builtins.import_meta_env,
         provide: self.builtins.provide,
         tree_shaking: self.builtins.tree_shaking.into(),
         minify_options: self.builtins.minify_options.map(|op| c::Minification {
@@ -496,7 +525,9 @@ impl TestConfig {
         remove_available_modules: self.optimization.remove_available_modules,
         remove_empty_chunks: self.optimization.remove_empty_chunks,
         side_effects: c::SideEffectOption::from(self.optimization.side_effects.as_str()),
+        concatenate_modules: self.optimization.concatenate_modules,
       },
+      records_path: None,
     };
     let mut plugins = Vec::new();
     for (name, desc) in &self.entry {
@@ -506,12 +537,13 @@ impl TestConfig {
             name.clone(),
             request.to_owned(),
             rspack_core::EntryOptions {
-              runtime: Some("runtime".to_string()),
+              runtime: desc.runtime.clone(),
               chunk_loading: None,
               async_chunks: Some(true),
               public_path: None,
               base_uri: None,
               filename: None,
+              library: None,
             },
           )
           .boxed(),
@@ -565,10 +597,25 @@ impl TestConfig {
       }
     }
     plugins.push(rspack_plugin_json::JsonPlugin {}.boxed());
-    plugins.push(rspack_plugin_runtime::ArrayPushCallbackChunkFormatPlugin {}.boxed());
+    match self.output.chunk_format.as_str() {
+      "array-push" => {
+        plugins.push(rspack_plugin_runtime::ArrayPushCallbackChunkFormatPlugin {}.boxed())
+      }
+      chunk_format => plugins.push(
+        rspack_plugin_runtime::CustomChunkFormatPlugin::new(chunk_format.to_string()).boxed(),
+      ),
+    }
     plugins.push(rspack_plugin_runtime::CssModulesPlugin {}.boxed());
-    plugins.push(rspack_plugin_runtime::JsonpChunkLoadingPlugin {}.boxed());
+    match c::ChunkLoadingType::from(self.output.chunk_loading.as_str()) {
+      c::ChunkLoadingType::Jsonp => {
+        plugins.push(rspack_plugin_runtime::JsonpChunkLoadingPlugin {}.boxed())
+      }
+      chunk_loading_type => {
+        rspack_plugin_runtime::enable_chunk_loading_plugin(chunk_loading_type, &mut plugins)
+      }
+    }
     plugins.push(rspack_plugin_runtime::RuntimePlugin {}.boxed());
+    plugins.push(rspack_plugin_runtime::ChunkPrefetchPreloadPlugin.boxed());
     if options.dev_server.hot {
       plugins.push(rspack_plugin_runtime::HotModuleReplacementPlugin {}.boxed());
     }
@@ -598,10 +645,21 @@ impl TestConfig {
     plugins.push(rspack_plugin_remove_empty_chunks::RemoveEmptyChunksPlugin.boxed());
 
     plugins.push(rspack_plugin_javascript::InferAsyncModulesPlugin {}.boxed());
+    if self.optimization.concatenate_modules {
+      plugins.push(rspack_plugin_javascript::ModuleConcatenationPlugin.boxed());
+    }
+    if self.experiments.async_web_assembly || self.experiments.sync_web_assembly {
+      rspack_plugin_wasm::enable_wasm_loading_plugin(
+        self.output.wasm_loading.as_str().into(),
+        &mut plugins,
+      );
+    }
     if self.experiments.async_web_assembly {
-      plugins.push(rspack_plugin_wasm::FetchCompileAsyncWasmPlugin {}.boxed());
       plugins.push(rspack_plugin_wasm::AsyncWasmPlugin::new().boxed());
     }
+    if self.experiments.sync_web_assembly {
+      plugins.push(rspack_plugin_wasm::SyncWasmPlugin::new().boxed());
+    }
     plugins.push(rspack_plugin_externals::http_url_external_plugin(true));
 
     (options, plugins)
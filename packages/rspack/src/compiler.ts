@@ -41,6 +41,7 @@ import { checkVersion } from "./util/bindingVersionCheck";
 import Watching from "./watching";
 import { NormalModule } from "./normalModule";
 import { normalizeJsModule } from "./util/normalization";
+import { resolveRuntimeDefine } from "./lib/DefinePlugin";
 
 class EntryPlugin {
 	constructor(
@@ -893,6 +894,9 @@ class Compiler {
 			if (error) {
 				return callback && callback(error);
 			}
+			instance?.unsafe_set_runtime_define(
+				resolveRuntimeDefine(this.options.builtins.define ?? {})
+			);
 			const unsafe_build = instance?.unsafe_build;
 			const build_cb = unsafe_build?.bind(instance) as typeof unsafe_build;
 			build_cb?.(error => {
@@ -914,6 +918,9 @@ class Compiler {
 			if (error) {
 				return callback && callback(error);
 			}
+			instance?.unsafe_set_runtime_define(
+				resolveRuntimeDefine(this.options.builtins.define ?? {})
+			);
 			const unsafe_rebuild = instance?.unsafe_rebuild;
 			const rebuild_cb = unsafe_rebuild?.bind(
 				instance
@@ -10,6 +10,7 @@ import type {
 	RawAssetGeneratorOptions,
 	RawParserOptions,
 	RawAssetParserOptions,
+	RawJavascriptParserOptions,
 	RawAssetParserDataUrl,
 	RawAssetGeneratorDataUrl,
 	RawAssetInlineGeneratorOptions,
@@ -45,6 +46,7 @@ import {
 	RuleSetLogicalConditions,
 	RuleSetRule,
 	SnapshotOptions,
+	CacheOptions,
 	StatsValue,
 	Target,
 	AssetGeneratorDataUrl,
@@ -53,6 +55,7 @@ import {
 	AssetResourceGeneratorOptions,
 	AssetParserDataUrl,
 	AssetParserOptions,
+	JavascriptParserOptions,
 	ParserOptionsByModuleType,
 	GeneratorOptionsByModuleType,
 	ExperimentsNormalized,
@@ -101,18 +104,8 @@ export const getRawOptions = (
 			hot: options.devServer?.hot ?? false
 		},
 		snapshot: getRawSnapshotOptions(options.snapshot),
-		cache: {
-			type: options.cache ? "memory" : "disable",
-			// TODO: implement below cache options
-			maxGenerations: 0,
-			maxAge: 0,
-			profile: false,
-			buildDependencies: [],
-			cacheDirectory: "",
-			cacheLocation: "",
-			name: "",
-			version: ""
-		},
+		recordsPath: options.recordsPath,
+		cache: getRawCacheOptions(options.cache!),
 		experiments: getRawExperiments(options.experiments),
 		node: getRawNode(options.node),
 		// TODO: refactor builtins
@@ -252,7 +245,7 @@ function getRawExternalsPresets(
 	};
 }
 
-function getRawLibrary(
+export function getRawLibrary(
 	library: LibraryOptions
 ): RawOptions["output"]["library"] {
 	const {
@@ -367,6 +360,14 @@ const getRawModuleRule = (
 					])
 			  )
 			: undefined,
+		with: rule.with
+			? Object.fromEntries(
+					Object.entries(rule.with).map(([k, v]) => [
+						k,
+						getRawRuleSetCondition(v)
+					])
+			  )
+			: undefined,
 		resource: rule.resource ? getRawRuleSetCondition(rule.resource) : undefined,
 		resourceQuery: rule.resourceQuery
 			? getRawRuleSetCondition(rule.resourceQuery)
@@ -486,11 +487,25 @@ function getRawParserOptions(
 			asset: getRawAssetParserOptions(parser)
 		};
 	}
+	if (type.startsWith("javascript")) {
+		return {
+			type: "javascript",
+			javascript: getRawJavascriptParserOptions(parser)
+		};
+	}
 	return {
 		type: "unknown"
 	};
 }
 
+function getRawJavascriptParserOptions(
+	parser: JavascriptParserOptions
+): RawJavascriptParserOptions {
+	return {
+		amd: parser.amd
+	};
+}
+
 function getRawAssetParserOptions(
 	parser: AssetParserOptions
 ): RawAssetParserOptions {
@@ -647,6 +662,16 @@ function getRawExternals(externals: Externals): RawOptions["externals"] {
 				type: "array",
 				arrayPayload: value
 			};
+		} else if (typeof value === "object" && value !== null) {
+			return {
+				type: "object",
+				objectPayload: Object.fromEntries(
+					Object.entries(value).map(([k, v]) => [
+						k,
+						Array.isArray(v) ? v : [v]
+					])
+				)
+			};
 		}
 		throw new Error("unreachable");
 	}
@@ -665,8 +690,9 @@ function getRawOptimization(
 			!isNil(optimization.removeAvailableModules) &&
 			!isNil(optimization.removeEmptyChunks) &&
 			!isNil(optimization.sideEffects) &&
-			!isNil(optimization.realContentHash),
-		"optimization.moduleIds, optimization.removeAvailableModules, optimization.removeEmptyChunks, optimization.sideEffects, optimization.realContentHash should not be nil after defaults"
+			!isNil(optimization.realContentHash) &&
+			!isNil(optimization.concatenateModules),
+		"optimization.moduleIds, optimization.removeAvailableModules, optimization.removeEmptyChunks, optimization.sideEffects, optimization.realContentHash, optimization.concatenateModules should not be nil after defaults"
 	);
 	return {
 		splitChunks: toRawSplitChunksOptions(optimization.splitChunks),
@@ -674,7 +700,8 @@ function getRawOptimization(
 		removeAvailableModules: optimization.removeAvailableModules,
 		removeEmptyChunks: optimization.removeEmptyChunks,
 		sideEffects: String(optimization.sideEffects),
-		realContentHash: optimization.realContentHash
+		realContentHash: optimization.realContentHash,
+		concatenateModules: optimization.concatenateModules
 	};
 }
 
@@ -710,7 +737,7 @@ function toRawSplitChunksOptions(
 function getRawSnapshotOptions(
 	snapshot: SnapshotOptions
 ): RawOptions["snapshot"] {
-	const { resolve, module } = snapshot;
+	const { resolve, module, immutablePaths, managedPaths } = snapshot;
 	assert(!isNil(resolve) && !isNil(module));
 	const { timestamp: resolveTimestamp, hash: resolveHash } = resolve;
 	const { timestamp: moduleTimestamp, hash: moduleHash } = module;
@@ -728,7 +755,53 @@ function getRawSnapshotOptions(
 		module: {
 			timestamp: moduleTimestamp,
 			hash: moduleHash
-		}
+		},
+		immutablePaths,
+		managedPaths
+	};
+}
+
+function getRawCacheOptions(cache: CacheOptions): RawOptions["cache"] {
+	const disabled = {
+		type: "disable",
+		maxGenerations: 0,
+		maxAge: 0,
+		profile: false,
+		buildDependencies: [],
+		cacheDirectory: "",
+		cacheLocation: "",
+		name: "",
+		version: ""
+	};
+	if (cache === false) {
+		return disabled;
+	}
+	if (cache === true || cache.type === "memory") {
+		return {
+			...disabled,
+			type: "memory",
+			maxGenerations: cache === true ? 0 : cache.maxGenerations ?? 0
+		};
+	}
+	const {
+		maxAge,
+		profile,
+		buildDependencies,
+		cacheDirectory,
+		cacheLocation,
+		name,
+		version
+	} = cache;
+	return {
+		...disabled,
+		type: "filesystem",
+		maxAge: maxAge ?? 0,
+		profile: profile ?? false,
+		buildDependencies: Object.values(buildDependencies ?? {}).flat(),
+		cacheDirectory: cacheDirectory ?? "",
+		cacheLocation: cacheLocation ?? "",
+		name: name ?? "",
+		version: version ?? ""
 	};
 }
 
@@ -739,6 +812,7 @@ function getRawExperiments(
 		lazyCompilation,
 		incrementalRebuild,
 		asyncWebAssembly,
+		syncWebAssembly,
 		newSplitChunks,
 		css
 	} = experiments;
@@ -746,6 +820,7 @@ function getRawExperiments(
 		!isNil(lazyCompilation) &&
 			!isNil(incrementalRebuild) &&
 			!isNil(asyncWebAssembly) &&
+			!isNil(syncWebAssembly) &&
 			!isNil(newSplitChunks) &&
 			!isNil(css)
 	);
@@ -754,6 +829,7 @@ function getRawExperiments(
 		lazyCompilation,
 		incrementalRebuild: getRawIncrementalRebuild(incrementalRebuild),
 		asyncWebAssembly,
+		syncWebAssembly,
 		newSplitChunks,
 		css
 	};
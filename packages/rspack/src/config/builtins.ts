@@ -19,10 +19,17 @@ import type {
 	RawBannerConditions,
 	RawBannerCondition,
 	RawMinificationCondition,
-	RawMinificationConditions
+	RawMinificationConditions,
+	RawContainerPluginOptions,
+	RawContainerReferencePluginOptions,
+	RawConsumeSharedPluginOptions,
+	RawProvideSharedPluginOptions
 } from "@rspack/binding";
 import { loadConfig } from "browserslist";
 import { Optimization } from "..";
+import { getRawLibrary } from "./adapter";
+import { CodeValue, RuntimeValue, toCode } from "../lib/DefinePlugin";
+import type { ExternalsType, LibraryOptions } from "./types";
 
 export type BuiltinsHtmlPluginConfig = Omit<RawHtmlPluginConfig, "meta"> & {
 	meta?: Record<string, string | Record<string, string>>;
@@ -66,7 +73,8 @@ export interface Builtins {
 	progress?: boolean | RawProgressPluginConfig;
 	react?: RawReactOptions;
 	noEmitAssets?: boolean;
-	define?: Record<string, string | boolean | undefined>;
+	define?: Record<string, CodeValue>;
+	importMetaEnv?: Record<string, string | number | boolean | null | undefined>;
 	provide?: Record<string, string | string[]>;
 	html?: Array<BuiltinsHtmlPluginConfig>;
 	decorator?: boolean | Partial<RawDecoratorOptions>;
@@ -80,8 +88,58 @@ export interface Builtins {
 	pluginImport?: PluginImportConfig[];
 	relay?: RelayConfig;
 	codeGeneration?: Partial<RawCodeGeneration>;
+	container?: ContainerConfig;
+	containerReference?: ContainerReferenceConfig;
+	share?: ShareConfig;
 }
 
+type ContainerOptionsObject<T> = Record<string, string | string[] | T>;
+type ContainerOptions<T> =
+	| (string | ContainerOptionsObject<T>)[]
+	| ContainerOptionsObject<T>;
+
+export type ExposesConfig = {
+	import: string | string[];
+	name?: string;
+};
+
+export type ContainerConfig = {
+	name: string;
+	exposes: ContainerOptions<ExposesConfig>;
+	filename?: string;
+	library?: LibraryOptions;
+	runtime?: string;
+	shareScope?: string;
+};
+
+export type RemotesConfig = {
+	external: string | string[];
+	shareScope?: string;
+};
+
+export type ContainerReferenceConfig = {
+	remoteType: ExternalsType;
+	remotes: ContainerOptions<RemotesConfig>;
+	shareScope?: string;
+};
+
+export type SharedConfig = {
+	eager?: boolean;
+	import?: false | string;
+	packageName?: string;
+	requiredVersion?: false | string;
+	shareKey?: string;
+	shareScope?: string;
+	singleton?: boolean;
+	strictVersion?: boolean;
+	version?: false | string;
+};
+
+export type ShareConfig = {
+	shared: ContainerOptions<SharedConfig>;
+	shareScope?: string;
+};
+
 export type PluginImportConfig = {
 	libraryName: string;
 	libraryDirectory?: string;
@@ -177,13 +235,24 @@ function resolvePluginImport(
 }
 
 function resolveDefine(define: Builtins["define"]): RawBuiltins["define"] {
-	// @ts-expect-error
-	const entries = Object.entries(define).map(([key, value]) => {
-		if (typeof value !== "string") {
-			value = value === undefined ? "undefined" : JSON.stringify(value);
-		}
-		return [key, value];
-	});
+	// The runtime values are evaluated before each build, see `resolveRuntimeDefine`
+	const entries = Object.entries(define!)
+		.filter(([, value]) => !(value instanceof RuntimeValue))
+		.map(([key, value]) => [key, toCode(value)]);
+	return Object.fromEntries(entries);
+}
+
+function resolveImportMetaEnv(
+	importMetaEnv: Builtins["importMetaEnv"]
+): RawBuiltins["importMetaEnv"] {
+	if (!importMetaEnv) {
+		return undefined;
+	}
+	// Unlike `define`, the values of `import.meta.env` are values instead of code
+	const entries = Object.entries(importMetaEnv).map(([key, value]) => [
+		key,
+		value === undefined ? "undefined" : JSON.stringify(value)
+	]);
 	return Object.fromEntries(entries);
 }
 
@@ -391,6 +460,7 @@ export function resolveBuiltinsOptions(
 		react: builtins.react ?? {},
 		noEmitAssets: builtins.noEmitAssets ?? false,
 		define: resolveDefine(builtins.define || {}),
+		importMetaEnv: resolveImportMetaEnv(builtins.importMetaEnv),
 		provide: resolveProvide(builtins.provide),
 		html: resolveHtml(builtins.html || []),
 		presetEnv,
@@ -405,7 +475,11 @@ export function resolveBuiltinsOptions(
 		relay: builtins.relay
 			? resolveRelay(builtins.relay, contextPath)
 			: undefined,
-		codeGeneration: resolveCodeGeneration(builtins)
+		codeGeneration: resolveCodeGeneration(builtins),
+		container: resolveContainer(builtins.container),
+		containerReference: resolveContainerReference(builtins.containerReference),
+		provideShared: resolveProvideShared(builtins.share),
+		consumeShared: resolveConsumeShared(builtins.share)
 	};
 }
 
@@ -539,3 +613,154 @@ export function resolveCodeGeneration(builtins: Builtins): RawCodeGeneration {
 		...builtins.codeGeneration
 	};
 }
+
+function parseContainerOptions<T, R>(
+	options: ContainerOptions<T>,
+	normalizeSimple: (item: string | string[], key: string) => R,
+	normalizeOptions: (item: T, key: string) => R
+): [string, R][] {
+	const items: [string, R][] = [];
+	const parseObject = (object: ContainerOptionsObject<T>) => {
+		for (const [key, value] of Object.entries(object)) {
+			if (typeof value === "string" || Array.isArray(value)) {
+				items.push([key, normalizeSimple(value, key)]);
+			} else {
+				items.push([key, normalizeOptions(value, key)]);
+			}
+		}
+	};
+	if (Array.isArray(options)) {
+		for (const item of options) {
+			if (typeof item === "string") {
+				items.push([item, normalizeSimple(item, item)]);
+			} else {
+				parseObject(item);
+			}
+		}
+	} else {
+		parseObject(options);
+	}
+	return items;
+}
+
+function resolveContainer(
+	container?: ContainerConfig
+): RawContainerPluginOptions | undefined {
+	if (!container) {
+		return undefined;
+	}
+	return {
+		name: container.name,
+		shareScope: container.shareScope ?? "default",
+		library: getRawLibrary(
+			container.library ?? { type: "var", name: container.name }
+		)!,
+		runtime: container.runtime,
+		filename: container.filename,
+		exposes: parseContainerOptions<
+			ExposesConfig,
+			Omit<RawContainerPluginOptions["exposes"][number], "key">
+		>(
+			container.exposes,
+			item => ({
+				import: Array.isArray(item) ? item : [item]
+			}),
+			item => ({
+				import: Array.isArray(item.import) ? item.import : [item.import],
+				name: item.name
+			})
+		).map(([key, options]) => ({ key, ...options }))
+	};
+}
+
+function resolveContainerReference(
+	containerReference?: ContainerReferenceConfig
+): RawContainerReferencePluginOptions | undefined {
+	if (!containerReference) {
+		return undefined;
+	}
+	const shareScope = containerReference.shareScope ?? "default";
+	return {
+		remoteType: containerReference.remoteType,
+		remotes: parseContainerOptions<
+			RemotesConfig,
+			Omit<RawContainerReferencePluginOptions["remotes"][number], "key">
+		>(
+			containerReference.remotes,
+			item => ({
+				external: Array.isArray(item) ? item : [item],
+				shareScope
+			}),
+			item => ({
+				external: Array.isArray(item.external)
+					? item.external
+					: [item.external],
+				shareScope: item.shareScope ?? shareScope
+			})
+		).map(([key, options]) => ({ key, ...options }))
+	};
+}
+
+function isRequiredVersion(version: string) {
+	return /^([\d^=v<>~]|[*xX]$)/.test(version);
+}
+
+function parseSharedOptions(share: ShareConfig): [string, SharedConfig][] {
+	return parseContainerOptions<SharedConfig, SharedConfig>(
+		share.shared,
+		(item, key) => {
+			if (typeof item !== "string") {
+				throw new Error("Unexpected array in shared");
+			}
+			return item === key || !isRequiredVersion(item)
+				? { import: item }
+				: { import: key, requiredVersion: item };
+		},
+		item => item
+	);
+}
+
+function resolveProvideShared(
+	share?: ShareConfig
+): RawProvideSharedPluginOptions | undefined {
+	if (!share) {
+		return undefined;
+	}
+	const shareScope = share.shareScope ?? "default";
+	return {
+		provides: parseSharedOptions(share)
+			.filter(([, options]) => options.import !== false)
+			.map(([key, options]) => ({
+				key: options.import || key,
+				shareKey: options.shareKey || key,
+				shareScope: options.shareScope || shareScope,
+				version: options.version,
+				eager: !!options.eager
+			}))
+	};
+}
+
+function resolveConsumeShared(
+	share?: ShareConfig
+): RawConsumeSharedPluginOptions | undefined {
+	if (!share) {
+		return undefined;
+	}
+	const shareScope = share.shareScope ?? "default";
+	return {
+		consumes: parseSharedOptions(share).map(([key, options]) => ({
+			key,
+			import: options.import === false ? undefined : options.import || key,
+			shareScope: options.shareScope || shareScope,
+			shareKey: options.shareKey || key,
+			requiredVersion: options.requiredVersion,
+			packageName: options.packageName,
+			strictVersion:
+				typeof options.strictVersion === "boolean"
+					? options.strictVersion
+					: options.import !== false && !options.singleton,
+			singleton: !!options.singleton,
+			eager: !!options.eager
+		}))
+	};
+}
@@ -73,12 +73,15 @@ export const applyRspackOptionsDefaults = (
 	const futureDefaults = options.experiments.futureDefaults ?? false;
 	F(options, "cache", () => development);
 
-	applyExperimentsDefaults(options.experiments, { cache: options.cache! });
+	applyExperimentsDefaults(options.experiments, { cache: !!options.cache });
 
-	applySnapshotDefaults(options.snapshot, { production });
+	applySnapshotDefaults(options.snapshot, {
+		production,
+		context: options.context!
+	});
 
 	applyModuleDefaults(options.module, {
-		// syncWebAssembly: options.experiments.syncWebAssembly,
+		syncWebAssembly: options.experiments.syncWebAssembly!,
 		asyncWebAssembly: options.experiments.asyncWebAssembly!,
 		css: options.experiments.css!
 	});
@@ -121,6 +124,14 @@ export const applyRspackOptionsDefaults = (
 		options.resolve
 	);
 
+	// the container entry is exposed by its own library
+	if (options.builtins.container) {
+		const libraryType = options.builtins.container.library?.type ?? "var";
+		if (!options.output.enabledLibraryTypes!.includes(libraryType)) {
+			options.output.enabledLibraryTypes!.push(libraryType);
+		}
+	}
+
 	// TODO: refactor builtins
 	options.builtins = oldBuiltins.resolveBuiltinsOptions(options.builtins, {
 		contextPath: options.context!,
@@ -155,6 +166,7 @@ const applyExperimentsDefaults = (
 	D(experiments, "incrementalRebuild", {});
 	D(experiments, "lazyCompilation", false);
 	D(experiments, "asyncWebAssembly", false);
+	D(experiments, "syncWebAssembly", false);
 	D(experiments, "newSplitChunks", true);
 	D(experiments, "css", true); // we not align with webpack about the default value for better DX
 
@@ -175,7 +187,7 @@ const applyExperimentsDefaults = (
 
 const applySnapshotDefaults = (
 	snapshot: SnapshotOptions,
-	{ production }: { production: boolean }
+	{ production, context }: { production: boolean; context: string }
 ) => {
 	F(snapshot, "module", () =>
 		production
@@ -187,11 +199,17 @@ const applySnapshotDefaults = (
 			? { timestamp: true, hash: true }
 			: { timestamp: true, hash: false }
 	);
+	D(snapshot, "immutablePaths", []);
+	F(snapshot, "managedPaths", () => [path.resolve(context, "node_modules")]);
 };
 
 const applyModuleDefaults = (
 	module: ModuleOptions,
-	{ asyncWebAssembly, css }: { asyncWebAssembly: boolean; css: boolean }
+	{
+		syncWebAssembly,
+		asyncWebAssembly,
+		css
+	}: { syncWebAssembly: boolean; asyncWebAssembly: boolean; css: boolean }
 ) => {
 	F(module.parser!, "asset", () => ({}));
 	F(module.parser!.asset!, "dataUrlCondition", () => ({}));
@@ -265,6 +283,12 @@ const applyModuleDefaults = (
 			{
 				test: /\.tsx$/i,
 				type: "tsx"
+			},
+			{
+				with: {
+					type: "json"
+				},
+				type: "json"
 			}
 		];
 
@@ -290,6 +314,28 @@ const applyModuleDefaults = (
 				mimetype: "application/wasm",
 				...wasm
 			});
+		} else if (syncWebAssembly) {
+			const wasm = {
+				type: "webassembly/sync",
+				rules: [
+					{
+						descriptionData: {
+							type: "module"
+						},
+						resolve: {
+							fullySpecified: true
+						}
+					}
+				]
+			};
+			rules.push({
+				test: /\.wasm$/i,
+				...wasm
+			});
+			rules.push({
+				mimetype: "application/wasm",
+				...wasm
+			});
 		}
 
 		if (css) {
@@ -663,6 +709,7 @@ const applyOptimizationDefaults = (
 	F(optimization, "sideEffects", () => (production ? true : "flag"));
 	D(optimization, "runtimeChunk", false);
 	D(optimization, "realContentHash", production);
+	D(optimization, "concatenateModules", production);
 	D(optimization, "minimize", production);
 	A(optimization, "minimizer", () => []);
 	const { splitChunks } = optimization;
@@ -754,7 +801,7 @@ const getResolveDefaults = ({
 				preferRelative: true
 			},
 			commonjs: cjsDeps(),
-			// amd: cjsDeps(),
+			amd: cjsDeps(),
 			// for backward-compat: loadModule
 			// loader: cjsDeps(),
 			// for backward-compat: Custom Dependency and getResolve without dependencyType
@@ -192,7 +192,11 @@ export const getNormalizedRspackOptions = (
 			module: optionalNestedConfig(snapshot.module, module => ({
 				timestamp: module.timestamp,
 				hash: module.hash
-			}))
+			})),
+			immutablePaths: optionalNestedArray(snapshot.immutablePaths, p => [
+				...p
+			]),
+			managedPaths: optionalNestedArray(snapshot.managedPaths, p => [...p])
 		})),
 		cache: optionalNestedConfig(config.cache, cache => cache),
 		stats: nestedConfig(config.stats, stats => {
@@ -232,6 +236,7 @@ export const getNormalizedRspackOptions = (
 			};
 		}),
 		plugins: nestedArray(config.plugins, p => [...p]),
+		recordsPath: config.recordsPath,
 		experiments: nestedConfig(config.experiments, experiments => ({
 			...experiments,
 			incrementalRebuild: optionalNestedConfig(
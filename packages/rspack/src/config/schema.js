@@ -51,7 +51,93 @@ module.exports = {
 		CacheOptions: {
 			description:
 				"Cache generated modules and chunks to improve performance for multiple incremental builds.",
-			type: "boolean"
+			anyOf: [
+				{
+					type: "boolean"
+				},
+				{
+					$ref: "#/definitions/MemoryCacheOptions"
+				},
+				{
+					$ref: "#/definitions/FileCacheOptions"
+				}
+			]
+		},
+		FileCacheOptions: {
+			description: "Options object for persistent file-based caching.",
+			type: "object",
+			additionalProperties: false,
+			properties: {
+				buildDependencies: {
+					description:
+						"Dependencies the build depends on (in multiple categories, default categories: 'defaultWebpack').",
+					type: "object",
+					additionalProperties: {
+						description: "List of dependencies the build depends on.",
+						type: "array",
+						items: {
+							description:
+								"Request to a dependency (resolved as directory relative to the context directory).",
+							type: "string",
+							minLength: 1
+						}
+					}
+				},
+				cacheDirectory: {
+					description:
+						"Base directory for the cache (defaults to node_modules/.cache/rspack).",
+					type: "string"
+				},
+				cacheLocation: {
+					description:
+						"Locations for the cache (defaults to cacheDirectory / name).",
+					type: "string"
+				},
+				maxAge: {
+					description:
+						"Time for which unused cache entries stay in the filesystem cache at minimum (in milliseconds, 0 means forever).",
+					type: "number",
+					minimum: 0
+				},
+				name: {
+					description:
+						"Name for the cache. Different names will lead to different coexisting caches.",
+					type: "string"
+				},
+				profile: {
+					description:
+						"Track and log detailed timing information for individual cache items.",
+					type: "boolean"
+				},
+				type: {
+					description: "Filesystem caching.",
+					enum: ["filesystem"]
+				},
+				version: {
+					description:
+						"Version of the cache data. Different versions won't allow to reuse the cache and override existing content. Update the version when config changed in a way which doesn't allow to reuse cache. This will delete the existing cache.",
+					type: "string"
+				}
+			},
+			required: ["type"]
+		},
+		MemoryCacheOptions: {
+			description: "Options object for in-memory caching.",
+			type: "object",
+			additionalProperties: false,
+			properties: {
+				maxGenerations: {
+					description:
+						"Number of generations unused cache entries stay in memory cache at minimum (1 = may be removed after unused for a single compilation, ..., Infinity: kept forever).",
+					type: "number",
+					minimum: 1
+				},
+				type: {
+					description: "In memory caching.",
+					enum: ["memory"]
+				}
+			},
+			required: ["type"]
 		},
 		ChunkFilename: {
 			description:
@@ -358,6 +444,10 @@ module.exports = {
 					description: "Support WebAssembly as asynchronous EcmaScript Module.",
 					type: "boolean"
 				},
+				syncWebAssembly: {
+					description: "Support WebAssembly as synchronous EcmaScript Module (outdated).",
+					type: "boolean"
+				},
 				incrementalRebuild: {
 					description: "Rebuild incrementally",
 					anyOf: [
@@ -449,6 +539,25 @@ module.exports = {
 					description:
 						"`true`: The dependency name is used as target of the external.",
 					type: "boolean"
+				},
+				{
+					description:
+						"The target of the external for each external type, e.g. `{ root: 'React', commonjs: 'react' }`.",
+					type: "object",
+					additionalProperties: {
+						anyOf: [
+							{
+								type: "array",
+								items: {
+									type: "string",
+									minLength: 1
+								}
+							},
+							{
+								type: "string"
+							}
+						]
+					}
 				}
 			]
 		},
@@ -646,6 +755,17 @@ module.exports = {
 				}
 			}
 		},
+		JavascriptParserOptions: {
+			description: "Parser options for javascript modules.",
+			type: "object",
+			additionalProperties: true,
+			properties: {
+				amd: {
+					description: "Parse AMD `define` and `require`.",
+					type: "boolean"
+				}
+			}
+		},
 		Library: {
 			description:
 				"Make the output files a library, exporting the exports of the entry point.",
@@ -900,6 +1020,11 @@ module.exports = {
 			type: "object",
 			additionalProperties: false,
 			properties: {
+				concatenateModules: {
+					description:
+						"Concatenate modules when possible to generate less modules, more efficient code and enable more optimizations by the minimizer.",
+					type: "boolean"
+				},
 				chunkIds: {
 					description:
 						"Define the algorithm to choose chunk ids (named: readable ids for better debugging, deterministic: numeric hash ids for better long term caching, size: numeric ids focused on minimal initial download size, total-size: numeric ids focused on minimal total download size, false: no algorithm used, as custom one can be provided via plugin).",
@@ -1378,6 +1503,18 @@ module.exports = {
 			properties: {
 				asset: {
 					$ref: "#/definitions/AssetParserOptions"
+				},
+				javascript: {
+					$ref: "#/definitions/JavascriptParserOptions"
+				},
+				"javascript/auto": {
+					$ref: "#/definitions/JavascriptParserOptions"
+				},
+				"javascript/dynamic": {
+					$ref: "#/definitions/JavascriptParserOptions"
+				},
+				"javascript/esm": {
+					$ref: "#/definitions/JavascriptParserOptions"
 				}
 			}
 		},
@@ -1421,6 +1558,12 @@ module.exports = {
 				}
 			]
 		},
+		RecordsPath: {
+			description:
+				"Store compiler state to a json file, module ids and chunk ids are kept stable between builds.",
+			type: "string",
+			absolutePath: true
+		},
 		Resolve: {
 			description: "Options for the resolver.",
 			oneOf: [
@@ -1750,6 +1893,13 @@ module.exports = {
 						$ref: "#/definitions/RuleSetConditionOrConditions"
 					}
 				},
+				with: {
+					description: "Match values of the import attributes of the dependency.",
+					type: "object",
+					additionalProperties: {
+						$ref: "#/definitions/RuleSetConditionOrConditions"
+					}
+				},
 				oneOf: {
 					description: "Only execute the first matching rule in this array.",
 					type: "array",
@@ -1941,6 +2091,24 @@ module.exports = {
 			type: "object",
 			additionalProperties: false,
 			properties: {
+				immutablePaths: {
+					description:
+						"List of paths that are managed by a package manager and contain a version or hash in its path so all files are immutable.",
+					type: "array",
+					items: {
+						type: "string",
+						minLength: 1
+					}
+				},
+				managedPaths: {
+					description:
+						"List of paths that are managed by a package manager and can be trusted to not be modified otherwise.",
+					type: "array",
+					items: {
+						type: "string",
+						minLength: 1
+					}
+				},
 				module: {
 					description:
 						"Options for snapshotting dependencies of modules to determine if they need to be built again.",
@@ -2298,6 +2466,9 @@ module.exports = {
 		plugins: {
 			$ref: "#/definitions/Plugins"
 		},
+		recordsPath: {
+			$ref: "#/definitions/RecordsPath"
+		},
 		resolve: {
 			$ref: "#/definitions/Resolve"
 		},
@@ -49,6 +49,7 @@ export interface RspackOptionsNormalized {
 	stats: StatsValue;
 	optimization: Optimization;
 	plugins: Plugins;
+	recordsPath?: RecordsPath;
 	experiments: ExperimentsNormalized;
 	watch?: Watch;
 	watchOptions: WatchOptions;
@@ -320,6 +321,9 @@ export interface RuleSetRule {
 	descriptionData?: {
 		[k: string]: RuleSetCondition;
 	};
+	with?: {
+		[k: string]: RuleSetCondition;
+	};
 	oneOf?: RuleSetRule[];
 	rules?: RuleSetRule[];
 	type?: string;
@@ -370,6 +374,16 @@ export type RuleSetLoaderOptions =
 export type ParserOptionsByModuleType = ParserOptionsByModuleTypeKnown;
 export interface ParserOptionsByModuleTypeKnown {
 	asset?: AssetParserOptions;
+	javascript?: JavascriptParserOptions;
+	"javascript/auto"?: JavascriptParserOptions;
+	"javascript/dynamic"?: JavascriptParserOptions;
+	"javascript/esm"?: JavascriptParserOptions;
+}
+export interface JavascriptParserOptions {
+	/**
+	 * Parse AMD `define` and `require`.
+	 */
+	amd?: boolean;
 }
 export interface AssetParserOptions {
 	dataUrlCondition?: AssetParserDataUrl;
@@ -481,7 +495,11 @@ export interface ExternalItemFunctionData {
 export interface ExternalItemObjectUnknown {
 	[k: string]: ExternalItemValue;
 }
-export type ExternalItemValue = string | boolean;
+export type ExternalItemValue =
+	| string
+	| boolean
+	| string[]
+	| Record<string, string | string[]>;
 
 ///// ExternalsType /////
 export type ExternalsType =
@@ -565,6 +583,9 @@ export interface NodeOptions {
 	global?: boolean | "warn";
 }
 
+///// RecordsPath /////
+export type RecordsPath = string;
+
 ///// Snapshot /////
 export interface SnapshotOptions {
 	module?: {
@@ -575,11 +596,32 @@ export interface SnapshotOptions {
 		hash?: boolean;
 		timestamp?: boolean;
 	};
+	immutablePaths?: string[];
+	managedPaths?: string[];
 }
 
 ///// Cache /////
-// TODO: align with webpack
-export type CacheOptions = true | false;
+export type CacheOptions = true | false | MemoryCacheOptions | FileCacheOptions;
+export interface MemoryCacheOptions {
+	type: "memory";
+	maxGenerations?: number;
+}
+export interface FileCacheOptions {
+	type: "filesystem";
+	/**
+	 * Additional code dependencies of the build, the cache is invalidated when they change.
+	 */
+	buildDependencies?: { [k: string]: string[] };
+	cacheDirectory?: string;
+	cacheLocation?: string;
+	/**
+	 * The amount of time in milliseconds that unused cache entries are allowed to stay in the filesystem cache, `0` means entries never expire.
+	 */
+	maxAge?: number;
+	name?: string;
+	profile?: boolean;
+	version?: string;
+}
 
 ///// Stats /////
 export type StatsValue =
@@ -638,6 +680,7 @@ export interface Experiments {
 	lazyCompilation?: boolean;
 	incrementalRebuild?: boolean | IncrementalRebuildOptions;
 	asyncWebAssembly?: boolean;
+	syncWebAssembly?: boolean;
 	outputModule?: boolean;
 	newSplitChunks?: boolean;
 	css?: boolean;
@@ -650,6 +693,7 @@ export interface ExperimentsNormalized {
 	lazyCompilation?: boolean;
 	incrementalRebuild?: false | IncrementalRebuildOptions;
 	asyncWebAssembly?: boolean;
+	syncWebAssembly?: boolean;
 	outputModule?: boolean;
 	newSplitChunks?: boolean;
 	css?: boolean;
@@ -38,7 +38,8 @@ export function builtins() {
 				sri: z.enum(["sha256", "sha384", "sha512"]).optional(),
 				minify: z.boolean().optional(),
 				favicon: z.string().optional(),
-				meta: z.record(z.string().or(z.record(z.string()))).optional()
+				meta: z.record(z.string().or(z.record(z.string()))).optional(),
+				preload: z.boolean().optional()
 			})
 			.array()
 			.optional()
@@ -0,0 +1,24 @@
+import { z } from "zod";
+
+export function cache() {
+	return z
+		.boolean()
+		.or(
+			z.strictObject({
+				type: z.literal("memory"),
+				maxGenerations: z.number().optional()
+			})
+		)
+		.or(
+			z.strictObject({
+				type: z.literal("filesystem"),
+				buildDependencies: z.record(z.string().array()).optional(),
+				cacheDirectory: z.string().optional(),
+				cacheLocation: z.string().optional(),
+				maxAge: z.number().optional(),
+				name: z.string().optional(),
+				profile: z.boolean().optional(),
+				version: z.string().optional()
+			})
+		);
+}
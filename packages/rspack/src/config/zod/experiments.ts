@@ -3,6 +3,7 @@ import { z } from "zod";
 export function experiments() {
 	return z.object({
 		asyncWebAssembly: z.boolean().optional(),
+		syncWebAssembly: z.boolean().optional(),
 		incrementalRebuild: z
 			.boolean()
 			.or(
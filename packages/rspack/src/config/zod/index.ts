@@ -11,6 +11,7 @@ import { watchOptions } from "./watch-options";
 import { target } from "./target";
 import { stats } from "./stats";
 import { snapshot } from "./snapshot";
+import { cache } from "./cache";
 import { output } from "./output";
 import { devtool } from "./devtool";
 import { optimization } from "./optimization";
@@ -28,7 +29,7 @@ export function configSchema() {
 			externalsType: externalsType().optional(),
 			externalsPresets: externalsPresets().optional(),
 			infrastructureLogging: infrastructureLogging().optional(),
-			cache: z.boolean().optional(),
+			cache: cache().optional(),
 			context: z.string().optional(),
 			dependencies: z.string().array().optional(),
 			devtool: devtool().optional(),
@@ -41,6 +42,7 @@ export function configSchema() {
 			optimization: optimization().optional(),
 			resolve: resolve().optional(),
 			plugins: plugins().optional(),
+			recordsPath: z.string().optional(),
 			// TODO(hyf0): what's the usage of this?
 			name: z.string().optional(),
 			// TODO
@@ -27,6 +27,7 @@ export function optimization() {
 		removeAvailableModules: z.boolean().optional(),
 		removeEmptyChunks: z.boolean().optional(),
 		realContentHash: z.boolean().optional(),
+		concatenateModules: z.boolean().optional(),
 		sideEffects: z.enum(["flag"]).or(z.boolean()).optional()
 	});
 }
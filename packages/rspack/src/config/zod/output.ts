@@ -11,7 +11,9 @@ function chunkLoading() {
 }
 
 function wasmLoadingType() {
-	return z.enum(["...", "fetch-streaming", "fetch", "async-node"]);
+	return z
+		.enum(["...", "fetch-streaming", "fetch", "async-node", "universal"])
+		.or(z.string());
 }
 
 function wasmLoading() {
@@ -92,6 +94,7 @@ export function output() {
 		auxiliaryComment: auxiliaryComment.optional(),
 		chunkFormat: z
 			.enum(["array-push", "commonjs", "module"])
+			.or(z.string())
 			.or(z.literal(false))
 			.optional(),
 		chunkLoading: chunkLoading().optional(),
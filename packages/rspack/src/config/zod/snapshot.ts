@@ -13,6 +13,8 @@ export function snapshot() {
 				hash: z.boolean().optional(),
 				timestamp: z.boolean().optional()
 			})
-			.optional()
+			.optional(),
+		immutablePaths: z.array(z.string()).optional(),
+		managedPaths: z.array(z.string()).optional()
 	});
 }
@@ -9,6 +9,7 @@ export * from "./chunk_group";
 export * from "./normalModuleFactory";
 export { cachedCleverMerge as cleverMerge } from "./util/cleverMerge";
 export { BannerPlugin } from "./lib/BannerPlugin";
+export { DefinePlugin } from "./lib/DefinePlugin";
 export { EnvironmentPlugin } from "./lib/EnvironmentPlugin";
 export { LoaderOptionsPlugin } from "./lib/LoaderOptionsPlugin";
 import { Configuration } from "./config";
@@ -0,0 +1,164 @@
+/**
+ * The following code is modified based on
+ * https://github.com/webpack/webpack/blob/4b4ca3b/lib/DefinePlugin.js
+ *
+ * MIT Licensed
+ * Author Tobias Koppers @sokra
+ * Copyright (c) JS Foundation and other contributors
+ * https://github.com/webpack/webpack/blob/main/LICENSE
+ */
+
+import type { Compiler, RspackPluginInstance } from "../";
+
+export type CodeValuePrimitive =
+	| null
+	| undefined
+	| RegExp
+	| Function
+	| string
+	| number
+	| boolean
+	| bigint;
+
+export type CodeValue =
+	| CodeValuePrimitive
+	| RuntimeValue
+	| CodeValue[]
+	| { [key: string]: CodeValue };
+
+export type RuntimeValueOptions = {
+	version?: string | (() => string);
+};
+
+export type GeneratorArgs = {
+	key: string;
+};
+
+/**
+ * A value computed by `fn` before each build, the modules using it are
+ * rebuilt when the result changes.
+ * - `true` as options evaluates it on every build
+ * - `{ version }` evaluates it again when the version changes
+ * - otherwise it's only evaluated once
+ */
+export class RuntimeValue {
+	fn: (args: GeneratorArgs) => CodeValuePrimitive;
+	options: true | RuntimeValueOptions;
+	#cache?: { version?: string; code: string };
+
+	constructor(
+		fn: (args: GeneratorArgs) => CodeValuePrimitive,
+		options: true | RuntimeValueOptions = {}
+	) {
+		this.fn = fn;
+		this.options = options;
+	}
+
+	exec(key: string): string {
+		const version =
+			this.options === true
+				? undefined
+				: typeof this.options.version === "function"
+				? this.options.version()
+				: this.options.version;
+		if (this.options === true || this.#cache?.version !== version) {
+			this.#cache = undefined;
+		}
+		if (!this.#cache) {
+			this.#cache = { version, code: toCode(this.fn({ key })) };
+		}
+		return this.#cache.code;
+	}
+}
+
+/**
+ * Convert the value of a definition to the code it's replaced with, strings
+ * are code already, the runtime values in objects are omitted as they are
+ * defined by their own keys, e.g. `X.a`.
+ */
+export function toCode(value: CodeValue): string {
+	if (value === null) {
+		return "null";
+	}
+	if (value === undefined) {
+		return "undefined";
+	}
+	if (typeof value === "string") {
+		return value;
+	}
+	if (typeof value === "bigint") {
+		return `${value}n`;
+	}
+	if (typeof value === "function") {
+		return `(${value.toString()})`;
+	}
+	if (value instanceof RegExp) {
+		return value.toString();
+	}
+	if (value instanceof RuntimeValue) {
+		return "undefined";
+	}
+	if (Array.isArray(value)) {
+		return `[${value.map(toCode).join(",")}]`;
+	}
+	if (typeof value === "object") {
+		const properties = Object.entries(value)
+			.filter(([, value]) => !(value instanceof RuntimeValue))
+			.map(([key, value]) => `${JSON.stringify(key)}:${toCode(value)}`);
+		return `({${properties.join(",")}})`;
+	}
+	return JSON.stringify(value);
+}
+
+/**
+ * Evaluate the runtime values in the definitions, including the ones nested in
+ * objects, the keys of the nested ones are joined with `.`.
+ */
+export function resolveRuntimeDefine(
+	define: Record<string, CodeValue>,
+	prefix = ""
+): Record<string, string> {
+	const runtimeDefine: Record<string, string> = {};
+	for (const [key, value] of Object.entries(define)) {
+		const fullKey = prefix + key;
+		if (value instanceof RuntimeValue) {
+			runtimeDefine[fullKey] = value.exec(fullKey);
+		} else if (
+			value &&
+			typeof value === "object" &&
+			!Array.isArray(value) &&
+			!(value instanceof RegExp)
+		) {
+			Object.assign(
+				runtimeDefine,
+				resolveRuntimeDefine(value as Record<string, CodeValue>, `${fullKey}.`)
+			);
+		}
+	}
+	return runtimeDefine;
+}
+
+export class DefinePlugin implements RspackPluginInstance {
+	name = "DefinePlugin";
+	definitions: Record<string, CodeValue>;
+
+	constructor(definitions: Record<string, CodeValue>) {
+		this.definitions = definitions;
+	}
+
+	static runtimeValue(
+		fn: (args: GeneratorArgs) => CodeValuePrimitive,
+		options?: true | RuntimeValueOptions
+	) {
+		return new RuntimeValue(fn, options);
+	}
+
+	apply(compiler: Compiler) {
+		compiler.hooks.initialize.tap(this.name, () => {
+			compiler.options.builtins.define = {
+				...compiler.options.builtins.define,
+				...this.definitions
+			};
+		});
+	}
+}
@@ -125,7 +125,9 @@ describe("snapshots", () => {
 		-   "mode": "none",
 		+   "mode": undefined,
 		@@ ... @@
+		-     "concatenateModules": false,
 		-     "minimize": false,
+		+     "concatenateModules": true,
 		+     "minimize": true,
 		@@ ... @@
 		-     "moduleIds": "named",
@@ -177,7 +179,9 @@ describe("snapshots", () => {
 		-   "mode": "none",
 		+   "mode": "production",
 		@@ ... @@
+		-     "concatenateModules": false,
 		-     "minimize": false,
+		+     "concatenateModules": true,
 		+     "minimize": true,
 		@@ ... @@
 		-     "moduleIds": "named",
@@ -317,6 +321,7 @@ describe("snapshots", () => {
 			-     "asyncWebAssembly": false,
 			+     "asyncWebAssembly": true,
 			@@ ... @@
+			-     "syncWebAssembly": false,
 			+     "syncWebAssembly": true,
 			@@ ... @@
 			+       },
@@ -625,6 +630,11 @@ describe("snapshots", () => {
 		@@ ... @@
 		-           "browser",
 		@@ ... @@
+		-         "browserField": true,
+		+         "browserField": false,
+		@@ ... @@
+		-           "browser",
+		@@ ... @@
 		-       "browser",
 		+       "node",
 		@@ ... @@
@@ -727,6 +737,11 @@ describe("snapshots", () => {
 		@@ ... @@
 		-           "browser",
 		@@ ... @@
+		-         "browserField": true,
+		+         "browserField": false,
+		@@ ... @@
+		-           "browser",
+		@@ ... @@
 		-       "browser",
 		+       "node",
 		+       "electron",
@@ -812,6 +827,11 @@ describe("snapshots", () => {
 		@@ ... @@
 		-           "browser",
 		@@ ... @@
+		-         "browserField": true,
+		+         "browserField": false,
+		@@ ... @@
+		-           "browser",
+		@@ ... @@
 		+       "node",
 		@@ ... @@
 		+       "electron",
@@ -1028,6 +1048,9 @@ describe("snapshots", () => {
 			@@ ... @@
 			-     "uniqueName": "@rspack/core",
 			+     "uniqueName": "browserslist-test",
+			@@ ... @@
+			-       "<cwd>/node_modules",
+			+       "<cwd>/tests/fixtures/browserslist/node_modules",
 		`)
 	);
 
@@ -1062,6 +1085,9 @@ describe("snapshots", () => {
 			@@ ... @@
 			-     "uniqueName": "@rspack/core",
 			+     "uniqueName": "",
+			@@ ... @@
+			-       "<cwd>/node_modules",
+			+       "<cwd>/tests/fixtures/node_modules",
 		`),
 		() => {
 			process.chdir(path.resolve(__dirname, "fixtures"));
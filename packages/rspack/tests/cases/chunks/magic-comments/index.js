@@ -0,0 +1,53 @@
+it("should bundle the module of eager mode into the same chunk", function () {
+	expect(__webpack_modules__[require.resolveWeak("./eager")]).toBeDefined();
+	return import(/* webpackMode: "eager" */ "./eager").then(function (eager) {
+		expect(eager.default).toBe("eager");
+	});
+});
+
+it("should not bundle the module of weak mode", function () {
+	return import(/* webpackMode: "weak" */ "./weak").then(
+		function () {
+			throw new Error("should not load the weak module");
+		},
+		function (err) {
+			expect(err.message).toMatch(/not available/);
+		}
+	);
+});
+
+it("should keep the import of webpackIgnore as is", function () {
+	function load() {
+		return import(/* webpackIgnore: true */ "./ignored.js");
+	}
+	expect(load.toString()).toContain("./ignored.js");
+});
+
+it("should filter the context modules by webpackInclude and webpackExclude", function () {
+	function load(name) {
+		return import(
+			/* webpackInclude: /\.js$/, webpackExclude: /excluded/ */ "./dir/" + name
+		);
+	}
+	return Promise.all([
+		load("a.js").then(function (a) {
+			expect(a.default).toBe("a");
+		}),
+		load("excluded.js").then(
+			function () {
+				throw new Error("should not include the excluded module");
+			},
+			function (err) {
+				expect(err.message).toMatch(/Cannot find module/);
+			}
+		),
+		load("b.json").then(
+			function () {
+				throw new Error("should not include the module not matched");
+			},
+			function (err) {
+				expect(err.message).toMatch(/Cannot find module/);
+			}
+		)
+	]);
+});
@@ -0,0 +1,6 @@
+it("should allow to run a synchronous WebAssembly module with imports", function () {
+	return import("./module-with-wasm").then(function (wasm) {
+		const result = wasm.addNumber(3);
+		expect(result).toEqual(11);
+	});
+});
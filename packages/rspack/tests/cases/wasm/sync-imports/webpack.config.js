@@ -0,0 +1,17 @@
+module.exports = {
+	output: {
+		wasmLoading: "async-node"
+	},
+	module: {
+		rules: [
+			{
+				test: /\.wat$/,
+				use: "wast-loader",
+				type: "webassembly/sync"
+			}
+		]
+	},
+	experiments: {
+		syncWebAssembly: true
+	}
+};
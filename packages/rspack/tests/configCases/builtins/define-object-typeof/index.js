@@ -0,0 +1,35 @@
+it("should expand the object values into member expressions", () => {
+	expect(OBJ.a.b).toBe(1);
+	expect(OBJ.a["c-d"]).toBe("cd");
+	expect(OBJ.fn()).toBe(2);
+	expect(OBJ.regexp.test("abc")).toBe(true);
+	expect(OBJ.a).toEqual({ b: 1, "c-d": "cd" });
+});
+
+it("should evaluate typeof of the definitions", () => {
+	expect(typeof OBJ).toBe("object");
+	expect(typeof OBJ.a.b).toBe("number");
+	expect(typeof OBJ.fn).toBe("function");
+	expect(typeof process).toBe("object");
+	expect(typeof process.env).toBe("object");
+	expect(typeof process.env.NODE_ENV).toBe("string");
+	expect(typeof TYPED_STRING).toBe("string");
+	expect(typeof UNKNOWN).toBe("undefined");
+});
+
+it("should remove the dead branches of the evaluated conditions", () => {
+	if (typeof OBJ === "undefined") {
+		require("fail");
+	}
+	if (process.env.NODE_ENV !== "test") {
+		require("fail");
+	}
+	expect(typeof OBJ === "object" ? OBJ.a.b : require("fail")).toBe(1);
+});
+
+it("should replace the runtime values", () => {
+	expect(RUNTIME).toBe("runtime");
+	expect(typeof RUNTIME).toBe("string");
+	expect(NESTED.value).toBe(1);
+	expect(NESTED.runtime).toBe(2);
+});
@@ -0,0 +1,31 @@
+const { DefinePlugin } = require("../../../../");
+
+module.exports = {
+	entry: {
+		main: ["./index.js"]
+	},
+	builtins: {
+		define: {
+			OBJ: {
+				a: {
+					b: 1,
+					"c-d": '"cd"'
+				},
+				fn: () => 2,
+				regexp: /abc/
+			},
+			"process.env.NODE_ENV": '"test"',
+			TYPED_STRING: '"string"',
+			UNKNOWN: "globalThis.unknown"
+		}
+	},
+	plugins: [
+		new DefinePlugin({
+			RUNTIME: DefinePlugin.runtimeValue(() => JSON.stringify("runtime"), true),
+			NESTED: {
+				value: 1,
+				runtime: DefinePlugin.runtimeValue(() => "2")
+			}
+		})
+	]
+};
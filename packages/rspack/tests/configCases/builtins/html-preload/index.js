@@ -0,0 +1,14 @@
+const fs = require("fs");
+const path = require("path");
+
+it("html preload", () => {
+	import(/* webpackChunkName: "preloaded", webpackPreload: true */ "./preloaded");
+	import(/* webpackChunkName: "lazy" */ "./lazy");
+
+	const htmlPath = path.join(__dirname, "./index.html");
+	const htmlContent = fs.readFileSync(htmlPath, "utf-8");
+	expect(htmlContent).toMatch(
+		/<link href="[^"]*preloaded\.js" rel="preload" as="script"/
+	);
+	expect(htmlContent).not.toContain("lazy.js");
+});
@@ -0,0 +1,12 @@
+module.exports = {
+	output: {
+		chunkFilename: "[name].js"
+	},
+	builtins: {
+		html: [
+			{
+				preload: true
+			}
+		]
+	}
+};
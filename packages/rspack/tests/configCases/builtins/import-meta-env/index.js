@@ -0,0 +1,30 @@
+it("should replace import.meta.env", () => {
+	expect(import.meta.env).toEqual({
+		MODE: "production",
+		DEV: false,
+		PROD: true,
+		BASE_URL: "/",
+		COUNT: 0,
+		EMPTY: undefined
+	});
+	expect(import.meta.env.MODE).toBe("production");
+	expect(import.meta.env["BASE_URL"]).toBe("/");
+	expect(import.meta.env.UNKNOWN).toBe(undefined);
+});
+
+it("should evaluate typeof import.meta.env", () => {
+	expect(typeof import.meta.env).toBe("object");
+	expect(typeof import.meta.env.MODE).toBe("string");
+	expect(typeof import.meta.env.DEV).toBe("boolean");
+	expect(typeof import.meta.env.COUNT).toBe("number");
+	expect(typeof import.meta.env.EMPTY).toBe("undefined");
+	expect(typeof import.meta.env.UNKNOWN).toBe("undefined");
+});
+
+it("should fold the logical expressions of import.meta.env", () => {
+	expect(import.meta.env && import.meta.env.MODE).toBe("production");
+	expect(import.meta.env.DEV && "dev").toBe(false);
+	expect(import.meta.env.PROD && "prod").toBe("prod");
+	expect(import.meta.env.COUNT || 10).toBe(10);
+	expect(import.meta.env.UNKNOWN || "fallback").toBe("fallback");
+});
@@ -0,0 +1,15 @@
+module.exports = {
+	entry: {
+		main: ["./index.js"]
+	},
+	builtins: {
+		importMetaEnv: {
+			MODE: "production",
+			DEV: false,
+			PROD: true,
+			BASE_URL: "/",
+			COUNT: 0,
+			EMPTY: undefined
+		}
+	}
+};
@@ -0,0 +1,3 @@
+export const Buffer = {
+	from: value => `buffer:${value}`
+};
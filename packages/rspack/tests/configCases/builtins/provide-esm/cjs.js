@@ -0,0 +1,4 @@
+module.exports = {
+	name: "cjs",
+	"3-f": "fff"
+};
@@ -0,0 +1,34 @@
+import * as consumer from "./consumer";
+import strict from "./strict.mjs";
+
+it("should provide the namespace of a module", function () {
+	expect(lib.default).toBe("lib");
+	expect(lib.debounce).toBeTypeOf("function");
+});
+
+it("should provide a member of a module", function () {
+	expect(debounce(1)).toBe(1);
+	expect(libDefault).toBe("lib");
+	expect(nestedValue).toBe("nested");
+	expect(dashed).toBe("fff");
+});
+
+it("should provide a namespaced identifier", function () {
+	expect(Buffer.from("a")).toBe("buffer:a");
+});
+
+it("should provide the exports of a CommonJS module", function () {
+	expect(cjs.name).toBe("cjs");
+	expect(strict()).toBe("cjs");
+});
+
+it("should keep the exports type of harmony modules", function () {
+	expect(consumer.value).toBe("debounced");
+	expect(consumer.__esModule).toBe(true);
+	expect(Object.keys(consumer)).toEqual(["value"]);
+});
+
+it("should not provide declared identifiers", function () {
+	const debounce = "local";
+	expect(debounce).toBe("local");
+});
@@ -0,0 +1,3 @@
+export const debounce = fn => fn;
+export const nested = { value: "nested" };
+export default "lib";
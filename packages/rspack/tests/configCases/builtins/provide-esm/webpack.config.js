@@ -0,0 +1,16 @@
+module.exports = {
+	entry: {
+		main: ["./index.js"]
+	},
+	builtins: {
+		provide: {
+			lib: "./lib",
+			debounce: ["./lib", "debounce"],
+			libDefault: ["./lib", "default"],
+			nestedValue: ["./lib", "nested", "value"],
+			"Buffer.from": ["./buffer", "Buffer", "from"],
+			cjs: "./cjs",
+			dashed: ["./cjs", "3-f"]
+		}
+	}
+};
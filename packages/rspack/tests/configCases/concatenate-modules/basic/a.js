@@ -0,0 +1,9 @@
+const value = "a";
+export let counter = 0;
+export function increment() {
+	counter++;
+}
+export default function () {
+	return value;
+}
+export { value };
@@ -0,0 +1,4 @@
+const value = "b";
+export const obj = { value };
+export default value;
+export * from "./c";
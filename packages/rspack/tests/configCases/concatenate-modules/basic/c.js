@@ -0,0 +1 @@
+export const fromC = "c";
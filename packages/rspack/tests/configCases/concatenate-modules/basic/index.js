@@ -0,0 +1,25 @@
+import getA, { value, counter, increment } from "./a";
+import b, { obj, fromC } from "./b";
+import * as ns from "./b";
+
+it("should keep the bindings of concatenated modules", () => {
+	const value2 = "index";
+	expect(getA()).toBe("a");
+	expect(value).toBe("a");
+	expect(value2).toBe("index");
+	expect(b).toBe("b");
+	expect(obj).toEqual({ value: "b" });
+	expect(fromC).toBe("c");
+});
+
+it("should keep live bindings", () => {
+	expect(counter).toBe(0);
+	increment();
+	expect(counter).toBe(1);
+});
+
+it("should create the namespace object", () => {
+	expect(ns.default).toBe("b");
+	expect(ns.fromC).toBe("c");
+	expect(Object.keys(ns).sort()).toEqual(["default", "fromC", "obj"]);
+});
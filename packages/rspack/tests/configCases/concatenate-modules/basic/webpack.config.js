@@ -0,0 +1,7 @@
+/**@type {import('@rspack/cli').Configuration}*/
+module.exports = {
+	context: __dirname,
+	optimization: {
+		concatenateModules: true
+	}
+};
@@ -0,0 +1,32 @@
+it("should expose modules from the container", async () => {
+	const container = __non_webpack_require__("./container-file.js");
+	expect(typeof container.get).toBe("function");
+	expect(typeof container.init).toBe("function");
+	container.init({});
+
+	const testFactory = await container.get("./test");
+	expect(typeof testFactory).toBe("function");
+	const test = testFactory();
+	expect(test.default).toBe("test");
+	expect(test.other).toBe("other");
+
+	const test2 = (await container.get("./test2"))();
+	expect(test2.default).toBe("test2 initialized");
+
+	const main = (await container.get("."))();
+	expect(main.default).toBe("main");
+});
+
+it("should reject modules which are not exposed", async () => {
+	const container = __non_webpack_require__("./container-file.js");
+	await expect(container.get("./missing")).rejects.toThrow(
+		'Module "./missing" does not exist in container.'
+	);
+});
+
+it("should throw when initialized with a different share scope", () => {
+	const container = __non_webpack_require__("./container-file.js");
+	expect(() => container.init({})).toThrow(
+		"Container initialization failed as it has already been initialized with a different share scope"
+	);
+});
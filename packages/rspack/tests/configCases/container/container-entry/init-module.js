@@ -0,0 +1 @@
+global.__container_init_module__ = "initialized";
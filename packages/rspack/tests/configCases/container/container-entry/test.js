@@ -0,0 +1,2 @@
+export default "test";
+export const other = "other";
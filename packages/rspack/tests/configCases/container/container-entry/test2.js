@@ -0,0 +1 @@
+export default "test2 " + global.__container_init_module__;
@@ -0,0 +1,21 @@
+module.exports = {
+	builtins: {
+		container: {
+			name: "container",
+			filename: "container-file.js",
+			library: {
+				type: "commonjs-module"
+			},
+			exposes: [
+				"./test",
+				{
+					"./test2": ["./init-module", "./test2"],
+					".": {
+						import: "./main",
+						name: "main-exposed"
+					}
+				}
+			]
+		}
+	}
+};
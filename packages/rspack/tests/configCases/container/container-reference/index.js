@@ -0,0 +1,7 @@
+it("should load modules from the remote container", async () => {
+	global.__container_reference_self__ = __non_webpack_require__(
+		"./container-file.js"
+	);
+	const { default: test } = await import("self/test");
+	expect(test).toBe("test");
+});
@@ -0,0 +1,20 @@
+module.exports = {
+	builtins: {
+		container: {
+			name: "container",
+			filename: "container-file.js",
+			library: {
+				type: "commonjs-module"
+			},
+			exposes: {
+				"./test": "./test"
+			}
+		},
+		containerReference: {
+			remoteType: "var",
+			remotes: {
+				self: "global.__container_reference_self__"
+			}
+		}
+	}
+};
@@ -0,0 +1,5 @@
+it("should load the remote container from the first working fallback", async () => {
+	__non_webpack_require__("./container-file.js");
+	const { default: test } = await import("remote/test");
+	expect(test).toBe("test");
+});
@@ -0,0 +1,29 @@
+module.exports = {
+	target: "web",
+	output: {
+		publicPath: "https://test.cases/path/"
+	},
+	builtins: {
+		container: {
+			name: "container",
+			filename: "container-file.js",
+			library: {
+				type: "window",
+				name: "__container_fallback__"
+			},
+			exposes: {
+				"./test": "./test"
+			}
+		},
+		containerReference: {
+			remoteType: "var",
+			remotes: {
+				remote: [
+					"window.__missing_container__.first",
+					"window.__missing_container__.second",
+					"window.__container_fallback__"
+				]
+			}
+		}
+	}
+};
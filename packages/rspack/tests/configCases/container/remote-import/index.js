@@ -0,0 +1,5 @@
+it("should load the remote container with import chunk loading", async () => {
+	await import(/* webpackIgnore: true */ "./container-file.js");
+	const { default: test } = await import("remote/test");
+	expect(test).toBe("test");
+});
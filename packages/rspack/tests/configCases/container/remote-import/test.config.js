@@ -0,0 +1,6 @@
+module.exports = {
+	moduleScope(scope) {
+		// the container assigns itself to `self` like a container of another build
+		scope.self = scope;
+	}
+};
@@ -0,0 +1,29 @@
+module.exports = {
+	output: {
+		filename: "[name].js",
+		chunkFormat: "module",
+		chunkLoading: "import"
+	},
+	experiments: {
+		outputModule: true
+	},
+	builtins: {
+		container: {
+			name: "container",
+			filename: "container-file.js",
+			library: {
+				type: "self",
+				name: "__container_import__"
+			},
+			exposes: {
+				"./test": "./test"
+			}
+		},
+		containerReference: {
+			remoteType: "var",
+			remotes: {
+				remote: "self.__container_import__"
+			}
+		}
+	}
+};
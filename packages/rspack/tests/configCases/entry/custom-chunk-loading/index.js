@@ -0,0 +1,7 @@
+const fs = require("fs");
+
+it("should build an entry with a chunk loading type provided by plugins", async () => {
+	const code = await fs.promises.readFile(__filename, "utf-8");
+	expect(code.includes("__webpack_require__.f.readFileVm")).toBe(false);
+	expect(code.includes("__webpack_require__.f.require")).toBe(false);
+});
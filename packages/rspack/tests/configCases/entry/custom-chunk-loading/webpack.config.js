@@ -0,0 +1,11 @@
+module.exports = {
+	entry: {
+		main: {
+			import: "./index.js",
+			chunkLoading: "custom-loader"
+		}
+	},
+	output: {
+		enabledChunkLoadingTypes: ["custom-loader"]
+	}
+};
@@ -0,0 +1,16 @@
+const SCRIPT_URL = "https://example.com/missing-lib.js";
+
+it("should reject with a ScriptExternalLoadError when the script does not set the global", async function () {
+	let error;
+	try {
+		await import("./module");
+	} catch (e) {
+		error = e;
+	}
+	expect(error).toBeDefined();
+	expect(error.name).toBe("ScriptExternalLoadError");
+	expect(error.type).toBe("missing");
+	expect(error.request).toBe(SCRIPT_URL);
+	expect(error.message).toBe(`Loading script failed.\n(missing: ${SCRIPT_URL})`);
+	expect(window.scriptExternalLib).toBeUndefined();
+});
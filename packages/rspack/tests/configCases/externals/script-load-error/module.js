@@ -0,0 +1,3 @@
+import lib from "lib";
+
+export default lib;
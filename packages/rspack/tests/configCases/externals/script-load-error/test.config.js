@@ -0,0 +1,19 @@
+const PUBLIC_PATH = "https://test.cases/path/";
+
+module.exports = {
+	moduleScope(scope) {
+		// execute the chunks appended as script tags by the jsonp chunk loading,
+		// the external script loads without setting its global
+		if (!scope.require) return;
+		const { head } = scope.window.document;
+		if (head._executeScripts) return;
+		head._executeScripts = true;
+		const appendChild = head.appendChild.bind(head);
+		head.appendChild = node => {
+			if (node._type === "script" && node.src.startsWith(PUBLIC_PATH)) {
+				scope.require("./" + node.src.slice(PUBLIC_PATH.length));
+			}
+			appendChild(node);
+		};
+	}
+};
@@ -0,0 +1,10 @@
+module.exports = {
+	target: "web",
+	output: {
+		publicPath: "https://test.cases/path/"
+	},
+	externalsType: "script",
+	externals: {
+		lib: "scriptExternalLib@https://example.com/missing-lib.js"
+	}
+};
@@ -0,0 +1,6 @@
+import lib from "lib";
+
+it("should load the script and resolve to the global it sets", function () {
+	expect(lib).toEqual({ value: 42 });
+	expect(lib).toBe(window.scriptExternalLib);
+});
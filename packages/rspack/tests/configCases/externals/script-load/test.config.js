@@ -0,0 +1,18 @@
+const SCRIPT_URL = "https://example.com/script-external-lib.js";
+
+module.exports = {
+	moduleScope(scope) {
+		// run the external script when it is appended as script tag by `__webpack_require__.l`
+		const { window } = scope;
+		const { head } = window.document;
+		if (head._executeScripts) return;
+		head._executeScripts = true;
+		const appendChild = head.appendChild.bind(head);
+		head.appendChild = node => {
+			if (node._type === "script" && node.src === SCRIPT_URL) {
+				window.scriptExternalLib = { value: 42 };
+			}
+			appendChild(node);
+		};
+	}
+};
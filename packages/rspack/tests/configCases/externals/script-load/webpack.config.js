@@ -0,0 +1,7 @@
+module.exports = {
+	target: "web",
+	externalsType: "script",
+	externals: {
+		lib: "scriptExternalLib@https://example.com/script-external-lib.js"
+	}
+};
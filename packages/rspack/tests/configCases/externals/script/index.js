@@ -0,0 +1,9 @@
+import "./inject";
+
+import { value } from "nested";
+import lib from "lib";
+
+it("should resolve script externals to the global once loaded", function () {
+	expect(value).toBe(42);
+	expect(lib).toBe(global.scriptExternalLib);
+});
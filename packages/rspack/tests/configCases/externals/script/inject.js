@@ -0,0 +1 @@
+global.scriptExternalLib = { nested: { value: 42 } };
@@ -0,0 +1,11 @@
+module.exports = {
+	externalsType: "script",
+	externals: {
+		nested: [
+			"https://example.com/script-external-lib.js",
+			"scriptExternalLib",
+			"nested"
+		],
+		lib: "scriptExternalLib@https://example.com/script-external-lib.js"
+	}
+};
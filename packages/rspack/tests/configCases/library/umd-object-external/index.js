@@ -0,0 +1,13 @@
+import fs from "external-fs";
+
+const source = require("fs").readFileSync(__filename, "utf-8");
+
+it("should resolve the external request of the library type", function () {
+	expect(fs).toBe(require("fs"));
+});
+
+it("should render the object form external for each library type", function () {
+	expect(source).toContain("require('fs')");
+	expect(source).toContain("define([\"external-fs-amd\"]");
+	expect(source).toContain("root['ExternalFs']['default']");
+});
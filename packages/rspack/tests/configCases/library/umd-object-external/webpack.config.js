@@ -0,0 +1,15 @@
+/** @type {import("../../../../dist").Configuration} */
+module.exports = {
+	output: {
+		libraryTarget: "umd",
+		library: "objectExternalLibrary"
+	},
+	externals: {
+		"external-fs": {
+			root: ["ExternalFs", "default"],
+			commonjs: "fs",
+			commonjs2: "fs",
+			amd: "external-fs-amd"
+		}
+	}
+};
@@ -0,0 +1,3 @@
+define(["./dep", "exports"], function (dep, exports) {
+	exports.name = "array:" + dep.name;
+});
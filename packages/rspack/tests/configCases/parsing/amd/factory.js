@@ -0,0 +1,4 @@
+define(function (require, exports, module) {
+	var dep = require("./dep");
+	module.exports = { name: "factory:" + dep.name };
+});
@@ -0,0 +1,30 @@
+it("should define a module with an object", function () {
+	expect(require("./object").name).toBe("object");
+});
+
+it("should define a module with a factory", function () {
+	expect(require("./factory").name).toBe("factory:dep");
+});
+
+it("should define a module with dependencies", function () {
+	expect(require("./array").name).toBe("array:dep");
+	expect(require("./named").name).toBe("named:dep");
+});
+
+it("should define a umd module", function () {
+	expect(require("./umd").name).toBe("umd:dep");
+});
+
+it("should evaluate define", function () {
+	expect(typeof define).toBe("function");
+	expect(typeof define.amd).toBe("object");
+	expect(define.amd).toEqual({});
+});
+
+it("should load modules with AMD require", function (done) {
+	require(["./dep", "./object"], function (dep, object) {
+		expect(dep.name).toBe("dep");
+		expect(object.name).toBe("object");
+		done();
+	}, done);
+});
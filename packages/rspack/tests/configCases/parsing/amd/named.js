@@ -0,0 +1,3 @@
+define("named", ["./dep"], function (dep) {
+	return { name: "named:" + dep.name };
+});
@@ -0,0 +1,9 @@
+(function (root, factory) {
+	if (typeof define === "function" && define.amd) {
+		define(["./dep"], factory);
+	} else {
+		root.umd = factory(root.dep);
+	}
+})(this, function (dep) {
+	return { name: "umd:" + dep.name };
+});
@@ -0,0 +1,10 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	module: {
+		parser: {
+			javascript: {
+				amd: true
+			}
+		}
+	}
+};
@@ -0,0 +1,5 @@
+module.exports = [
+	[
+		/Should not import the named export 'name' from JSON module '\.\/data\.json' \(only default export is available\)/
+	]
+];
@@ -0,0 +1,5 @@
+import { name } from "./data.json" with { type: "json" };
+
+it("should error", function () {
+	throw new Error("should not be executed");
+});
@@ -0,0 +1,13 @@
+/**
+ * @type {import('@rspack/core').RspackOptions}
+ */
+module.exports = {
+	module: {
+		rules: [
+			{
+				test: /index\.js$/,
+				type: "javascript/esm"
+			}
+		]
+	}
+};
@@ -0,0 +1,5 @@
+module.exports = [
+	[
+		/Import attribute type 'css' of '\.\/data\.json' is not supported, only 'json' is supported/
+	]
+];
@@ -0,0 +1,5 @@
+import data from "./data.json" with { type: "css" };
+
+it("should error", function () {
+	throw new Error("should not be executed");
+});
@@ -0,0 +1,21 @@
+import data from "./data.json" assert { type: "json" };
+import dataWith from "./data.json" with { type: "json" };
+import text from "./data.txt" with { type: "json" };
+
+it("should import json modules with import assertions", () => {
+	expect(data.name).toBe("data");
+	expect(data.value).toBe(42);
+});
+
+it("should import json modules with import attributes", () => {
+	expect(dataWith.name).toBe("data");
+});
+
+it("should match module rules against import attributes", () => {
+	expect(text.name).toBe("text");
+});
+
+it("should import json modules with dynamic import assertions", async () => {
+	const mod = await import("./data.json", { assert: { type: "json" } });
+	expect(mod.default.value).toBe(42);
+});
@@ -0,0 +1,15 @@
+/**
+ * @type {import('@rspack/core').RspackOptions}
+ */
+module.exports = {
+	context: __dirname,
+	module: {
+		rules: [
+			{
+				test: /\.txt$/,
+				with: { type: "json" },
+				type: "json"
+			}
+		]
+	}
+};
@@ -0,0 +1,12 @@
+import value from "package";
+
+it("should consume the eager shared module synchronously", () => {
+	expect(value).toBe("package");
+});
+
+it("should bundle the eager shared module into the initial chunk", () => {
+	const shared = __STATS__.modules.find(m =>
+		m.name.includes("node_modules/package/index.js")
+	);
+	expect(shared.chunks).toEqual(["main"]);
+});
@@ -0,0 +1,5 @@
+it("should throw when the shared module does not satisfy the strict version", async () => {
+	await expect(import("package")).rejects.toThrow(
+		/^Unsatisfied version 1\.2\.3 from .+ of shared singleton module package \(required \^2\.0\.0\)$/
+	);
+});
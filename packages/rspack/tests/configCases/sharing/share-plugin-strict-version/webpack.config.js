@@ -0,0 +1,13 @@
+module.exports = {
+	builtins: {
+		share: {
+			shared: {
+				package: {
+					singleton: true,
+					strictVersion: true,
+					requiredVersion: "^2.0.0"
+				}
+			}
+		}
+	}
+};
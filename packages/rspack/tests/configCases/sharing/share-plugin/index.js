@@ -0,0 +1,4 @@
+it("should load the shared module from the share scope", async () => {
+	const { default: value } = await import("package");
+	expect(value).toBe("package");
+});
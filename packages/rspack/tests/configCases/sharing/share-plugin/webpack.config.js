@@ -0,0 +1,11 @@
+module.exports = {
+	builtins: {
+		share: {
+			shared: {
+				package: {
+					singleton: true
+				}
+			}
+		}
+	}
+};
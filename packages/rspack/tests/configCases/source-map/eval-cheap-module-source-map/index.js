@@ -0,0 +1,23 @@
+import { answer } from "./answer.ts";
+
+it("should map original lines of loaders if cheap module option is used", async () => {
+	const fs = require("fs");
+	const sourceMap = require("source-map");
+	expect(answer.value).toBe(42);
+	const source = fs.readFileSync(__filename, "utf-8");
+	const base64 =
+		/sourceMappingURL\s*=\s*data:application\/json;charset=utf-8;base64,([A-Za-z0-9+/=]*)\\n\/\/# sourceURL=webpack-internal:\/\/\/\.\/answer\.ts/.exec(
+			source
+		)[1];
+	const map = JSON.parse(Buffer.from(base64, "base64").toString("utf-8"));
+	expect(map.sources).toContain("./answer.ts");
+	expect(map.sourcesContent[0]).toContain("interface Answer");
+	const consumer = await new sourceMap.SourceMapConsumer(map);
+	const lines = [];
+	consumer.eachMapping(m => {
+		expect(m.originalColumn).toBe(0);
+		lines.push(m.originalLine);
+	});
+	// `export const answer` is the 5th line of answer.ts
+	expect(lines).toContain(5);
+});
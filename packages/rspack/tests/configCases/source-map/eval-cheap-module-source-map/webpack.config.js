@@ -0,0 +1,24 @@
+module.exports = {
+	module: {
+		rules: [
+			{
+				test: /\.ts$/,
+				use: {
+					loader: "builtin:swc-loader",
+					options: {
+						sourceMap: true,
+						jsc: {
+							parser: {
+								syntax: "typescript"
+							}
+						}
+					}
+				},
+				type: "javascript/auto"
+			}
+		]
+	},
+	devtool: "eval-cheap-module-source-map",
+	externals: ["source-map"],
+	externalsType: "commonjs"
+};
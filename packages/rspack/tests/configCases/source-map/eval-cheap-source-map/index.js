@@ -0,0 +1,19 @@
+import { answer } from "./answer.ts";
+
+it("should only map transformed lines if cheap option is used", async () => {
+	const fs = require("fs");
+	const sourceMap = require("source-map");
+	expect(answer.value).toBe(42);
+	const source = fs.readFileSync(__filename, "utf-8");
+	const base64 =
+		/sourceMappingURL\s*=\s*data:application\/json;charset=utf-8;base64,([A-Za-z0-9+/=]*)\\n\/\/# sourceURL=webpack-internal:\/\/\/\.\/answer\.ts/.exec(
+			source
+		)[1];
+	const map = JSON.parse(Buffer.from(base64, "base64").toString("utf-8"));
+	expect(map.sources).toContain("./answer.ts");
+	expect(map.sourcesContent[0]).not.toContain("interface Answer");
+	const consumer = await new sourceMap.SourceMapConsumer(map);
+	consumer.eachMapping(m => {
+		expect(m.originalColumn).toBe(0);
+	});
+});
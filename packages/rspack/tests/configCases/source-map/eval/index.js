@@ -0,0 +1,10 @@
+it("should wrap modules in eval with sourceURL", () => {
+	const fs = require("fs");
+	const source = fs.readFileSync(__filename, "utf-8");
+	expect(source).toMatch(
+		/eval\("[^\n]*\\n\/\/# sourceURL=webpack:\/\/[^\n]*\/\.\/index\.js\\n"\);/
+	);
+	// the text is split so it doesn't appear in the source of this module
+	expect(source).not.toContain("sourceMapping" + "URL=data:");
+	expect(fs.existsSync(__filename + ".map")).toBe(false);
+});
@@ -0,0 +1,6 @@
+module.exports = [
+	[
+		/WebAssembly module is included in initial chunk/,
+		/\* \.\/index\.js --> \.\/module-with-wasm\.js --> \.\/wasm\.wat/
+	]
+];
@@ -0,0 +1,5 @@
+import { addNumber } from "./module-with-wasm";
+
+it("should error", function () {
+	throw new Error("should not be executed");
+});
@@ -0,0 +1 @@
+export { addNumber } from "./wasm.wat";
@@ -0,0 +1 @@
+export default "chunk1-a";
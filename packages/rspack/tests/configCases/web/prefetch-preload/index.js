@@ -0,0 +1,46 @@
+it("should prefetch and preload child chunks on chunk load", () => {
+	let link, script;
+
+	expect(document.head._children).toHaveLength(1);
+
+	// prefetch of the entry chunk's children after startup
+	link = document.head._children[0];
+	expect(link._type).toBe("link");
+	expect(link.rel).toBe("prefetch");
+	expect(link.as).toBe("script");
+	expect(link.href).toBe("https://example.com/public/path/chunk1.js");
+
+	const promise = import(
+		/* webpackChunkName: "chunk1", webpackPrefetch: true */ "./chunk1"
+	);
+
+	expect(document.head._children).toHaveLength(3);
+
+	script = document.head._children[1];
+	expect(script._type).toBe("script");
+	expect(script.src).toBe("https://example.com/public/path/chunk1.js");
+
+	// preload of chunk1's children along with chunk1
+	link = document.head._children[2];
+	expect(link._type).toBe("link");
+	expect(link.rel).toBe("preload");
+	expect(link.as).toBe("script");
+	expect(link.href).toBe("https://example.com/public/path/chunk1-b.js");
+
+	__non_webpack_require__("./chunk1.js");
+
+	return promise.then(() => {
+		expect(document.head._children).toHaveLength(5);
+
+		// prefetch of chunk1's children after chunk1 is loaded, higher order first
+		link = document.head._children[3];
+		expect(link._type).toBe("link");
+		expect(link.rel).toBe("prefetch");
+		expect(link.href).toBe("https://example.com/public/path/chunk1-c.js");
+
+		link = document.head._children[4];
+		expect(link._type).toBe("link");
+		expect(link.rel).toBe("prefetch");
+		expect(link.href).toBe("https://example.com/public/path/chunk1-a.js");
+	});
+});
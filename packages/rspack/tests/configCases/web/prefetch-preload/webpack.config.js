@@ -0,0 +1,7 @@
+module.exports = {
+	target: "web",
+	output: {
+		publicPath: "https://example.com/public/path/",
+		chunkFilename: "[name].js"
+	}
+};